@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vector_traits::orient2d;
+use vector_traits_fuzz::{decode_points, orient2d_exact};
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(p) = decode_points(data, 3) {
+        assert_eq!(
+            orient2d(p[0], p[1], p[2]),
+            orient2d_exact(p[0], p[1], p[2]),
+            "{:?}",
+            p
+        );
+    }
+});
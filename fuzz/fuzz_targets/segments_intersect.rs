@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vector_traits::segments_intersect;
+use vector_traits_fuzz::{decode_points, segments_intersect_exact};
+
+fuzz_target!(|data: &[u8]| {
+    if let Some(p) = decode_points(data, 4) {
+        assert_eq!(
+            segments_intersect(p[0], p[1], p[2], p[3]),
+            segments_intersect_exact(p[0], p[1], p[2], p[3]),
+            "{:?}",
+            p
+        );
+    }
+});
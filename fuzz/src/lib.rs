@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Exact rational reference implementations of the predicates, and the input decoding shared by
+//! the fuzz targets.
+
+use num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, Zero};
+use vector_traits::{glam::DVec2, IntersectionKind, Orientation};
+
+/// Coordinates are restricted to this magnitude, so that no intermediate value of the floating
+/// point predicates can overflow or underflow.
+const LIMIT: f64 = 1.0e100;
+
+#[derive(Clone)]
+struct Point {
+    x: BigRational,
+    y: BigRational,
+}
+
+impl Point {
+    fn new(v: DVec2) -> Self {
+        Self {
+            x: BigRational::from_float(v.x).unwrap(),
+            y: BigRational::from_float(v.y).unwrap(),
+        }
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        Self {
+            x: &self.x - &rhs.x,
+            y: &self.y - &rhs.y,
+        }
+    }
+
+    fn cross(&self, rhs: &Self) -> BigRational {
+        &self.x * &rhs.y - &self.y * &rhs.x
+    }
+
+    fn dot(&self, rhs: &Self) -> BigRational {
+        &self.x * &rhs.x + &self.y * &rhs.y
+    }
+
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero()
+    }
+}
+
+/// Decodes `count` (at least three) points from the fuzzer input. Non-finite and out of range values
+/// are mapped into range. The first byte selects an optional degeneracy: the third point is snapped to (the
+/// float closest to) a point on the line through the first two, or duplicates one of them.
+pub fn decode_points(data: &[u8], count: usize) -> Option<Vec<DVec2>> {
+    let (&mode, data) = data.split_first()?;
+    if data.len() < count * 16 {
+        return None;
+    }
+    let scalar = |i: usize| {
+        let v = f64::from_le_bytes(data[i * 8..i * 8 + 8].try_into().unwrap());
+        if !v.is_finite() {
+            0.0
+        } else if v.abs() > LIMIT {
+            v % LIMIT
+        } else if v != 0.0 && v.abs() < 1.0 / LIMIT {
+            0.0
+        } else {
+            v
+        }
+    };
+    let mut points: Vec<DVec2> = (0..count)
+        .map(|i| DVec2::new(scalar(2 * i), scalar(2 * i + 1)))
+        .collect();
+    match mode % 4 {
+        1 => {
+            let t = f64::from(mode) / 255.0 * 3.0 - 1.0;
+            points[2] = points[0] + (points[1] - points[0]) * t;
+        }
+        2 => points[2] = points[mode as usize % 2],
+        _ => {}
+    }
+    Some(points)
+}
+
+/// The exact orientation of `a`, `b` and `c`.
+pub fn orient2d_exact(a: DVec2, b: DVec2, c: DVec2) -> Orientation {
+    let (a, b, c) = (Point::new(a), Point::new(b), Point::new(c));
+    let det = b.sub(&a).cross(&c.sub(&a));
+    if det.is_positive() {
+        Orientation::CounterClockwise
+    } else if det.is_negative() {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+/// The exact classification of the intersection of `a1`-`a2` and `b1`-`b2`, computed from the
+/// parametric form of the segments rather than from orientations.
+pub fn segments_intersect_exact(a1: DVec2, a2: DVec2, b1: DVec2, b2: DVec2) -> IntersectionKind {
+    let (a1, a2, b1, b2) = (
+        Point::new(a1),
+        Point::new(a2),
+        Point::new(b1),
+        Point::new(b2),
+    );
+    let (da, db) = (a2.sub(&a1), b2.sub(&b1));
+    let zero = BigRational::zero();
+    let one = BigRational::from_integer(BigInt::from(1));
+    let denominator = da.cross(&db);
+
+    if !denominator.is_zero() {
+        let offset = b1.sub(&a1);
+        let t = offset.cross(&db) / &denominator;
+        let u = offset.cross(&da) / &denominator;
+        let inside = |p: &BigRational| *p >= zero && *p <= one;
+        let interior = |p: &BigRational| *p > zero && *p < one;
+        return if !inside(&t) || !inside(&u) {
+            IntersectionKind::None
+        } else if interior(&t) && interior(&u) {
+            IntersectionKind::Crossing
+        } else {
+            IntersectionKind::Touching
+        };
+    }
+
+    // parallel, or at least one of the segments is a point
+    let dir = if !da.is_zero() { da } else { db };
+    if dir.is_zero() {
+        return if a1.sub(&b1).is_zero() {
+            IntersectionKind::Touching
+        } else {
+            IntersectionKind::None
+        };
+    }
+    let on_line = |p: &Point| p.sub(&a1).cross(&dir).is_zero() && p.sub(&b1).cross(&dir).is_zero();
+    if ![&a1, &a2, &b1, &b2].into_iter().all(on_line) {
+        return IntersectionKind::None;
+    }
+    let param = |p: &Point| p.sub(&a1).dot(&dir);
+    let sorted = |p: BigRational, q: BigRational| if p <= q { (p, q) } else { (q, p) };
+    let (a_min, a_max) = sorted(param(&a1), param(&a2));
+    let (b_min, b_max) = sorted(param(&b1), param(&b2));
+    let lo = a_min.max(b_min);
+    let hi = a_max.min(b_max);
+    match lo.cmp(&hi) {
+        std::cmp::Ordering::Less => IntersectionKind::Overlapping,
+        std::cmp::Ordering::Equal => IntersectionKind::Touching,
+        std::cmp::Ordering::Greater => IntersectionKind::None,
+    }
+}
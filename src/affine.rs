@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `GenericAffine2` and `GenericAffine3`, placements of 2D and 3D geometry: a rotation, a uniform
+//! scale and a translation.
+//!
+//! `GenericAffine2` is implemented for `glam::Affine2` and `DAffine2`, for the cgmath `Decomposed`
+//! of a `Vector2` and a `Basis2`, for nalgebra `Affine2`, and for `AffineMatrix3`, a
+//! `GenericMatrix3` used as the affine map of its top two rows. The matrices themselves do not
+//! implement `GenericAffine2`, whose method names match those of `GenericMatrix3`.
+//! `GenericAffine3` is implemented for `glam::Affine3A` and `DAffine3`, which act on `Vec3` and
+//! `DVec3`, for the cgmath `Decomposed` of a `Vector3` and a `Quaternion`, and for nalgebra
+//! `Affine3`. cgmath `Decomposed` only has a uniform scale, so that is what the constructors take.
+
+use crate::{math, GenericMatrix3, GenericScalar, GenericVector2, GenericVector3, HasXYZ};
+use std::fmt::Debug;
+
+/// A 2D affine map.
+pub trait GenericAffine2: Copy + Debug + PartialEq + Sync + Send + 'static {
+    type Scalar: GenericScalar;
+    type Vector2: GenericVector2<Scalar = Self::Scalar>;
+
+    fn identity() -> Self;
+
+    /// Returns the map that scales by `scale`, then rotates counterclockwise by `angle` radians
+    /// and last translates by `translation`.
+    fn from_scale_angle_translation(
+        scale: Self::Scalar,
+        angle: Self::Scalar,
+        translation: Self::Vector2,
+    ) -> Self;
+
+    /// Returns the translation, the image of the origin.
+    fn translation(&self) -> Self::Vector2;
+
+    /// Applies the map to the point `p`.
+    fn transform_point2(&self, p: Self::Vector2) -> Self::Vector2;
+
+    /// Applies the map to the direction `v`, without the translation.
+    fn transform_vector2(&self, v: Self::Vector2) -> Self::Vector2;
+
+    /// Returns the inverse map, or `None` if the map is not invertible.
+    fn try_inverse(&self) -> Option<Self>;
+
+    /// Returns the map that applies `other` first and then `self`, like `self * other`.
+    fn compose(&self, other: &Self) -> Self;
+}
+
+/// A 3D affine map.
+pub trait GenericAffine3: Copy + Debug + PartialEq + Sync + Send + 'static {
+    type Scalar: GenericScalar;
+    type Vector3: GenericVector3<Scalar = Self::Scalar>;
+
+    fn identity() -> Self;
+
+    /// Returns the map that scales by `scale`, then rotates counterclockwise by `angle` radians
+    /// about the unit vector `axis` and last translates by `translation`.
+    fn from_scale_axis_angle_translation(
+        scale: Self::Scalar,
+        axis: Self::Vector3,
+        angle: Self::Scalar,
+        translation: Self::Vector3,
+    ) -> Self;
+
+    /// Returns the translation, the image of the origin.
+    fn translation(&self) -> Self::Vector3;
+
+    /// Applies the map to the point `p`.
+    fn transform_point3(&self, p: Self::Vector3) -> Self::Vector3;
+
+    /// Applies the map to the direction `v`, without the translation.
+    fn transform_vector3(&self, v: Self::Vector3) -> Self::Vector3;
+
+    /// Returns the inverse map, or `None` if the map is not invertible.
+    fn try_inverse(&self) -> Option<Self>;
+
+    /// Returns the map that applies `other` first and then `self`, like `self * other`.
+    fn compose(&self, other: &Self) -> Self;
+}
+
+/// A `GenericMatrix3` as a `GenericAffine2`, the affine map of the top two rows of the matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AffineMatrix3<M: GenericMatrix3>(pub M);
+
+impl<M: GenericMatrix3> GenericAffine2 for AffineMatrix3<M> {
+    type Scalar = M::Scalar;
+    type Vector2 = M::Vector2;
+
+    #[inline(always)]
+    fn identity() -> Self {
+        Self(M::identity())
+    }
+
+    #[inline(always)]
+    fn from_scale_angle_translation(
+        scale: Self::Scalar,
+        angle: Self::Scalar,
+        translation: Self::Vector2,
+    ) -> Self {
+        let (s, c) = (math::sin(angle) * scale, math::cos(angle) * scale);
+        let o = Self::Scalar::ZERO;
+        Self(M::from_cols(
+            M::Vector3::new_3d(c, s, o),
+            M::Vector3::new_3d(-s, c, o),
+            translation.to_3d(Self::Scalar::ONE),
+        ))
+    }
+
+    #[inline(always)]
+    fn translation(&self) -> Self::Vector2 {
+        self.0.col(2).to_2d()
+    }
+
+    #[inline(always)]
+    fn transform_point2(&self, p: Self::Vector2) -> Self::Vector2 {
+        self.0.transform_point2(p)
+    }
+
+    #[inline(always)]
+    fn transform_vector2(&self, v: Self::Vector2) -> Self::Vector2 {
+        self.0.transform_vector2(v)
+    }
+
+    #[inline(always)]
+    fn try_inverse(&self) -> Option<Self> {
+        self.0.try_inverse().map(Self)
+    }
+
+    #[inline(always)]
+    fn compose(&self, other: &Self) -> Self {
+        Self(self.0 * other.0)
+    }
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Angle normalization utilities, for heading and rotation code that has to handle wrap-around.
+//! All angles are in radians.
+
+use crate::GenericScalar;
+
+/// Returns `angle` wrapped into the range [0, tau).
+///
+/// Non-finite angles return NaN.
+#[inline(always)]
+pub fn normalize_angle_0_tau<S: GenericScalar>(angle: S) -> S {
+    let tau = S::TAU();
+    let r = angle % tau;
+    if r < S::ZERO {
+        let r = r + tau;
+        // a tiny negative remainder rounds up to tau, which is outside the range
+        if r < tau {
+            r
+        } else {
+            S::ZERO
+        }
+    } else {
+        r
+    }
+}
+
+/// Returns `angle` wrapped into the range (-pi, pi].
+///
+/// Non-finite angles return NaN.
+#[inline(always)]
+pub fn normalize_angle_pm_pi<S: GenericScalar>(angle: S) -> S {
+    let tau = S::TAU();
+    let r = angle % tau;
+    if r > S::PI() {
+        r - tau
+    } else if r <= -S::PI() {
+        r + tau
+    } else {
+        r
+    }
+}
+
+/// Returns the signed angle to rotate from `from` to `to` the shortest way, in the range (-pi, pi].
+///
+/// A positive result is a counterclockwise rotation.
+#[inline(always)]
+pub fn shortest_angle_delta<S: GenericScalar>(from: S, to: S) -> S {
+    normalize_angle_pm_pi(to - from)
+}
@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `AnyVector2` and `AnyVector3`, enums of the vectors of every enabled backend, for editors and
+//! tools that load data at runtime and only then know which backend it belongs to.
+//!
+//! The variants are `Glam(glam::Vec2)`, `GlamD(glam::DVec2)`, `Cgmath(cgmath::Vector2<f32>)` and
+//! so on, and every backend type converts with `From`. The variants have different scalar types,
+//! so the enums can not implement `GenericVector2`/`GenericVector3`. They implement the object
+//! safe `ObjectVector2`/`ObjectVector3` instead, by dispatching to the wrapped vector, and
+//! `as_object()` returns that vector as a trait object. The variants depend on the enabled
+//! features, so the enums are `#[non_exhaustive]`.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    ArrayVec2, ArrayVec3, DynScalar, DynVector2, DynVector3, HasXY, HasXYZ, ObjectVector2,
+    ObjectVector3, ScalarKind,
+};
+use std::any::Any;
+
+// Defines the enum, the `From` impls, `backend()` and `as_object()`.
+macro_rules! define_any_vector {
+    ($(#[$doc:meta])* $any_type:ident, $object_trait:ident,
+     $($(#[$cfg:meta])* $variant:ident($vec_type:ty) = $backend:literal),+ $(,)?) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[non_exhaustive]
+        pub enum $any_type {
+            $($(#[$cfg])* $variant($vec_type),)+
+        }
+
+        impl $any_type {
+            /// Returns the name of the backend, e.g. `"glam"`.
+            pub fn backend(&self) -> &'static str {
+                match self {
+                    $($(#[$cfg])* Self::$variant(_) => $backend,)+
+                }
+            }
+
+            /// Returns the wrapped vector as a trait object.
+            #[inline]
+            pub fn as_object(&self) -> &dyn $object_trait {
+                match self {
+                    $($(#[$cfg])* Self::$variant(v) => v,)+
+                }
+            }
+        }
+
+        $(
+            $(#[$cfg])*
+            impl From<$vec_type> for $any_type {
+                #[inline]
+                fn from(v: $vec_type) -> Self {
+                    Self::$variant(v)
+                }
+            }
+        )+
+    };
+}
+
+define_any_vector!(
+    /// A two-dimensional vector of any enabled backend.
+    AnyVector2,
+    ObjectVector2,
+    Array(ArrayVec2<f32>) = "array",
+    ArrayD(ArrayVec2<f64>) = "array",
+    #[cfg(feature = "glam")]
+    Glam(glam::Vec2) = "glam",
+    #[cfg(feature = "glam")]
+    GlamD(glam::DVec2) = "glam",
+    #[cfg(feature = "bevy_math")]
+    Bevy(bevy_math::Vec2) = "bevy_math",
+    #[cfg(feature = "bevy_math")]
+    BevyD(bevy_math::DVec2) = "bevy_math",
+    #[cfg(feature = "cgmath")]
+    Cgmath(cgmath::Vector2<f32>) = "cgmath",
+    #[cfg(feature = "cgmath")]
+    CgmathD(cgmath::Vector2<f64>) = "cgmath",
+    #[cfg(feature = "cgmath_017")]
+    Cgmath017(cgmath_017::Vector2<f32>) = "cgmath_017",
+    #[cfg(feature = "cgmath_017")]
+    Cgmath017D(cgmath_017::Vector2<f64>) = "cgmath_017",
+);
+
+define_any_vector!(
+    /// A three-dimensional vector of any enabled backend.
+    AnyVector3,
+    ObjectVector3,
+    Array(ArrayVec3<f32>) = "array",
+    ArrayD(ArrayVec3<f64>) = "array",
+    #[cfg(feature = "glam")]
+    Glam(glam::Vec3) = "glam",
+    #[cfg(feature = "glam")]
+    GlamD(glam::DVec3) = "glam",
+    #[cfg(feature = "bevy_math")]
+    Bevy(bevy_math::Vec3) = "bevy_math",
+    #[cfg(feature = "bevy_math")]
+    BevyD(bevy_math::DVec3) = "bevy_math",
+    #[cfg(feature = "cgmath")]
+    Cgmath(cgmath::Vector3<f32>) = "cgmath",
+    #[cfg(feature = "cgmath")]
+    CgmathD(cgmath::Vector3<f64>) = "cgmath",
+    #[cfg(feature = "cgmath_017")]
+    Cgmath017(cgmath_017::Vector3<f32>) = "cgmath_017",
+    #[cfg(feature = "cgmath_017")]
+    Cgmath017D(cgmath_017::Vector3<f64>) = "cgmath_017",
+);
+
+impl AnyVector2 {
+    /// Converts the vector to any `HasXY` vector, rounding to its scalar type.
+    #[inline]
+    pub fn to_xy<V: HasXY>(&self) -> V {
+        self.as_object().to_dyn().to_xy()
+    }
+}
+
+impl AnyVector3 {
+    /// Converts the vector to any `HasXYZ` vector, rounding to its scalar type.
+    #[inline]
+    pub fn to_xyz<V: HasXYZ>(&self) -> V {
+        self.as_object().to_dyn().to_xyz()
+    }
+}
+
+impl ObjectVector2 for AnyVector2 {
+    #[inline]
+    fn scalar_kind(&self) -> ScalarKind {
+        self.as_object().scalar_kind()
+    }
+
+    #[inline]
+    fn to_dyn(&self) -> DynVector2 {
+        self.as_object().to_dyn()
+    }
+
+    /// Returns the wrapped vector as `Any`.
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self.as_object().as_any()
+    }
+
+    #[inline]
+    fn clone_boxed(&self) -> Box<dyn ObjectVector2> {
+        Box::new(*self)
+    }
+
+    #[inline]
+    fn dyn_magnitude(&self) -> DynScalar {
+        self.as_object().dyn_magnitude()
+    }
+
+    #[inline]
+    fn dyn_dot(&self, other: &dyn ObjectVector2) -> DynScalar {
+        self.as_object().dyn_dot(other)
+    }
+
+    #[inline]
+    fn dyn_perp_dot(&self, other: &dyn ObjectVector2) -> DynScalar {
+        self.as_object().dyn_perp_dot(other)
+    }
+
+    #[inline]
+    fn dyn_distance(&self, other: &dyn ObjectVector2) -> DynScalar {
+        self.as_object().dyn_distance(other)
+    }
+
+    #[inline]
+    fn dyn_add(&self, other: &dyn ObjectVector2) -> Box<dyn ObjectVector2> {
+        self.as_object().dyn_add(other)
+    }
+
+    #[inline]
+    fn dyn_sub(&self, other: &dyn ObjectVector2) -> Box<dyn ObjectVector2> {
+        self.as_object().dyn_sub(other)
+    }
+
+    #[inline]
+    fn dyn_scale(&self, factor: f64) -> Box<dyn ObjectVector2> {
+        self.as_object().dyn_scale(factor)
+    }
+
+    #[inline]
+    fn dyn_normalize(&self) -> Box<dyn ObjectVector2> {
+        self.as_object().dyn_normalize()
+    }
+}
+
+impl ObjectVector3 for AnyVector3 {
+    #[inline]
+    fn scalar_kind(&self) -> ScalarKind {
+        self.as_object().scalar_kind()
+    }
+
+    #[inline]
+    fn to_dyn(&self) -> DynVector3 {
+        self.as_object().to_dyn()
+    }
+
+    /// Returns the wrapped vector as `Any`.
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self.as_object().as_any()
+    }
+
+    #[inline]
+    fn clone_boxed(&self) -> Box<dyn ObjectVector3> {
+        Box::new(*self)
+    }
+
+    #[inline]
+    fn dyn_magnitude(&self) -> DynScalar {
+        self.as_object().dyn_magnitude()
+    }
+
+    #[inline]
+    fn dyn_dot(&self, other: &dyn ObjectVector3) -> DynScalar {
+        self.as_object().dyn_dot(other)
+    }
+
+    #[inline]
+    fn dyn_cross(&self, other: &dyn ObjectVector3) -> Box<dyn ObjectVector3> {
+        self.as_object().dyn_cross(other)
+    }
+
+    #[inline]
+    fn dyn_distance(&self, other: &dyn ObjectVector3) -> DynScalar {
+        self.as_object().dyn_distance(other)
+    }
+
+    #[inline]
+    fn dyn_add(&self, other: &dyn ObjectVector3) -> Box<dyn ObjectVector3> {
+        self.as_object().dyn_add(other)
+    }
+
+    #[inline]
+    fn dyn_sub(&self, other: &dyn ObjectVector3) -> Box<dyn ObjectVector3> {
+        self.as_object().dyn_sub(other)
+    }
+
+    #[inline]
+    fn dyn_scale(&self, factor: f64) -> Box<dyn ObjectVector3> {
+        self.as_object().dyn_scale(factor)
+    }
+
+    #[inline]
+    fn dyn_normalize(&self) -> Box<dyn ObjectVector3> {
+        self.as_object().dyn_normalize()
+    }
+}
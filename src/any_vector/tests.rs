@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use crate::{
+    AnyVector2, AnyVector3, ArrayVec2, ArrayVec3, DynScalar, HasXY, HasXYZ, ObjectVector2,
+    ObjectVector3, ScalarKind,
+};
+
+// the backend is picked at runtime, e.g. from a file header
+fn load(backend: &str, xyz: [f64; 3]) -> AnyVector3 {
+    let [x, y, z] = xyz;
+    match backend {
+        "f32" => ArrayVec3::<f32>::new_3d(x as f32, y as f32, z as f32).into(),
+        #[cfg(feature = "glam")]
+        "glam" => glam::DVec3::new(x, y, z).into(),
+        #[cfg(feature = "cgmath")]
+        "cgmath" => cgmath::Vector3::<f32>::new(x as f32, y as f32, z as f32).into(),
+        _ => ArrayVec3::<f64>::new_3d(x, y, z).into(),
+    }
+}
+
+#[test]
+fn test_any_vector2() {
+    let a = AnyVector2::from(ArrayVec2::<f32>::new_2d(3.0, 4.0));
+    let b = AnyVector2::from(ArrayVec2::<f64>::new_2d(1.0, 2.0));
+    assert_eq!(a.backend(), "array");
+    assert_eq!(a.scalar_kind(), ScalarKind::F32);
+    assert_eq!(b.scalar_kind(), ScalarKind::F64);
+    assert_eq!(a.dyn_magnitude(), DynScalar::F32(5.0));
+    assert_eq!(a.dyn_dot(&b), DynScalar::F32(11.0));
+    assert_eq!(a.to_xy::<[f64; 2]>(), [3.0, 4.0]);
+    // the results are of the wrapped type
+    let sum = a.dyn_add(&b);
+    assert_eq!(
+        sum.as_any().downcast_ref::<ArrayVec2<f32>>(),
+        Some(&ArrayVec2::new_2d(4.0, 6.0))
+    );
+    assert!(a.as_any().is::<ArrayVec2<f32>>());
+    assert_eq!(a.clone_boxed().to_dyn(), a.to_dyn());
+}
+
+#[test]
+fn test_any_vector3() {
+    let backends = [
+        "f32",
+        "f64",
+        #[cfg(feature = "glam")]
+        "glam",
+        #[cfg(feature = "cgmath")]
+        "cgmath",
+    ];
+    for backend in backends {
+        let a = load(backend, [1.0, 0.0, 0.0]);
+        let b = load(backend, [0.0, 1.0, 0.0]);
+        let c = a.dyn_cross(&b);
+        assert_eq!(c.to_dyn().to_f64(), ArrayVec3::new_3d(0.0, 0.0, 1.0));
+        assert!((a.dyn_distance(&b).to_f64() - 2.0f64.sqrt()).abs() < 1e-6);
+        assert_eq!(
+            a.dyn_sub(&b).dyn_scale(2.0).to_dyn().to_f64(),
+            ArrayVec3::new_3d(2.0, -2.0, 0.0)
+        );
+        assert_eq!(a.to_xyz::<[f32; 3]>(), [1.0, 0.0, 0.0]);
+    }
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_any_vector_glam() {
+    let a: AnyVector2 = glam::Vec2::new(1.0, 2.0).into();
+    assert_eq!(a, AnyVector2::Glam(glam::Vec2::new(1.0, 2.0)));
+    assert_eq!(a.backend(), "glam");
+    // mixed backends are converted to the type of self
+    let b = AnyVector2::from(ArrayVec2::<f64>::new_2d(3.0, 4.0));
+    let sum = a.dyn_add(&b);
+    assert_eq!(
+        sum.as_any().downcast_ref::<glam::Vec2>(),
+        Some(&glam::Vec2::new(4.0, 6.0))
+    );
+    assert_eq!(b.dyn_perp_dot(&a), DynScalar::F64(2.0));
+}
@@ -0,0 +1,165 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Approximate comparison helpers for vectors and slices of vectors.
+
+use crate::{Approx, GenericScalar, HasXY};
+use num_traits::Float;
+use std::fmt::{Display, Formatter};
+
+/// Describes why two slices of vectors did not compare as approximately equal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SliceMismatch<T: HasXY> {
+    /// The slices have different lengths.
+    Length { left: usize, right: usize },
+    /// The vectors at `index` differ, `delta` is the largest absolute difference of any component.
+    Value {
+        index: usize,
+        left: T,
+        right: T,
+        delta: T::Scalar,
+    },
+}
+
+impl<T: HasXY> Display for SliceMismatch<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Length { left, right } => {
+                write!(f, "slice lengths differ: {} != {}", left, right)
+            }
+            Self::Value {
+                index,
+                left,
+                right,
+                delta,
+            } => write!(
+                f,
+                "first mismatch at index {}: {:?} != {:?} (delta: {})",
+                index, left, right, delta
+            ),
+        }
+    }
+}
+
+/// Returns the largest absolute difference between any two corresponding components of `a` and `b`.
+pub fn max_component_delta<T: HasXY>(a: T, b: T) -> T::Scalar {
+    (0..T::DIM)
+        .filter_map(|i| Some(Float::abs(a.get(i)? - b.get(i)?)))
+        .fold(T::Scalar::ZERO, Float::max)
+}
+
+/// Returns the default epsilon of the scalar type of `T`, used by the `assert_vec_*` macros.
+#[inline(always)]
+pub fn default_epsilon_of<T: HasXY>(_: &T) -> <T::Scalar as approx::AbsDiffEq>::Epsilon {
+    <T::Scalar as approx::AbsDiffEq>::default_epsilon()
+}
+
+/// Returns the default max ULPs of the scalar type of `T`, used by the `assert_vec_*` macros.
+#[inline(always)]
+pub fn default_max_ulps_of<T: HasXY>(_: &T) -> u32 {
+    <T::Scalar as approx::UlpsEq>::default_max_ulps()
+}
+
+/// Asserts that two vectors are approximately equal using `Approx::is_abs_diff_eq()`.
+/// On failure the vectors and the largest component delta are reported.
+///
+/// ```ignore
+/// assert_vec_abs_diff_eq!(a, b);
+/// assert_vec_abs_diff_eq!(a, b, epsilon = 1e-5);
+/// ```
+#[macro_export]
+macro_rules! assert_vec_abs_diff_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let left = $left;
+        let epsilon = $crate::approx_utils::default_epsilon_of(&left);
+        $crate::assert_vec_abs_diff_eq!(left, $right, epsilon = epsilon)
+    }};
+    ($left:expr, $right:expr, epsilon = $epsilon:expr $(,)?) => {{
+        let (left, right, epsilon) = ($left, $right, $epsilon);
+        if !$crate::Approx::is_abs_diff_eq(left, right, epsilon) {
+            panic!(
+                "assertion `abs_diff_eq(left, right, epsilon = {:?})` failed\n  left: {:?}\n right: {:?}\n delta: {:?}",
+                epsilon,
+                left,
+                right,
+                $crate::approx_utils::max_component_delta(left, right)
+            );
+        }
+    }};
+}
+
+/// Asserts that two vectors are approximately equal using `Approx::is_ulps_eq()`.
+/// On failure the vectors and the largest component delta are reported.
+///
+/// ```ignore
+/// assert_vec_ulps_eq!(a, b);
+/// assert_vec_ulps_eq!(a, b, epsilon = 1e-5, max_ulps = 8);
+/// ```
+#[macro_export]
+macro_rules! assert_vec_ulps_eq {
+    ($left:expr, $right:expr $(,)?) => {{
+        let left = $left;
+        let epsilon = $crate::approx_utils::default_epsilon_of(&left);
+        let max_ulps = $crate::approx_utils::default_max_ulps_of(&left);
+        $crate::assert_vec_ulps_eq!(left, $right, epsilon = epsilon, max_ulps = max_ulps)
+    }};
+    ($left:expr, $right:expr, epsilon = $epsilon:expr, max_ulps = $max_ulps:expr $(,)?) => {{
+        let (left, right, epsilon, max_ulps) = ($left, $right, $epsilon, $max_ulps);
+        if !$crate::Approx::is_ulps_eq(left, right, epsilon, max_ulps) {
+            panic!(
+                "assertion `ulps_eq(left, right, epsilon = {:?}, max_ulps = {:?})` failed\n  left: {:?}\n right: {:?}\n delta: {:?}",
+                epsilon,
+                max_ulps,
+                left,
+                right,
+                $crate::approx_utils::max_component_delta(left, right)
+            );
+        }
+    }};
+}
+
+/// Compares two slices of vectors element by element using `Approx::is_abs_diff_eq()`.
+/// On failure the index and delta of the first mismatch is reported.
+pub fn slices_abs_diff_eq<T: Approx>(
+    a: &[T],
+    b: &[T],
+    epsilon: <T::Scalar as approx::AbsDiffEq>::Epsilon,
+) -> Result<(), SliceMismatch<T>> {
+    slices_eq_by(a, b, |l, r| l.is_abs_diff_eq(r, epsilon))
+}
+
+/// Compares two slices of vectors element by element using `Approx::is_ulps_eq()`.
+/// On failure the index and delta of the first mismatch is reported.
+pub fn slices_ulps_eq<T: Approx>(
+    a: &[T],
+    b: &[T],
+    epsilon: <T::Scalar as approx::AbsDiffEq>::Epsilon,
+    max_ulps: u32,
+) -> Result<(), SliceMismatch<T>> {
+    slices_eq_by(a, b, |l, r| l.is_ulps_eq(r, epsilon, max_ulps))
+}
+
+#[inline(always)]
+fn slices_eq_by<T: HasXY, F: Fn(T, T) -> bool>(
+    a: &[T],
+    b: &[T],
+    eq: F,
+) -> Result<(), SliceMismatch<T>> {
+    if a.len() != b.len() {
+        return Err(SliceMismatch::Length {
+            left: a.len(),
+            right: b.len(),
+        });
+    }
+    match a.iter().zip(b.iter()).position(|(l, r)| !eq(*l, *r)) {
+        None => Ok(()),
+        Some(index) => Err(SliceMismatch::Value {
+            index,
+            left: a[index],
+            right: b[index],
+            delta: max_component_delta(a[index], b[index]),
+        }),
+    }
+}
@@ -0,0 +1,538 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! The vector traits for plain coordinate arrays, as produced by FFI and file format code.
+//!
+//! `[S; 2]` and `[S; 3]` implement `HasXY`/`HasXYZ` and `Approx` directly, so slices of raw
+//! arrays go through the algorithms written against the storage traits without a conversion
+//! pass. Arrays have no arithmetic operators and this crate can not add them to a foreign type,
+//! so the generic vector traits are implemented by the `ArrayVec2` and `ArrayVec3` newtypes
+//! instead. They are `#[repr(transparent)]` and convert to and from the arrays for free.
+//!
+//! With `HasXY` in scope, `array.get(i)` resolves to `HasXY::get()` and returns the component by
+//! value. Call `<[S]>::get()` for the slice method.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    math, Approx, Axis2, Axis3, GenericScalar, GenericVector2, GenericVector3, HasXY, HasXYZ,
+    IntVec2, IntVec3, Mask2, Mask3,
+};
+use num_traits::Float;
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+impl<S: GenericScalar> HasXY for [S; 2] {
+    type Scalar = S;
+    const DIM: usize = 2;
+    #[inline(always)]
+    fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+        [x, y]
+    }
+    #[inline(always)]
+    fn x(self) -> Self::Scalar {
+        self[0]
+    }
+    #[inline(always)]
+    fn set_x(&mut self, val: Self::Scalar) {
+        self[0] = val
+    }
+    #[inline(always)]
+    fn x_mut(&mut self) -> &mut Self::Scalar {
+        &mut self[0]
+    }
+    #[inline(always)]
+    fn y(self) -> Self::Scalar {
+        self[1]
+    }
+    #[inline(always)]
+    fn set_y(&mut self, val: Self::Scalar) {
+        self[1] = val
+    }
+    #[inline(always)]
+    fn y_mut(&mut self) -> &mut Self::Scalar {
+        &mut self[1]
+    }
+    #[inline(always)]
+    fn get(self, index: usize) -> Option<Self::Scalar> {
+        <[S]>::get(&self, index).copied()
+    }
+    #[inline(always)]
+    fn canonicalize(self) -> Self {
+        self.map(GenericScalar::canonicalize)
+    }
+    #[inline(always)]
+    fn flush_denormals(self) -> Self {
+        self.map(GenericScalar::flush_denormal)
+    }
+}
+
+impl<S: GenericScalar> Index<Axis2> for [S; 2] {
+    type Output = S;
+
+    #[inline(always)]
+    fn index(&self, axis: Axis2) -> &Self::Output {
+        &self[axis.index()]
+    }
+}
+
+impl<S: GenericScalar> Approx for [S; 2] {}
+
+impl<S: GenericScalar> HasXY for [S; 3] {
+    type Scalar = S;
+    const DIM: usize = 3;
+    #[inline(always)]
+    fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+        [x, y, S::ZERO]
+    }
+    #[inline(always)]
+    fn x(self) -> Self::Scalar {
+        self[0]
+    }
+    #[inline(always)]
+    fn set_x(&mut self, val: Self::Scalar) {
+        self[0] = val
+    }
+    #[inline(always)]
+    fn x_mut(&mut self) -> &mut Self::Scalar {
+        &mut self[0]
+    }
+    #[inline(always)]
+    fn y(self) -> Self::Scalar {
+        self[1]
+    }
+    #[inline(always)]
+    fn set_y(&mut self, val: Self::Scalar) {
+        self[1] = val
+    }
+    #[inline(always)]
+    fn y_mut(&mut self) -> &mut Self::Scalar {
+        &mut self[1]
+    }
+    #[inline(always)]
+    fn get(self, index: usize) -> Option<Self::Scalar> {
+        <[S]>::get(&self, index).copied()
+    }
+    #[inline(always)]
+    fn canonicalize(self) -> Self {
+        self.map(GenericScalar::canonicalize)
+    }
+    #[inline(always)]
+    fn flush_denormals(self) -> Self {
+        self.map(GenericScalar::flush_denormal)
+    }
+}
+
+impl<S: GenericScalar> HasXYZ for [S; 3] {
+    #[inline(always)]
+    fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+        [x, y, z]
+    }
+    #[inline(always)]
+    fn z(self) -> Self::Scalar {
+        self[2]
+    }
+    #[inline(always)]
+    fn set_z(&mut self, val: Self::Scalar) {
+        self[2] = val
+    }
+    #[inline(always)]
+    fn z_mut(&mut self) -> &mut Self::Scalar {
+        &mut self[2]
+    }
+}
+
+impl<S: GenericScalar> Index<Axis3> for [S; 3] {
+    type Output = S;
+
+    #[inline(always)]
+    fn index(&self, axis: Axis3) -> &Self::Output {
+        &self[axis.index()]
+    }
+}
+
+impl<S: GenericScalar> Approx for [S; 3] {}
+
+/// A two-dimensional vector stored as `[S; 2]`, implementing `GenericVector2`.
+#[repr(transparent)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct ArrayVec2<S: GenericScalar>(pub [S; 2]);
+
+/// A three-dimensional vector stored as `[S; 3]`, implementing `GenericVector3`.
+#[repr(transparent)]
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct ArrayVec3<S: GenericScalar>(pub [S; 3]);
+
+impl<S: GenericScalar> ArrayVec2<S> {
+    /// Creates a new vector, this is the `const` equivalent of `HasXY::new_2d()`.
+    #[inline(always)]
+    pub const fn new(x: S, y: S) -> Self {
+        Self([x, y])
+    }
+}
+
+impl<S: GenericScalar> ArrayVec3<S> {
+    /// Creates a new vector, this is the `const` equivalent of `HasXYZ::new_3d()`.
+    #[inline(always)]
+    pub const fn new(x: S, y: S, z: S) -> Self {
+        Self([x, y, z])
+    }
+}
+
+impl<S: GenericScalar> HasXY for ArrayVec2<S> {
+    type Scalar = S;
+    const DIM: usize = 2;
+    #[inline(always)]
+    fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+        Self([x, y])
+    }
+    #[inline(always)]
+    fn x(self) -> Self::Scalar {
+        self.0[0]
+    }
+    #[inline(always)]
+    fn set_x(&mut self, val: Self::Scalar) {
+        self.0[0] = val
+    }
+    #[inline(always)]
+    fn x_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.0[0]
+    }
+    #[inline(always)]
+    fn y(self) -> Self::Scalar {
+        self.0[1]
+    }
+    #[inline(always)]
+    fn set_y(&mut self, val: Self::Scalar) {
+        self.0[1] = val
+    }
+    #[inline(always)]
+    fn y_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.0[1]
+    }
+    #[inline(always)]
+    fn get(self, index: usize) -> Option<Self::Scalar> {
+        HasXY::get(self.0, index)
+    }
+    #[inline(always)]
+    fn canonicalize(self) -> Self {
+        Self(self.0.canonicalize())
+    }
+    #[inline(always)]
+    fn flush_denormals(self) -> Self {
+        Self(self.0.flush_denormals())
+    }
+}
+
+impl<S: GenericScalar> HasXY for ArrayVec3<S> {
+    type Scalar = S;
+    const DIM: usize = 3;
+    #[inline(always)]
+    fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+        Self([x, y, S::ZERO])
+    }
+    #[inline(always)]
+    fn x(self) -> Self::Scalar {
+        self.0[0]
+    }
+    #[inline(always)]
+    fn set_x(&mut self, val: Self::Scalar) {
+        self.0[0] = val
+    }
+    #[inline(always)]
+    fn x_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.0[0]
+    }
+    #[inline(always)]
+    fn y(self) -> Self::Scalar {
+        self.0[1]
+    }
+    #[inline(always)]
+    fn set_y(&mut self, val: Self::Scalar) {
+        self.0[1] = val
+    }
+    #[inline(always)]
+    fn y_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.0[1]
+    }
+    #[inline(always)]
+    fn get(self, index: usize) -> Option<Self::Scalar> {
+        HasXY::get(self.0, index)
+    }
+    #[inline(always)]
+    fn canonicalize(self) -> Self {
+        Self(self.0.canonicalize())
+    }
+    #[inline(always)]
+    fn flush_denormals(self) -> Self {
+        Self(self.0.flush_denormals())
+    }
+}
+
+impl<S: GenericScalar> HasXYZ for ArrayVec3<S> {
+    #[inline(always)]
+    fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+        Self([x, y, z])
+    }
+    #[inline(always)]
+    fn z(self) -> Self::Scalar {
+        self.0[2]
+    }
+    #[inline(always)]
+    fn set_z(&mut self, val: Self::Scalar) {
+        self.0[2] = val
+    }
+    #[inline(always)]
+    fn z_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.0[2]
+    }
+}
+
+impl<S: GenericScalar> Approx for ArrayVec2<S> {}
+impl<S: GenericScalar> Approx for ArrayVec3<S> {}
+
+impl<S: GenericScalar> GenericVector2 for ArrayVec2<S> {
+    type Vector3 = ArrayVec3<S>;
+    type Mask = Mask2;
+    type IntVector = IntVec2<i32>;
+
+    #[inline(always)]
+    fn to_3d(self, z: Self::Scalar) -> Self::Vector3 {
+        ArrayVec3([self.0[0], self.0[1], z])
+    }
+
+    #[inline(always)]
+    fn magnitude(self) -> Self::Scalar {
+        math::sqrt(self.magnitude_sq())
+    }
+
+    #[inline(always)]
+    fn magnitude_sq(self) -> Self::Scalar {
+        math::dot(self, self)
+    }
+
+    #[inline(always)]
+    fn dot(self, other: Self) -> Self::Scalar {
+        math::dot(self, other)
+    }
+
+    #[inline(always)]
+    fn perp_dot(self, rhs: Self) -> Self::Scalar {
+        self.0[0] * rhs.0[1] - self.0[1] * rhs.0[0]
+    }
+
+    #[inline(always)]
+    fn distance(self, rhs: Self) -> Self::Scalar {
+        math::sqrt(self.distance_sq(rhs))
+    }
+
+    #[inline(always)]
+    fn distance_sq(self, rhs: Self) -> Self::Scalar {
+        (self - rhs).magnitude_sq()
+    }
+
+    #[inline(always)]
+    fn normalize(self) -> Self {
+        self / self.magnitude()
+    }
+
+    #[inline(always)]
+    fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+        let l = self.magnitude();
+        (l > epsilon && Float::is_finite(l)).then(|| self / l)
+    }
+}
+
+impl<S: GenericScalar> GenericVector3 for ArrayVec3<S> {
+    type Vector2 = ArrayVec2<S>;
+    type Mask = Mask3;
+    type IntVector = IntVec3<i32>;
+
+    #[inline(always)]
+    fn to_2d(&self) -> Self::Vector2 {
+        ArrayVec2([self.0[0], self.0[1]])
+    }
+
+    #[inline(always)]
+    fn magnitude(self) -> Self::Scalar {
+        math::sqrt(self.magnitude_sq())
+    }
+
+    #[inline(always)]
+    fn magnitude_sq(self) -> Self::Scalar {
+        math::dot(self, self)
+    }
+
+    #[inline(always)]
+    fn dot(self, other: Self) -> Self::Scalar {
+        math::dot(self, other)
+    }
+
+    #[inline(always)]
+    fn cross(self, rhs: Self) -> Self {
+        let ([x, y, z], [rx, ry, rz]) = (self.0, rhs.0);
+        Self([y * rz - z * ry, z * rx - x * rz, x * ry - y * rx])
+    }
+
+    #[inline(always)]
+    fn normalize(self) -> Self {
+        self / self.magnitude()
+    }
+
+    #[inline(always)]
+    fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+        let l = self.magnitude();
+        (l > epsilon && Float::is_finite(l)).then(|| self / l)
+    }
+
+    #[inline(always)]
+    fn distance(self, other: Self) -> Self::Scalar {
+        math::sqrt(self.distance_sq(other))
+    }
+
+    #[inline(always)]
+    fn distance_sq(self, rhs: Self) -> Self::Scalar {
+        (self - rhs).magnitude_sq()
+    }
+}
+
+impl<S: GenericScalar> Index<Axis2> for ArrayVec2<S> {
+    type Output = S;
+
+    #[inline(always)]
+    fn index(&self, axis: Axis2) -> &Self::Output {
+        &self.0[axis.index()]
+    }
+}
+
+impl<S: GenericScalar> Index<Axis3> for ArrayVec3<S> {
+    type Output = S;
+
+    #[inline(always)]
+    fn index(&self, axis: Axis3) -> &Self::Output {
+        &self.0[axis.index()]
+    }
+}
+
+/// The arithmetic operators, component by component.
+macro_rules! impl_array_vec_ops {
+    ($vec:ident, $n:literal) => {
+        impl<S: GenericScalar> From<[S; $n]> for $vec<S> {
+            #[inline(always)]
+            fn from(array: [S; $n]) -> Self {
+                Self(array)
+            }
+        }
+
+        impl<S: GenericScalar> From<$vec<S>> for [S; $n] {
+            #[inline(always)]
+            fn from(v: $vec<S>) -> Self {
+                v.0
+            }
+        }
+
+        impl<S: GenericScalar> Index<usize> for $vec<S> {
+            type Output = S;
+
+            #[inline(always)]
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.0[index]
+            }
+        }
+
+        impl<S: GenericScalar> Add for $vec<S> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+            }
+        }
+
+        impl<S: GenericScalar> Sub for $vec<S> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+            }
+        }
+
+        impl<S: GenericScalar> Mul<S> for $vec<S> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn mul(self, rhs: S) -> Self::Output {
+                Self(self.0.map(|c| c * rhs))
+            }
+        }
+
+        impl<S: GenericScalar> Div<S> for $vec<S> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn div(self, rhs: S) -> Self::Output {
+                Self(self.0.map(|c| c / rhs))
+            }
+        }
+
+        impl<S: GenericScalar> Neg for $vec<S> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn neg(self) -> Self::Output {
+                Self(self.0.map(|c| -c))
+            }
+        }
+
+        impl<S: GenericScalar> AddAssign for $vec<S> {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<S: GenericScalar> SubAssign for $vec<S> {
+            #[inline(always)]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl<S: GenericScalar> MulAssign<S> for $vec<S> {
+            #[inline(always)]
+            fn mul_assign(&mut self, rhs: S) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl<S: GenericScalar> DivAssign<S> for $vec<S> {
+            #[inline(always)]
+            fn div_assign(&mut self, rhs: S) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl<S: GenericScalar> Sum for $vec<S> {
+            #[inline(always)]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::default(), Add::add)
+            }
+        }
+
+        impl<'a, S: GenericScalar> Sum<&'a Self> for $vec<S> {
+            #[inline(always)]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+    };
+}
+
+impl_array_vec_ops!(ArrayVec2, 2);
+impl_array_vec_ops!(ArrayVec3, 3);
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{ArrayVec2, ArrayVec3};
+use crate::{distance_robust, Approx, Axis2, Axis3, GenericVector3, HasXY};
+
+#[test]
+fn test_arrays() {
+    crate::tests::tests::test_xy::<[f32; 2]>(1.0, 2.0);
+    crate::tests::tests::test_xy::<[f64; 2]>(1.0, 2.0);
+    crate::tests::tests::test_xy::<[f32; 3]>(1.0, 2.0);
+    crate::tests::tests::test_xyz::<[f64; 3]>(1.0, 2.0, 3.0);
+
+    let p = [1.0, 2.0, 3.0];
+    assert_eq!((p[Axis3::Z], [5.0, 6.0][Axis2::Y]), (3.0, 6.0));
+    assert!(p.is_abs_diff_eq([1.0, 2.0, 3.0 + 1e-9], 1e-6));
+    assert_eq!(distance_robust(p, [4.0, 6.0, 3.0]), 5.0);
+    assert_eq!(<[f32; 3]>::new_2d(1.0, 2.0), [1.0, 2.0, 0.0]);
+}
+
+#[test]
+fn test_array_vec() {
+    let a: ArrayVec3<f64> = [1.0, 0.0, 0.0].into();
+    let b = ArrayVec3::new(0.0, 1.0, 0.0);
+    assert_eq!(a.cross(b), ArrayVec3::new(0.0, 0.0, 1.0));
+    assert_eq!(<[f64; 3]>::from(a + b * 2.0), [1.0, 2.0, 0.0]);
+    assert_eq!(
+        [a, b].iter().sum::<ArrayVec3<f64>>(),
+        ArrayVec3::new(1.0, 1.0, 0.0)
+    );
+    assert_eq!(a.to_2d(), ArrayVec2::new_2d(1.0, 0.0));
+}
+
+// the generic tests of the glam backend
+#[test]
+fn test_array_vec_generic() {
+    crate::tests::tests::test_xy::<ArrayVec2<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<ArrayVec2<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<ArrayVec3<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<ArrayVec3<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xyz::<ArrayVec3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_xyz::<ArrayVec3<f64>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_gxy::<ArrayVec2<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_gxy::<ArrayVec2<f64>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_generic_xy::<ArrayVec2<f32>>(1.0, 2.0, 3.0, 0.00000001);
+    crate::tests::tests::test_generic_xy::<ArrayVec2<f64>>(1.0, 2.0, 3.0, 0.0000000000001);
+    crate::tests::tests::test_gxyz::<ArrayVec3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_gxyz::<ArrayVec3<f64>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_generic_xyz::<ArrayVec3<f32>>(1.0, 2.0, 3.0, 4.0, 0.0001);
+    crate::tests::tests::test_generic_xyz::<ArrayVec3<f64>>(1.0, 2.0, 3.0, 4.0, 0.0000000000001);
+    crate::tests::tests::test_slices_approx::<ArrayVec2<f32>>(1.0, 2.0);
+    crate::tests::tests::test_slices_approx::<ArrayVec2<f64>>(1.0, 2.0);
+    crate::tests::tests::test_assert_vec_macros::<ArrayVec2<f32>>(1.0, 2.0);
+    crate::tests::tests::test_assert_vec_macros::<ArrayVec2<f64>>(1.0, 2.0);
+    crate::tests::tests::test_axis::<ArrayVec3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_axis::<ArrayVec3<f64>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_int_storage::<ArrayVec3<f32>>();
+    crate::tests::tests::test_int_storage::<ArrayVec3<f64>>();
+    crate::tests::tests::test_segments_intersect::<ArrayVec2<f32>>();
+    crate::tests::tests::test_segments_intersect::<ArrayVec2<f64>>();
+    crate::tests::tests::test_summation::<ArrayVec2<f32>>();
+    crate::tests::tests::test_summation::<ArrayVec2<f64>>();
+    crate::tests::tests::test_checked::<ArrayVec2<f32>>(1.0, 2.0);
+    crate::tests::tests::test_checked::<ArrayVec2<f64>>(1.0, 2.0);
+    crate::tests::tests::test_distance_robust::<ArrayVec3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_distance_robust::<ArrayVec3<f64>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_strict_fp::<ArrayVec3<f32>>(0.1, 0.7, -0.3);
+    crate::tests::tests::test_strict_fp::<ArrayVec3<f64>>(0.1, 0.7, -0.3);
+    crate::tests::tests::test_sweep_cmp::<ArrayVec2<f32>>();
+    crate::tests::tests::test_sweep_cmp::<ArrayVec2<f64>>();
+    crate::tests::tests::test_snap::<ArrayVec3<f32>>();
+    crate::tests::tests::test_snap::<ArrayVec3<f64>>();
+    crate::tests::tests::test_angle::<ArrayVec2<f32>>();
+    crate::tests::tests::test_angle::<ArrayVec2<f64>>();
+    crate::tests::tests::test_interval::<ArrayVec2<f32>>();
+    crate::tests::tests::test_interval::<ArrayVec2<f64>>();
+    crate::tests::tests::test_error_bound::<ArrayVec3<f32>>();
+    crate::tests::tests::test_error_bound::<ArrayVec3<f64>>();
+    crate::tests::tests::test_sampling_2d::<ArrayVec2<f32>>();
+    crate::tests::tests::test_sampling_2d::<ArrayVec2<f64>>();
+    crate::tests::tests::test_sampling_3d::<ArrayVec3<f32>>();
+    crate::tests::tests::test_sampling_3d::<ArrayVec3<f64>>();
+    crate::tests::tests::test_euler::<ArrayVec3<f32>>();
+    crate::tests::tests::test_euler::<ArrayVec3<f64>>();
+    crate::tests::tests::test_look_rotation::<ArrayVec3<f32>>();
+    crate::tests::tests::test_look_rotation::<ArrayVec3<f64>>();
+    crate::tests::tests::test_bounding_sphere_2d::<ArrayVec2<f32>>();
+    crate::tests::tests::test_bounding_sphere_2d::<ArrayVec2<f64>>();
+    crate::tests::tests::test_bounding_sphere_3d::<ArrayVec3<f32>>();
+    crate::tests::tests::test_bounding_sphere_3d::<ArrayVec3<f64>>();
+    crate::tests::tests::test_principal_axes::<ArrayVec3<f32>>();
+    crate::tests::tests::test_principal_axes::<ArrayVec3<f64>>();
+    crate::tests::tests::test_fit_plane::<ArrayVec3<f32>>();
+    crate::tests::tests::test_fit_plane::<ArrayVec3<f64>>();
+    crate::tests::tests::test_grid_downsample_2d::<ArrayVec2<f32>>();
+    crate::tests::tests::test_grid_downsample_2d::<ArrayVec2<f64>>();
+    crate::tests::tests::test_grid_downsample_3d::<ArrayVec3<f32>>();
+    crate::tests::tests::test_grid_downsample_3d::<ArrayVec3<f64>>();
+    crate::tests::tests::test_weld_vertices_2d::<ArrayVec2<f32>>();
+    crate::tests::tests::test_weld_vertices_2d::<ArrayVec2<f64>>();
+    crate::tests::tests::test_weld_vertices_3d::<ArrayVec3<f32>>();
+    crate::tests::tests::test_weld_vertices_3d::<ArrayVec3<f64>>();
+    crate::tests::tests::test_direction_encoding::<ArrayVec3<f32>>();
+    crate::tests::tests::test_direction_encoding::<ArrayVec3<f64>>();
+    crate::tests::tests::test_meshgrid_2d::<ArrayVec2<f32>>();
+    crate::tests::tests::test_meshgrid_2d::<ArrayVec2<f64>>();
+    crate::tests::tests::test_meshgrid_3d::<ArrayVec3<f32>>();
+    crate::tests::tests::test_meshgrid_3d::<ArrayVec3<f64>>();
+    crate::tests::tests::test_convex_combination::<ArrayVec2<f32>>();
+    crate::tests::tests::test_convex_combination::<ArrayVec2<f64>>();
+    crate::tests::tests::test_rotate_90::<ArrayVec2<f32>>();
+    crate::tests::tests::test_rotate_90::<ArrayVec2<f64>>();
+    crate::tests::tests::test_orientation::<ArrayVec2<f32>>();
+    crate::tests::tests::test_orientation::<ArrayVec2<f64>>();
+    crate::tests::tests::test_project_onto_plane::<ArrayVec3<f32>>();
+    crate::tests::tests::test_project_onto_plane::<ArrayVec3<f64>>();
+    crate::tests::tests::test_segment_2d::<ArrayVec2<f32>>();
+    crate::tests::tests::test_segment_2d::<ArrayVec2<f64>>();
+    crate::tests::tests::test_segment_3d::<ArrayVec3<f32>>();
+    crate::tests::tests::test_segment_3d::<ArrayVec3<f64>>();
+    crate::tests::tests::test_distance_to_line::<ArrayVec3<f32>>();
+    crate::tests::tests::test_distance_to_line::<ArrayVec3<f64>>();
+    crate::tests::tests::test_signed_angle_about_axis::<ArrayVec3<f32>>();
+    crate::tests::tests::test_signed_angle_about_axis::<ArrayVec3<f64>>();
+    crate::tests::tests::test_running_stats::<ArrayVec3<f32>>();
+    crate::tests::tests::test_running_stats::<ArrayVec3<f64>>();
+    crate::tests::tests::test_flip::<ArrayVec3<f32>>();
+    crate::tests::tests::test_flip::<ArrayVec3<f64>>();
+    crate::tests::tests::test_drop_dominant_axis::<ArrayVec3<f32>>();
+    crate::tests::tests::test_drop_dominant_axis::<ArrayVec3<f64>>();
+    crate::tests::tests::test_cgmath_compat::<ArrayVec3<f32>>();
+    crate::tests::tests::test_cgmath_compat::<ArrayVec3<f64>>();
+    crate::tests::tests::test_sum::<ArrayVec3<f32>>();
+    crate::tests::tests::test_sum::<ArrayVec3<f64>>();
+    crate::tests::tests::test_scale::<ArrayVec3<f32>>();
+    crate::tests::tests::test_scale::<ArrayVec3<f64>>();
+    crate::tests::tests::test_batch::<ArrayVec3<f32>>();
+    crate::tests::tests::test_batch::<ArrayVec3<f64>>();
+    crate::tests::tests::test_flat::<ArrayVec3<f32>>();
+    crate::tests::tests::test_flat::<ArrayVec3<f64>>();
+}
+
+#[test]
+fn test_mask() {
+    crate::tests::tests::test_mask_2d::<ArrayVec2<f64>>();
+    crate::tests::tests::test_mask_3d::<ArrayVec3<f32>>();
+}
+
+#[test]
+fn test_int_conversion() {
+    crate::tests::tests::test_int_conversion_2d::<ArrayVec2<f32>>();
+    crate::tests::tests::test_int_conversion_3d::<ArrayVec3<f64>>();
+}
+
+#[test]
+fn test_int_vec() {
+    crate::tests::tests::test_int_vector::<crate::IntVec3<i32>>();
+    crate::tests::tests::test_int_vector::<crate::IntVec3<i64>>();
+}
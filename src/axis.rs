@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Typed axis enums, used for indexing vector components without magic numbers.
+
+use crate::{HasXY, HasXYZ};
+use num_traits::Float;
+
+/// An axis of a two-dimensional vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Axis2 {
+    X,
+    Y,
+}
+
+/// An axis of a three-dimensional vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Axis3 {
+    X,
+    Y,
+    Z,
+}
+
+impl Axis2 {
+    /// All the axes, in index order.
+    pub const ALL: [Self; 2] = [Self::X, Self::Y];
+
+    /// Returns the component index of this axis.
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the axis of the component with the largest magnitude.
+    /// Ties are resolved in favor of the lowest axis.
+    #[inline(always)]
+    pub fn dominant<T: HasXY>(v: T) -> Self {
+        if Float::abs(v.y()) > Float::abs(v.x()) {
+            Self::Y
+        } else {
+            Self::X
+        }
+    }
+}
+
+impl Axis3 {
+    /// All the axes, in index order.
+    pub const ALL: [Self; 3] = [Self::X, Self::Y, Self::Z];
+
+    /// Returns the component index of this axis.
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the axis of the component with the largest magnitude.
+    /// Ties are resolved in favor of the lowest axis.
+    #[inline(always)]
+    pub fn dominant<T: HasXYZ>(v: T) -> Self {
+        let (x, y, z) = (Float::abs(v.x()), Float::abs(v.y()), Float::abs(v.z()));
+        if z > x && z > y {
+            Self::Z
+        } else if y > x {
+            Self::Y
+        } else {
+            Self::X
+        }
+    }
+}
+
+impl From<Axis2> for Axis3 {
+    #[inline(always)]
+    fn from(axis: Axis2) -> Self {
+        match axis {
+            Axis2::X => Self::X,
+            Axis2::Y => Self::Y,
+        }
+    }
+}
+
+impl From<Axis2> for usize {
+    #[inline(always)]
+    fn from(axis: Axis2) -> Self {
+        axis.index()
+    }
+}
+
+impl From<Axis3> for usize {
+    #[inline(always)]
+    fn from(axis: Axis3) -> Self {
+        axis.index()
+    }
+}
@@ -0,0 +1,549 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Structure of arrays batches of vectors, for dot products, cross products and distances of
+//! many vectors at once.
+//!
+//! `ScalarBatch` abstracts over `LANES` scalars processed lane wise, like a SIMD register.
+//! `ArrayBatch` is the portable implementation for any scalar type, plain loops over an array
+//! that the compiler turns into SIMD instructions, and the `wide` feature adds `wide::f32x8` and
+//! friends. `GenericVector2xN` and `GenericVector3xN` are batches of vectors with one
+//! `ScalarBatch` per component, with lane extraction and insertion for any `HasXY`/`HasXYZ`
+//! vector and the batched operations as default methods. `Vec2xN` and `Vec3xN` implement them
+//! for every `ScalarBatch`, other batch vector types only need the component accessors.
+//!
+//! The batches use the plain `x * x' + y * y' (+ z * z')` evaluation, like `math::dot()`.
+
+use crate::{math, GenericScalar, HasXY, HasXYZ};
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// `LANES` scalars, processed lane wise like a SIMD register.
+pub trait ScalarBatch:
+    Copy
+    + Debug
+    + Default
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Sync
+    + Send
+    + 'static
+{
+    type Scalar: GenericScalar;
+    /// The number of scalars in the batch.
+    const LANES: usize;
+    /// Returns the batch with every lane set to `value`.
+    fn splat(value: Self::Scalar) -> Self;
+    /// Returns the batch with lane `i` set to `f(i)`.
+    fn from_fn<F: FnMut(usize) -> Self::Scalar>(f: F) -> Self;
+    /// Returns the scalar in lane `i`.
+    ///
+    /// # Panics
+    /// If `i >= LANES`.
+    fn lane(self, i: usize) -> Self::Scalar;
+    /// Sets lane `i` to `value`.
+    ///
+    /// # Panics
+    /// If `i >= LANES`.
+    fn set_lane(&mut self, i: usize, value: Self::Scalar);
+    /// Writes the lanes to `out`, as many as fit.
+    fn write_to(self, out: &mut [Self::Scalar]);
+    /// Returns the square root of every lane.
+    fn sqrt(self) -> Self;
+}
+
+/// `B::LANES` two-dimensional vectors, stored as one batch per component.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Vec2xN<B: ScalarBatch> {
+    pub x: B,
+    pub y: B,
+}
+
+/// `B::LANES` three-dimensional vectors, stored as one batch per component.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Vec3xN<B: ScalarBatch> {
+    pub x: B,
+    pub y: B,
+    pub z: B,
+}
+
+/// `LANES` two-dimensional vectors, processed one `ScalarBatch` per component.
+///
+/// Only the component accessors are required, the lane access and the batched operations are
+/// default methods.
+pub trait GenericVector2xN:
+    Copy
+    + Debug
+    + Default
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<<Self as GenericVector2xN>::Batch, Output = Self>
+    + Div<<Self as GenericVector2xN>::Batch, Output = Self>
+    + Neg<Output = Self>
+    + Sync
+    + Send
+    + 'static
+{
+    type Scalar: GenericScalar;
+    type Batch: ScalarBatch<Scalar = Self::Scalar>;
+
+    fn new_2d(x: Self::Batch, y: Self::Batch) -> Self;
+    fn x(&self) -> Self::Batch;
+    fn y(&self) -> Self::Batch;
+
+    /// Returns the batch with every lane set to `v`.
+    #[inline(always)]
+    fn splat<V: HasXY<Scalar = Self::Scalar>>(v: V) -> Self {
+        Self::new_2d(Self::Batch::splat(v.x()), Self::Batch::splat(v.y()))
+    }
+
+    /// Loads the first `LANES` vectors of `vectors`. Missing lanes are set to zero.
+    #[inline(always)]
+    fn load<V: HasXY<Scalar = Self::Scalar>>(vectors: &[V]) -> Self {
+        Self::new_2d(
+            Self::Batch::from_fn(|i| vectors.get(i).map_or(Self::Scalar::ZERO, |v| v.x())),
+            Self::Batch::from_fn(|i| vectors.get(i).map_or(Self::Scalar::ZERO, |v| v.y())),
+        )
+    }
+
+    /// Stores the lanes in `out`, as many as fit.
+    #[inline(always)]
+    fn store<V: HasXY<Scalar = Self::Scalar>>(&self, out: &mut [V]) {
+        for (i, v) in out.iter_mut().take(Self::Batch::LANES).enumerate() {
+            *v = self.lane(i);
+        }
+    }
+
+    /// Returns the vector in lane `i`.
+    ///
+    /// # Panics
+    /// If `i >= LANES`.
+    #[inline(always)]
+    fn lane<V: HasXY<Scalar = Self::Scalar>>(&self, i: usize) -> V {
+        V::new_2d(self.x().lane(i), self.y().lane(i))
+    }
+
+    /// Sets lane `i` to `v`.
+    ///
+    /// # Panics
+    /// If `i >= LANES`.
+    #[inline(always)]
+    fn set_lane<V: HasXY<Scalar = Self::Scalar>>(&mut self, i: usize, v: V) {
+        let (mut x, mut y) = (self.x(), self.y());
+        x.set_lane(i, v.x());
+        y.set_lane(i, v.y());
+        *self = Self::new_2d(x, y);
+    }
+
+    /// Returns the dot products of the lanes.
+    #[inline(always)]
+    fn dot(&self, other: &Self) -> Self::Batch {
+        self.x() * other.x() + self.y() * other.y()
+    }
+
+    /// Returns the perp dot products of the lanes, see `GenericVector2::perp_dot()`.
+    #[inline(always)]
+    fn perp_dot(&self, other: &Self) -> Self::Batch {
+        self.x() * other.y() - self.y() * other.x()
+    }
+
+    /// Returns the squared lengths of the lanes.
+    #[inline(always)]
+    fn magnitude_sq(&self) -> Self::Batch {
+        self.dot(self)
+    }
+
+    /// Returns the lengths of the lanes.
+    #[inline(always)]
+    fn magnitude(&self) -> Self::Batch {
+        self.magnitude_sq().sqrt()
+    }
+
+    /// Returns the squared distances between the lanes.
+    #[inline(always)]
+    fn distance_sq(&self, other: &Self) -> Self::Batch {
+        (*self - *other).magnitude_sq()
+    }
+
+    /// Returns the distances between the lanes.
+    #[inline(always)]
+    fn distance(&self, other: &Self) -> Self::Batch {
+        self.distance_sq(other).sqrt()
+    }
+
+    /// Returns the batch with every lane normalized. As with `GenericVector2::normalize()`, a
+    /// zero lane gives NaN components.
+    #[inline(always)]
+    fn normalize(&self) -> Self {
+        *self / self.magnitude()
+    }
+}
+
+/// `LANES` three-dimensional vectors, processed one `ScalarBatch` per component.
+///
+/// Only the component accessors are required, the lane access and the batched operations are
+/// default methods.
+pub trait GenericVector3xN:
+    Copy
+    + Debug
+    + Default
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<<Self as GenericVector3xN>::Batch, Output = Self>
+    + Div<<Self as GenericVector3xN>::Batch, Output = Self>
+    + Neg<Output = Self>
+    + Sync
+    + Send
+    + 'static
+{
+    type Scalar: GenericScalar;
+    type Batch: ScalarBatch<Scalar = Self::Scalar>;
+
+    fn new_3d(x: Self::Batch, y: Self::Batch, z: Self::Batch) -> Self;
+    fn x(&self) -> Self::Batch;
+    fn y(&self) -> Self::Batch;
+    fn z(&self) -> Self::Batch;
+
+    /// Returns the batch with every lane set to `v`.
+    #[inline(always)]
+    fn splat<V: HasXYZ<Scalar = Self::Scalar>>(v: V) -> Self {
+        Self::new_3d(
+            Self::Batch::splat(v.x()),
+            Self::Batch::splat(v.y()),
+            Self::Batch::splat(v.z()),
+        )
+    }
+
+    /// Loads the first `LANES` vectors of `vectors`. Missing lanes are set to zero.
+    #[inline(always)]
+    fn load<V: HasXYZ<Scalar = Self::Scalar>>(vectors: &[V]) -> Self {
+        Self::new_3d(
+            Self::Batch::from_fn(|i| vectors.get(i).map_or(Self::Scalar::ZERO, |v| v.x())),
+            Self::Batch::from_fn(|i| vectors.get(i).map_or(Self::Scalar::ZERO, |v| v.y())),
+            Self::Batch::from_fn(|i| vectors.get(i).map_or(Self::Scalar::ZERO, |v| v.z())),
+        )
+    }
+
+    /// Stores the lanes in `out`, as many as fit.
+    #[inline(always)]
+    fn store<V: HasXYZ<Scalar = Self::Scalar>>(&self, out: &mut [V]) {
+        for (i, v) in out.iter_mut().take(Self::Batch::LANES).enumerate() {
+            *v = self.lane(i);
+        }
+    }
+
+    /// Returns the vector in lane `i`.
+    ///
+    /// # Panics
+    /// If `i >= LANES`.
+    #[inline(always)]
+    fn lane<V: HasXYZ<Scalar = Self::Scalar>>(&self, i: usize) -> V {
+        V::new_3d(self.x().lane(i), self.y().lane(i), self.z().lane(i))
+    }
+
+    /// Sets lane `i` to `v`.
+    ///
+    /// # Panics
+    /// If `i >= LANES`.
+    #[inline(always)]
+    fn set_lane<V: HasXYZ<Scalar = Self::Scalar>>(&mut self, i: usize, v: V) {
+        let (mut x, mut y, mut z) = (self.x(), self.y(), self.z());
+        x.set_lane(i, v.x());
+        y.set_lane(i, v.y());
+        z.set_lane(i, v.z());
+        *self = Self::new_3d(x, y, z);
+    }
+
+    /// Returns the dot products of the lanes.
+    #[inline(always)]
+    fn dot(&self, other: &Self) -> Self::Batch {
+        self.x() * other.x() + self.y() * other.y() + self.z() * other.z()
+    }
+
+    /// Returns the cross products of the lanes.
+    #[inline(always)]
+    fn cross(&self, other: &Self) -> Self {
+        Self::new_3d(
+            self.y() * other.z() - self.z() * other.y(),
+            self.z() * other.x() - self.x() * other.z(),
+            self.x() * other.y() - self.y() * other.x(),
+        )
+    }
+
+    /// Returns the squared lengths of the lanes.
+    #[inline(always)]
+    fn magnitude_sq(&self) -> Self::Batch {
+        self.dot(self)
+    }
+
+    /// Returns the lengths of the lanes.
+    #[inline(always)]
+    fn magnitude(&self) -> Self::Batch {
+        self.magnitude_sq().sqrt()
+    }
+
+    /// Returns the squared distances between the lanes.
+    #[inline(always)]
+    fn distance_sq(&self, other: &Self) -> Self::Batch {
+        (*self - *other).magnitude_sq()
+    }
+
+    /// Returns the distances between the lanes.
+    #[inline(always)]
+    fn distance(&self, other: &Self) -> Self::Batch {
+        self.distance_sq(other).sqrt()
+    }
+
+    /// Returns the batch with every lane normalized. As with `GenericVector3::normalize()`, a
+    /// zero lane gives NaN components.
+    #[inline(always)]
+    fn normalize(&self) -> Self {
+        *self / self.magnitude()
+    }
+}
+
+impl<B: ScalarBatch> GenericVector2xN for Vec2xN<B> {
+    type Scalar = B::Scalar;
+    type Batch = B;
+
+    #[inline(always)]
+    fn new_2d(x: B, y: B) -> Self {
+        Self { x, y }
+    }
+    #[inline(always)]
+    fn x(&self) -> B {
+        self.x
+    }
+    #[inline(always)]
+    fn y(&self) -> B {
+        self.y
+    }
+}
+
+impl<B: ScalarBatch> GenericVector3xN for Vec3xN<B> {
+    type Scalar = B::Scalar;
+    type Batch = B;
+
+    #[inline(always)]
+    fn new_3d(x: B, y: B, z: B) -> Self {
+        Self { x, y, z }
+    }
+    #[inline(always)]
+    fn x(&self) -> B {
+        self.x
+    }
+    #[inline(always)]
+    fn y(&self) -> B {
+        self.y
+    }
+    #[inline(always)]
+    fn z(&self) -> B {
+        self.z
+    }
+}
+
+macro_rules! impl_batch_vec_ops {
+    ($vec_type:ident, $($c:ident),+) => {
+        impl<B: ScalarBatch> Add for $vec_type<B> {
+            type Output = Self;
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<B: ScalarBatch> Sub for $vec_type<B> {
+            type Output = Self;
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<B: ScalarBatch> Mul<B> for $vec_type<B> {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, rhs: B) -> Self {
+                Self { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl<B: ScalarBatch> Div<B> for $vec_type<B> {
+            type Output = Self;
+            #[inline(always)]
+            fn div(self, rhs: B) -> Self {
+                Self { $($c: self.$c / rhs),+ }
+            }
+        }
+
+        impl<B: ScalarBatch> Neg for $vec_type<B> {
+            type Output = Self;
+            #[inline(always)]
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+    };
+}
+
+impl_batch_vec_ops!(Vec2xN, x, y);
+impl_batch_vec_ops!(Vec3xN, x, y, z);
+
+/// `N` scalars, processed lane wise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArrayBatch<S: GenericScalar, const N: usize>(pub [S; N]);
+
+impl<S: GenericScalar, const N: usize> Default for ArrayBatch<S, N> {
+    fn default() -> Self {
+        Self([S::ZERO; N])
+    }
+}
+
+impl<S: GenericScalar, const N: usize> ScalarBatch for ArrayBatch<S, N> {
+    type Scalar = S;
+    const LANES: usize = N;
+
+    #[inline(always)]
+    fn splat(value: S) -> Self {
+        Self([value; N])
+    }
+    #[inline(always)]
+    fn from_fn<F: FnMut(usize) -> S>(f: F) -> Self {
+        Self(std::array::from_fn(f))
+    }
+    #[inline(always)]
+    fn lane(self, i: usize) -> S {
+        self.0[i]
+    }
+    #[inline(always)]
+    fn set_lane(&mut self, i: usize, value: S) {
+        self.0[i] = value;
+    }
+    #[inline(always)]
+    fn write_to(self, out: &mut [S]) {
+        for (o, v) in out.iter_mut().zip(self.0) {
+            *o = v;
+        }
+    }
+    #[inline(always)]
+    fn sqrt(self) -> Self {
+        Self(self.0.map(math::sqrt))
+    }
+}
+
+macro_rules! impl_array_batch_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl<S: GenericScalar, const N: usize> $trait for ArrayBatch<S, N> {
+            type Output = Self;
+            #[inline(always)]
+            fn $method(self, rhs: Self) -> Self {
+                Self(std::array::from_fn(|i| self.0[i] $op rhs.0[i]))
+            }
+        }
+    };
+}
+
+impl_array_batch_op!(Add, add, +);
+impl_array_batch_op!(Sub, sub, -);
+impl_array_batch_op!(Mul, mul, *);
+impl_array_batch_op!(Div, div, /);
+
+impl<S: GenericScalar, const N: usize> Neg for ArrayBatch<S, N> {
+    type Output = Self;
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self(self.0.map(|v| -v))
+    }
+}
+
+/// Returns the vectors of `vectors` in batches of `B::LANES`, with the number of used lanes of
+/// every batch. The unused lanes of the last batch are zero.
+pub fn batches_2d<B: ScalarBatch, V: HasXY<Scalar = B::Scalar>>(
+    vectors: &[V],
+) -> impl Iterator<Item = (Vec2xN<B>, usize)> + '_ {
+    vectors
+        .chunks(B::LANES)
+        .map(|chunk| (Vec2xN::load(chunk), chunk.len()))
+}
+
+/// Returns the vectors of `vectors` in batches of `B::LANES`, with the number of used lanes of
+/// every batch. The unused lanes of the last batch are zero.
+pub fn batches_3d<B: ScalarBatch, V: HasXYZ<Scalar = B::Scalar>>(
+    vectors: &[V],
+) -> impl Iterator<Item = (Vec3xN<B>, usize)> + '_ {
+    vectors
+        .chunks(B::LANES)
+        .map(|chunk| (Vec3xN::load(chunk), chunk.len()))
+}
+
+/// Returns the dot products of the pairs `a[i]`, `b[i]`, computed `B::LANES` pairs at a time.
+/// The extra vectors of the longer slice are ignored.
+pub fn batch_dots_2d<B: ScalarBatch, V: HasXY<Scalar = B::Scalar>>(
+    a: &[V],
+    b: &[V],
+) -> Vec<V::Scalar> {
+    let mut out = vec![V::Scalar::ZERO; a.len().min(b.len())];
+    for ((a, b), out) in a
+        .chunks(B::LANES)
+        .zip(b.chunks(B::LANES))
+        .zip(out.chunks_mut(B::LANES))
+    {
+        Vec2xN::<B>::load(a).dot(&Vec2xN::load(b)).write_to(out);
+    }
+    out
+}
+
+/// Returns the dot products of the pairs `a[i]`, `b[i]`, computed `B::LANES` pairs at a time.
+/// The extra vectors of the longer slice are ignored.
+pub fn batch_dots_3d<B: ScalarBatch, V: HasXYZ<Scalar = B::Scalar>>(
+    a: &[V],
+    b: &[V],
+) -> Vec<V::Scalar> {
+    let mut out = vec![V::Scalar::ZERO; a.len().min(b.len())];
+    for ((a, b), out) in a
+        .chunks(B::LANES)
+        .zip(b.chunks(B::LANES))
+        .zip(out.chunks_mut(B::LANES))
+    {
+        Vec3xN::<B>::load(a).dot(&Vec3xN::load(b)).write_to(out);
+    }
+    out
+}
+
+/// Returns the distances from every point of `points` to `to`, computed `B::LANES` points at a
+/// time.
+pub fn batch_distances_2d<B: ScalarBatch, V: HasXY<Scalar = B::Scalar>>(
+    points: &[V],
+    to: V,
+) -> Vec<V::Scalar> {
+    let to = Vec2xN::<B>::splat(to);
+    let mut out = vec![V::Scalar::ZERO; points.len()];
+    for (points, out) in points.chunks(B::LANES).zip(out.chunks_mut(B::LANES)) {
+        Vec2xN::<B>::load(points).distance(&to).write_to(out);
+    }
+    out
+}
+
+/// Returns the distances from every point of `points` to `to`, computed `B::LANES` points at a
+/// time.
+pub fn batch_distances_3d<B: ScalarBatch, V: HasXYZ<Scalar = B::Scalar>>(
+    points: &[V],
+    to: V,
+) -> Vec<V::Scalar> {
+    let to = Vec3xN::<B>::splat(to);
+    let mut out = vec![V::Scalar::ZERO; points.len()];
+    for (points, out) in points.chunks(B::LANES).zip(out.chunks_mut(B::LANES)) {
+        Vec3xN::<B>::load(points).distance(&to).write_to(out);
+    }
+    out
+}
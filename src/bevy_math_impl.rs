@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! The generic vector traits for the vector types of `bevy_math`.
+//!
+//! Bevy links its own version of glam, and the types of two glam versions are different types,
+//! so the impls of the `glam` feature do not apply to Bevy's vectors. This module implements the
+//! traits for `bevy_math::Vec2`, `Vec3`, `Vec3A`, `Vec4`, `DVec2`, `DVec3` and `DVec4`, for the
+//! matrices, the affines, the quaternions and the integer vectors, with the same code as the
+//! `glam` backend. `Vec3A` is paired with this module's own `Vec2A`, a wrapper around Bevy's
+//! `Vec2`, as `glam::Vec3A` is paired with `crate::Vec2A`.
+//!
+//! The unit directions `Dir2`, `Dir3` and `Dir3A` do not implement the storage traits, whose
+//! setters could break the unit length. They implement the read only `DirXY` and `DirXYZ`
+//! instead, and dereference to `Vec2`, `Vec3` and `Vec3A`, so `*dir` works with every generic
+//! function.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    Approx, Axis2, Axis3, GenericAffine2, GenericAffine3, GenericIntVector2, GenericIntVector3,
+    GenericMask, GenericMatrix2, GenericMatrix3, GenericMatrix4, GenericRotation3, GenericScalar,
+    GenericVector2, GenericVector3, GenericVector4, HasXY, HasXYZ, HasXYZW,
+};
+use approx::{AbsDiffEq, UlpsEq};
+use bevy_math::{
+    vec2, vec3a, Affine2, Affine3A, BVec2, BVec3, BVec3A, DAffine2, DAffine3, DMat2, DMat3, DMat4,
+    DQuat, DVec2, DVec3, DVec4, Dir2, Dir3, Dir3A, I64Vec2, I64Vec3, IVec2, IVec3, Mat2, Mat3,
+    Mat4, Quat, U64Vec2, U64Vec3, UVec2, UVec3, Vec2, Vec3, Vec3A, Vec4,
+};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, DivAssign, Index, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+impl_vector2!(Vec2, f32, Vec3);
+impl_approx2!(Vec2);
+impl_vector2!(DVec2, f64, DVec3);
+impl_approx2!(DVec2);
+
+impl_vector3!(Vec3, f32, Vec2);
+impl_approx3!(Vec3);
+impl_vector3!(DVec3, f64, DVec2);
+impl_approx3!(DVec3);
+
+impl_vector4!(Vec4, f32, Vec3);
+impl_approx4!(Vec4);
+impl_vector4!(DVec4, f64, DVec3);
+impl_approx4!(DVec4);
+
+impl_int_vector2!(IVec2, i32, IVec3);
+impl_int_vector2!(UVec2, u32, UVec3);
+impl_int_vector2!(I64Vec2, i64, I64Vec3);
+impl_int_vector2!(U64Vec2, u64, U64Vec3);
+
+impl_int_vector3!(IVec3, i32, IVec2);
+impl_int_vector3!(UVec3, u32, UVec2);
+impl_int_vector3!(I64Vec3, i64, I64Vec2);
+impl_int_vector3!(U64Vec3, u64, U64Vec2);
+
+impl_mask!(BVec2, 2, 0, 1);
+impl_mask!(BVec3, 3, 0, 1, 2);
+impl_mask!(BVec3A, 3, 0, 1, 2);
+
+impl_matrix3!(Mat3, f32, Vec2, Vec3);
+impl_matrix3!(DMat3, f64, DVec2, DVec3);
+
+impl_matrix2!(Mat2, f32, Vec2);
+impl_matrix2!(DMat2, f64, DVec2);
+
+impl_matrix4!(Mat4, f32, Vec3, Vec4, Mat3);
+impl_matrix4!(DMat4, f64, DVec3, DVec4, DMat3);
+
+impl_affine2!(Affine2, f32, Vec2);
+impl_affine2!(DAffine2, f64, DVec2);
+
+impl_affine3!(Affine3A, f32, Vec3, Quat);
+impl_affine3!(DAffine3, f64, DVec3, DQuat);
+
+impl_rotation3!(Quat, f32, Vec3, Mat3);
+impl_rotation3!(DQuat, f64, DVec3, DMat3);
+
+impl_vec3a!();
+
+/// Read only `HasXY` style access to the components of a unit direction.
+pub trait DirXY: Copy {
+    /// The vector type the direction dereferences to.
+    type Vector: HasXY;
+
+    /// Returns the direction as a vector of unit length.
+    fn to_vector(self) -> Self::Vector;
+
+    #[inline(always)]
+    fn x(self) -> <Self::Vector as HasXY>::Scalar {
+        self.to_vector().x()
+    }
+
+    #[inline(always)]
+    fn y(self) -> <Self::Vector as HasXY>::Scalar {
+        self.to_vector().y()
+    }
+
+    /// Returns the component at `index`, or `None` if `index` is not less than the dimension.
+    #[inline(always)]
+    fn get(self, index: usize) -> Option<<Self::Vector as HasXY>::Scalar> {
+        self.to_vector().get(index)
+    }
+}
+
+/// Read only `HasXYZ` style access to the components of a 3D unit direction.
+pub trait DirXYZ: DirXY {
+    fn z(self) -> <Self::Vector as HasXY>::Scalar;
+}
+
+impl DirXY for Dir2 {
+    type Vector = Vec2;
+    #[inline(always)]
+    fn to_vector(self) -> Vec2 {
+        *self
+    }
+}
+
+impl DirXY for Dir3 {
+    type Vector = Vec3;
+    #[inline(always)]
+    fn to_vector(self) -> Vec3 {
+        *self
+    }
+}
+
+impl DirXYZ for Dir3 {
+    #[inline(always)]
+    fn z(self) -> f32 {
+        self.to_vector().z
+    }
+}
+
+impl DirXY for Dir3A {
+    type Vector = Vec3A;
+    #[inline(always)]
+    fn to_vector(self) -> Vec3A {
+        *self
+    }
+}
+
+impl DirXYZ for Dir3A {
+    #[inline(always)]
+    fn z(self) -> f32 {
+        self.to_vector().z
+    }
+}
@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+// the generic tests of the glam backend, run on the glam version linked by Bevy
+#[test]
+fn test_bevy_math() {
+    crate::tests::tests::test_xy::<bevy_math::Vec2>(1.0, 2.0);
+    crate::tests::tests::test_xy::<bevy_math::DVec2>(1.0, 2.0);
+    crate::tests::tests::test_xy::<bevy_math::Vec3>(1.0, 2.0);
+    crate::tests::tests::test_xy::<bevy_math::DVec3>(1.0, 2.0);
+    crate::tests::tests::test_xyz::<bevy_math::Vec3>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_xyz::<bevy_math::DVec3>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_gxy::<bevy_math::Vec2>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_gxy::<bevy_math::DVec2>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_generic_xy::<bevy_math::Vec2>(1.0, 2.0, 3.0, 0.00000001);
+    crate::tests::tests::test_generic_xy::<bevy_math::DVec2>(1.0, 2.0, 3.0, 0.0000000000001);
+    crate::tests::tests::test_gxyz::<bevy_math::Vec3>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_gxyz::<bevy_math::DVec3>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_generic_xyz::<bevy_math::Vec3>(1.0, 2.0, 3.0, 4.0, 0.0001);
+    crate::tests::tests::test_generic_xyz::<bevy_math::DVec3>(1.0, 2.0, 3.0, 4.0, 0.0000000000001);
+    crate::tests::tests::test_slices_approx::<bevy_math::Vec2>(1.0, 2.0);
+    crate::tests::tests::test_slices_approx::<bevy_math::DVec2>(1.0, 2.0);
+    crate::tests::tests::test_assert_vec_macros::<bevy_math::Vec2>(1.0, 2.0);
+    crate::tests::tests::test_assert_vec_macros::<bevy_math::DVec2>(1.0, 2.0);
+    crate::tests::tests::test_axis::<bevy_math::Vec3>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_axis::<bevy_math::DVec3>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_int_storage::<bevy_math::Vec3>();
+    crate::tests::tests::test_int_storage::<bevy_math::DVec3>();
+    crate::tests::tests::test_segments_intersect::<bevy_math::Vec2>();
+    crate::tests::tests::test_segments_intersect::<bevy_math::DVec2>();
+    crate::tests::tests::test_summation::<bevy_math::Vec2>();
+    crate::tests::tests::test_summation::<bevy_math::DVec2>();
+    crate::tests::tests::test_checked::<bevy_math::Vec2>(1.0, 2.0);
+    crate::tests::tests::test_checked::<bevy_math::DVec2>(1.0, 2.0);
+    crate::tests::tests::test_distance_robust::<bevy_math::Vec3>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_distance_robust::<bevy_math::DVec3>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_strict_fp::<bevy_math::Vec3>(0.1, 0.7, -0.3);
+    crate::tests::tests::test_strict_fp::<bevy_math::DVec3>(0.1, 0.7, -0.3);
+    crate::tests::tests::test_vector4::<bevy_math::Vec4>();
+    crate::tests::tests::test_vector4::<bevy_math::DVec4>();
+    crate::tests::tests::test_sweep_cmp::<bevy_math::Vec2>();
+    crate::tests::tests::test_sweep_cmp::<bevy_math::DVec2>();
+    crate::tests::tests::test_snap::<bevy_math::Vec3>();
+    crate::tests::tests::test_snap::<bevy_math::DVec3>();
+    crate::tests::tests::test_angle::<bevy_math::Vec2>();
+    crate::tests::tests::test_angle::<bevy_math::DVec2>();
+    crate::tests::tests::test_interval::<bevy_math::Vec2>();
+    crate::tests::tests::test_interval::<bevy_math::DVec2>();
+    crate::tests::tests::test_error_bound::<bevy_math::Vec3>();
+    crate::tests::tests::test_error_bound::<bevy_math::DVec3>();
+    crate::tests::tests::test_sampling_2d::<bevy_math::Vec2>();
+    crate::tests::tests::test_sampling_2d::<bevy_math::DVec2>();
+    crate::tests::tests::test_sampling_3d::<bevy_math::Vec3>();
+    crate::tests::tests::test_sampling_3d::<bevy_math::DVec3>();
+    crate::tests::tests::test_euler::<bevy_math::Vec3>();
+    crate::tests::tests::test_euler::<bevy_math::DVec3>();
+    crate::tests::tests::test_look_rotation::<bevy_math::Vec3>();
+    crate::tests::tests::test_look_rotation::<bevy_math::DVec3>();
+    crate::tests::tests::test_bounding_sphere_2d::<bevy_math::Vec2>();
+    crate::tests::tests::test_bounding_sphere_2d::<bevy_math::DVec2>();
+    crate::tests::tests::test_bounding_sphere_3d::<bevy_math::Vec3>();
+    crate::tests::tests::test_bounding_sphere_3d::<bevy_math::DVec3>();
+    crate::tests::tests::test_principal_axes::<bevy_math::Vec3>();
+    crate::tests::tests::test_principal_axes::<bevy_math::DVec3>();
+    crate::tests::tests::test_fit_plane::<bevy_math::Vec3>();
+    crate::tests::tests::test_fit_plane::<bevy_math::DVec3>();
+    crate::tests::tests::test_grid_downsample_2d::<bevy_math::Vec2>();
+    crate::tests::tests::test_grid_downsample_2d::<bevy_math::DVec2>();
+    crate::tests::tests::test_grid_downsample_3d::<bevy_math::Vec3>();
+    crate::tests::tests::test_grid_downsample_3d::<bevy_math::DVec3>();
+    crate::tests::tests::test_weld_vertices_2d::<bevy_math::Vec2>();
+    crate::tests::tests::test_weld_vertices_2d::<bevy_math::DVec2>();
+    crate::tests::tests::test_weld_vertices_3d::<bevy_math::Vec3>();
+    crate::tests::tests::test_weld_vertices_3d::<bevy_math::DVec3>();
+    crate::tests::tests::test_direction_encoding::<bevy_math::Vec3>();
+    crate::tests::tests::test_direction_encoding::<bevy_math::DVec3>();
+    crate::tests::tests::test_meshgrid_2d::<bevy_math::Vec2>();
+    crate::tests::tests::test_meshgrid_2d::<bevy_math::DVec2>();
+    crate::tests::tests::test_meshgrid_3d::<bevy_math::Vec3>();
+    crate::tests::tests::test_meshgrid_3d::<bevy_math::DVec3>();
+    crate::tests::tests::test_convex_combination::<bevy_math::Vec2>();
+    crate::tests::tests::test_convex_combination::<bevy_math::DVec2>();
+    crate::tests::tests::test_rotate_90::<bevy_math::Vec2>();
+    crate::tests::tests::test_rotate_90::<bevy_math::DVec2>();
+    crate::tests::tests::test_orientation::<bevy_math::Vec2>();
+    crate::tests::tests::test_orientation::<bevy_math::DVec2>();
+    crate::tests::tests::test_project_onto_plane::<bevy_math::Vec3>();
+    crate::tests::tests::test_project_onto_plane::<bevy_math::DVec3>();
+    crate::tests::tests::test_segment_2d::<bevy_math::Vec2>();
+    crate::tests::tests::test_segment_2d::<bevy_math::DVec2>();
+    crate::tests::tests::test_segment_3d::<bevy_math::Vec3>();
+    crate::tests::tests::test_segment_3d::<bevy_math::DVec3>();
+    crate::tests::tests::test_distance_to_line::<bevy_math::Vec3>();
+    crate::tests::tests::test_distance_to_line::<bevy_math::DVec3>();
+    crate::tests::tests::test_signed_angle_about_axis::<bevy_math::Vec3>();
+    crate::tests::tests::test_signed_angle_about_axis::<bevy_math::DVec3>();
+    crate::tests::tests::test_running_stats::<bevy_math::Vec3>();
+    crate::tests::tests::test_running_stats::<bevy_math::DVec3>();
+    crate::tests::tests::test_flip::<bevy_math::Vec3>();
+    crate::tests::tests::test_flip::<bevy_math::DVec3>();
+    crate::tests::tests::test_drop_dominant_axis::<bevy_math::Vec3>();
+    crate::tests::tests::test_drop_dominant_axis::<bevy_math::DVec3>();
+    crate::tests::tests::test_cgmath_compat::<bevy_math::Vec3>();
+    crate::tests::tests::test_cgmath_compat::<bevy_math::DVec3>();
+    crate::tests::tests::test_sum::<bevy_math::Vec3>();
+    crate::tests::tests::test_sum::<bevy_math::DVec3>();
+    crate::tests::tests::test_scale::<bevy_math::Vec3>();
+    crate::tests::tests::test_scale::<bevy_math::DVec3>();
+    crate::tests::tests::test_batch::<bevy_math::Vec3>();
+    crate::tests::tests::test_batch::<bevy_math::DVec3>();
+}
+
+#[test]
+fn test_bevy_dir() {
+    fn angle<T: crate::GenericVector3>(a: T, b: T) -> T::Scalar {
+        crate::GenericVector3::angle_between(a, b)
+    }
+    // the unit directions go through the generic code as vectors
+    let a = angle(*bevy_math::Dir3::Z, bevy_math::Vec3::X);
+    assert!((a - std::f32::consts::FRAC_PI_2).abs() < 1e-6);
+}
+
+#[test]
+fn test_matrix3() {
+    crate::tests::tests::test_matrix3::<bevy_math::Mat3>();
+    crate::tests::tests::test_matrix3::<bevy_math::DMat3>();
+}
+
+#[test]
+fn test_matrix2() {
+    crate::tests::tests::test_matrix2::<bevy_math::Mat2>();
+    crate::tests::tests::test_matrix2::<bevy_math::DMat2>();
+}
+
+#[test]
+fn test_matrix4() {
+    crate::tests::tests::test_matrix4::<bevy_math::Mat4>();
+    crate::tests::tests::test_matrix4::<bevy_math::DMat4>();
+}
+
+#[test]
+fn test_affine2() {
+    crate::tests::tests::test_affine2::<bevy_math::Affine2>();
+    crate::tests::tests::test_affine2::<bevy_math::DAffine2>();
+}
+
+#[test]
+fn test_affine3() {
+    crate::tests::tests::test_affine3::<bevy_math::Affine3A>();
+    crate::tests::tests::test_affine3::<bevy_math::DAffine3>();
+}
+
+#[test]
+fn test_rotation3() {
+    crate::tests::tests::test_rotation3::<bevy_math::Quat>();
+    crate::tests::tests::test_rotation3::<bevy_math::DQuat>();
+}
+
+#[test]
+fn test_int_vector() {
+    crate::tests::tests::test_int_vector::<bevy_math::IVec3>();
+    crate::tests::tests::test_int_vector::<bevy_math::U64Vec3>();
+}
+
+#[test]
+fn test_vec3a() {
+    use super::Vec2A;
+    crate::tests::tests::test_xy::<Vec2A>(1.0, 2.0);
+    crate::tests::tests::test_xy::<bevy_math::Vec3A>(1.0, 2.0);
+    crate::tests::tests::test_xyz::<bevy_math::Vec3A>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_gxy::<Vec2A>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_generic_xy::<Vec2A>(1.0, 2.0, 3.0, 0.00000001);
+    crate::tests::tests::test_gxyz::<bevy_math::Vec3A>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_generic_xyz::<bevy_math::Vec3A>(1.0, 2.0, 3.0, 4.0, 0.0001);
+    crate::tests::tests::test_axis::<bevy_math::Vec3A>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_summation::<Vec2A>();
+}
+
+#[test]
+fn test_dir_views() {
+    use super::{DirXY, DirXYZ};
+    let d = bevy_math::Dir2::new(bevy_math::vec2(3.0, 4.0)).unwrap();
+    assert_eq!((d.x(), d.y()), (0.6, 0.8));
+    assert_eq!((d.get(1), d.get(2)), (Some(0.8), None));
+    let d = bevy_math::Dir3::new(bevy_math::vec3(0.0, 3.0, 4.0)).unwrap();
+    assert_eq!((d.x(), d.y(), d.z()), (0.0, 0.6, 0.8));
+    assert_eq!(d.get(2), Some(0.8));
+    let d = bevy_math::Dir3A::Z;
+    assert_eq!(d.z(), 1.0);
+    assert_eq!(crate::GenericVector3::dot(d.to_vector(), *d), 1.0);
+}
+
+#[test]
+fn test_mask() {
+    crate::tests::tests::test_mask_2d::<bevy_math::Vec2>();
+    crate::tests::tests::test_mask_3d::<bevy_math::DVec3>();
+}
+
+#[test]
+fn test_int_conversion() {
+    crate::tests::tests::test_int_conversion_2d::<bevy_math::Vec2>();
+    crate::tests::tests::test_int_conversion_3d::<bevy_math::DVec3>();
+}
@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `BigVec2` and `BigVec3`, vectors over an arbitrary precision `BigScalar`.
+//!
+//! The vector traits require `Copy` scalars, so arbitrary precision numbers can not be plugged
+//! into `GenericVector2` and the algorithms written against it. These vectors cover the exact
+//! verification of such algorithms instead: they are created from `f64` input without rounding,
+//! and their dot and cross products, determinants and squared distances are exact. Operations that
+//! would round, division and square roots, are left out.
+
+use crate::{BigScalar, HasXY, HasXYZ, Orientation, ScalarCore};
+use std::{
+    cmp::Ordering,
+    ops::{Add, Mul, Neg, Sub},
+};
+
+/// A two-dimensional vector over an arbitrary precision scalar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BigVec2<S: BigScalar> {
+    pub x: S,
+    pub y: S,
+}
+
+/// A three-dimensional vector over an arbitrary precision scalar.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BigVec3<S: BigScalar> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
+}
+
+impl<S: BigScalar> BigVec2<S> {
+    #[inline(always)]
+    pub const fn new(x: S, y: S) -> Self {
+        Self { x, y }
+    }
+
+    /// Converts a float vector exactly, or returns `None` if a component is not finite.
+    pub fn from_float<V: HasXY>(v: V) -> Option<Self> {
+        Some(Self::new(
+            S::from_f64_exact(v.x().into())?,
+            S::from_f64_exact(v.y().into())?,
+        ))
+    }
+
+    /// Converts to a float vector, rounding each component to the nearest value.
+    pub fn to_float<V: HasXY>(&self) -> V {
+        V::new_2d(
+            V::Scalar::from_f64_lossy(self.x.to_f64_lossy()),
+            V::Scalar::from_f64_lossy(self.y.to_f64_lossy()),
+        )
+    }
+
+    pub fn dot(&self, rhs: &Self) -> S {
+        self.x.clone() * rhs.x.clone() + self.y.clone() * rhs.y.clone()
+    }
+
+    /// Returns the z component of the cross product of the vectors extended to 3D, positive if
+    /// `rhs` is counterclockwise from `self`.
+    pub fn perp_dot(&self, rhs: &Self) -> S {
+        self.x.clone() * rhs.y.clone() - self.y.clone() * rhs.x.clone()
+    }
+
+    pub fn magnitude_sq(&self) -> S {
+        self.dot(self)
+    }
+
+    pub fn distance_sq(&self, rhs: &Self) -> S {
+        (self.clone() - rhs.clone()).magnitude_sq()
+    }
+
+    /// Returns the orientation of the points `a`, `b` and `c`, computed with the exact
+    /// determinant. This agrees with `orient2d()` and can be used to verify it.
+    pub fn orient2d(a: &Self, b: &Self, c: &Self) -> Orientation {
+        let det = (b.clone() - a.clone()).perp_dot(&(c.clone() - a.clone()));
+        match det.partial_cmp(&S::zero_value()) {
+            Some(Ordering::Greater) => Orientation::CounterClockwise,
+            Some(Ordering::Less) => Orientation::Clockwise,
+            _ => Orientation::Collinear,
+        }
+    }
+}
+
+impl<S: BigScalar> BigVec3<S> {
+    #[inline(always)]
+    pub const fn new(x: S, y: S, z: S) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Converts a float vector exactly, or returns `None` if a component is not finite.
+    pub fn from_float<V: HasXYZ>(v: V) -> Option<Self> {
+        Some(Self::new(
+            S::from_f64_exact(v.x().into())?,
+            S::from_f64_exact(v.y().into())?,
+            S::from_f64_exact(v.z().into())?,
+        ))
+    }
+
+    /// Converts to a float vector, rounding each component to the nearest value.
+    pub fn to_float<V: HasXYZ>(&self) -> V {
+        V::new_3d(
+            V::Scalar::from_f64_lossy(self.x.to_f64_lossy()),
+            V::Scalar::from_f64_lossy(self.y.to_f64_lossy()),
+            V::Scalar::from_f64_lossy(self.z.to_f64_lossy()),
+        )
+    }
+
+    pub fn dot(&self, rhs: &Self) -> S {
+        self.x.clone() * rhs.x.clone()
+            + self.y.clone() * rhs.y.clone()
+            + self.z.clone() * rhs.z.clone()
+    }
+
+    pub fn cross(&self, rhs: &Self) -> Self {
+        Self::new(
+            self.y.clone() * rhs.z.clone() - self.z.clone() * rhs.y.clone(),
+            self.z.clone() * rhs.x.clone() - self.x.clone() * rhs.z.clone(),
+            self.x.clone() * rhs.y.clone() - self.y.clone() * rhs.x.clone(),
+        )
+    }
+
+    pub fn magnitude_sq(&self) -> S {
+        self.dot(self)
+    }
+
+    pub fn distance_sq(&self, rhs: &Self) -> S {
+        (self.clone() - rhs.clone()).magnitude_sq()
+    }
+}
+
+macro_rules! impl_big_vec_ops {
+    ($vec_type:ident, $($c:ident),+) => {
+        impl<S: BigScalar> Add for $vec_type<S> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<S: BigScalar> Sub for $vec_type<S> {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<S: BigScalar> Mul<S> for $vec_type<S> {
+            type Output = Self;
+            fn mul(self, rhs: S) -> Self {
+                Self { $($c: self.$c * rhs.clone()),+ }
+            }
+        }
+
+        impl<S: BigScalar> Neg for $vec_type<S> {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+    };
+}
+
+impl_big_vec_ops!(BigVec2, x, y);
+impl_big_vec_ops!(BigVec3, x, y, z);
@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Bounding spheres (and circles) of point sets, for culling and collision broad-phase tests.
+//!
+//! `bounding_sphere_2d()` and `bounding_sphere_3d()` use Ritter's algorithm, a fast approximation
+//! that is typically 5-20% larger than the minimal sphere. The `_exact` variants use Welzl's
+//! algorithm and return the minimal sphere, in expected linear time. The radius of every variant
+//! is measured from the final center, so every point is inside the returned sphere.
+
+use crate::{math, sampling::SampleRng, GenericScalar, GenericVector2, GenericVector3};
+
+type Point<S> = [S; 3];
+
+#[inline(always)]
+fn sub<S: GenericScalar>(a: Point<S>, b: Point<S>) -> Point<S> {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+#[inline(always)]
+fn dot<S: GenericScalar>(a: Point<S>, b: Point<S>) -> S {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[inline(always)]
+fn distance_sq<S: GenericScalar>(a: Point<S>, b: Point<S>) -> S {
+    let d = sub(a, b);
+    dot(d, d)
+}
+
+/// Returns the point farthest from `from`.
+fn farthest<S: GenericScalar>(points: &[Point<S>], from: Point<S>) -> Point<S> {
+    points
+        .iter()
+        .copied()
+        .max_by(|a, b| distance_sq(*a, from).total_cmp(&distance_sq(*b, from)))
+        .unwrap_or(from)
+}
+
+/// Returns the center and the distance to the farthest point from it.
+fn enclose<S: GenericScalar>(points: &[Point<S>], center: Point<S>) -> (Point<S>, S) {
+    let radius_sq = points
+        .iter()
+        .map(|p| distance_sq(*p, center))
+        .fold(S::ZERO, num_traits::Float::max);
+    (center, math::sqrt(radius_sq))
+}
+
+fn ritter<S: GenericScalar>(points: &[Point<S>]) -> Option<(Point<S>, S)> {
+    let a = farthest(points, *points.first()?);
+    let b = farthest(points, a);
+    let half: S = 0.5.into();
+    let mut center = [
+        (a[0] + b[0]) * half,
+        (a[1] + b[1]) * half,
+        (a[2] + b[2]) * half,
+    ];
+    let mut radius = math::sqrt(distance_sq(a, b)) * half;
+    for p in points {
+        let d = math::sqrt(distance_sq(*p, center));
+        if d > radius {
+            // grow the sphere just enough to touch `p`, keeping the opposite side in place
+            let new_radius = (radius + d) * half;
+            let t = (new_radius - radius) / d;
+            let offset = sub(*p, center);
+            center = [
+                center[0] + offset[0] * t,
+                center[1] + offset[1] * t,
+                center[2] + offset[2] * t,
+            ];
+            radius = new_radius;
+        }
+    }
+    Some(enclose(points, center))
+}
+
+/// A candidate ball of Welzl's algorithm, `radius_sq` is negative for the empty ball.
+#[derive(Clone, Copy)]
+struct Ball<S> {
+    center: Point<S>,
+    radius_sq: S,
+}
+
+impl<S: GenericScalar> Ball<S> {
+    #[inline(always)]
+    fn contains(&self, p: Point<S>) -> bool {
+        // the tolerance keeps rounding errors from sending boundary points into the recursion
+        distance_sq(p, self.center) <= self.radius_sq * (S::ONE + S::EPSILON * 16.0.into())
+    }
+
+    /// Returns the smallest ball with all of `boundary` on its surface, or `None` if the
+    /// boundary points are affinely dependent.
+    fn through(boundary: &[Point<S>]) -> Option<Self> {
+        let (&origin, rest) = boundary.split_first()?;
+        // the center is origin + sum(l[i] * v[i]), equidistant to all the points:
+        // 2 * v[i].dot(c) = v[i].dot(v[i]) for every i
+        let v: Vec<Point<S>> = rest.iter().map(|p| sub(*p, origin)).collect();
+        let n = v.len();
+        let mut m = [[S::ZERO; 4]; 3];
+        for i in 0..n {
+            for j in 0..n {
+                m[i][j] = S::TWO * dot(v[i], v[j]);
+            }
+            m[i][3] = dot(v[i], v[i]);
+        }
+        let l = solve(&mut m, n)?;
+        let mut c = origin;
+        for (li, vi) in l.iter().zip(v.iter()).take(n) {
+            for k in 0..3 {
+                c[k] += *li * vi[k];
+            }
+        }
+        Some(Self {
+            center: c,
+            radius_sq: distance_sq(origin, c),
+        })
+    }
+}
+
+/// Solves the `n * n` system in `m`, with the right hand side in the last column, by Gaussian
+/// elimination with partial pivoting.
+fn solve<S: GenericScalar>(m: &mut [[S; 4]; 3], n: usize) -> Option<[S; 3]> {
+    let scale = m
+        .iter()
+        .take(n)
+        .flat_map(|row| row.iter().take(n))
+        .fold(S::ZERO, |a, b| {
+            num_traits::Float::max(a, num_traits::Float::abs(*b))
+        });
+    for col in 0..n {
+        let pivot = (col..n).max_by(|a, b| {
+            num_traits::Float::abs(m[*a][col]).total_cmp(&num_traits::Float::abs(m[*b][col]))
+        })?;
+        if num_traits::Float::abs(m[pivot][col]) <= scale * S::EPSILON * 64.0.into() {
+            return None;
+        }
+        m.swap(col, pivot);
+        let pivot_row = m[col];
+        for row in m.iter_mut().take(n).skip(col + 1) {
+            let f = row[col] / pivot_row[col];
+            for (r, p) in row.iter_mut().zip(pivot_row.iter()).skip(col) {
+                *r -= f * *p;
+            }
+        }
+    }
+    let mut x = [S::ZERO; 3];
+    for row in (0..n).rev() {
+        let mut sum = m[row][3];
+        for k in row + 1..n {
+            sum -= m[row][k] * x[k];
+        }
+        x[row] = sum / m[row][row];
+    }
+    Some(x)
+}
+
+/// Returns the minimal ball of `points[..n]` with `boundary` on its surface.
+fn welzl_ball<S: GenericScalar>(
+    points: &[Point<S>],
+    n: usize,
+    boundary: &mut Vec<Point<S>>,
+    dim: usize,
+) -> Ball<S> {
+    // an affinely dependent boundary is only reached through rounding, it is then enough to
+    // drop the last added point as the final radius is measured from the center anyway
+    let mut ball = (0..boundary.len())
+        .rev()
+        .find_map(|len| Ball::through(&boundary[..=len]))
+        .unwrap_or(Ball {
+            center: [S::ZERO; 3],
+            radius_sq: -S::ONE,
+        });
+    if boundary.len() == dim + 1 {
+        return ball;
+    }
+    for i in 0..n {
+        if !ball.contains(points[i]) {
+            boundary.push(points[i]);
+            ball = welzl_ball(points, i, boundary, dim);
+            let _ = boundary.pop();
+        }
+    }
+    ball
+}
+
+fn welzl<S: GenericScalar>(mut points: Vec<Point<S>>, dim: usize) -> Option<(Point<S>, S)> {
+    if points.is_empty() {
+        return None;
+    }
+    // a fixed seed keeps the result reproducible, the shuffle is only there to avoid the
+    // quadratic worst case of sorted input
+    let mut rng = SampleRng::new(0x5eed_b0b5);
+    for i in (1..points.len()).rev() {
+        let j = (rng.next_u64() % (i as u64 + 1)) as usize;
+        points.swap(i, j);
+    }
+    let ball = welzl_ball(&points, points.len(), &mut Vec::with_capacity(dim + 1), dim);
+    Some(enclose(&points, ball.center))
+}
+
+#[inline(always)]
+fn points_2d<T: GenericVector2, I: IntoIterator<Item = T>>(points: I) -> Vec<Point<T::Scalar>> {
+    points
+        .into_iter()
+        .map(|v| [v.x(), v.y(), T::Scalar::ZERO])
+        .collect()
+}
+
+#[inline(always)]
+fn points_3d<T: GenericVector3, I: IntoIterator<Item = T>>(points: I) -> Vec<Point<T::Scalar>> {
+    points.into_iter().map(|v| [v.x(), v.y(), v.z()]).collect()
+}
+
+/// Returns the center and radius of a bounding circle of `points`, using Ritter's algorithm.
+/// Returns `None` if there are no points.
+pub fn bounding_sphere_2d<T: GenericVector2, I: IntoIterator<Item = T>>(
+    points: I,
+) -> Option<(T, T::Scalar)> {
+    ritter(&points_2d(points)).map(|(c, r)| (T::new_2d(c[0], c[1]), r))
+}
+
+/// Returns the center and radius of a bounding sphere of `points`, using Ritter's algorithm.
+/// Returns `None` if there are no points.
+pub fn bounding_sphere_3d<T: GenericVector3, I: IntoIterator<Item = T>>(
+    points: I,
+) -> Option<(T, T::Scalar)> {
+    ritter(&points_3d(points)).map(|(c, r)| (T::new_3d(c[0], c[1], c[2]), r))
+}
+
+/// Returns the center and radius of the minimal bounding circle of `points`, using Welzl's
+/// algorithm. Returns `None` if there are no points.
+pub fn bounding_sphere_exact_2d<T: GenericVector2, I: IntoIterator<Item = T>>(
+    points: I,
+) -> Option<(T, T::Scalar)> {
+    welzl(points_2d(points), 2).map(|(c, r)| (T::new_2d(c[0], c[1]), r))
+}
+
+/// Returns the center and radius of the minimal bounding sphere of `points`, using Welzl's
+/// algorithm. Returns `None` if there are no points.
+pub fn bounding_sphere_exact_3d<T: GenericVector3, I: IntoIterator<Item = T>>(
+    points: I,
+) -> Option<(T, T::Scalar)> {
+    welzl(points_3d(points), 3).map(|(c, r)| (T::new_3d(c[0], c[1], c[2]), r))
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! The generic vector traits for the vector types of cgmath 0.17.
+//!
+//! The types of two cgmath versions are different types, so the impls of the `cgmath` feature,
+//! which links cgmath 0.18, do not apply to a downstream pinned to 0.17. This module implements
+//! the traits for `Vector2`, `Vector3`, `Vector4`, `Point2` and `Point3` of cgmath 0.17, and the
+//! matrix and rotation traits for its matrices, `Quaternion` and `Basis3`, with the same code as
+//! the `cgmath` backend. Both features can be enabled together.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    Approx, Axis2, Axis3, GenericAffine2, GenericAffine3, GenericMatrix2, GenericMatrix3,
+    GenericMatrix4, GenericRotation3, GenericScalar, GenericVector2, GenericVector3,
+    GenericVector4, HasXY, HasXYZ, HasXYZW,
+};
+use ::cgmath_017 as cgmath;
+pub use ::cgmath_017::{
+    Matrix2, Matrix3, Matrix4, MetricSpace, Point2, Point3, Vector2, Vector3, Vector4,
+};
+use approx::{AbsDiffEq, UlpsEq};
+use std::ops::Index;
+
+impl_cgmath_vector2!(Vector2<f32>, Vector3<f32>);
+impl_cgmath_vector2!(Vector2<f64>, Vector3<f64>);
+
+impl_cgmath_vector3!(Vector3<f32>, Vector2<f32>);
+impl_cgmath_vector3!(Vector3<f64>, Vector2<f64>);
+
+impl_cgmath_vector4!(Vector4<f32>, Vector3<f32>);
+impl_cgmath_vector4!(Vector4<f64>, Vector3<f64>);
+
+impl_cgmath_point!(Point2<f32>, Point3<f32>, f32);
+impl_cgmath_point!(Point2<f64>, Point3<f64>, f64);
+
+impl_cgmath_matrix3!(Matrix3<f32>, Vector2<f32>, Vector3<f32>, f32);
+impl_cgmath_matrix3!(Matrix3<f64>, Vector2<f64>, Vector3<f64>, f64);
+
+impl_cgmath_matrix2!(f32);
+impl_cgmath_matrix2!(f64);
+
+impl_cgmath_matrix4!(f32);
+impl_cgmath_matrix4!(f64);
+
+impl_cgmath_affine2!(f32);
+impl_cgmath_affine2!(f64);
+
+impl_cgmath_affine3!(f32);
+impl_cgmath_affine3!(f64);
+
+impl_cgmath_rotation3!(f32);
+impl_cgmath_rotation3!(f64);
+
+impl_cgmath_basis3!(f32);
+impl_cgmath_basis3!(f64);
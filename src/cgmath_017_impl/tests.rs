@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use cgmath_017 as cgmath;
+
+#[test]
+fn test_xy() {
+    crate::tests::tests::test_xy::<cgmath::Vector2<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<cgmath::Vector2<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<cgmath::Vector3<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<cgmath::Vector3<f64>>(1.0, 2.0);
+}
+
+#[test]
+fn test_xyz() {
+    crate::tests::tests::test_xyz::<cgmath::Vector3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_xyz::<cgmath::Vector3<f64>>(1.0, 2.0, 3.0);
+}
+
+#[test]
+fn test_gxy() {
+    crate::tests::tests::test_gxy::<cgmath::Vector2<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_gxy::<cgmath::Vector2<f64>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_generic_xy::<cgmath::Vector2<f32>>(1.0, 2.0, 3.0, 0.00000001);
+    crate::tests::tests::test_generic_xy::<cgmath::Vector2<f64>>(1.0, 2.0, 3.0, 0.0000000000001);
+}
+
+#[test]
+fn test_gxyz() {
+    crate::tests::tests::test_gxyz::<cgmath::Vector3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_gxyz::<cgmath::Vector3<f64>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_generic_xyz::<cgmath::Vector3<f32>>(1.0, 2.0, 3.0, 4.0, 0.0001);
+    crate::tests::tests::test_generic_xyz::<cgmath::Vector3<f64>>(
+        1.0,
+        2.0,
+        3.0,
+        4.0,
+        0.0000000000001,
+    );
+}
+
+#[test]
+fn test_vector4() {
+    crate::tests::tests::test_vector4::<cgmath::Vector4<f32>>();
+    crate::tests::tests::test_vector4::<cgmath::Vector4<f64>>();
+}
+
+#[test]
+fn test_points() {
+    crate::tests::tests::test_xy::<cgmath::Point2<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xyz::<cgmath::Point3<f64>>(1.0, 2.0, 3.0);
+    let p = cgmath::Point3::new(1.0, 2.0, 3.0);
+    assert_eq!(
+        crate::distance_robust(p, cgmath::Point3::new(4.0, 6.0, 3.0)),
+        5.0
+    );
+}
+
+#[test]
+fn test_matrix3() {
+    crate::tests::tests::test_matrix3::<cgmath::Matrix3<f32>>();
+    crate::tests::tests::test_matrix3::<cgmath::Matrix3<f64>>();
+}
+
+#[test]
+fn test_matrix2() {
+    crate::tests::tests::test_matrix2::<cgmath::Matrix2<f32>>();
+    crate::tests::tests::test_matrix2::<cgmath::Matrix2<f64>>();
+}
+
+#[test]
+fn test_matrix4() {
+    crate::tests::tests::test_matrix4::<cgmath::Matrix4<f32>>();
+    crate::tests::tests::test_matrix4::<cgmath::Matrix4<f64>>();
+}
+
+#[test]
+fn test_affine2() {
+    crate::tests::tests::test_affine2::<
+        cgmath::Decomposed<cgmath::Vector2<f64>, cgmath::Basis2<f64>>,
+    >();
+    crate::tests::tests::test_affine2::<crate::AffineMatrix3<cgmath::Matrix3<f64>>>();
+}
+
+#[test]
+fn test_affine3() {
+    crate::tests::tests::test_affine3::<
+        cgmath::Decomposed<cgmath::Vector3<f64>, cgmath::Quaternion<f64>>,
+    >();
+}
+
+#[test]
+fn test_rotation3() {
+    crate::tests::tests::test_rotation3::<cgmath::Quaternion<f64>>();
+}
+
+#[test]
+fn test_basis3() {
+    crate::tests::tests::test_matrix_rotation3::<cgmath::Basis3<f64>>();
+}
+
+#[test]
+fn test_mask() {
+    crate::tests::tests::test_mask_2d::<cgmath::Vector2<f64>>();
+    crate::tests::tests::test_mask_3d::<cgmath::Vector3<f32>>();
+}
+
+#[test]
+fn test_int_conversion() {
+    crate::tests::tests::test_int_conversion_2d::<cgmath::Vector2<f64>>();
+    crate::tests::tests::test_int_conversion_3d::<cgmath::Vector3<f32>>();
+}
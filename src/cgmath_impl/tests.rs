@@ -38,3 +38,355 @@ fn test_gxyz() {
         0.0000000000001,
     );
 }
+
+#[test]
+fn test_slices_approx() {
+    crate::tests::tests::test_slices_approx::<cgmath::Vector2<f32>>(1.0, 2.0);
+    crate::tests::tests::test_slices_approx::<cgmath::Vector2<f64>>(1.0, 2.0);
+}
+
+#[test]
+fn test_assert_vec_macros() {
+    crate::tests::tests::test_assert_vec_macros::<cgmath::Vector2<f32>>(1.0, 2.0);
+    crate::tests::tests::test_assert_vec_macros::<cgmath::Vector2<f64>>(1.0, 2.0);
+}
+
+#[test]
+fn test_axis() {
+    crate::tests::tests::test_axis::<cgmath::Vector3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_axis::<cgmath::Vector3<f64>>(1.0, 2.0, 3.0);
+}
+
+#[test]
+fn test_int_storage() {
+    crate::tests::tests::test_int_storage::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_int_storage::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_segments_intersect() {
+    crate::tests::tests::test_segments_intersect::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_segments_intersect::<cgmath::Vector2<f64>>();
+}
+
+#[test]
+fn test_summation() {
+    crate::tests::tests::test_summation::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_summation::<cgmath::Vector2<f64>>();
+}
+
+#[test]
+fn test_checked() {
+    crate::tests::tests::test_checked::<cgmath::Vector2<f64>>(1.0, 2.0);
+}
+
+#[test]
+fn test_distance_robust() {
+    crate::tests::tests::test_distance_robust::<cgmath::Vector3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_distance_robust::<cgmath::Vector3<f64>>(1.0, 2.0, 3.0);
+}
+
+#[test]
+fn test_strict_fp() {
+    crate::tests::tests::test_strict_fp::<cgmath::Vector3<f32>>(0.1, 0.7, -0.3);
+    crate::tests::tests::test_strict_fp::<cgmath::Vector3<f64>>(0.1, 0.7, -0.3);
+}
+
+#[test]
+fn test_sweep_cmp() {
+    crate::tests::tests::test_sweep_cmp::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_sweep_cmp::<cgmath::Vector2<f64>>();
+}
+
+#[test]
+fn test_snap() {
+    crate::tests::tests::test_snap::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_snap::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_angle() {
+    crate::tests::tests::test_angle::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_angle::<cgmath::Vector2<f64>>();
+}
+
+#[test]
+fn test_interval() {
+    crate::tests::tests::test_interval::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_interval::<cgmath::Vector2<f64>>();
+}
+
+#[test]
+fn test_error_bound() {
+    crate::tests::tests::test_error_bound::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_error_bound::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_sampling() {
+    crate::tests::tests::test_sampling_2d::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_sampling_2d::<cgmath::Vector2<f64>>();
+    crate::tests::tests::test_sampling_3d::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_sampling_3d::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_euler() {
+    crate::tests::tests::test_euler::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_euler::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_look_rotation() {
+    crate::tests::tests::test_look_rotation::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_look_rotation::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_bounding_sphere_2d() {
+    crate::tests::tests::test_bounding_sphere_2d::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_bounding_sphere_2d::<cgmath::Vector2<f64>>();
+}
+
+#[test]
+fn test_bounding_sphere_3d() {
+    crate::tests::tests::test_bounding_sphere_3d::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_bounding_sphere_3d::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_principal_axes() {
+    crate::tests::tests::test_principal_axes::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_principal_axes::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_fit_plane() {
+    crate::tests::tests::test_fit_plane::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_fit_plane::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_grid_downsample_2d() {
+    crate::tests::tests::test_grid_downsample_2d::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_grid_downsample_2d::<cgmath::Vector2<f64>>();
+}
+
+#[test]
+fn test_grid_downsample_3d() {
+    crate::tests::tests::test_grid_downsample_3d::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_grid_downsample_3d::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_weld_vertices_2d() {
+    crate::tests::tests::test_weld_vertices_2d::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_weld_vertices_2d::<cgmath::Vector2<f64>>();
+}
+
+#[test]
+fn test_weld_vertices_3d() {
+    crate::tests::tests::test_weld_vertices_3d::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_weld_vertices_3d::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_direction_encoding() {
+    crate::tests::tests::test_direction_encoding::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_direction_encoding::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_meshgrid_2d() {
+    crate::tests::tests::test_meshgrid_2d::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_meshgrid_2d::<cgmath::Vector2<f64>>();
+}
+
+#[test]
+fn test_meshgrid_3d() {
+    crate::tests::tests::test_meshgrid_3d::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_meshgrid_3d::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_convex_combination() {
+    crate::tests::tests::test_convex_combination::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_convex_combination::<cgmath::Vector2<f64>>();
+}
+
+#[test]
+fn test_rotate_90() {
+    crate::tests::tests::test_rotate_90::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_rotate_90::<cgmath::Vector2<f64>>();
+}
+
+#[test]
+fn test_orientation() {
+    crate::tests::tests::test_orientation::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_orientation::<cgmath::Vector2<f64>>();
+}
+
+#[test]
+fn test_project_onto_plane() {
+    crate::tests::tests::test_project_onto_plane::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_project_onto_plane::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_segment() {
+    crate::tests::tests::test_segment_2d::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_segment_2d::<cgmath::Vector2<f64>>();
+    crate::tests::tests::test_segment_3d::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_segment_3d::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_distance_to_line() {
+    crate::tests::tests::test_distance_to_line::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_distance_to_line::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_signed_angle_about_axis() {
+    crate::tests::tests::test_signed_angle_about_axis::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_signed_angle_about_axis::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_running_stats() {
+    crate::tests::tests::test_running_stats::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_running_stats::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_matrix3() {
+    crate::tests::tests::test_matrix3::<cgmath::Matrix3<f32>>();
+    crate::tests::tests::test_matrix3::<cgmath::Matrix3<f64>>();
+}
+
+#[test]
+fn test_matrix2() {
+    crate::tests::tests::test_matrix2::<cgmath::Matrix2<f32>>();
+    crate::tests::tests::test_matrix2::<cgmath::Matrix2<f64>>();
+}
+
+#[test]
+fn test_matrix4() {
+    crate::tests::tests::test_matrix4::<cgmath::Matrix4<f32>>();
+    crate::tests::tests::test_matrix4::<cgmath::Matrix4<f64>>();
+}
+
+#[test]
+fn test_affine2() {
+    crate::tests::tests::test_affine2::<
+        cgmath::Decomposed<cgmath::Vector2<f32>, cgmath::Basis2<f32>>,
+    >();
+    crate::tests::tests::test_affine2::<
+        cgmath::Decomposed<cgmath::Vector2<f64>, cgmath::Basis2<f64>>,
+    >();
+    crate::tests::tests::test_affine2::<crate::AffineMatrix3<cgmath::Matrix3<f64>>>();
+}
+
+#[test]
+fn test_affine3() {
+    crate::tests::tests::test_affine3::<
+        cgmath::Decomposed<cgmath::Vector3<f32>, cgmath::Quaternion<f32>>,
+    >();
+    crate::tests::tests::test_affine3::<
+        cgmath::Decomposed<cgmath::Vector3<f64>, cgmath::Quaternion<f64>>,
+    >();
+}
+
+#[test]
+fn test_rotation3() {
+    crate::tests::tests::test_rotation3::<cgmath::Quaternion<f32>>();
+    crate::tests::tests::test_rotation3::<cgmath::Quaternion<f64>>();
+}
+
+#[test]
+fn test_basis3() {
+    crate::tests::tests::test_matrix_rotation3::<cgmath::Basis3<f32>>();
+    crate::tests::tests::test_matrix_rotation3::<cgmath::Basis3<f64>>();
+}
+
+#[test]
+fn test_flip() {
+    crate::tests::tests::test_flip::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_flip::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_drop_dominant_axis() {
+    crate::tests::tests::test_drop_dominant_axis::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_drop_dominant_axis::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_cgmath_compat() {
+    crate::tests::tests::test_cgmath_compat::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_cgmath_compat::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_sum() {
+    crate::tests::tests::test_sum::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_sum::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_scale() {
+    crate::tests::tests::test_scale::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_scale::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_points() {
+    use crate::{Approx, Axis3, HasXYZ};
+    crate::tests::tests::test_xy::<cgmath::Point2<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<cgmath::Point2<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<cgmath::Point3<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<cgmath::Point3<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xyz::<cgmath::Point3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_xyz::<cgmath::Point3<f64>>(1.0, 2.0, 3.0);
+
+    let p = cgmath::Point3::new_3d(1.0, 2.0, 3.0);
+    assert_eq!(p[Axis3::Z], 3.0);
+    assert!(p.is_abs_diff_eq(cgmath::Point3::new(1.0, 2.0, 3.0 + 1e-9), 1e-6));
+    // the generic algorithms over HasXY take points directly
+    let q = cgmath::Point3::new(4.0, 6.0, 3.0);
+    assert_eq!(crate::distance_robust(p, q), 5.0);
+    assert_eq!(
+        crate::math::dot(cgmath::Point2::new(1.0, 2.0), cgmath::Point2::new(3.0, 4.0)),
+        11.0
+    );
+}
+
+#[test]
+fn test_batch() {
+    crate::tests::tests::test_batch::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_batch::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_flat() {
+    crate::tests::tests::test_flat::<cgmath::Vector3<f32>>();
+    crate::tests::tests::test_flat::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_vector4() {
+    crate::tests::tests::test_vector4::<cgmath::Vector4<f32>>();
+    crate::tests::tests::test_vector4::<cgmath::Vector4<f64>>();
+}
+
+#[test]
+fn test_mask() {
+    crate::tests::tests::test_mask_2d::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_mask_3d::<cgmath::Vector3<f64>>();
+}
+
+#[test]
+fn test_int_conversion() {
+    crate::tests::tests::test_int_conversion_2d::<cgmath::Vector2<f32>>();
+    crate::tests::tests::test_int_conversion_3d::<cgmath::Vector3<f64>>();
+}
@@ -0,0 +1,926 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! The trait impls of the cgmath vector types, shared by the `cgmath` backend and the
+//! `cgmath_017` backend, which links cgmath 0.17. The invoking module imports the traits and
+//! types the impls refer to, with the cgmath crate in scope as `cgmath`.
+
+macro_rules! impl_cgmath_vector2 {
+    ($vec2_type:ty, $vec3_type:ty) => {
+        impl HasXY for $vec2_type {
+            type Scalar = <$vec2_type as cgmath::VectorSpace>::Scalar;
+            const DIM: usize = 2;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                <$vec2_type>::new(x, y)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_2d(self.x.canonicalize(), self.y.canonicalize())
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_2d(self.x.flush_denormal(), self.y.flush_denormal())
+            }
+        }
+
+        impl Index<Axis2> for $vec2_type {
+            type Output = <Self as HasXY>::Scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis2) -> &Self::Output {
+                match axis {
+                    Axis2::X => &self.x,
+                    Axis2::Y => &self.y,
+                }
+            }
+        }
+
+        impl GenericVector2 for $vec2_type {
+            type Vector3 = $vec3_type;
+            type Mask = crate::Mask2;
+            type IntVector = crate::IntVec2<i32>;
+
+            #[inline(always)]
+            fn to_3d(self, z: Self::Scalar) -> Self::Vector3 {
+                <$vec3_type>::new(self.x, self.y, z)
+            }
+            #[inline(always)]
+            fn magnitude(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::magnitude_sq(self)),
+                    cgmath::InnerSpace::magnitude(self)
+                )
+            }
+            #[inline(always)]
+            fn magnitude_sq(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self, self),
+                    cgmath::InnerSpace::magnitude2(self)
+                )
+            }
+            #[inline(always)]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self, rhs),
+                    <$vec2_type as cgmath::InnerSpace>::dot(self, rhs)
+                )
+            }
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                strict_or!(
+                    self / crate::math::sqrt(Self::magnitude_sq(self)),
+                    <$vec2_type as cgmath::InnerSpace>::normalize(self)
+                )
+            }
+            #[inline(always)]
+            fn perp_dot(self, other: Self) -> Self::Scalar {
+                self.x * other.y - self.y * other.x
+            }
+            #[inline(always)]
+            fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                let l = Self::magnitude(self);
+                if l > epsilon && l.is_finite() {
+                    Some(self / l)
+                } else {
+                    None
+                }
+            }
+            #[inline(always)]
+            fn distance(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::distance_sq(self, rhs)),
+                    <$vec2_type as MetricSpace>::distance(self, rhs)
+                )
+            }
+            #[inline(always)]
+            fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self - rhs, self - rhs),
+                    <$vec2_type>::distance2(self, rhs)
+                )
+            }
+        }
+
+        impl Approx for $vec2_type {
+            #[inline(always)]
+            fn is_ulps_eq(
+                self,
+                other: Self,
+                epsilon: <Self::Scalar as AbsDiffEq>::Epsilon,
+                max_ulps: u32,
+            ) -> bool {
+                self.x.ulps_eq(&other.x, epsilon, max_ulps)
+                    && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+            }
+            #[inline(always)]
+            fn is_abs_diff_eq(
+                self,
+                other: Self,
+                epsilon: <Self::Scalar as AbsDiffEq>::Epsilon,
+            ) -> bool {
+                self.x.abs_diff_eq(&other.x, epsilon) && self.y.abs_diff_eq(&other.y, epsilon)
+            }
+        }
+    };
+}
+
+macro_rules! impl_cgmath_vector3 {
+    ($vec3_type:ty, $vec2_type:ty) => {
+        impl HasXY for $vec3_type {
+            type Scalar = <$vec3_type as cgmath::VectorSpace>::Scalar;
+            const DIM: usize = 3;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                <$vec3_type>::new(x, y, Self::Scalar::ZERO)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    2 => Some(self.z),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_3d(
+                    self.x.canonicalize(),
+                    self.y.canonicalize(),
+                    self.z.canonicalize(),
+                )
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_3d(
+                    self.x.flush_denormal(),
+                    self.y.flush_denormal(),
+                    self.z.flush_denormal(),
+                )
+            }
+        }
+
+        impl HasXYZ for $vec3_type {
+            #[inline(always)]
+            fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+                <$vec3_type>::new(x, y, z)
+            }
+            #[inline(always)]
+            fn z(self) -> Self::Scalar {
+                self.z
+            }
+            #[inline(always)]
+            fn set_z(&mut self, val: Self::Scalar) {
+                self.z = val
+            }
+            #[inline(always)]
+            fn z_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.z
+            }
+        }
+
+        impl Index<Axis3> for $vec3_type {
+            type Output = <Self as HasXY>::Scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis3) -> &Self::Output {
+                match axis {
+                    Axis3::X => &self.x,
+                    Axis3::Y => &self.y,
+                    Axis3::Z => &self.z,
+                }
+            }
+        }
+
+        impl GenericVector3 for $vec3_type {
+            type Vector2 = $vec2_type;
+            type Mask = crate::Mask3;
+            type IntVector = crate::IntVec3<i32>;
+            #[inline(always)]
+            fn to_2d(&self) -> Self::Vector2 {
+                Self::Vector2::new(self.x, self.y)
+            }
+            #[inline(always)]
+            fn magnitude(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::magnitude_sq(self)),
+                    cgmath::InnerSpace::magnitude(self)
+                )
+            }
+            #[inline(always)]
+            fn magnitude_sq(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self, self),
+                    cgmath::InnerSpace::magnitude2(self)
+                )
+            }
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                strict_or!(
+                    self / crate::math::sqrt(Self::magnitude_sq(self)),
+                    cgmath::InnerSpace::normalize(self)
+                )
+            }
+            #[inline(always)]
+            fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                let l = Self::magnitude(self);
+                if l > epsilon && l.is_finite() {
+                    Some(self / l)
+                } else {
+                    None
+                }
+            }
+            #[inline(always)]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self, rhs),
+                    <$vec3_type as cgmath::InnerSpace>::dot(self, rhs)
+                )
+            }
+            #[inline(always)]
+            fn cross(self, rhs: Self) -> Self {
+                <$vec3_type>::cross(self, rhs)
+            }
+            #[inline(always)]
+            fn distance(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::distance_sq(self, rhs)),
+                    <$vec3_type as MetricSpace>::distance(self, rhs)
+                )
+            }
+            #[inline(always)]
+            fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self - rhs, self - rhs),
+                    <$vec3_type>::distance2(self, rhs)
+                )
+            }
+        }
+
+        impl Approx for $vec3_type {
+            #[inline(always)]
+            fn is_ulps_eq(
+                self,
+                other: Self,
+                epsilon: <Self::Scalar as AbsDiffEq>::Epsilon,
+                max_ulps: u32,
+            ) -> bool {
+                self.x.ulps_eq(&other.x, epsilon, max_ulps)
+                    && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+                    && self.z.ulps_eq(&other.z, epsilon, max_ulps)
+            }
+            #[inline(always)]
+            fn is_abs_diff_eq(
+                self,
+                other: Self,
+                epsilon: <Self::Scalar as AbsDiffEq>::Epsilon,
+            ) -> bool {
+                self.x.abs_diff_eq(&other.x, epsilon)
+                    && self.y.abs_diff_eq(&other.y, epsilon)
+                    && self.z.abs_diff_eq(&other.z, epsilon)
+            }
+        }
+    };
+}
+
+macro_rules! impl_cgmath_vector4 {
+    ($vec4_type:ty, $vec3_type:ty) => {
+        impl HasXY for $vec4_type {
+            type Scalar = <$vec4_type as cgmath::VectorSpace>::Scalar;
+            const DIM: usize = 4;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                <$vec4_type>::new(x, y, Self::Scalar::ZERO, Self::Scalar::ZERO)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    2 => Some(self.z),
+                    3 => Some(self.w),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_4d(
+                    self.x.canonicalize(),
+                    self.y.canonicalize(),
+                    self.z.canonicalize(),
+                    self.w.canonicalize(),
+                )
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_4d(
+                    self.x.flush_denormal(),
+                    self.y.flush_denormal(),
+                    self.z.flush_denormal(),
+                    self.w.flush_denormal(),
+                )
+            }
+        }
+
+        impl HasXYZ for $vec4_type {
+            #[inline(always)]
+            fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+                <$vec4_type>::new(x, y, z, Self::Scalar::ZERO)
+            }
+            #[inline(always)]
+            fn z(self) -> Self::Scalar {
+                self.z
+            }
+            #[inline(always)]
+            fn set_z(&mut self, val: Self::Scalar) {
+                self.z = val
+            }
+            #[inline(always)]
+            fn z_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.z
+            }
+        }
+
+        impl HasXYZW for $vec4_type {
+            #[inline(always)]
+            fn new_4d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar, w: Self::Scalar) -> Self {
+                <$vec4_type>::new(x, y, z, w)
+            }
+            #[inline(always)]
+            fn w(self) -> Self::Scalar {
+                self.w
+            }
+            #[inline(always)]
+            fn set_w(&mut self, val: Self::Scalar) {
+                self.w = val
+            }
+            #[inline(always)]
+            fn w_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.w
+            }
+        }
+
+        impl GenericVector4 for $vec4_type {
+            type Vector3 = $vec3_type;
+            #[inline(always)]
+            fn truncate(self) -> Self::Vector3 {
+                <$vec4_type>::truncate(self)
+            }
+            #[inline(always)]
+            fn magnitude(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::magnitude_sq(self)),
+                    cgmath::InnerSpace::magnitude(self)
+                )
+            }
+            #[inline(always)]
+            fn magnitude_sq(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self, self),
+                    cgmath::InnerSpace::magnitude2(self)
+                )
+            }
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                strict_or!(
+                    self / crate::math::sqrt(Self::magnitude_sq(self)),
+                    cgmath::InnerSpace::normalize(self)
+                )
+            }
+            #[inline(always)]
+            fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                let l = Self::magnitude(self);
+                if l > epsilon && l.is_finite() {
+                    Some(self / l)
+                } else {
+                    None
+                }
+            }
+            #[inline(always)]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self, rhs),
+                    <$vec4_type as cgmath::InnerSpace>::dot(self, rhs)
+                )
+            }
+            #[inline(always)]
+            fn distance(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::distance_sq(self, rhs)),
+                    <$vec4_type as MetricSpace>::distance(self, rhs)
+                )
+            }
+            #[inline(always)]
+            fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self - rhs, self - rhs),
+                    <$vec4_type>::distance2(self, rhs)
+                )
+            }
+        }
+
+        impl Approx for $vec4_type {
+            #[inline(always)]
+            fn is_ulps_eq(
+                self,
+                other: Self,
+                epsilon: <Self::Scalar as AbsDiffEq>::Epsilon,
+                max_ulps: u32,
+            ) -> bool {
+                self.x.ulps_eq(&other.x, epsilon, max_ulps)
+                    && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+                    && self.z.ulps_eq(&other.z, epsilon, max_ulps)
+                    && self.w.ulps_eq(&other.w, epsilon, max_ulps)
+            }
+            #[inline(always)]
+            fn is_abs_diff_eq(
+                self,
+                other: Self,
+                epsilon: <Self::Scalar as AbsDiffEq>::Epsilon,
+            ) -> bool {
+                self.x.abs_diff_eq(&other.x, epsilon)
+                    && self.y.abs_diff_eq(&other.y, epsilon)
+                    && self.z.abs_diff_eq(&other.z, epsilon)
+                    && self.w.abs_diff_eq(&other.w, epsilon)
+            }
+        }
+    };
+}
+
+// cgmath points are storage only: the difference of two points is a vector and points can not be
+// added, so they implement HasXY, HasXYZ and Approx but not the generic vector traits.
+macro_rules! impl_cgmath_point {
+    ($point2_type:ty, $point3_type:ty, $scalar:ty) => {
+        impl HasXY for $point2_type {
+            type Scalar = $scalar;
+            const DIM: usize = 2;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                <$point2_type>::new(x, y)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_2d(self.x.canonicalize(), self.y.canonicalize())
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_2d(self.x.flush_denormal(), self.y.flush_denormal())
+            }
+        }
+
+        impl Index<Axis2> for $point2_type {
+            type Output = $scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis2) -> &Self::Output {
+                match axis {
+                    Axis2::X => &self.x,
+                    Axis2::Y => &self.y,
+                }
+            }
+        }
+
+        impl Approx for $point2_type {}
+
+        impl HasXY for $point3_type {
+            type Scalar = $scalar;
+            const DIM: usize = 3;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                <$point3_type>::new(x, y, Self::Scalar::ZERO)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    2 => Some(self.z),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_3d(
+                    self.x.canonicalize(),
+                    self.y.canonicalize(),
+                    self.z.canonicalize(),
+                )
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_3d(
+                    self.x.flush_denormal(),
+                    self.y.flush_denormal(),
+                    self.z.flush_denormal(),
+                )
+            }
+        }
+
+        impl HasXYZ for $point3_type {
+            #[inline(always)]
+            fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+                <$point3_type>::new(x, y, z)
+            }
+            #[inline(always)]
+            fn z(self) -> Self::Scalar {
+                self.z
+            }
+            #[inline(always)]
+            fn set_z(&mut self, val: Self::Scalar) {
+                self.z = val
+            }
+            #[inline(always)]
+            fn z_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.z
+            }
+        }
+
+        impl Index<Axis3> for $point3_type {
+            type Output = $scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis3) -> &Self::Output {
+                match axis {
+                    Axis3::X => &self.x,
+                    Axis3::Y => &self.y,
+                    Axis3::Z => &self.z,
+                }
+            }
+        }
+
+        impl Approx for $point3_type {}
+    };
+}
+
+macro_rules! impl_cgmath_matrix3 {
+    ($mat_type:ty, $vec2_type:ty, $vec3_type:ty, $scalar:ty) => {
+        impl GenericMatrix3 for $mat_type {
+            type Scalar = $scalar;
+            type Vector2 = $vec2_type;
+            type Vector3 = $vec3_type;
+            #[inline(always)]
+            fn from_cols(x: $vec3_type, y: $vec3_type, z: $vec3_type) -> Self {
+                <$mat_type>::from_cols(x, y, z)
+            }
+            #[inline(always)]
+            fn col(&self, index: usize) -> $vec3_type {
+                self[index]
+            }
+        }
+    };
+}
+
+macro_rules! impl_cgmath_matrix2 {
+    ($scalar:ty) => {
+        impl GenericMatrix2 for Matrix2<$scalar> {
+            type Scalar = $scalar;
+            type Vector2 = Vector2<$scalar>;
+            #[inline(always)]
+            fn from_cols(x: Vector2<$scalar>, y: Vector2<$scalar>) -> Self {
+                Matrix2::from_cols(x, y)
+            }
+            #[inline(always)]
+            fn col(&self, index: usize) -> Vector2<$scalar> {
+                self[index]
+            }
+        }
+    };
+}
+
+macro_rules! impl_cgmath_matrix4 {
+    ($scalar:ty) => {
+        impl GenericMatrix4 for Matrix4<$scalar> {
+            type Scalar = $scalar;
+            type Vector3 = Vector3<$scalar>;
+            type Vector4 = Vector4<$scalar>;
+            type Matrix3 = Matrix3<$scalar>;
+            #[inline(always)]
+            fn from_cols(
+                x: Vector4<$scalar>,
+                y: Vector4<$scalar>,
+                z: Vector4<$scalar>,
+                w: Vector4<$scalar>,
+            ) -> Self {
+                Matrix4::from_cols(x, y, z, w)
+            }
+            #[inline(always)]
+            fn col(&self, index: usize) -> Vector4<$scalar> {
+                self[index]
+            }
+        }
+    };
+}
+
+// the cgmath 2D placement: a uniform scale, a rotation and a displacement
+macro_rules! impl_cgmath_affine2 {
+    ($scalar:ty) => {
+        impl GenericAffine2 for cgmath::Decomposed<Vector2<$scalar>, cgmath::Basis2<$scalar>> {
+            type Scalar = $scalar;
+            type Vector2 = Vector2<$scalar>;
+            #[inline(always)]
+            fn identity() -> Self {
+                Self::from_scale_angle_translation(1.0, 0.0, Vector2::new(0.0, 0.0))
+            }
+            #[inline(always)]
+            fn from_scale_angle_translation(
+                scale: $scalar,
+                angle: $scalar,
+                translation: Vector2<$scalar>,
+            ) -> Self {
+                Self {
+                    scale,
+                    rot: cgmath::Rotation2::from_angle(cgmath::Rad(angle)),
+                    disp: translation,
+                }
+            }
+            #[inline(always)]
+            fn translation(&self) -> Vector2<$scalar> {
+                self.disp
+            }
+            #[inline(always)]
+            fn transform_point2(&self, p: Vector2<$scalar>) -> Vector2<$scalar> {
+                let p = <Self as cgmath::Transform<Point2<$scalar>>>::transform_point(
+                    self,
+                    Point2::new(p.x, p.y),
+                );
+                Vector2::new(p.x, p.y)
+            }
+            #[inline(always)]
+            fn transform_vector2(&self, v: Vector2<$scalar>) -> Vector2<$scalar> {
+                <Self as cgmath::Transform<Point2<$scalar>>>::transform_vector(self, v)
+            }
+            #[inline(always)]
+            fn try_inverse(&self) -> Option<Self> {
+                <Self as cgmath::Transform<Point2<$scalar>>>::inverse_transform(self)
+            }
+            #[inline(always)]
+            fn compose(&self, other: &Self) -> Self {
+                <Self as cgmath::Transform<Point2<$scalar>>>::concat(self, other)
+            }
+        }
+    };
+}
+
+// the cgmath 3D placement: a uniform scale, a quaternion rotation and a displacement
+macro_rules! impl_cgmath_affine3 {
+    ($scalar:ty) => {
+        impl GenericAffine3 for cgmath::Decomposed<Vector3<$scalar>, cgmath::Quaternion<$scalar>> {
+            type Scalar = $scalar;
+            type Vector3 = Vector3<$scalar>;
+            #[inline(always)]
+            fn identity() -> Self {
+                Self {
+                    scale: 1.0,
+                    rot: cgmath::Quaternion::new(1.0, 0.0, 0.0, 0.0),
+                    disp: Vector3::new(0.0, 0.0, 0.0),
+                }
+            }
+            #[inline(always)]
+            fn from_scale_axis_angle_translation(
+                scale: $scalar,
+                axis: Vector3<$scalar>,
+                angle: $scalar,
+                translation: Vector3<$scalar>,
+            ) -> Self {
+                Self {
+                    scale,
+                    rot: cgmath::Rotation3::from_axis_angle(axis, cgmath::Rad(angle)),
+                    disp: translation,
+                }
+            }
+            #[inline(always)]
+            fn translation(&self) -> Vector3<$scalar> {
+                self.disp
+            }
+            #[inline(always)]
+            fn transform_point3(&self, p: Vector3<$scalar>) -> Vector3<$scalar> {
+                let p = <Self as cgmath::Transform<Point3<$scalar>>>::transform_point(
+                    self,
+                    Point3::new(p.x, p.y, p.z),
+                );
+                Vector3::new(p.x, p.y, p.z)
+            }
+            #[inline(always)]
+            fn transform_vector3(&self, v: Vector3<$scalar>) -> Vector3<$scalar> {
+                <Self as cgmath::Transform<Point3<$scalar>>>::transform_vector(self, v)
+            }
+            #[inline(always)]
+            fn try_inverse(&self) -> Option<Self> {
+                <Self as cgmath::Transform<Point3<$scalar>>>::inverse_transform(self)
+            }
+            #[inline(always)]
+            fn compose(&self, other: &Self) -> Self {
+                <Self as cgmath::Transform<Point3<$scalar>>>::concat(self, other)
+            }
+        }
+    };
+}
+
+macro_rules! impl_cgmath_rotation3 {
+    ($scalar:ty) => {
+        impl GenericRotation3 for cgmath::Quaternion<$scalar> {
+            type Scalar = $scalar;
+            type Vector3 = Vector3<$scalar>;
+            type Matrix3 = Matrix3<$scalar>;
+            #[inline(always)]
+            fn from_xyzw(x: $scalar, y: $scalar, z: $scalar, w: $scalar) -> Self {
+                Self::new(w, x, y, z)
+            }
+            #[inline(always)]
+            fn to_xyzw(self) -> [$scalar; 4] {
+                [self.v.x, self.v.y, self.v.z, self.s]
+            }
+            #[inline(always)]
+            fn from_axis_angle(axis: Vector3<$scalar>, angle: $scalar) -> Self {
+                cgmath::Rotation3::from_axis_angle(axis, cgmath::Rad(angle))
+            }
+            #[inline(always)]
+            fn rotate_vector(self, v: Vector3<$scalar>) -> Vector3<$scalar> {
+                self * v
+            }
+            #[inline(always)]
+            fn compose(self, other: Self) -> Self {
+                self * other
+            }
+            #[inline(always)]
+            fn to_matrix(self) -> Matrix3<$scalar> {
+                self.into()
+            }
+        }
+    };
+}
+
+// the cgmath rotation matrix, converted to and from quaternions for the components
+macro_rules! impl_cgmath_basis3 {
+    ($scalar:ty) => {
+        impl GenericRotation3 for cgmath::Basis3<$scalar> {
+            type Scalar = $scalar;
+            type Vector3 = Vector3<$scalar>;
+            type Matrix3 = Matrix3<$scalar>;
+            #[inline(always)]
+            fn from_xyzw(x: $scalar, y: $scalar, z: $scalar, w: $scalar) -> Self {
+                cgmath::Quaternion::new(w, x, y, z).into()
+            }
+            #[inline(always)]
+            fn to_xyzw(self) -> [$scalar; 4] {
+                let q = cgmath::Quaternion::from(self);
+                [q.v.x, q.v.y, q.v.z, q.s]
+            }
+            #[inline(always)]
+            fn from_axis_angle(axis: Vector3<$scalar>, angle: $scalar) -> Self {
+                cgmath::Rotation3::from_axis_angle(axis, cgmath::Rad(angle))
+            }
+            #[inline(always)]
+            fn rotate_vector(self, v: Vector3<$scalar>) -> Vector3<$scalar> {
+                cgmath::Rotation::rotate_vector(&self, v)
+            }
+            #[inline(always)]
+            fn compose(self, other: Self) -> Self {
+                self * other
+            }
+            #[inline(always)]
+            fn to_matrix(self) -> Matrix3<$scalar> {
+                self.into()
+            }
+        }
+    };
+}
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Checked vector arithmetic that reports where non-finite values (NaN or infinity) first appear.
+
+use crate::HasXY;
+use num_traits::Float;
+use std::{
+    fmt::{Display, Formatter},
+    ops::{Add, Div, Mul, Sub},
+    panic::Location,
+};
+
+/// The operation that produced a non-finite result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CheckedOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    /// A value checked with `CheckedVector::check_finite()`.
+    Check,
+}
+
+/// The error returned when a checked operation produces a vector with a non-finite component.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NonFiniteError<T: HasXY> {
+    /// The operation that produced the result.
+    pub op: CheckedOp,
+    /// The left hand side of the operation.
+    pub lhs: T,
+    /// The non-finite result.
+    pub result: T,
+    /// The source location of the call to the checked operation.
+    pub location: &'static Location<'static>,
+}
+
+impl<T: HasXY> NonFiniteError<T> {
+    /// Returns true if `lhs` was already non-finite, i.e. the NaN or infinity entered earlier.
+    pub fn lhs_was_non_finite(&self) -> bool {
+        !is_finite(self.lhs)
+    }
+}
+
+impl<T: HasXY> Display for NonFiniteError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?} of {:?} produced the non-finite result {:?} at {}",
+            self.op, self.lhs, self.result, self.location
+        )
+    }
+}
+
+impl<T: HasXY> std::error::Error for NonFiniteError<T> {}
+
+/// Returns true if every component of `v` is finite.
+#[inline(always)]
+pub fn is_finite<T: HasXY>(v: T) -> bool {
+    (0..T::DIM).filter_map(|i| v.get(i)).all(Float::is_finite)
+}
+
+/// Arithmetic that returns an error when any component of the result is not finite.
+///
+/// This is implemented for every vector type with the required operators, including all the
+/// `GenericVector2` and `GenericVector3` types.
+pub trait CheckedVector:
+    HasXY
+    + Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + Mul<Self::Scalar, Output = Self>
+    + Div<Self::Scalar, Output = Self>
+{
+    /// Returns `self` if every component is finite.
+    #[track_caller]
+    #[inline(always)]
+    fn check_finite(self) -> Result<Self, NonFiniteError<Self>> {
+        checked(CheckedOp::Check, self, self)
+    }
+
+    #[track_caller]
+    #[inline(always)]
+    fn checked_add(self, rhs: Self) -> Result<Self, NonFiniteError<Self>> {
+        checked(CheckedOp::Add, self, self + rhs)
+    }
+
+    #[track_caller]
+    #[inline(always)]
+    fn checked_sub(self, rhs: Self) -> Result<Self, NonFiniteError<Self>> {
+        checked(CheckedOp::Sub, self, self - rhs)
+    }
+
+    #[track_caller]
+    #[inline(always)]
+    fn checked_mul(self, rhs: Self::Scalar) -> Result<Self, NonFiniteError<Self>> {
+        checked(CheckedOp::Mul, self, self * rhs)
+    }
+
+    #[track_caller]
+    #[inline(always)]
+    fn checked_div(self, rhs: Self::Scalar) -> Result<Self, NonFiniteError<Self>> {
+        checked(CheckedOp::Div, self, self / rhs)
+    }
+}
+
+impl<T> CheckedVector for T where
+    T: HasXY
+        + Add<T, Output = T>
+        + Sub<T, Output = T>
+        + Mul<T::Scalar, Output = T>
+        + Div<T::Scalar, Output = T>
+{
+}
+
+#[track_caller]
+#[inline(always)]
+fn checked<T: HasXY>(op: CheckedOp, lhs: T, result: T) -> Result<T, NonFiniteError<T>> {
+    if is_finite(result) {
+        Ok(result)
+    } else {
+        Err(NonFiniteError {
+            op,
+            lhs,
+            result,
+            location: Location::caller(),
+        })
+    }
+}
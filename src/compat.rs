@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Method names from other vector libraries, for moving existing code onto the generic traits
+//! without renaming every call site at once.
+
+use crate::{math, GenericScalar, HasXY};
+use std::ops::{Add, Mul, Sub};
+
+/// The `cgmath` names of common vector operations, for any 2D or 3D vector type.
+///
+/// Replace `use cgmath::{InnerSpace, VectorSpace}` with `use vector_traits::CgmathCompat` and
+/// the calls keep compiling against a generic `T: GenericVector2` or `T: GenericVector3`. Having
+/// both in scope makes the calls on `cgmath` types ambiguous. Angles are plain radians rather than
+/// `cgmath::Rad`.
+pub trait CgmathCompat: HasXY {
+    /// Returns the squared length of the vector, like `magnitude_sq()`.
+    fn magnitude2(self) -> Self::Scalar;
+    /// Returns the vector scaled to the length `magnitude`. As in `cgmath`, the zero vector
+    /// gives NaN components.
+    fn normalize_to(self, magnitude: Self::Scalar) -> Self;
+    /// Returns the unsigned angle between the vectors, in radians in the range [0, pi], like
+    /// `angle_between()`.
+    fn angle(self, other: Self) -> Self::Scalar;
+    /// Returns the linear interpolation `self + (other - self) * amount`.
+    fn lerp(self, other: Self, amount: Self::Scalar) -> Self;
+}
+
+impl<T> CgmathCompat for T
+where
+    T: HasXY + Add<T, Output = T> + Sub<T, Output = T> + Mul<T::Scalar, Output = T>,
+{
+    #[inline(always)]
+    fn magnitude2(self) -> Self::Scalar {
+        math::dot(self, self)
+    }
+
+    #[inline(always)]
+    fn normalize_to(self, magnitude: Self::Scalar) -> Self {
+        self * (magnitude / math::sqrt(self.magnitude2()))
+    }
+
+    #[inline(always)]
+    fn angle(self, other: Self) -> Self::Scalar {
+        // Kahan's formula works in any dimension and stays accurate near 0 and pi
+        let a = self * math::sqrt(other.magnitude2());
+        let b = other * math::sqrt(self.magnitude2());
+        let (d, s) = (a - b, a + b);
+        T::Scalar::TWO * math::atan2(math::sqrt(d.magnitude2()), math::sqrt(s.magnitude2()))
+    }
+
+    #[inline(always)]
+    fn lerp(self, other: Self, amount: Self::Scalar) -> Self {
+        self + (other - self) * amount
+    }
+}
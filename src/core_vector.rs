@@ -0,0 +1,245 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `CoreVec2` and `CoreVec3`, vectors over any `ScalarCore` scalar.
+//!
+//! The vector traits require `GenericScalar`, the `f32`/`f64` interface. Scalars that only
+//! implement the float-agnostic `ScalarCore`, the fixed point types of the `fixed` feature, the
+//! double-double `TwoFloat` of the `twofloat` feature and the software floats of the `softfloat`
+//! feature, use these vectors instead. They provide the operators, dot and cross products and
+//! magnitudes, and convert to and from every `HasXY`/`HasXYZ` type.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{HasXY, HasXYZ, ScalarCore};
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+/// A two-dimensional vector over a `ScalarCore` scalar, with C layout.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CoreVec2<S: ScalarCore> {
+    pub x: S,
+    pub y: S,
+}
+
+/// A three-dimensional vector over a `ScalarCore` scalar, with C layout.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CoreVec3<S: ScalarCore> {
+    pub x: S,
+    pub y: S,
+    pub z: S,
+}
+
+impl<S: ScalarCore> CoreVec2<S> {
+    #[inline(always)]
+    pub const fn new(x: S, y: S) -> Self {
+        Self { x, y }
+    }
+
+    #[inline(always)]
+    pub fn dot(self, rhs: Self) -> S {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// Returns the z component of the cross product of the vectors extended to 3D, positive if
+    /// `rhs` is counterclockwise from `self`.
+    #[inline(always)]
+    pub fn perp_dot(self, rhs: Self) -> S {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    #[inline(always)]
+    pub fn magnitude_sq(self) -> S {
+        self.dot(self)
+    }
+
+    #[inline(always)]
+    pub fn magnitude(self) -> S {
+        self.magnitude_sq().sqrt_value()
+    }
+
+    #[inline(always)]
+    pub fn distance_sq(self, rhs: Self) -> S {
+        (self - rhs).magnitude_sq()
+    }
+
+    #[inline(always)]
+    pub fn distance(self, rhs: Self) -> S {
+        (self - rhs).magnitude()
+    }
+
+    #[inline(always)]
+    pub fn to_3d(self, z: S) -> CoreVec3<S> {
+        CoreVec3::new(self.x, self.y, z)
+    }
+
+    /// Converts to a float vector, rounding to the float precision.
+    #[inline(always)]
+    pub fn to_float<V: HasXY>(self) -> V {
+        V::new_2d(
+            V::Scalar::from_f64_lossy(self.x.to_f64_lossy()),
+            V::Scalar::from_f64_lossy(self.y.to_f64_lossy()),
+        )
+    }
+
+    /// Converts from a float vector, see `ScalarCore::from_f64_lossy()`.
+    #[inline(always)]
+    pub fn from_float<V: HasXY>(v: V) -> Self {
+        Self::new(
+            S::from_f64_lossy(v.x().to_f64_lossy()),
+            S::from_f64_lossy(v.y().to_f64_lossy()),
+        )
+    }
+}
+
+impl<S: ScalarCore> CoreVec3<S> {
+    #[inline(always)]
+    pub const fn new(x: S, y: S, z: S) -> Self {
+        Self { x, y, z }
+    }
+
+    #[inline(always)]
+    pub fn dot(self, rhs: Self) -> S {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    #[inline(always)]
+    pub fn cross(self, rhs: Self) -> Self {
+        Self::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+
+    #[inline(always)]
+    pub fn magnitude_sq(self) -> S {
+        self.dot(self)
+    }
+
+    #[inline(always)]
+    pub fn magnitude(self) -> S {
+        self.magnitude_sq().sqrt_value()
+    }
+
+    #[inline(always)]
+    pub fn distance_sq(self, rhs: Self) -> S {
+        (self - rhs).magnitude_sq()
+    }
+
+    #[inline(always)]
+    pub fn distance(self, rhs: Self) -> S {
+        (self - rhs).magnitude()
+    }
+
+    #[inline(always)]
+    pub fn to_2d(self) -> CoreVec2<S> {
+        CoreVec2::new(self.x, self.y)
+    }
+
+    /// Converts to a float vector, rounding to the float precision.
+    #[inline(always)]
+    pub fn to_float<V: HasXYZ>(self) -> V {
+        V::new_3d(
+            V::Scalar::from_f64_lossy(self.x.to_f64_lossy()),
+            V::Scalar::from_f64_lossy(self.y.to_f64_lossy()),
+            V::Scalar::from_f64_lossy(self.z.to_f64_lossy()),
+        )
+    }
+
+    /// Converts from a float vector, see `ScalarCore::from_f64_lossy()`.
+    #[inline(always)]
+    pub fn from_float<V: HasXYZ>(v: V) -> Self {
+        Self::new(
+            S::from_f64_lossy(v.x().to_f64_lossy()),
+            S::from_f64_lossy(v.y().to_f64_lossy()),
+            S::from_f64_lossy(v.z().to_f64_lossy()),
+        )
+    }
+}
+
+macro_rules! impl_core_vec_ops {
+    ($vec_type:ident, $($c:ident),+) => {
+        impl<S: ScalarCore> Add for $vec_type<S> {
+            type Output = Self;
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($c: self.$c + rhs.$c),+ }
+            }
+        }
+
+        impl<S: ScalarCore> Sub for $vec_type<S> {
+            type Output = Self;
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($c: self.$c - rhs.$c),+ }
+            }
+        }
+
+        impl<S: ScalarCore> Mul<S> for $vec_type<S> {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, rhs: S) -> Self {
+                Self { $($c: self.$c * rhs),+ }
+            }
+        }
+
+        impl<S: ScalarCore> Div<S> for $vec_type<S> {
+            type Output = Self;
+            #[inline(always)]
+            fn div(self, rhs: S) -> Self {
+                Self { $($c: self.$c / rhs),+ }
+            }
+        }
+
+        impl<S: ScalarCore> Neg for $vec_type<S> {
+            type Output = Self;
+            #[inline(always)]
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+
+        impl<S: ScalarCore> AddAssign for $vec_type<S> {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$c += rhs.$c;)+
+            }
+        }
+
+        impl<S: ScalarCore> SubAssign for $vec_type<S> {
+            #[inline(always)]
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$c -= rhs.$c;)+
+            }
+        }
+
+        impl<S: ScalarCore> Sum for $vec_type<S> {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::default(), |a, b| a + b)
+            }
+        }
+    };
+}
+
+impl_core_vec_ops!(CoreVec2, x, y);
+impl_core_vec_ops!(CoreVec3, x, y, z);
+
+impl<S: ScalarCore> From<[S; 2]> for CoreVec2<S> {
+    fn from(array: [S; 2]) -> Self {
+        Self::new(array[0], array[1])
+    }
+}
+
+impl<S: ScalarCore> From<[S; 3]> for CoreVec3<S> {
+    fn from(array: [S; 3]) -> Self {
+        Self::new(array[0], array[1], array[2])
+    }
+}
@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+#[test]
+fn test_core_vector() {
+    crate::tests::tests::test_core_vector::<f32>();
+    crate::tests::tests::test_core_vector::<f64>();
+}
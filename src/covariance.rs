@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Covariance and principal axes of point clouds, the building blocks of oriented bounding box
+//! fitting and normal estimation.
+//!
+//! The covariance is the population covariance (divided by the number of points), computed in
+//! two passes around the centroid so that point clouds far from the origin keep their precision.
+
+use crate::{GenericScalar, GenericVector3};
+use num_traits::{Float, FromPrimitive};
+
+/// The principal axes of a point cloud, as returned by `principal_axes()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrincipalAxes<V: GenericVector3> {
+    pub centroid: V,
+    /// Orthonormal axes, sorted by decreasing variance. The axes form a right handed frame.
+    pub axes: [V; 3],
+    /// The variance of the points along each of the axes.
+    pub variances: [V::Scalar; 3],
+}
+
+/// Returns the centroid of `points`, or `None` if there are no points.
+pub fn centroid3<V: GenericVector3>(points: &[V]) -> Option<V> {
+    let first = *points.first()?;
+    // summing the offsets from the first point keeps the precision of clouds far from the origin
+    let sum = points[1..].iter().fold(
+        V::new_3d(V::Scalar::ZERO, V::Scalar::ZERO, V::Scalar::ZERO),
+        |sum, p| sum + (*p - first),
+    );
+    Some(first + sum / V::Scalar::from_usize(points.len())?)
+}
+
+/// Returns the row major covariance matrix of `points`, or `None` if there are no points.
+pub fn covariance3<V: GenericVector3>(points: &[V]) -> Option<[[V::Scalar; 3]; 3]> {
+    Some(covariance_around(points, centroid3(points)?))
+}
+
+fn covariance_around<V: GenericVector3>(points: &[V], centroid: V) -> [[V::Scalar; 3]; 3] {
+    let mut c = [[V::Scalar::ZERO; 3]; 3];
+    for p in points {
+        let d = *p - centroid;
+        let d = [d.x(), d.y(), d.z()];
+        for (row, di) in c.iter_mut().zip(d) {
+            for (cij, dj) in row.iter_mut().zip(d) {
+                *cij += di * dj;
+            }
+        }
+    }
+    let n = V::Scalar::from_usize(points.len()).unwrap();
+    c.map(|row| row.map(|v| v / n))
+}
+
+/// Returns the eigenvalues and the matching unit eigenvectors of the symmetric matrix `m`, sorted
+/// by decreasing eigenvalue, using the cyclic Jacobi method.
+///
+/// The eigenvectors are the columns of the returned matrix, and form a right handed frame.
+pub fn symmetric_eigen3<S: GenericScalar>(m: &[[S; 3]; 3]) -> ([S; 3], [[S; 3]; 3]) {
+    let mut a = *m;
+    let mut v = [[S::ZERO; 3]; 3];
+    (0..3).for_each(|i| v[i][i] = S::ONE);
+    for _ in 0..64 {
+        let off = a[0][1] * a[0][1] + a[0][2] * a[0][2] + a[1][2] * a[1][2];
+        let diag = a[0][0] * a[0][0] + a[1][1] * a[1][1] + a[2][2] * a[2][2];
+        if off <= diag * S::EPSILON * S::EPSILON {
+            break;
+        }
+        for (p, q) in [(0, 1), (0, 2), (1, 2)] {
+            if a[p][q] == S::ZERO {
+                continue;
+            }
+            // the rotation that zeroes a[p][q], with |t| <= 1 for stability
+            let theta = (a[q][q] - a[p][p]) / (S::TWO * a[p][q]);
+            let t =
+                Float::signum(theta) / (Float::abs(theta) + Float::sqrt(theta * theta + S::ONE));
+            let t = if Float::is_finite(t) { t } else { S::ZERO };
+            let c = S::ONE / Float::sqrt(t * t + S::ONE);
+            let s = t * c;
+            for row in a.iter_mut() {
+                let (akp, akq) = (row[p], row[q]);
+                row[p] = c * akp - s * akq;
+                row[q] = s * akp + c * akq;
+            }
+            let (ap, aq) = (a[p], a[q]);
+            a[p] = std::array::from_fn(|k| c * ap[k] - s * aq[k]);
+            a[q] = std::array::from_fn(|k| s * ap[k] + c * aq[k]);
+            for row in v.iter_mut() {
+                let (vp, vq) = (row[p], row[q]);
+                row[p] = c * vp - s * vq;
+                row[q] = s * vp + c * vq;
+            }
+        }
+    }
+    let mut order = [0, 1, 2];
+    order.sort_by(|i, j| a[*j][*j].total_cmp(&a[*i][*i]));
+    let values = order.map(|i| a[i][i]);
+    let mut vectors = [[S::ZERO; 3]; 3];
+    for (col, i) in order.iter().enumerate() {
+        for row in 0..3 {
+            vectors[row][col] = v[row][*i];
+        }
+    }
+    // make the frame right handed, the third axis is the cross product of the first two
+    let cross = [
+        vectors[1][0] * vectors[2][1] - vectors[2][0] * vectors[1][1],
+        vectors[2][0] * vectors[0][1] - vectors[0][0] * vectors[2][1],
+        vectors[0][0] * vectors[1][1] - vectors[1][0] * vectors[0][1],
+    ];
+    if cross[0] * vectors[0][2] + cross[1] * vectors[1][2] + cross[2] * vectors[2][2] < S::ZERO {
+        vectors.iter_mut().for_each(|row| row[2] = -row[2]);
+    }
+    (values, vectors)
+}
+
+/// Returns the centroid, the principal axes and the variances along them of `points`, or `None`
+/// if there are no points.
+///
+/// Axes with equal variance, e.g. every axis of a single point, are an arbitrary orthonormal
+/// basis of their common subspace. If a coordinate is NaN or infinite the variances are NaN, and
+/// the axes are meaningless.
+pub fn principal_axes<V: GenericVector3>(points: &[V]) -> Option<PrincipalAxes<V>> {
+    let centroid = centroid3(points)?;
+    let (variances, vectors) = symmetric_eigen3(&covariance_around(points, centroid));
+    let axis = |col: usize| V::new_3d(vectors[0][col], vectors[1][col], vectors[2][col]);
+    Some(PrincipalAxes {
+        centroid,
+        axes: [axis(0), axis(1), axis(2)],
+        // rounding can make the variance of a flat direction slightly negative, NaN is kept
+        variances: variances.map(|v| {
+            if v < V::Scalar::ZERO {
+                V::Scalar::ZERO
+            } else {
+                v
+            }
+        }),
+    })
+}
@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Arbitrary precision scalars with `dashu_float::FBig`, for exact verification passes.
+//!
+//! `FBig` implements `BigScalar` with unlimited precision, so the `BigVec2` and `BigVec3` vectors
+//! over it compute exact dot and cross products, determinants and squared distances of `f64`
+//! input, at the cost of a heap allocation per value. `dashu` is used rather than `rug`, which
+//! needs the GMP C library, or `astro-float`, whose operations take an explicit precision and
+//! rounding mode instead of implementing the operator traits.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{BigScalar, BigVec2, BigVec3};
+pub use ::dashu_float::FBig;
+
+impl BigScalar for FBig {
+    #[inline(always)]
+    fn zero_value() -> Self {
+        FBig::ZERO
+    }
+    #[inline(always)]
+    fn from_f64_exact(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            // FBig has infinities, but its arithmetic panics on them
+            return None;
+        }
+        // precision 0 is unlimited, the ring operations then never round
+        FBig::try_from(value)
+            .ok()
+            .map(|v| v.with_precision(0).value())
+    }
+    #[inline(always)]
+    fn to_f64_lossy(&self) -> f64 {
+        self.to_f64().value()
+    }
+}
+
+/// A two-dimensional vector over `FBig`.
+pub type FBigVec2 = BigVec2<FBig>;
+
+/// A three-dimensional vector over `FBig`.
+pub type FBigVec3 = BigVec3<FBig>;
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{FBig, FBigVec2, FBigVec3};
+use crate::{orient2d, BigScalar};
+
+#[test]
+fn test_fbig_scalar() {
+    assert_eq!(FBig::from_f64_exact(f64::NAN), None);
+    assert_eq!(FBig::from_f64_exact(f64::INFINITY), None);
+    let big = FBig::from_f64_exact(1.0e300).unwrap();
+    let small = FBig::from_f64_exact(1.0e-300).unwrap();
+    // no rounding, the small value survives the round trip through the large one
+    assert_eq!((big.clone() + small.clone()) - big, small);
+    assert_eq!(small.to_f64_lossy(), 1.0e-300);
+}
+
+#[test]
+fn test_fbig_vec() {
+    let a = FBigVec3::from_float([1.0_f64, 2.0, 3.0]).unwrap();
+    let b = FBigVec3::from_float([4.0_f32, 5.0, 6.0]).unwrap();
+    assert_eq!(a.dot(&b).to_f64_lossy(), 32.0);
+    assert_eq!(a.cross(&b).to_float::<[f64; 3]>(), [-3.0, 6.0, -3.0]);
+    assert_eq!(a.distance_sq(&b).to_f64_lossy(), 27.0);
+    assert_eq!(
+        (-a.clone() + b.clone() - b).to_float::<[f64; 3]>(),
+        [-1.0, -2.0, -3.0]
+    );
+    assert_eq!(FBigVec3::from_float([f64::NAN, 0.0, 0.0]), None);
+
+    // the squared distances of these points to the origin differ by 2^-104, which f64 rounds away
+    let eps = f64::EPSILON;
+    let p = [1.0 + eps, 1.0 - eps];
+    let q = [1.0, 1.0];
+    assert_eq!(p[0] * p[0] + p[1] * p[1], q[0] * q[0] + q[1] * q[1]);
+    let (bp, bq) = (
+        FBigVec2::from_float(p).unwrap(),
+        FBigVec2::from_float(q).unwrap(),
+    );
+    assert!(bp.magnitude_sq() > bq.magnitude_sq());
+    assert_eq!(
+        (bp.magnitude_sq() - bq.magnitude_sq()).to_f64_lossy(),
+        2.0 * eps * eps
+    );
+
+    // the exact determinant agrees with the robust predicate
+    let (c, d) = ([0.5_f64, 0.5], [12.0_f64, 12.0]);
+    let e = [24.0_f64, 24.0 + eps * 16.0];
+    let big = |v: [f64; 2]| FBigVec2::from_float(v).unwrap();
+    assert_eq!(
+        FBigVec2::orient2d(&big(c), &big(d), &big(e)),
+        orient2d(c, d, e)
+    );
+    assert_eq!(
+        FBigVec2::orient2d(&big(c), &big(d), &big([24.0, 24.0])),
+        orient2d(c, d, [24.0, 24.0])
+    );
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Mappings between unit directions and the plane, for compact normal storage and for running
+//! 2D algorithms on direction data.
+//!
+//! The octahedral mapping covers the whole sphere with the square [-1, 1]², with low and
+//! fairly even distortion, which makes it the usual choice for storing normals. The stereographic
+//! projection is conformal (it keeps angles), so it suits algorithms working on the geometry of
+//! the directions, but it stretches towards the excluded south pole.
+
+use crate::{math, GenericScalar, GenericVector2, GenericVector3, HasXY, HasXYZ};
+use num_traits::Float;
+
+/// Returns 1 for positive values and zero, and -1 for negative values.
+#[inline(always)]
+fn sign_not_zero<S: GenericScalar>(v: S) -> S {
+    if v >= S::ZERO {
+        S::ONE
+    } else {
+        -S::ONE
+    }
+}
+
+/// Maps a direction to the square [-1, 1]², the upper hemisphere to the diamond
+/// `|x| + |y| <= 1` and the lower hemisphere to the corners outside of it.
+///
+/// `v` does not have to be normalized, but must not be the zero vector.
+#[inline(always)]
+pub fn octahedral_encode<V: GenericVector3>(v: V) -> V::Vector2 {
+    let l1 = Float::abs(v.x()) + Float::abs(v.y()) + Float::abs(v.z());
+    let (x, y) = (v.x() / l1, v.y() / l1);
+    if v.z() >= V::Scalar::ZERO {
+        V::Vector2::new_2d(x, y)
+    } else {
+        // fold the lower hemisphere over the edges of the diamond
+        V::Vector2::new_2d(
+            (V::Scalar::ONE - Float::abs(y)) * sign_not_zero(x),
+            (V::Scalar::ONE - Float::abs(x)) * sign_not_zero(y),
+        )
+    }
+}
+
+/// Maps a point of the square [-1, 1]² back to a unit direction, the inverse of
+/// `octahedral_encode()`.
+#[inline(always)]
+pub fn octahedral_decode<V: GenericVector2>(e: V) -> V::Vector3 {
+    let (x, y) = (e.x(), e.y());
+    let z = V::Scalar::ONE - Float::abs(x) - Float::abs(y);
+    let v = if z >= V::Scalar::ZERO {
+        V::Vector3::new_3d(x, y, z)
+    } else {
+        V::Vector3::new_3d(
+            (V::Scalar::ONE - Float::abs(y)) * sign_not_zero(x),
+            (V::Scalar::ONE - Float::abs(x)) * sign_not_zero(y),
+            z,
+        )
+    };
+    v.normalize()
+}
+
+/// Projects a direction from the south pole `(0, 0, -1)` onto the xy plane. The upper hemisphere
+/// maps to the unit disk and the lower hemisphere outside of it.
+///
+/// `v` does not have to be normalized. Returns `None` for the zero vector and for directions at
+/// (or rounding to) the south pole, which maps to infinity.
+#[inline(always)]
+pub fn stereographic_project<V: GenericVector3>(v: V) -> Option<V::Vector2> {
+    let v = v.safe_normalize()?;
+    let d = V::Scalar::ONE + v.z();
+    if d <= V::Scalar::ZERO {
+        return None;
+    }
+    let p = V::Vector2::new_2d(v.x() / d, v.y() / d);
+    (Float::is_finite(p.x()) && Float::is_finite(p.y())).then_some(p)
+}
+
+/// Maps a point of the plane back to a unit direction, the inverse of `stereographic_project()`.
+#[inline(always)]
+pub fn stereographic_unproject<V: GenericVector2>(p: V) -> V::Vector3 {
+    let r_sq = math::dot(p, p);
+    let d = V::Scalar::ONE + r_sq;
+    V::Vector3::new_3d(
+        V::Scalar::TWO * p.x() / d,
+        V::Scalar::TWO * p.y() / d,
+        (V::Scalar::ONE - r_sq) / d,
+    )
+}
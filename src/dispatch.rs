@@ -0,0 +1,610 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Runtime selection of the scalar precision, for the common `--precision=f64` command line
+//! option of tools that write their algorithms once against `GenericScalar`.
+//!
+//! `dispatch_scalar!` monomorphizes a call for both precisions and picks one at runtime. Where
+//! that would duplicate a whole call graph, `DynScalar`, `DynVector2` and `DynVector3` carry the
+//! precision as data instead: every operation matches on it and runs the `f32` or `f64` code of
+//! `ArrayVec2` and `ArrayVec3`. Operations that mix the precisions are evaluated in `f64`, which
+//! represents every `f32` exactly.
+//!
+//! The dynamic types can not implement `GenericScalar` and `GenericVector2`/`GenericVector3`,
+//! whose scalar type is fixed at compile time, so they have inherent methods with the same names.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    ArrayVec2, ArrayVec3, GenericScalar, GenericVector2, GenericVector3, HasXY, HasXYZ, ScalarCore,
+};
+use std::{
+    any::TypeId,
+    cmp::Ordering,
+    fmt::{Display, Formatter},
+    ops::{Add, Div, Mul, Neg, Sub},
+    str::FromStr,
+};
+
+/// The scalar type to run with, selected at runtime. See `dispatch_scalar!`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScalarKind {
+    F32,
+    #[default]
+    F64,
+}
+
+impl ScalarKind {
+    /// Returns the precision of the scalar type `S`.
+    #[inline]
+    pub fn of<S: GenericScalar>() -> Self {
+        if TypeId::of::<S>() == TypeId::of::<f32>() {
+            Self::F32
+        } else {
+            Self::F64
+        }
+    }
+
+    /// Returns the name of the scalar type, `"f32"` or `"f64"`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::F32 => "f32",
+            Self::F64 => "f64",
+        }
+    }
+}
+
+impl Display for ScalarKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The error of parsing a `ScalarKind` from anything but `f32`, `f64`, `single` or `double`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseScalarKindError(pub String);
+
+impl Display for ParseScalarKindError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "unknown precision {:?}, expected f32 or f64 (or single or double)",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseScalarKindError {}
+
+impl FromStr for ScalarKind {
+    type Err = ParseScalarKindError;
+
+    /// Parses `f32`, `f64`, `single` or `double`, ignoring case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "f32" | "single" => Ok(Self::F32),
+            "f64" | "double" => Ok(Self::F64),
+            _ => Err(ParseScalarKindError(s.to_string())),
+        }
+    }
+}
+
+/// Evaluates an expression with a type alias bound to `f32` or `f64`, selected by a runtime
+/// `ScalarKind`. Further aliases can pick one type per precision, e.g. the vector types.
+///
+/// Both branches are compiled, so the expression must type check for both scalar types, and the
+/// value of the expression must have the same type in both.
+///
+/// ```ignore
+/// let kind: ScalarKind = args.precision.parse()?;
+/// let area = dispatch_scalar!(kind, S => compute_area::<S>(&input));
+/// dispatch_scalar!(kind, S, V2 = (glam::Vec2, glam::DVec2) => run::<V2>(&input));
+/// ```
+#[macro_export]
+macro_rules! dispatch_scalar {
+    ($kind:expr, $scalar:ident $(, $alias:ident = ($f32_type:ty, $f64_type:ty))* => $body:expr $(,)?) => {
+        match $kind {
+            $crate::ScalarKind::F32 => {
+                #[allow(dead_code)]
+                type $scalar = f32;
+                $(
+                    #[allow(dead_code)]
+                    type $alias = $f32_type;
+                )*
+                $body
+            }
+            $crate::ScalarKind::F64 => {
+                #[allow(dead_code)]
+                type $scalar = f64;
+                $(
+                    #[allow(dead_code)]
+                    type $alias = $f64_type;
+                )*
+                $body
+            }
+        }
+    };
+}
+
+/// A scalar of the precision selected at runtime.
+#[derive(Debug, Clone, Copy)]
+pub enum DynScalar {
+    F32(f32),
+    F64(f64),
+}
+
+impl DynScalar {
+    /// Returns `value` rounded to the precision `kind`.
+    #[inline]
+    pub fn new(kind: ScalarKind, value: f64) -> Self {
+        match kind {
+            ScalarKind::F32 => Self::F32(value as f32),
+            ScalarKind::F64 => Self::F64(value),
+        }
+    }
+
+    /// Returns the precision of the scalar.
+    #[inline]
+    pub const fn kind(self) -> ScalarKind {
+        match self {
+            Self::F32(_) => ScalarKind::F32,
+            Self::F64(_) => ScalarKind::F64,
+        }
+    }
+
+    /// Returns the scalar as `f64`, which is exact for both precisions.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        match self {
+            Self::F32(v) => v as f64,
+            Self::F64(v) => v,
+        }
+    }
+
+    /// Returns the scalar rounded to the precision `kind`.
+    #[inline]
+    pub fn to_kind(self, kind: ScalarKind) -> Self {
+        if self.kind() == kind {
+            self
+        } else {
+            Self::new(kind, self.to_f64())
+        }
+    }
+
+    /// Returns the absolute value.
+    #[inline]
+    pub fn abs(self) -> Self {
+        match self {
+            Self::F32(v) => Self::F32(v.abs()),
+            Self::F64(v) => Self::F64(v.abs()),
+        }
+    }
+
+    /// Returns the square root.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        match self {
+            Self::F32(v) => Self::F32(crate::math::sqrt(v)),
+            Self::F64(v) => Self::F64(crate::math::sqrt(v)),
+        }
+    }
+}
+
+impl Default for DynScalar {
+    /// Zero, of the default precision `f64`.
+    fn default() -> Self {
+        Self::F64(0.0)
+    }
+}
+
+impl Display for DynScalar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::F32(v) => Display::fmt(v, f),
+            Self::F64(v) => Display::fmt(v, f),
+        }
+    }
+}
+
+impl From<f32> for DynScalar {
+    fn from(value: f32) -> Self {
+        Self::F32(value)
+    }
+}
+
+impl From<f64> for DynScalar {
+    fn from(value: f64) -> Self {
+        Self::F64(value)
+    }
+}
+
+/// Compares the values, regardless of the precision: `F32(0.5) == F64(0.5)`.
+impl PartialEq for DynScalar {
+    fn eq(&self, other: &Self) -> bool {
+        match (*self, *other) {
+            (Self::F32(a), Self::F32(b)) => a == b,
+            (a, b) => a.to_f64() == b.to_f64(),
+        }
+    }
+}
+
+impl PartialOrd for DynScalar {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (*self, *other) {
+            (Self::F32(a), Self::F32(b)) => a.partial_cmp(&b),
+            (a, b) => a.to_f64().partial_cmp(&b.to_f64()),
+        }
+    }
+}
+
+macro_rules! impl_dyn_scalar_op {
+    ($op_trait:ident, $op_fn:ident, $op:tt) => {
+        impl $op_trait for DynScalar {
+            type Output = Self;
+            #[inline]
+            fn $op_fn(self, rhs: Self) -> Self {
+                match (self, rhs) {
+                    (Self::F32(a), Self::F32(b)) => Self::F32(a $op b),
+                    (a, b) => Self::F64(a.to_f64() $op b.to_f64()),
+                }
+            }
+        }
+    };
+}
+
+impl_dyn_scalar_op!(Add, add, +);
+impl_dyn_scalar_op!(Sub, sub, -);
+impl_dyn_scalar_op!(Mul, mul, *);
+impl_dyn_scalar_op!(Div, div, /);
+
+impl Neg for DynScalar {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        match self {
+            Self::F32(v) => Self::F32(-v),
+            Self::F64(v) => Self::F64(-v),
+        }
+    }
+}
+
+/// A two-dimensional vector of the precision selected at runtime.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DynVector2 {
+    F32(ArrayVec2<f32>),
+    F64(ArrayVec2<f64>),
+}
+
+/// A three-dimensional vector of the precision selected at runtime.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DynVector3 {
+    F32(ArrayVec3<f32>),
+    F64(ArrayVec3<f64>),
+}
+
+// Binds the vector of either precision to `$v` and evaluates `$body`, which must have the same
+// type for both.
+macro_rules! dyn_match {
+    ($value:expr, $dyn_type:ident, $v:ident => $body:expr) => {
+        match $value {
+            $dyn_type::F32($v) => $body,
+            $dyn_type::F64($v) => $body,
+        }
+    };
+}
+
+// Evaluates a binary operation on two vectors, in f64 when the precisions differ.
+macro_rules! dyn_zip {
+    ($a:expr, $b:expr, $dyn_type:ident, ($x:ident, $y:ident) => $body:expr) => {
+        match ($a, $b) {
+            ($dyn_type::F32($x), $dyn_type::F32($y)) => $body,
+            ($x, $y) => {
+                let ($x, $y) = ($x.to_f64(), $y.to_f64());
+                $body
+            }
+        }
+    };
+}
+
+impl DynVector2 {
+    /// Returns the vector with the components rounded to the precision `kind`.
+    #[inline]
+    pub fn new(kind: ScalarKind, x: f64, y: f64) -> Self {
+        match kind {
+            ScalarKind::F32 => Self::F32(ArrayVec2::new_2d(x as f32, y as f32)),
+            ScalarKind::F64 => Self::F64(ArrayVec2::new_2d(x, y)),
+        }
+    }
+
+    /// Converts any `HasXY` vector to the precision `kind`.
+    #[inline]
+    pub fn from_xy<V: HasXY>(kind: ScalarKind, v: V) -> Self {
+        Self::new(kind, v.x().to_f64_lossy(), v.y().to_f64_lossy())
+    }
+
+    /// Converts the vector to any `HasXY` vector, rounding to its scalar type.
+    #[inline]
+    pub fn to_xy<V: HasXY>(self) -> V {
+        let v = self.to_f64();
+        V::new_2d(
+            V::Scalar::from_f64_lossy(v.x()),
+            V::Scalar::from_f64_lossy(v.y()),
+        )
+    }
+
+    /// Returns the precision of the vector.
+    #[inline]
+    pub const fn kind(self) -> ScalarKind {
+        match self {
+            Self::F32(_) => ScalarKind::F32,
+            Self::F64(_) => ScalarKind::F64,
+        }
+    }
+
+    /// Returns the vector in `f64`, which is exact for both precisions.
+    #[inline]
+    pub fn to_f64(self) -> ArrayVec2<f64> {
+        match self {
+            Self::F32(v) => ArrayVec2::new_2d(v.x() as f64, v.y() as f64),
+            Self::F64(v) => v,
+        }
+    }
+
+    /// Returns the vector rounded to the precision `kind`.
+    #[inline]
+    pub fn to_kind(self, kind: ScalarKind) -> Self {
+        if self.kind() == kind {
+            self
+        } else {
+            Self::from_xy(kind, self.to_f64())
+        }
+    }
+
+    #[inline]
+    pub fn x(self) -> DynScalar {
+        dyn_match!(self, Self, v => v.x().into())
+    }
+
+    #[inline]
+    pub fn y(self) -> DynScalar {
+        dyn_match!(self, Self, v => v.y().into())
+    }
+
+    /// Returns the 3D vector with the `z` coordinate, in `f64` if the precisions differ.
+    #[inline]
+    pub fn to_3d(self, z: DynScalar) -> DynVector3 {
+        match (self, z) {
+            (Self::F32(v), DynScalar::F32(z)) => DynVector3::F32(v.to_3d(z)),
+            (v, z) => DynVector3::F64(v.to_f64().to_3d(z.to_f64())),
+        }
+    }
+
+    #[inline]
+    pub fn magnitude(self) -> DynScalar {
+        dyn_match!(self, Self, v => v.magnitude().into())
+    }
+
+    #[inline]
+    pub fn magnitude_sq(self) -> DynScalar {
+        dyn_match!(self, Self, v => v.magnitude_sq().into())
+    }
+
+    #[inline]
+    pub fn normalize(self) -> Self {
+        dyn_match!(self, Self, v => v.normalize().into())
+    }
+
+    #[inline]
+    pub fn safe_normalize(self) -> Option<Self> {
+        dyn_match!(self, Self, v => v.safe_normalize().map(Into::into))
+    }
+
+    #[inline]
+    pub fn dot(self, other: Self) -> DynScalar {
+        dyn_zip!(self, other, Self, (a, b) => a.dot(b).into())
+    }
+
+    #[inline]
+    pub fn perp_dot(self, other: Self) -> DynScalar {
+        dyn_zip!(self, other, Self, (a, b) => a.perp_dot(b).into())
+    }
+
+    #[inline]
+    pub fn distance(self, other: Self) -> DynScalar {
+        dyn_zip!(self, other, Self, (a, b) => a.distance(b).into())
+    }
+
+    #[inline]
+    pub fn distance_sq(self, other: Self) -> DynScalar {
+        dyn_zip!(self, other, Self, (a, b) => a.distance_sq(b).into())
+    }
+}
+
+impl DynVector3 {
+    /// Returns the vector with the components rounded to the precision `kind`.
+    #[inline]
+    pub fn new(kind: ScalarKind, x: f64, y: f64, z: f64) -> Self {
+        match kind {
+            ScalarKind::F32 => Self::F32(ArrayVec3::new_3d(x as f32, y as f32, z as f32)),
+            ScalarKind::F64 => Self::F64(ArrayVec3::new_3d(x, y, z)),
+        }
+    }
+
+    /// Converts any `HasXYZ` vector to the precision `kind`.
+    #[inline]
+    pub fn from_xyz<V: HasXYZ>(kind: ScalarKind, v: V) -> Self {
+        Self::new(
+            kind,
+            v.x().to_f64_lossy(),
+            v.y().to_f64_lossy(),
+            v.z().to_f64_lossy(),
+        )
+    }
+
+    /// Converts the vector to any `HasXYZ` vector, rounding to its scalar type.
+    #[inline]
+    pub fn to_xyz<V: HasXYZ>(self) -> V {
+        let v = self.to_f64();
+        V::new_3d(
+            V::Scalar::from_f64_lossy(v.x()),
+            V::Scalar::from_f64_lossy(v.y()),
+            V::Scalar::from_f64_lossy(v.z()),
+        )
+    }
+
+    /// Returns the precision of the vector.
+    #[inline]
+    pub const fn kind(self) -> ScalarKind {
+        match self {
+            Self::F32(_) => ScalarKind::F32,
+            Self::F64(_) => ScalarKind::F64,
+        }
+    }
+
+    /// Returns the vector in `f64`, which is exact for both precisions.
+    #[inline]
+    pub fn to_f64(self) -> ArrayVec3<f64> {
+        match self {
+            Self::F32(v) => ArrayVec3::new_3d(v.x() as f64, v.y() as f64, v.z() as f64),
+            Self::F64(v) => v,
+        }
+    }
+
+    /// Returns the vector rounded to the precision `kind`.
+    #[inline]
+    pub fn to_kind(self, kind: ScalarKind) -> Self {
+        if self.kind() == kind {
+            self
+        } else {
+            Self::from_xyz(kind, self.to_f64())
+        }
+    }
+
+    #[inline]
+    pub fn x(self) -> DynScalar {
+        dyn_match!(self, Self, v => v.x().into())
+    }
+
+    #[inline]
+    pub fn y(self) -> DynScalar {
+        dyn_match!(self, Self, v => v.y().into())
+    }
+
+    #[inline]
+    pub fn z(self) -> DynScalar {
+        dyn_match!(self, Self, v => v.z().into())
+    }
+
+    #[inline]
+    pub fn to_2d(self) -> DynVector2 {
+        dyn_match!(self, Self, v => v.to_2d().into())
+    }
+
+    #[inline]
+    pub fn magnitude(self) -> DynScalar {
+        dyn_match!(self, Self, v => v.magnitude().into())
+    }
+
+    #[inline]
+    pub fn magnitude_sq(self) -> DynScalar {
+        dyn_match!(self, Self, v => v.magnitude_sq().into())
+    }
+
+    #[inline]
+    pub fn normalize(self) -> Self {
+        dyn_match!(self, Self, v => v.normalize().into())
+    }
+
+    #[inline]
+    pub fn safe_normalize(self) -> Option<Self> {
+        dyn_match!(self, Self, v => v.safe_normalize().map(Into::into))
+    }
+
+    #[inline]
+    pub fn dot(self, other: Self) -> DynScalar {
+        dyn_zip!(self, other, Self, (a, b) => a.dot(b).into())
+    }
+
+    #[inline]
+    pub fn cross(self, other: Self) -> Self {
+        dyn_zip!(self, other, Self, (a, b) => a.cross(b).into())
+    }
+
+    #[inline]
+    pub fn distance(self, other: Self) -> DynScalar {
+        dyn_zip!(self, other, Self, (a, b) => a.distance(b).into())
+    }
+
+    #[inline]
+    pub fn distance_sq(self, other: Self) -> DynScalar {
+        dyn_zip!(self, other, Self, (a, b) => a.distance_sq(b).into())
+    }
+}
+
+macro_rules! impl_dyn_vector_ops {
+    ($dyn_type:ident, $vec_type:ident) => {
+        impl From<$vec_type<f32>> for $dyn_type {
+            fn from(v: $vec_type<f32>) -> Self {
+                Self::F32(v)
+            }
+        }
+
+        impl From<$vec_type<f64>> for $dyn_type {
+            fn from(v: $vec_type<f64>) -> Self {
+                Self::F64(v)
+            }
+        }
+
+        impl Add for $dyn_type {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                dyn_zip!(self, rhs, Self, (a, b) => (a + b).into())
+            }
+        }
+
+        impl Sub for $dyn_type {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                dyn_zip!(self, rhs, Self, (a, b) => (a - b).into())
+            }
+        }
+
+        impl Neg for $dyn_type {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self {
+                dyn_match!(self, Self, v => (-v).into())
+            }
+        }
+
+        impl Mul<DynScalar> for $dyn_type {
+            type Output = Self;
+            #[inline]
+            fn mul(self, rhs: DynScalar) -> Self {
+                match (self, rhs) {
+                    (Self::F32(v), DynScalar::F32(s)) => Self::F32(v * s),
+                    (v, s) => Self::F64(v.to_f64() * s.to_f64()),
+                }
+            }
+        }
+
+        impl Div<DynScalar> for $dyn_type {
+            type Output = Self;
+            #[inline]
+            fn div(self, rhs: DynScalar) -> Self {
+                match (self, rhs) {
+                    (Self::F32(v), DynScalar::F32(s)) => Self::F32(v / s),
+                    (v, s) => Self::F64(v.to_f64() / s.to_f64()),
+                }
+            }
+        }
+    };
+}
+
+impl_dyn_vector_ops!(DynVector2, ArrayVec2);
+impl_dyn_vector_ops!(DynVector3, ArrayVec3);
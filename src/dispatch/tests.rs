@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use crate::{ArrayVec2, ArrayVec3, DynScalar, DynVector2, DynVector3, ScalarKind};
+
+// one code path for both precisions
+fn path_length(kind: ScalarKind, points: &[[f64; 3]]) -> DynScalar {
+    let points: Vec<_> = points
+        .iter()
+        .map(|p| DynVector3::new(kind, p[0], p[1], p[2]))
+        .collect();
+    points
+        .windows(2)
+        .map(|w| w[0].distance(w[1]))
+        .fold(DynScalar::new(kind, 0.0), |a, b| a + b)
+}
+
+#[test]
+fn test_dyn_vector() {
+    let points = [[0.0, 0.0, 0.0], [3.0, 4.0, 0.0], [3.0, 4.0, 12.0]];
+    for kind in [ScalarKind::F32, ScalarKind::F64] {
+        let length = path_length(kind, &points);
+        assert_eq!(length.kind(), kind);
+        assert_eq!(length, DynScalar::F64(17.0));
+    }
+
+    // 0.1 is not representable, so the precisions differ
+    let a = DynVector2::new(ScalarKind::F32, 0.1, 0.0);
+    let b = DynVector2::new(ScalarKind::F64, 0.1, 0.0);
+    assert_ne!(a.x(), b.x());
+    assert_eq!(a.x(), DynScalar::F64(0.1f32 as f64));
+    // mixed operations are evaluated in f64
+    assert_eq!((a - b).kind(), ScalarKind::F64);
+    assert_eq!((a - a).kind(), ScalarKind::F32);
+    assert_eq!(a.to_kind(ScalarKind::F64).x(), a.x());
+    assert_eq!(b.to_kind(ScalarKind::F32), a);
+    assert!(DynScalar::F32(1.0) < DynScalar::F64(1.5));
+
+    let v = DynVector2::from_xy(ScalarKind::F32, [3.0f64, 4.0]);
+    assert_eq!(v, DynVector2::F32(ArrayVec2([3.0, 4.0])));
+    assert_eq!(v.magnitude(), DynScalar::F32(5.0));
+    assert_eq!(
+        v.perp_dot(DynVector2::new(ScalarKind::F32, 1.0, 0.0)),
+        DynScalar::F32(-4.0)
+    );
+    assert_eq!((v * DynScalar::F32(2.0)).to_xy::<(f64, f64)>(), (6.0, 8.0));
+    assert_eq!(
+        -v / DynScalar::F64(2.0),
+        DynVector2::F64(ArrayVec2([-1.5, -2.0]))
+    );
+    assert!((v.normalize().magnitude_sq().to_f64() - 1.0).abs() < 1e-6);
+    assert!(DynVector2::new(ScalarKind::F64, 0.0, 0.0)
+        .safe_normalize()
+        .is_none());
+
+    let x = DynVector3::from(ArrayVec3([1.0f64, 0.0, 0.0]));
+    let y = v.to_3d(DynScalar::F32(0.0)).normalize();
+    assert_eq!(y.kind(), ScalarKind::F32);
+    assert_eq!(x.cross(y).to_xyz::<[f32; 3]>(), [0.0, 0.0, 0.8]);
+    assert_eq!(x.dot(y), DynScalar::F32(0.6));
+    assert_eq!(y.to_2d().y(), DynScalar::F32(0.8));
+    assert_eq!(
+        DynScalar::new(ScalarKind::F32, 2.0).sqrt().to_string(),
+        "1.4142135"
+    );
+}
@@ -0,0 +1,61 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Overflow and underflow safe distance computations, for coordinates so large (or small) that
+//! squaring them leaves the range of the scalar type.
+
+use crate::{math, GenericScalar, HasXY};
+use num_traits::Float;
+
+/// Returns the squared distance between `a` and `b` as a `(scale, sum)` pair, where
+/// `distance_sq == scale * scale * sum`.
+///
+/// The component differences are divided by their largest magnitude before squaring, so `sum` is
+/// in the range [1, 4 * DIM] and never overflows or underflows, even when the squared distance itself
+/// is not representable. `scale` is zero for identical points, and NaN if any difference is NaN.
+pub fn distance_sq_scaled<T: HasXY>(a: T, b: T) -> (T::Scalar, T::Scalar) {
+    // every component up to `T::DIM`, with no fixed size buffer
+    let diffs = move |factor: T::Scalar| {
+        (0..T::DIM).filter_map(move |i| Some(a.get(i)? * factor - b.get(i)? * factor))
+    };
+    // unlike `Float::max()` this keeps a NaN
+    let max_abs = |factor: T::Scalar| {
+        diffs(factor).map(Float::abs).fold(T::Scalar::ZERO, |m, d| {
+            if d > m || Float::is_nan(d) {
+                d
+            } else {
+                m
+            }
+        })
+    };
+    let (mut factor, mut unscale_sq) = (T::Scalar::ONE, T::Scalar::ONE);
+    let mut max = max_abs(factor);
+    if Float::is_infinite(max) {
+        // the difference of two huge values may overflow, halving is exact for those
+        (factor, unscale_sq) = (0.5.into(), 4.0.into());
+        max = max_abs(factor);
+    }
+    if max == T::Scalar::ZERO || !Float::is_finite(max) {
+        return (max, unscale_sq);
+    }
+    let sum = diffs(factor).fold(T::Scalar::ZERO, |s, d| s + (d / max) * (d / max));
+    (max, sum * unscale_sq)
+}
+
+/// Returns the distance between `a` and `b`, without intermediate overflow or underflow.
+///
+/// When the squared distance is within the normal range of the scalar type the result is computed
+/// directly, with the same cost and accuracy as `distance()`. Otherwise it is computed from
+/// `distance_sq_scaled()`. The result is NaN if any component difference is NaN.
+pub fn distance_robust<T: HasXY>(a: T, b: T) -> T::Scalar {
+    let sum = (0..T::DIM)
+        .filter_map(|i| Some(a.get(i)? - b.get(i)?))
+        .fold(T::Scalar::ZERO, |s, d| s + d * d);
+    if Float::is_normal(sum) {
+        return math::sqrt(sum);
+    }
+    let (scale, sum) = distance_sq_scaled(a, b);
+    scale * math::sqrt(sum)
+}
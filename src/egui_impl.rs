@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `HasXY` and `Approx` for `egui::Vec2` and `egui::Pos2`, so geometry computed with the storage
+//! traits can be drawn in egui without converting every point at the boundary.
+//!
+//! The feature depends on `emath`, the math crate egui re-exports the two types from, rather than
+//! on all of egui. `GenericVector2` is not implemented: it requires `std::iter::Sum`, which
+//! `emath::Vec2` does not implement and this crate can not provide for a foreign type, and a
+//! `Pos2` can not be added to another `Pos2`. `to_egui_vec2()`, `to_pos2()`, `from_egui_vec2()`
+//! and `from_pos2()` convert to and from any vector type.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{Approx, Axis2, GenericScalar, HasXY};
+pub use ::emath::{Pos2, Vec2};
+use std::ops::Index;
+
+macro_rules! impl_egui {
+    ($vec:ident, $ctor:path) => {
+        impl HasXY for $vec {
+            type Scalar = f32;
+            const DIM: usize = 2;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                $ctor(x, y)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                $ctor(self.x.canonicalize(), self.y.canonicalize())
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                $ctor(self.x.flush_denormal(), self.y.flush_denormal())
+            }
+        }
+
+        impl Index<Axis2> for $vec {
+            type Output = f32;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis2) -> &Self::Output {
+                &self[axis.index()]
+            }
+        }
+
+        impl Approx for $vec {}
+    };
+}
+
+impl_egui!(Vec2, ::emath::vec2);
+impl_egui!(Pos2, ::emath::pos2);
+
+/// Returns the x and y of `v` as an `egui::Vec2`.
+#[inline(always)]
+pub fn to_egui_vec2<V: HasXY<Scalar = f32>>(v: V) -> Vec2 {
+    Vec2::new(v.x(), v.y())
+}
+
+/// Returns the x and y of `v` as an `egui::Pos2`.
+#[inline(always)]
+pub fn to_pos2<V: HasXY<Scalar = f32>>(v: V) -> Pos2 {
+    Pos2::new(v.x(), v.y())
+}
+
+/// Returns the `egui::Vec2` as a vector of type `V`.
+#[inline(always)]
+pub fn from_egui_vec2<V: HasXY<Scalar = f32>>(v: Vec2) -> V {
+    V::new_2d(v.x, v.y)
+}
+
+/// Returns the `egui::Pos2` as a vector of type `V`.
+#[inline(always)]
+pub fn from_pos2<V: HasXY<Scalar = f32>>(p: Pos2) -> V {
+    V::new_2d(p.x, p.y)
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{from_egui_vec2, from_pos2, to_egui_vec2, to_pos2, Pos2, Vec2};
+use crate::{closest_point_on_segment, distance_robust, Approx, Axis2, HasXY};
+
+#[test]
+fn test_egui() {
+    crate::tests::tests::test_xy::<Vec2>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Pos2>(1.0, 2.0);
+
+    let p = Pos2::new_2d(3.0, 4.0);
+    assert_eq!((p[Axis2::Y], Vec2::new(5.0, 6.0)[Axis2::X]), (4.0, 5.0));
+    assert!(p.is_abs_diff_eq(Pos2::new(3.0, 4.0 + 1e-7), 1e-6));
+    assert_eq!(distance_robust(p, Pos2::ZERO), 5.0);
+    // the operators of Vec2 are enough for the storage trait algorithms
+    let (c, t) = closest_point_on_segment(Vec2::new(1.0, 1.0), Vec2::ZERO, Vec2::new(2.0, 0.0));
+    assert_eq!((c, t), (Vec2::new(1.0, 0.0), 0.5));
+}
+
+#[test]
+fn test_conversions() {
+    let v: Vec2 = from_pos2(Pos2::new(1.0, 2.0));
+    assert_eq!(v, Vec2::new(1.0, 2.0));
+    assert_eq!(to_pos2(v), Pos2::new(1.0, 2.0));
+    let p: Pos2 = from_egui_vec2(Vec2::new(3.0, 4.0));
+    assert_eq!(to_egui_vec2(p), Vec2::new(3.0, 4.0));
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Products with a forward error bound, so callers can decide when to escalate to higher
+//! precision or to exact arithmetic.
+//!
+//! Every function returns the computed value together with a bound `e` such that the exact
+//! product of the (exactly represented) inputs is within `value - e ..= value + e`. The bounds
+//! come from the standard analysis of recursive summation, `|error| <= gamma(n) * sum(|a_i * b_i|)`
+//! with `gamma(n) = n * u / (1 - n * u)` and `u = EPSILON / 2`, plus a term for underflow. They
+//! hold whether or not the compiler contracts the operations into fused multiply-adds.
+
+use crate::{GenericScalar, GenericVector3, HasXY};
+use num_traits::Float;
+
+/// Returns `gamma(n)`, slightly rounded up so that the bound itself is not underestimated by the
+/// rounding of its own computation.
+#[inline(always)]
+fn gamma<S: GenericScalar>(n: usize) -> S {
+    let nu = S::from_usize(n).unwrap() * S::EPSILON * 0.5.into();
+    nu / (S::ONE - nu) * (S::ONE + S::EPSILON * 4.0.into())
+}
+
+/// Returns the bound for a sum of `n` products with the absolute sum `abs_sum`.
+#[inline(always)]
+fn bound<S: GenericScalar>(n: usize, abs_sum: S) -> S {
+    // every product can lose up to half the smallest subnormal to underflow
+    let underflow = S::from_usize(n).unwrap() * <S as Float>::min_positive_value() * S::EPSILON;
+    gamma::<S>(n) * abs_sum + underflow
+}
+
+/// Returns `a.dot(b)` and its forward error bound.
+/// The dot product is computed in a fixed order, like `math::dot()`.
+pub fn dot_with_error<T: HasXY>(a: T, b: T) -> (T::Scalar, T::Scalar) {
+    let value = crate::math::dot(a, b);
+    let abs_sum = (0..T::DIM)
+        .filter_map(|i| Some(Float::abs(a.get(i)? * b.get(i)?)))
+        .fold(T::Scalar::ZERO, |sum, p| sum + p);
+    (value, bound(T::DIM, abs_sum))
+}
+
+/// Returns `x0 * y1 - y0 * x1` and its forward error bound.
+#[inline(always)]
+fn perp_dot<S: GenericScalar>(x0: S, y0: S, x1: S, y1: S) -> (S, S) {
+    let (p, q) = (x0 * y1, y0 * x1);
+    (p - q, bound(2, Float::abs(p) + Float::abs(q)))
+}
+
+/// Returns the perp dot product (the z component of the 2d cross product) of `a` and `b`, and
+/// its forward error bound. Only the x and y components are considered.
+pub fn perp_dot_with_error<T: HasXY>(a: T, b: T) -> (T::Scalar, T::Scalar) {
+    perp_dot(a.x(), a.y(), b.x(), b.y())
+}
+
+/// Returns `a.cross(b)` and a vector holding the forward error bound of each component.
+pub fn cross_with_error<T: GenericVector3>(a: T, b: T) -> (T, T) {
+    let (x, ex) = perp_dot(a.y(), a.z(), b.y(), b.z());
+    let (y, ey) = perp_dot(a.z(), a.x(), b.z(), b.x());
+    let (z, ez) = perp_dot(a.x(), a.y(), b.x(), b.y());
+    (T::new_3d(x, y, z), T::new_3d(ex, ey, ez))
+}
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Euler angles with an explicit rotation order.
+//!
+//! `EulerAngles::new(EulerOrder::XYZ, [a, b, c])` rotates by `a` about the x axis first, then by
+//! `b` about the y axis and last by `c` about the z axis. The axes are the fixed world axes
+//! (extrinsic rotations), which is the same rotation as intrinsic rotations about the rotating
+//! body axes in the reverse order, Z then Y' then X''. As a matrix this is `Rz(c) * Ry(b) * Rx(a)`.
+//! All angles are in radians and the rotations are counterclockwise when looking down the axis.
+
+use crate::{math, Axis3, GenericScalar, GenericVector3};
+
+/// The order of the rotations of `EulerAngles`, the first axis is rotated about first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX,
+}
+
+impl EulerOrder {
+    /// Returns the axes, in the order the rotations are applied.
+    #[inline(always)]
+    pub const fn axes(self) -> [Axis3; 3] {
+        match self {
+            Self::XYZ => [Axis3::X, Axis3::Y, Axis3::Z],
+            Self::XZY => [Axis3::X, Axis3::Z, Axis3::Y],
+            Self::YXZ => [Axis3::Y, Axis3::X, Axis3::Z],
+            Self::YZX => [Axis3::Y, Axis3::Z, Axis3::X],
+            Self::ZXY => [Axis3::Z, Axis3::X, Axis3::Y],
+            Self::ZYX => [Axis3::Z, Axis3::Y, Axis3::X],
+        }
+    }
+
+    /// Returns true for the cyclic orders XYZ, YZX and ZXY.
+    #[inline(always)]
+    const fn is_cyclic(self) -> bool {
+        matches!(self, Self::XYZ | Self::YZX | Self::ZXY)
+    }
+}
+
+/// Three rotation angles, applied in the order given by `order`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EulerAngles<S: GenericScalar> {
+    pub order: EulerOrder,
+    /// The angle of each rotation, in the order they are applied.
+    pub angles: [S; 3],
+}
+
+/// Returns the row major matrix of a rotation by `angle` about `axis`.
+fn axis_rotation<S: GenericScalar>(axis: Axis3, angle: S) -> [[S; 3]; 3] {
+    let (s, c) = (math::sin(angle), math::cos(angle));
+    let (o, l) = (S::ZERO, S::ONE);
+    match axis {
+        Axis3::X => [[l, o, o], [o, c, -s], [o, s, c]],
+        Axis3::Y => [[c, o, s], [o, l, o], [-s, o, c]],
+        Axis3::Z => [[c, -s, o], [s, c, o], [o, o, l]],
+    }
+}
+
+fn mul<S: GenericScalar>(a: &[[S; 3]; 3], b: &[[S; 3]; 3]) -> [[S; 3]; 3] {
+    std::array::from_fn(|i| {
+        std::array::from_fn(|j| a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j])
+    })
+}
+
+impl<S: GenericScalar> EulerAngles<S> {
+    #[inline(always)]
+    pub fn new(order: EulerOrder, angles: [S; 3]) -> Self {
+        Self { order, angles }
+    }
+
+    /// Returns the rotation as a row major matrix, to be multiplied with column vectors.
+    ///
+    /// `GenericMatrix3::from_euler()` and `GenericRotation3::from_euler()` return the matrix and
+    /// quaternion types of the backends instead.
+    pub fn to_matrix(&self) -> [[S; 3]; 3] {
+        let [a, b, c] = self.order.axes();
+        let first = axis_rotation(a, self.angles[0]);
+        let second = axis_rotation(b, self.angles[1]);
+        let third = axis_rotation(c, self.angles[2]);
+        mul(&third, &mul(&second, &first))
+    }
+
+    /// Extracts the angles of a row major rotation matrix, for the given order.
+    ///
+    /// The second angle is in the range [-pi/2, pi/2], the others in [-pi, pi]. At gimbal lock,
+    /// where the second angle is +-pi/2, only the sum or difference of the other two angles is
+    /// defined, the third angle is then set to zero.
+    pub fn from_matrix(order: EulerOrder, m: &[[S; 3]; 3]) -> Self {
+        let [i, j, k] = order.axes().map(Axis3::index);
+        let p = if order.is_cyclic() { S::ONE } else { -S::ONE };
+        let sin_second = GenericScalar::clamp(-p * m[k][i], -S::ONE, S::ONE);
+        let second = math::asin(sin_second);
+        // close to gimbal lock the first and third angles can not be separated
+        let locked = num_traits::Float::abs(sin_second) > S::ONE - S::EPSILON * 16.0.into();
+        let (first, third) = if locked {
+            (math::atan2(-p * m[j][k], m[j][j]), S::ZERO)
+        } else {
+            (
+                math::atan2(p * m[k][j], m[k][k]),
+                math::atan2(p * m[j][i], m[i][i]),
+            )
+        };
+        Self::new(order, [first, second, third])
+    }
+
+    /// Returns `v` rotated by the angles.
+    pub fn rotate_vector<V: GenericVector3<Scalar = S>>(&self, v: V) -> V {
+        let m = self.to_matrix();
+        let row = |r: [S; 3]| r[0] * v.x() + r[1] * v.y() + r[2] * v.z();
+        V::new_3d(row(m[0]), row(m[1]), row(m[2]))
+    }
+
+    /// Returns the same rotation expressed in another order.
+    pub fn reorder(&self, order: EulerOrder) -> Self {
+        Self::from_matrix(order, &self.to_matrix())
+    }
+}
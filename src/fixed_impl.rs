@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Fixed point scalars and vectors, for deterministic lockstep simulations that can not use
+//! floats.
+//!
+//! `GenericScalar` is the `f32`/`f64` interface, and every algorithm of this crate is written
+//! against it. The fixed point types of the `fixed` crate implement only `ScalarCore`, the
+//! float-agnostic core below `GenericScalar`, which is enough for `FixedVec2` and `FixedVec3`,
+//! the `CoreVec2` and `CoreVec3` vectors over a fixed point scalar. Their operators, dot and cross
+//! products and magnitudes use integer arithmetic that gives bit identical results on every
+//! platform. They convert to and from every `HasXY`/`HasXYZ` type for rendering, which is the only
+//! place a float is involved.
+//!
+//! `ScalarCore` is implemented for `I16F16`, `I32F32` and `I48F16`. The arithmetic operators of
+//! the fixed types panic on overflow in debug builds and wrap in release builds. `sqrt_value()`
+//! returns zero for negative input.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{CoreVec2, CoreVec3, ScalarCore};
+pub use ::fixed::types::{I16F16, I32F32, I48F16};
+
+macro_rules! impl_fixed_scalar {
+    ($fixed_type:ty) => {
+        impl ScalarCore for $fixed_type {
+            #[inline(always)]
+            fn abs_value(self) -> Self {
+                <$fixed_type>::abs(self)
+            }
+            #[inline(always)]
+            fn sqrt_value(self) -> Self {
+                <$fixed_type>::checked_sqrt(self).unwrap_or(<$fixed_type>::ZERO)
+            }
+            #[inline(always)]
+            fn to_f64_lossy(self) -> f64 {
+                self.to_num()
+            }
+            #[inline(always)]
+            fn from_f64_lossy(value: f64) -> Self {
+                if value.is_nan() {
+                    <$fixed_type>::ZERO
+                } else {
+                    <$fixed_type>::saturating_from_num(value)
+                }
+            }
+        }
+    };
+}
+
+impl_fixed_scalar!(I16F16);
+impl_fixed_scalar!(I32F32);
+impl_fixed_scalar!(I48F16);
+
+/// A two-dimensional vector over a fixed point scalar.
+pub type FixedVec2<S> = CoreVec2<S>;
+
+/// A three-dimensional vector over a fixed point scalar.
+pub type FixedVec3<S> = CoreVec3<S>;
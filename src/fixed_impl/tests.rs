@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{FixedVec2, FixedVec3, I16F16, I32F32, I48F16};
+use crate::ScalarCore;
+
+#[test]
+fn test_fixed_scalar() {
+    crate::tests::tests::test_core_vector::<I16F16>();
+    crate::tests::tests::test_core_vector::<I32F32>();
+    crate::tests::tests::test_core_vector::<I48F16>();
+
+    assert_eq!(I32F32::from_f64_lossy(1.0e30), I32F32::MAX);
+    assert_eq!(I32F32::from_f64_lossy(f64::NAN), I32F32::ZERO);
+    assert_eq!(I16F16::from_num(-4).sqrt_value(), I16F16::ZERO);
+    assert_eq!(I16F16::from_num(0.25).to_f64_lossy(), 0.25);
+}
+
+#[test]
+fn test_fixed_vec() {
+    let v = FixedVec3::<I32F32>::from_float([1.5_f64, -2.25, 0.1]);
+    assert_eq!(v.x, I32F32::from_num(1.5));
+    // 0.1 is rounded to the nearest multiple of 2^-32
+    assert!((v.z.to_f64_lossy() - 0.1).abs() < 1.0e-9);
+    let f: [f32; 3] = v.to_float();
+    assert_eq!(f[..2], [1.5, -2.25]);
+    let w = FixedVec2::<I16F16>::from_float((3.0_f32, 4.0));
+    assert_eq!(
+        w,
+        FixedVec2::from([I16F16::from_num(3), I16F16::from_num(4)])
+    );
+    let mut p = w;
+    p += w;
+    p -= w * I16F16::from_num(3);
+    assert_eq!(p.to_float::<(f64, f64)>(), (-3.0, -4.0));
+
+    // a lockstep step: the same integer operations give the same bits everywhere
+    let step = |pos: FixedVec2<I32F32>, vel: FixedVec2<I32F32>| pos + vel / I32F32::from_num(60);
+    let mut pos = FixedVec2::<I32F32>::default();
+    let vel = FixedVec2::<I32F32>::from_float([1.0_f64, 0.5]);
+    for _ in 0..60 {
+        pos = step(pos, vel);
+    }
+    // 1/60 is truncated to 71582788 * 2^-32, so the sum ends 16 steps of 2^-32 short of 1
+    assert_eq!(pos.x.to_bits(), 0xFFFF_FFF0);
+}
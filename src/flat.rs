@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Conversions between any vector type and flat `[Scalar; 2]`/`[Scalar; 3]` arrays, the layout of
+//! `vecmath`, the Piston crates and most C APIs.
+//!
+//! `IntoFlat` and `FromFlat` are implemented for every `HasXY` type with `N = 2`, and for every
+//! `HasXYZ` type with `N = 3`, so a 3D vector can be flattened to either. The slice functions
+//! convert whole point sets, to arrays or to one flat run of scalars.
+
+use crate::{HasXY, HasXYZ};
+
+/// Converts a vector to a `[Scalar; N]` array.
+pub trait IntoFlat<const N: usize>: HasXY {
+    /// Returns the first `N` components of the vector as an array.
+    fn into_flat(self) -> [Self::Scalar; N];
+}
+
+/// Creates a vector from a `[Scalar; N]` array.
+pub trait FromFlat<const N: usize>: HasXY {
+    /// Creates a vector from the components in `flat`. A 3D vector created from a
+    /// `[Scalar; 2]` has a zero z, as with `HasXY::new_2d()`.
+    fn from_flat(flat: [Self::Scalar; N]) -> Self;
+}
+
+impl<T: HasXY> IntoFlat<2> for T {
+    #[inline(always)]
+    fn into_flat(self) -> [Self::Scalar; 2] {
+        [self.x(), self.y()]
+    }
+}
+
+impl<T: HasXYZ> IntoFlat<3> for T {
+    #[inline(always)]
+    fn into_flat(self) -> [Self::Scalar; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+}
+
+impl<T: HasXY> FromFlat<2> for T {
+    #[inline(always)]
+    fn from_flat(flat: [Self::Scalar; 2]) -> Self {
+        T::new_2d(flat[0], flat[1])
+    }
+}
+
+impl<T: HasXYZ> FromFlat<3> for T {
+    #[inline(always)]
+    fn from_flat(flat: [Self::Scalar; 3]) -> Self {
+        T::new_3d(flat[0], flat[1], flat[2])
+    }
+}
+
+/// Returns the vectors as `[Scalar; N]` arrays.
+pub fn to_flat_arrays<T: IntoFlat<N>, const N: usize>(vectors: &[T]) -> Vec<[T::Scalar; N]> {
+    vectors.iter().map(|v| v.into_flat()).collect()
+}
+
+/// Returns the `[Scalar; N]` arrays as vectors of type `T`.
+pub fn from_flat_arrays<T: FromFlat<N>, const N: usize>(arrays: &[[T::Scalar; N]]) -> Vec<T> {
+    arrays.iter().map(|a| T::from_flat(*a)).collect()
+}
+
+/// Returns the components of the vectors as one run of scalars, `N` per vector, e.g.
+/// `[x0, y0, z0, x1, y1, z1, ..]` for `N = 3`.
+pub fn to_flat_scalars<T: IntoFlat<N>, const N: usize>(vectors: &[T]) -> Vec<T::Scalar> {
+    vectors.iter().flat_map(|v| v.into_flat()).collect()
+}
+
+/// Returns the run of scalars as vectors of type `T`, reading `N` scalars per vector.
+///
+/// Returns `None` if the length of `scalars` is not a multiple of `N`.
+pub fn from_flat_scalars<T: FromFlat<N>, const N: usize>(scalars: &[T::Scalar]) -> Option<Vec<T>> {
+    let chunks = scalars.chunks_exact(N);
+    if !chunks.remainder().is_empty() {
+        return None;
+    }
+    Some(
+        chunks
+            .map(|c| T::from_flat(std::array::from_fn(|i| c[i])))
+            .collect(),
+    )
+}
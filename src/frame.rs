@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Orientation frames built from direction vectors, for camera and tool orientation code.
+//!
+//! The frames follow the z-up convention of `GenericVector3::from_yaw_pitch()`: the local x axis
+//! is forward, the local z axis is up and the local y axis points left.
+
+use crate::GenericVector3;
+
+/// Returns the rotation that turns the local x axis to `forward` and the local z axis as close
+/// to `up` as possible, as a row major matrix like `EulerAngles::to_matrix()`.
+///
+/// The columns of the matrix are the forward, left and up directions. Returns `None` if either
+/// vector has zero length or if they are parallel. `GenericMatrix3::look_rotation()` and
+/// `GenericRotation3::look_rotation()` return the matrix and quaternion types of the backends.
+pub fn look_rotation<V: GenericVector3>(forward: V, up: V) -> Option<[[V::Scalar; 3]; 3]> {
+    let [f, l, u] = look_frame(forward, up)?;
+    Some([
+        [f.x(), l.x(), u.x()],
+        [f.y(), l.y(), u.y()],
+        [f.z(), l.z(), u.z()],
+    ])
+}
+
+/// Returns the orthonormal `[forward, left, up]` frame of `look_rotation()` as vectors.
+pub fn look_frame<V: GenericVector3>(forward: V, up: V) -> Option<[V; 3]> {
+    let forward = forward.safe_normalize()?;
+    let left = up.cross(forward).safe_normalize()?;
+    Some([forward, left, forward.cross(left)])
+}
@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `HasXY` and `Approx` for `geo_types::Coord` and `geo_types::Point`, so GIS geometries can be
+//! fed into the algorithms written against the storage traits without a copy into another vector
+//! type.
+//!
+//! `GenericVector2` is not implemented: it requires `Index<usize>`, the assignment operators and
+//! `std::iter::Sum`, which the geo types do not implement and this crate can not provide for a
+//! foreign type. Both types do implement `Add`, `Sub`, `Mul` and `Div` by a scalar, which is
+//! enough for e.g. `closest_point_on_segment()` and `RunningStats`. `to_coord()` and
+//! `from_coord()` convert to and from any vector type.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{Approx, Axis2, GenericScalar, HasXY};
+pub use ::geo_types::{Coord, Point};
+use std::ops::Index;
+
+macro_rules! impl_geo {
+    ($scalar:ty) => {
+        impl HasXY for Coord<$scalar> {
+            type Scalar = $scalar;
+            const DIM: usize = 2;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                Coord { x, y }
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_2d(self.x.canonicalize(), self.y.canonicalize())
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_2d(self.x.flush_denormal(), self.y.flush_denormal())
+            }
+        }
+
+        impl Index<Axis2> for Coord<$scalar> {
+            type Output = $scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis2) -> &Self::Output {
+                match axis {
+                    Axis2::X => &self.x,
+                    Axis2::Y => &self.y,
+                }
+            }
+        }
+
+        impl Approx for Coord<$scalar> {}
+
+        impl HasXY for Point<$scalar> {
+            type Scalar = $scalar;
+            const DIM: usize = 2;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                Point(Coord { x, y })
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.0.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.0.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.0.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.0.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.0.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.0.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                HasXY::get(self.0, index)
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Point(self.0.canonicalize())
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Point(self.0.flush_denormals())
+            }
+        }
+
+        impl Index<Axis2> for Point<$scalar> {
+            type Output = $scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis2) -> &Self::Output {
+                &self.0[axis]
+            }
+        }
+
+        impl Approx for Point<$scalar> {}
+    };
+}
+
+impl_geo!(f32);
+impl_geo!(f64);
+
+/// Returns the x and y of `v` as a `geo_types::Coord`.
+#[inline(always)]
+pub fn to_coord<V: HasXY>(v: V) -> Coord<V::Scalar> {
+    Coord { x: v.x(), y: v.y() }
+}
+
+/// Returns the `geo_types::Coord` as a vector of type `V`.
+#[inline(always)]
+pub fn from_coord<V: HasXY>(c: Coord<V::Scalar>) -> V {
+    V::new_2d(c.x, c.y)
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{from_coord, to_coord, Coord, Point};
+use crate::{closest_point_on_segment, distance_robust, Approx, Axis2, HasXY, RunningStats};
+
+#[test]
+fn test_geo() {
+    crate::tests::tests::test_xy::<Coord<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Coord<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Point<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Point<f64>>(1.0, 2.0);
+
+    let p = Point::new_2d(3.0, 4.0);
+    assert_eq!(
+        (p[Axis2::Y], Coord { x: 5.0, y: 6.0 }[Axis2::X]),
+        (4.0, 5.0)
+    );
+    assert!(p.is_abs_diff_eq(Point::new(3.0, 4.0 + 1e-9), 1e-6));
+    assert_eq!(distance_robust(p, Point::new(0.0, 0.0)), 5.0);
+    // the geo operators are enough for the storage trait algorithms
+    let (c, t) = closest_point_on_segment(
+        Coord { x: 1.0, y: 1.0 },
+        Coord { x: 0.0, y: 0.0 },
+        Coord { x: 2.0, y: 0.0 },
+    );
+    assert_eq!((c, t), (Coord { x: 1.0, y: 0.0 }, 0.5));
+    let stats: RunningStats<Point<f64>> = [Point::new(1.0, 2.0), Point::new(3.0, 4.0)]
+        .into_iter()
+        .collect();
+    assert_eq!(stats.mean(), Some(Point::new(2.0, 3.0)));
+}
+
+#[test]
+fn test_conversions() {
+    let p: Point<f64> = from_coord(Coord { x: 1.0, y: 2.0 });
+    assert_eq!(p, Point::new(1.0, 2.0));
+    assert_eq!(to_coord(p), Coord { x: 1.0, y: 2.0 });
+    let a: [f32; 3] = from_coord(Coord { x: 1.0, y: 2.0 });
+    assert_eq!(a, [1.0, 2.0, 0.0]);
+}
@@ -17,12 +17,26 @@ fn test_vec2a() {
     assert_eq!(a.x(), c.x());
     assert_eq!(a.y(), c.y());
     assert_eq!(a.0, d);
+    assert_eq!(Vec2A::default(), Vec2A::new(0.0, 0.0));
+    let (ra, rb) = (&a, &b);
+    assert_eq!(ra + rb, Vec2A::new(2.0, 4.0));
+    assert_eq!(a - rb, Vec2A::new(0.0, 0.0));
+    assert_eq!(2.0 * a, a * 2.0);
+    assert_eq!([a, b].iter().sum::<Vec2A>(), Vec2A::new(2.0, 4.0));
 
     let a = Vec2A::new(1.0, 2.0);
     assert_eq!(a.x(), b.x());
     assert_eq!(a.y(), b.y());
 }
 
+#[test]
+fn test_vec2a_const() {
+    const TABLE: [Vec2A; 3] = [Vec2A::ZERO, Vec2A::new(1.0, 2.0), Vec2A::splat(3.0)];
+    assert_eq!(TABLE[0], Vec2A::new_2d(0.0, 0.0));
+    assert_eq!(TABLE[1], Vec2A::new_2d(1.0, 2.0));
+    assert_eq!(TABLE[2], Vec2A::new_2d(3.0, 3.0));
+}
+
 #[test]
 fn test_xy() {
     crate::tests::tests::test_xy::<glam::Vec2>(1.0, 2.0);
@@ -59,3 +73,452 @@ fn test_gxyz() {
     crate::tests::tests::test_generic_xyz::<glam::Vec3A>(1.0, 2.0, 3.0, 4.0, 0.0001);
     crate::tests::tests::test_generic_xyz::<glam::DVec3>(1.0, 2.0, 3.0, 4.0, 0.0000000000001);
 }
+
+#[test]
+fn test_slices_approx() {
+    crate::tests::tests::test_slices_approx::<glam::Vec2>(1.0, 2.0);
+    crate::tests::tests::test_slices_approx::<Vec2A>(1.0, 2.0);
+    crate::tests::tests::test_slices_approx::<glam::DVec2>(1.0, 2.0);
+}
+
+#[test]
+fn test_assert_vec_macros() {
+    crate::tests::tests::test_assert_vec_macros::<glam::Vec2>(1.0, 2.0);
+    crate::tests::tests::test_assert_vec_macros::<Vec2A>(1.0, 2.0);
+    crate::tests::tests::test_assert_vec_macros::<glam::DVec2>(1.0, 2.0);
+}
+
+#[test]
+fn test_vec2a_approx() {
+    let a = Vec2A::new(1.0, 2.0);
+    let b = Vec2A::new(1.0, 2.5);
+    approx::assert_ulps_eq!(a, a);
+    approx::assert_ulps_ne!(a, b);
+    approx::assert_abs_diff_eq!(a, b, epsilon = 0.5);
+    approx::assert_abs_diff_ne!(a, b);
+}
+
+#[test]
+fn test_axis() {
+    crate::tests::tests::test_axis::<glam::Vec3>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_axis::<glam::Vec3A>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_axis::<glam::DVec3>(1.0, 2.0, 3.0);
+}
+
+#[test]
+fn test_int_storage() {
+    crate::tests::tests::test_int_storage::<glam::Vec3>();
+    crate::tests::tests::test_int_storage::<glam::Vec3A>();
+    crate::tests::tests::test_int_storage::<glam::DVec3>();
+}
+
+#[test]
+fn test_segments_intersect() {
+    crate::tests::tests::test_segments_intersect::<glam::Vec2>();
+    crate::tests::tests::test_segments_intersect::<Vec2A>();
+    crate::tests::tests::test_segments_intersect::<glam::DVec2>();
+}
+
+#[test]
+fn test_summation() {
+    crate::tests::tests::test_summation::<glam::Vec2>();
+    crate::tests::tests::test_summation::<Vec2A>();
+    crate::tests::tests::test_summation::<glam::DVec2>();
+}
+
+#[test]
+fn test_checked() {
+    crate::tests::tests::test_checked::<glam::Vec2>(1.0, 2.0);
+    crate::tests::tests::test_checked::<Vec2A>(1.0, 2.0);
+    crate::tests::tests::test_checked::<glam::DVec2>(1.0, 2.0);
+}
+
+#[test]
+fn test_distance_robust() {
+    crate::tests::tests::test_distance_robust::<glam::Vec3>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_distance_robust::<glam::Vec3A>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_distance_robust::<glam::DVec3>(1.0, 2.0, 3.0);
+    // all four components of a 4D vector take part
+    let d = crate::distance_robust(glam::Vec4::new(0.0, 0.0, 0.0, 3.0), glam::Vec4::Z * 4.0);
+    assert_eq!(d, 5.0);
+    let big = f32::MAX / 4.0;
+    let d = crate::distance_robust(glam::Vec4::new(0.0, 0.0, 0.0, big), glam::Vec4::ZERO);
+    assert_eq!(d, big);
+}
+
+#[test]
+fn test_strict_fp() {
+    crate::tests::tests::test_strict_fp::<glam::Vec3>(0.1, 0.7, -0.3);
+    crate::tests::tests::test_strict_fp::<glam::Vec3A>(0.1, 0.7, -0.3);
+    crate::tests::tests::test_strict_fp::<glam::DVec3>(0.1, 0.7, -0.3);
+}
+
+#[test]
+fn test_sweep_cmp() {
+    crate::tests::tests::test_sweep_cmp::<glam::Vec2>();
+    crate::tests::tests::test_sweep_cmp::<Vec2A>();
+    crate::tests::tests::test_sweep_cmp::<glam::DVec2>();
+}
+
+#[test]
+fn test_snap() {
+    crate::tests::tests::test_snap::<glam::Vec3>();
+    crate::tests::tests::test_snap::<glam::Vec3A>();
+    crate::tests::tests::test_snap::<glam::DVec3>();
+}
+
+#[test]
+fn test_angle() {
+    crate::tests::tests::test_angle::<glam::Vec2>();
+    crate::tests::tests::test_angle::<glam::DVec2>();
+}
+
+#[test]
+fn test_interval() {
+    crate::tests::tests::test_interval::<glam::Vec2>();
+    crate::tests::tests::test_interval::<Vec2A>();
+    crate::tests::tests::test_interval::<glam::DVec2>();
+}
+
+#[test]
+fn test_error_bound() {
+    crate::tests::tests::test_error_bound::<glam::Vec3>();
+    crate::tests::tests::test_error_bound::<glam::Vec3A>();
+    crate::tests::tests::test_error_bound::<glam::DVec3>();
+}
+
+#[test]
+fn test_sampling() {
+    crate::tests::tests::test_sampling_2d::<glam::Vec2>();
+    crate::tests::tests::test_sampling_2d::<Vec2A>();
+    crate::tests::tests::test_sampling_2d::<glam::DVec2>();
+    crate::tests::tests::test_sampling_3d::<glam::Vec3>();
+    crate::tests::tests::test_sampling_3d::<glam::Vec3A>();
+    crate::tests::tests::test_sampling_3d::<glam::DVec3>();
+}
+
+#[test]
+fn test_euler() {
+    crate::tests::tests::test_euler::<glam::Vec3>();
+    crate::tests::tests::test_euler::<glam::Vec3A>();
+    crate::tests::tests::test_euler::<glam::DVec3>();
+}
+
+#[test]
+fn test_look_rotation() {
+    crate::tests::tests::test_look_rotation::<glam::Vec3>();
+    crate::tests::tests::test_look_rotation::<glam::Vec3A>();
+    crate::tests::tests::test_look_rotation::<glam::DVec3>();
+}
+
+#[test]
+fn test_bounding_sphere_2d() {
+    crate::tests::tests::test_bounding_sphere_2d::<glam::Vec2>();
+    crate::tests::tests::test_bounding_sphere_2d::<Vec2A>();
+    crate::tests::tests::test_bounding_sphere_2d::<glam::DVec2>();
+}
+
+#[test]
+fn test_bounding_sphere_3d() {
+    crate::tests::tests::test_bounding_sphere_3d::<glam::Vec3>();
+    crate::tests::tests::test_bounding_sphere_3d::<glam::Vec3A>();
+    crate::tests::tests::test_bounding_sphere_3d::<glam::DVec3>();
+}
+
+#[test]
+fn test_principal_axes() {
+    crate::tests::tests::test_principal_axes::<glam::Vec3>();
+    crate::tests::tests::test_principal_axes::<glam::Vec3A>();
+    crate::tests::tests::test_principal_axes::<glam::DVec3>();
+}
+
+#[test]
+fn test_fit_plane() {
+    crate::tests::tests::test_fit_plane::<glam::Vec3>();
+    crate::tests::tests::test_fit_plane::<glam::Vec3A>();
+    crate::tests::tests::test_fit_plane::<glam::DVec3>();
+}
+
+#[test]
+fn test_grid_downsample_2d() {
+    crate::tests::tests::test_grid_downsample_2d::<glam::Vec2>();
+    crate::tests::tests::test_grid_downsample_2d::<Vec2A>();
+    crate::tests::tests::test_grid_downsample_2d::<glam::DVec2>();
+}
+
+#[test]
+fn test_grid_downsample_3d() {
+    crate::tests::tests::test_grid_downsample_3d::<glam::Vec3>();
+    crate::tests::tests::test_grid_downsample_3d::<glam::Vec3A>();
+    crate::tests::tests::test_grid_downsample_3d::<glam::DVec3>();
+}
+
+#[test]
+fn test_weld_vertices_2d() {
+    crate::tests::tests::test_weld_vertices_2d::<glam::Vec2>();
+    crate::tests::tests::test_weld_vertices_2d::<Vec2A>();
+    crate::tests::tests::test_weld_vertices_2d::<glam::DVec2>();
+}
+
+#[test]
+fn test_weld_vertices_3d() {
+    crate::tests::tests::test_weld_vertices_3d::<glam::Vec3>();
+    crate::tests::tests::test_weld_vertices_3d::<glam::Vec3A>();
+    crate::tests::tests::test_weld_vertices_3d::<glam::DVec3>();
+}
+
+#[test]
+fn test_direction_encoding() {
+    crate::tests::tests::test_direction_encoding::<glam::Vec3>();
+    crate::tests::tests::test_direction_encoding::<glam::Vec3A>();
+    crate::tests::tests::test_direction_encoding::<glam::DVec3>();
+}
+
+#[test]
+fn test_meshgrid_2d() {
+    crate::tests::tests::test_meshgrid_2d::<glam::Vec2>();
+    crate::tests::tests::test_meshgrid_2d::<Vec2A>();
+    crate::tests::tests::test_meshgrid_2d::<glam::DVec2>();
+}
+
+#[test]
+fn test_meshgrid_3d() {
+    crate::tests::tests::test_meshgrid_3d::<glam::Vec3>();
+    crate::tests::tests::test_meshgrid_3d::<glam::Vec3A>();
+    crate::tests::tests::test_meshgrid_3d::<glam::DVec3>();
+}
+
+#[test]
+fn test_convex_combination() {
+    crate::tests::tests::test_convex_combination::<glam::Vec2>();
+    crate::tests::tests::test_convex_combination::<Vec2A>();
+    crate::tests::tests::test_convex_combination::<glam::DVec2>();
+}
+
+#[test]
+fn test_rotate_90() {
+    crate::tests::tests::test_rotate_90::<glam::Vec2>();
+    crate::tests::tests::test_rotate_90::<Vec2A>();
+    crate::tests::tests::test_rotate_90::<glam::DVec2>();
+}
+
+#[test]
+fn test_orientation() {
+    crate::tests::tests::test_orientation::<glam::Vec2>();
+    crate::tests::tests::test_orientation::<Vec2A>();
+    crate::tests::tests::test_orientation::<glam::DVec2>();
+}
+
+#[test]
+fn test_dispatch_scalar() {
+    use crate::{dispatch_scalar, GenericScalar, GenericVector2, ScalarKind};
+
+    fn sum_of_squares<S: GenericScalar>(n: u8) -> f64 {
+        (1..=n)
+            .map(|i| <S as From<u8>>::from(i) * <S as From<u8>>::from(i))
+            .fold(S::ZERO, |a, b| a + b)
+            .into()
+    }
+    fn diagonal<V: GenericVector2>() -> f64 {
+        let one = V::Scalar::ONE;
+        V::new_2d(one, one).magnitude().into()
+    }
+
+    assert_eq!("F32".parse::<ScalarKind>(), Ok(ScalarKind::F32));
+    assert_eq!("double".parse::<ScalarKind>(), Ok(ScalarKind::F64));
+    assert!("f16".parse::<ScalarKind>().is_err());
+    assert_eq!(ScalarKind::default().to_string(), "f64");
+
+    for kind in [ScalarKind::F32, ScalarKind::F64] {
+        assert_eq!(dispatch_scalar!(kind, S => sum_of_squares::<S>(3)), 14.0);
+        let size = dispatch_scalar!(kind, S => size_of::<S>());
+        assert_eq!(size, if kind == ScalarKind::F32 { 4 } else { 8 });
+        let d = dispatch_scalar!(kind, S, V2 = (glam::Vec2, glam::DVec2) => diagonal::<V2>());
+        assert!((d - std::f64::consts::SQRT_2).abs() < 1e-6);
+    }
+    let d =
+        dispatch_scalar!(ScalarKind::F32, S, V2 = (glam::Vec2, glam::DVec2) => diagonal::<V2>());
+    assert_ne!(d, std::f64::consts::SQRT_2);
+}
+
+#[test]
+fn test_project_onto_plane() {
+    crate::tests::tests::test_project_onto_plane::<glam::Vec3>();
+    crate::tests::tests::test_project_onto_plane::<glam::Vec3A>();
+    crate::tests::tests::test_project_onto_plane::<glam::DVec3>();
+}
+
+#[test]
+fn test_segment() {
+    crate::tests::tests::test_segment_2d::<glam::Vec2>();
+    crate::tests::tests::test_segment_2d::<Vec2A>();
+    crate::tests::tests::test_segment_2d::<glam::DVec2>();
+    crate::tests::tests::test_segment_3d::<glam::Vec3>();
+    crate::tests::tests::test_segment_3d::<glam::Vec3A>();
+    crate::tests::tests::test_segment_3d::<glam::DVec3>();
+}
+
+#[test]
+fn test_distance_to_line() {
+    crate::tests::tests::test_distance_to_line::<glam::Vec3>();
+    crate::tests::tests::test_distance_to_line::<glam::Vec3A>();
+    crate::tests::tests::test_distance_to_line::<glam::DVec3>();
+}
+
+#[test]
+fn test_signed_angle_about_axis() {
+    crate::tests::tests::test_signed_angle_about_axis::<glam::Vec3>();
+    crate::tests::tests::test_signed_angle_about_axis::<glam::Vec3A>();
+    crate::tests::tests::test_signed_angle_about_axis::<glam::DVec3>();
+}
+
+#[test]
+fn test_running_stats() {
+    crate::tests::tests::test_running_stats::<glam::Vec3>();
+    crate::tests::tests::test_running_stats::<glam::Vec3A>();
+    crate::tests::tests::test_running_stats::<glam::DVec3>();
+}
+
+#[test]
+fn test_matrix3() {
+    crate::tests::tests::test_matrix3::<glam::Mat3>();
+    crate::tests::tests::test_matrix3::<glam::DMat3>();
+}
+
+#[test]
+fn test_matrix2() {
+    crate::tests::tests::test_matrix2::<glam::Mat2>();
+    crate::tests::tests::test_matrix2::<glam::DMat2>();
+}
+
+#[test]
+fn test_matrix4() {
+    crate::tests::tests::test_matrix4::<glam::Mat4>();
+    crate::tests::tests::test_matrix4::<glam::DMat4>();
+}
+
+#[test]
+fn test_affine2() {
+    crate::tests::tests::test_affine2::<glam::Affine2>();
+    crate::tests::tests::test_affine2::<glam::DAffine2>();
+    crate::tests::tests::test_affine2::<crate::AffineMatrix3<glam::Mat3>>();
+    crate::tests::tests::test_affine2::<crate::AffineMatrix3<glam::DMat3>>();
+}
+
+#[test]
+fn test_affine3() {
+    crate::tests::tests::test_affine3::<glam::Affine3A>();
+    crate::tests::tests::test_affine3::<glam::DAffine3>();
+}
+
+#[test]
+fn test_rotation3() {
+    crate::tests::tests::test_rotation3::<glam::Quat>();
+    crate::tests::tests::test_rotation3::<glam::DQuat>();
+}
+
+#[test]
+fn test_flip() {
+    crate::tests::tests::test_flip::<glam::Vec3>();
+    crate::tests::tests::test_flip::<glam::Vec3A>();
+    crate::tests::tests::test_flip::<glam::DVec3>();
+}
+
+#[test]
+fn test_drop_dominant_axis() {
+    crate::tests::tests::test_drop_dominant_axis::<glam::Vec3>();
+    crate::tests::tests::test_drop_dominant_axis::<glam::Vec3A>();
+    crate::tests::tests::test_drop_dominant_axis::<glam::DVec3>();
+}
+
+#[test]
+fn test_cgmath_compat() {
+    crate::tests::tests::test_cgmath_compat::<glam::Vec3>();
+    crate::tests::tests::test_cgmath_compat::<glam::Vec3A>();
+    crate::tests::tests::test_cgmath_compat::<glam::DVec3>();
+}
+
+#[test]
+fn test_sum() {
+    crate::tests::tests::test_sum::<glam::Vec3>();
+    crate::tests::tests::test_sum::<glam::Vec3A>();
+    crate::tests::tests::test_sum::<glam::DVec3>();
+}
+
+#[test]
+fn test_scale() {
+    crate::tests::tests::test_scale::<glam::Vec3>();
+    crate::tests::tests::test_scale::<glam::Vec3A>();
+    crate::tests::tests::test_scale::<glam::DVec3>();
+}
+
+#[test]
+fn test_batch() {
+    crate::tests::tests::test_batch::<glam::Vec3>();
+    crate::tests::tests::test_batch::<glam::Vec3A>();
+    crate::tests::tests::test_batch::<glam::DVec3>();
+}
+
+#[test]
+fn test_flat() {
+    crate::tests::tests::test_flat::<glam::Vec3>();
+    crate::tests::tests::test_flat::<glam::Vec3A>();
+    crate::tests::tests::test_flat::<glam::DVec3>();
+}
+
+#[test]
+fn test_int_vector() {
+    use crate::{GenericIntVector2, GenericIntVector3};
+    crate::tests::tests::test_int_vector::<glam::IVec3>();
+    crate::tests::tests::test_int_vector::<glam::UVec3>();
+    crate::tests::tests::test_int_vector::<glam::I64Vec3>();
+    crate::tests::tests::test_int_vector::<glam::U64Vec3>();
+
+    // signed components
+    let a = glam::ivec3(-1, 5, -3);
+    assert_eq!(a.manhattan_length(), 9);
+    assert_eq!(GenericIntVector3::min_element(a), -3);
+    assert_eq!(glam::ivec2(i32::MIN, 0).manhattan_length(), i32::MIN);
+    assert_eq!(
+        GenericIntVector2::wrapping_sub(glam::ivec2(i32::MIN, 0), glam::ivec2(1, 0)),
+        glam::ivec2(i32::MAX, 0)
+    );
+    assert_eq!(
+        glam::IVec3::from_float_lossy(glam::vec3(-1.9, 5.0, f32::NAN)),
+        glam::ivec3(-1, 5, 0)
+    );
+    assert_eq!(
+        glam::UVec3::from_float_checked(glam::vec3(-1.0, 5.0, 3.0)),
+        None
+    );
+    assert_eq!(
+        glam::I64Vec3::new(-1, 5, -3).to_float_checked::<glam::DVec3>(),
+        Some(glam::dvec3(-1.0, 5.0, -3.0))
+    );
+}
+
+#[test]
+fn test_vector4() {
+    crate::tests::tests::test_vector4::<glam::Vec4>();
+    crate::tests::tests::test_vector4::<glam::DVec4>();
+}
+
+#[test]
+fn test_mask() {
+    crate::tests::tests::test_mask_2d::<glam::Vec2>();
+    crate::tests::tests::test_mask_2d::<glam::DVec2>();
+    crate::tests::tests::test_mask_2d::<Vec2A>();
+    crate::tests::tests::test_mask_3d::<glam::Vec3>();
+    crate::tests::tests::test_mask_3d::<glam::DVec3>();
+    crate::tests::tests::test_mask_3d::<glam::Vec3A>();
+}
+
+#[test]
+fn test_int_conversion() {
+    crate::tests::tests::test_int_conversion_2d::<glam::Vec2>();
+    crate::tests::tests::test_int_conversion_2d::<glam::DVec2>();
+    crate::tests::tests::test_int_conversion_2d::<Vec2A>();
+    crate::tests::tests::test_int_conversion_3d::<glam::Vec3>();
+    crate::tests::tests::test_int_conversion_3d::<glam::DVec3>();
+    crate::tests::tests::test_int_conversion_3d::<glam::Vec3A>();
+}
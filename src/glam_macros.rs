@@ -0,0 +1,1471 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! The trait impls of the glam vector types, shared by the `glam` backend and the `bevy_math`
+//! backend, which links its own version of glam. The invoking module imports the traits and
+//! types the impls refer to.
+
+macro_rules! impl_vector2 {
+    ($vec_type:tt, $scalar_type:ty, $vec3_type:ty) => {
+        impl HasXY for $vec_type {
+            type Scalar = $scalar_type;
+            const DIM: usize = 2;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                <$vec_type>::new(x, y)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_2d(self.x.canonicalize(), self.y.canonicalize())
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_2d(self.x.flush_denormal(), self.y.flush_denormal())
+            }
+        }
+
+        impl Index<Axis2> for $vec_type {
+            type Output = <Self as HasXY>::Scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis2) -> &Self::Output {
+                match axis {
+                    Axis2::X => &self.x,
+                    Axis2::Y => &self.y,
+                }
+            }
+        }
+
+        impl GenericVector2 for $vec_type {
+            type Vector3 = $vec3_type;
+            type Mask = BVec2;
+            type IntVector = IVec2;
+            #[inline(always)]
+            fn cmplt(self, rhs: Self) -> BVec2 {
+                <$vec_type>::cmplt(self, rhs)
+            }
+            #[inline(always)]
+            fn cmple(self, rhs: Self) -> BVec2 {
+                <$vec_type>::cmple(self, rhs)
+            }
+            #[inline(always)]
+            fn cmpgt(self, rhs: Self) -> BVec2 {
+                <$vec_type>::cmpgt(self, rhs)
+            }
+            #[inline(always)]
+            fn cmpeq(self, rhs: Self) -> BVec2 {
+                <$vec_type>::cmpeq(self, rhs)
+            }
+            #[inline(always)]
+            fn select(mask: BVec2, if_true: Self, if_false: Self) -> Self {
+                <$vec_type>::select(mask, if_true, if_false)
+            }
+            #[inline(always)]
+            fn as_ivec(self) -> IVec2 {
+                <$vec_type>::as_ivec2(&self)
+            }
+            #[inline(always)]
+            fn floor_to_int(self) -> IVec2 {
+                <$vec_type>::floor(self).as_ivec2()
+            }
+            #[inline(always)]
+            fn round_to_int(self) -> IVec2 {
+                <$vec_type>::round(self).as_ivec2()
+            }
+
+            #[inline(always)]
+            fn to_3d(self, z: Self::Scalar) -> Self::Vector3 {
+                <$vec3_type>::new(self.x, self.y, z)
+            }
+            #[inline(always)]
+            fn magnitude(self) -> Self::Scalar {
+                strict_or!(crate::math::sqrt(Self::magnitude_sq(self)), self.length())
+            }
+            #[inline(always)]
+            fn magnitude_sq(self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, self), self.length_squared())
+            }
+            #[inline(always)]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, rhs), <$vec_type>::dot(self, rhs))
+            }
+            #[inline(always)]
+            fn perp_dot(self, rhs: Self) -> Self::Scalar {
+                self.perp_dot(rhs)
+            }
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                strict_or!(
+                    self / crate::math::sqrt(Self::magnitude_sq(self)),
+                    <$vec_type>::normalize(self)
+                )
+            }
+            #[inline(always)]
+            fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                let l = Self::magnitude(self);
+                if l > epsilon && l.is_finite() {
+                    Some(self / l)
+                } else {
+                    None
+                }
+            }
+            #[inline(always)]
+            fn distance(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::distance_sq(self, rhs)),
+                    <$vec_type>::distance(self, rhs)
+                )
+            }
+            #[inline(always)]
+            fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self - rhs, self - rhs),
+                    <$vec_type>::distance_squared(self, rhs)
+                )
+            }
+        }
+    };
+}
+
+macro_rules! impl_approx2 {
+    ($vec_type:tt) => {
+        impl Approx for $vec_type {
+            #[inline(always)]
+            fn is_ulps_eq(
+                self,
+                other: Self,
+                epsilon: <Self::Scalar as AbsDiffEq>::Epsilon,
+                max_ulps: u32,
+            ) -> bool {
+                self.x().ulps_eq(&other.x(), epsilon, max_ulps)
+                    && self.y().ulps_eq(&other.y(), epsilon, max_ulps)
+            }
+            #[inline(always)]
+            fn is_abs_diff_eq(
+                self,
+                other: Self,
+                epsilon: <Self::Scalar as AbsDiffEq>::Epsilon,
+            ) -> bool {
+                self.x().abs_diff_eq(&other.x(), epsilon)
+                    && self.y().abs_diff_eq(&other.y(), epsilon)
+            }
+        }
+    };
+}
+
+macro_rules! impl_vector3 {
+    ($vec_type:ty, $scalar_type:ty, $vec2_type:ty) => {
+        impl HasXY for $vec_type {
+            type Scalar = $scalar_type;
+            const DIM: usize = 3;
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                <$vec_type>::new(x, y, Self::Scalar::ZERO)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    2 => Some(self.z),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_3d(
+                    self.x.canonicalize(),
+                    self.y.canonicalize(),
+                    self.z.canonicalize(),
+                )
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_3d(
+                    self.x.flush_denormal(),
+                    self.y.flush_denormal(),
+                    self.z.flush_denormal(),
+                )
+            }
+        }
+
+        impl HasXYZ for $vec_type {
+            #[inline(always)]
+            fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+                <$vec_type>::new(x, y, z)
+            }
+            #[inline(always)]
+            fn z(self) -> Self::Scalar {
+                self.z
+            }
+            #[inline(always)]
+            fn set_z(&mut self, val: Self::Scalar) {
+                self.z = val
+            }
+            #[inline(always)]
+            fn z_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.z
+            }
+        }
+
+        impl Index<Axis3> for $vec_type {
+            type Output = <Self as HasXY>::Scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis3) -> &Self::Output {
+                match axis {
+                    Axis3::X => &self.x,
+                    Axis3::Y => &self.y,
+                    Axis3::Z => &self.z,
+                }
+            }
+        }
+
+        impl GenericVector3 for $vec_type {
+            type Vector2 = $vec2_type;
+            type Mask = BVec3;
+            type IntVector = IVec3;
+            #[inline(always)]
+            fn cmplt(self, rhs: Self) -> BVec3 {
+                <$vec_type>::cmplt(self, rhs)
+            }
+            #[inline(always)]
+            fn cmple(self, rhs: Self) -> BVec3 {
+                <$vec_type>::cmple(self, rhs)
+            }
+            #[inline(always)]
+            fn cmpgt(self, rhs: Self) -> BVec3 {
+                <$vec_type>::cmpgt(self, rhs)
+            }
+            #[inline(always)]
+            fn cmpeq(self, rhs: Self) -> BVec3 {
+                <$vec_type>::cmpeq(self, rhs)
+            }
+            #[inline(always)]
+            fn select(mask: BVec3, if_true: Self, if_false: Self) -> Self {
+                <$vec_type>::select(mask, if_true, if_false)
+            }
+            #[inline(always)]
+            fn as_ivec(self) -> IVec3 {
+                <$vec_type>::as_ivec3(&self)
+            }
+            #[inline(always)]
+            fn floor_to_int(self) -> IVec3 {
+                <$vec_type>::floor(self).as_ivec3()
+            }
+            #[inline(always)]
+            fn round_to_int(self) -> IVec3 {
+                <$vec_type>::round(self).as_ivec3()
+            }
+            #[inline(always)]
+            fn to_2d(&self) -> Self::Vector2 {
+                <$vec2_type>::new(self.x, self.y)
+            }
+            #[inline(always)]
+            fn magnitude(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::magnitude_sq(self)),
+                    <$vec_type>::length(self)
+                )
+            }
+            #[inline(always)]
+            fn magnitude_sq(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self, self),
+                    <$vec_type>::length_squared(self)
+                )
+            }
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                strict_or!(
+                    self / crate::math::sqrt(Self::magnitude_sq(self)),
+                    <$vec_type>::normalize(self)
+                )
+            }
+            #[inline(always)]
+            fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                let l = Self::magnitude(self);
+                if l > epsilon && l.is_finite() {
+                    Some(self / l)
+                } else {
+                    None
+                }
+            }
+            #[inline(always)]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, rhs), <$vec_type>::dot(self, rhs))
+            }
+            #[inline(always)]
+            fn cross(self, rhs: Self) -> Self {
+                <$vec_type>::cross(self, rhs)
+            }
+            #[inline(always)]
+            fn distance(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::distance_sq(self, rhs)),
+                    <$vec_type>::distance(self, rhs)
+                )
+            }
+            #[inline(always)]
+            fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self - rhs, self - rhs),
+                    <$vec_type>::distance_squared(self, rhs)
+                )
+            }
+        }
+    };
+}
+
+macro_rules! impl_approx3 {
+    ($vec_type:ty) => {
+        impl Approx for $vec_type {
+            #[inline(always)]
+            fn is_ulps_eq(
+                self,
+                other: Self,
+                epsilon: <Self::Scalar as AbsDiffEq>::Epsilon,
+                max_ulps: u32,
+            ) -> bool {
+                self.x.ulps_eq(&other.x, epsilon, max_ulps)
+                    && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+                    && self.z.ulps_eq(&other.z, epsilon, max_ulps)
+            }
+            #[inline(always)]
+            fn is_abs_diff_eq(
+                self,
+                other: Self,
+                epsilon: <Self::Scalar as AbsDiffEq>::Epsilon,
+            ) -> bool {
+                self.x.abs_diff_eq(&other.x, epsilon)
+                    && self.y.abs_diff_eq(&other.y, epsilon)
+                    && self.z.abs_diff_eq(&other.z, epsilon)
+            }
+        }
+    };
+}
+
+macro_rules! impl_vector4 {
+    ($vec_type:ty, $scalar_type:ty, $vec3_type:ty) => {
+        impl HasXY for $vec_type {
+            type Scalar = $scalar_type;
+            const DIM: usize = 4;
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                <$vec_type>::new(x, y, Self::Scalar::ZERO, Self::Scalar::ZERO)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    2 => Some(self.z),
+                    3 => Some(self.w),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_4d(
+                    self.x.canonicalize(),
+                    self.y.canonicalize(),
+                    self.z.canonicalize(),
+                    self.w.canonicalize(),
+                )
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_4d(
+                    self.x.flush_denormal(),
+                    self.y.flush_denormal(),
+                    self.z.flush_denormal(),
+                    self.w.flush_denormal(),
+                )
+            }
+        }
+
+        impl HasXYZ for $vec_type {
+            #[inline(always)]
+            fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+                <$vec_type>::new(x, y, z, Self::Scalar::ZERO)
+            }
+            #[inline(always)]
+            fn z(self) -> Self::Scalar {
+                self.z
+            }
+            #[inline(always)]
+            fn set_z(&mut self, val: Self::Scalar) {
+                self.z = val
+            }
+            #[inline(always)]
+            fn z_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.z
+            }
+        }
+
+        impl HasXYZW for $vec_type {
+            #[inline(always)]
+            fn new_4d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar, w: Self::Scalar) -> Self {
+                <$vec_type>::new(x, y, z, w)
+            }
+            #[inline(always)]
+            fn w(self) -> Self::Scalar {
+                self.w
+            }
+            #[inline(always)]
+            fn set_w(&mut self, val: Self::Scalar) {
+                self.w = val
+            }
+            #[inline(always)]
+            fn w_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.w
+            }
+        }
+
+        impl GenericVector4 for $vec_type {
+            type Vector3 = $vec3_type;
+            #[inline(always)]
+            fn truncate(self) -> Self::Vector3 {
+                <$vec_type>::truncate(self)
+            }
+            #[inline(always)]
+            fn magnitude(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::magnitude_sq(self)),
+                    <$vec_type>::length(self)
+                )
+            }
+            #[inline(always)]
+            fn magnitude_sq(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self, self),
+                    <$vec_type>::length_squared(self)
+                )
+            }
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                strict_or!(
+                    self / crate::math::sqrt(Self::magnitude_sq(self)),
+                    <$vec_type>::normalize(self)
+                )
+            }
+            #[inline(always)]
+            fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                let l = Self::magnitude(self);
+                if l > epsilon && l.is_finite() {
+                    Some(self / l)
+                } else {
+                    None
+                }
+            }
+            #[inline(always)]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, rhs), <$vec_type>::dot(self, rhs))
+            }
+            #[inline(always)]
+            fn distance(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::distance_sq(self, rhs)),
+                    <$vec_type>::distance(self, rhs)
+                )
+            }
+            #[inline(always)]
+            fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self - rhs, self - rhs),
+                    <$vec_type>::distance_squared(self, rhs)
+                )
+            }
+        }
+    };
+}
+macro_rules! impl_approx4 {
+    ($vec_type:ty) => {
+        impl Approx for $vec_type {
+            #[inline(always)]
+            fn is_ulps_eq(
+                self,
+                other: Self,
+                epsilon: <Self::Scalar as AbsDiffEq>::Epsilon,
+                max_ulps: u32,
+            ) -> bool {
+                self.x.ulps_eq(&other.x, epsilon, max_ulps)
+                    && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+                    && self.z.ulps_eq(&other.z, epsilon, max_ulps)
+                    && self.w.ulps_eq(&other.w, epsilon, max_ulps)
+            }
+            #[inline(always)]
+            fn is_abs_diff_eq(
+                self,
+                other: Self,
+                epsilon: <Self::Scalar as AbsDiffEq>::Epsilon,
+            ) -> bool {
+                self.x.abs_diff_eq(&other.x, epsilon)
+                    && self.y.abs_diff_eq(&other.y, epsilon)
+                    && self.z.abs_diff_eq(&other.z, epsilon)
+                    && self.w.abs_diff_eq(&other.w, epsilon)
+            }
+        }
+    };
+}
+macro_rules! impl_int_vector2 {
+    ($vec_type:ty, $scalar_type:ty, $vec3_type:ty) => {
+        impl GenericIntVector2 for $vec_type {
+            type Scalar = $scalar_type;
+            type Vector3 = $vec3_type;
+
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                <$vec_type>::new(x, y)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn dot(self, other: Self) -> Self::Scalar {
+                <$vec_type>::dot(self, other)
+            }
+            #[inline(always)]
+            fn min(self, other: Self) -> Self {
+                <$vec_type>::min(self, other)
+            }
+            #[inline(always)]
+            fn max(self, other: Self) -> Self {
+                <$vec_type>::max(self, other)
+            }
+            #[inline(always)]
+            fn min_element(self) -> Self::Scalar {
+                <$vec_type>::min_element(self)
+            }
+            #[inline(always)]
+            fn max_element(self) -> Self::Scalar {
+                <$vec_type>::max_element(self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_int_vector3 {
+    ($vec_type:ty, $scalar_type:ty, $vec2_type:ty) => {
+        impl GenericIntVector3 for $vec_type {
+            type Scalar = $scalar_type;
+            type Vector2 = $vec2_type;
+
+            #[inline(always)]
+            fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+                <$vec_type>::new(x, y, z)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn z(self) -> Self::Scalar {
+                self.z
+            }
+            #[inline(always)]
+            fn dot(self, other: Self) -> Self::Scalar {
+                <$vec_type>::dot(self, other)
+            }
+            #[inline(always)]
+            fn min(self, other: Self) -> Self {
+                <$vec_type>::min(self, other)
+            }
+            #[inline(always)]
+            fn max(self, other: Self) -> Self {
+                <$vec_type>::max(self, other)
+            }
+            #[inline(always)]
+            fn min_element(self) -> Self::Scalar {
+                <$vec_type>::min_element(self)
+            }
+            #[inline(always)]
+            fn max_element(self) -> Self::Scalar {
+                <$vec_type>::max_element(self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_matrix3 {
+    ($mat_type:ty, $scalar_type:ty, $vec2_type:ty, $vec3_type:ty) => {
+        impl GenericMatrix3 for $mat_type {
+            type Scalar = $scalar_type;
+            type Vector2 = $vec2_type;
+            type Vector3 = $vec3_type;
+            #[inline(always)]
+            fn from_cols(x: $vec3_type, y: $vec3_type, z: $vec3_type) -> Self {
+                <$mat_type>::from_cols(x, y, z)
+            }
+            #[inline(always)]
+            fn col(&self, index: usize) -> $vec3_type {
+                <$mat_type>::col(self, index)
+            }
+        }
+    };
+}
+
+macro_rules! impl_matrix2 {
+    ($mat_type:ty, $scalar_type:ty, $vec2_type:ty) => {
+        impl GenericMatrix2 for $mat_type {
+            type Scalar = $scalar_type;
+            type Vector2 = $vec2_type;
+            #[inline(always)]
+            fn from_cols(x: $vec2_type, y: $vec2_type) -> Self {
+                <$mat_type>::from_cols(x, y)
+            }
+            #[inline(always)]
+            fn col(&self, index: usize) -> $vec2_type {
+                <$mat_type>::col(self, index)
+            }
+        }
+    };
+}
+
+macro_rules! impl_matrix4 {
+    ($mat_type:ty, $scalar_type:ty, $vec3_type:ty, $vec4_type:ty, $mat3_type:ty) => {
+        impl GenericMatrix4 for $mat_type {
+            type Scalar = $scalar_type;
+            type Vector3 = $vec3_type;
+            type Vector4 = $vec4_type;
+            type Matrix3 = $mat3_type;
+            #[inline(always)]
+            fn from_cols(x: $vec4_type, y: $vec4_type, z: $vec4_type, w: $vec4_type) -> Self {
+                <$mat_type>::from_cols(x, y, z, w)
+            }
+            #[inline(always)]
+            fn col(&self, index: usize) -> $vec4_type {
+                <$mat_type>::col(self, index)
+            }
+        }
+    };
+}
+
+macro_rules! impl_affine2 {
+    ($affine_type:ty, $scalar_type:ty, $vec2_type:ty) => {
+        impl GenericAffine2 for $affine_type {
+            type Scalar = $scalar_type;
+            type Vector2 = $vec2_type;
+            #[inline(always)]
+            fn identity() -> Self {
+                <$affine_type>::IDENTITY
+            }
+            #[inline(always)]
+            fn from_scale_angle_translation(
+                scale: $scalar_type,
+                angle: $scalar_type,
+                translation: $vec2_type,
+            ) -> Self {
+                <$affine_type>::from_scale_angle_translation(
+                    <$vec2_type>::splat(scale),
+                    angle,
+                    translation,
+                )
+            }
+            #[inline(always)]
+            fn translation(&self) -> $vec2_type {
+                self.translation
+            }
+            #[inline(always)]
+            fn transform_point2(&self, p: $vec2_type) -> $vec2_type {
+                <$affine_type>::transform_point2(self, p)
+            }
+            #[inline(always)]
+            fn transform_vector2(&self, v: $vec2_type) -> $vec2_type {
+                <$affine_type>::transform_vector2(self, v)
+            }
+            #[inline(always)]
+            fn try_inverse(&self) -> Option<Self> {
+                // glam does not check, a singular map inverts to non-finite elements
+                let det = self.matrix2.determinant();
+                if det == 0.0 || !det.is_finite() {
+                    None
+                } else {
+                    Some(self.inverse())
+                }
+            }
+            #[inline(always)]
+            fn compose(&self, other: &Self) -> Self {
+                *self * *other
+            }
+        }
+    };
+}
+
+macro_rules! impl_affine3 {
+    ($affine_type:ty, $scalar_type:ty, $vec3_type:ty, $quat_type:ty) => {
+        impl GenericAffine3 for $affine_type {
+            type Scalar = $scalar_type;
+            type Vector3 = $vec3_type;
+            #[inline(always)]
+            fn identity() -> Self {
+                <$affine_type>::IDENTITY
+            }
+            #[inline(always)]
+            fn from_scale_axis_angle_translation(
+                scale: $scalar_type,
+                axis: $vec3_type,
+                angle: $scalar_type,
+                translation: $vec3_type,
+            ) -> Self {
+                <$affine_type>::from_scale_rotation_translation(
+                    <$vec3_type>::splat(scale),
+                    <$quat_type>::from_axis_angle(axis, angle),
+                    translation,
+                )
+            }
+            #[inline(always)]
+            fn translation(&self) -> $vec3_type {
+                self.translation.into()
+            }
+            #[inline(always)]
+            fn transform_point3(&self, p: $vec3_type) -> $vec3_type {
+                <$affine_type>::transform_point3(self, p)
+            }
+            #[inline(always)]
+            fn transform_vector3(&self, v: $vec3_type) -> $vec3_type {
+                <$affine_type>::transform_vector3(self, v)
+            }
+            #[inline(always)]
+            fn try_inverse(&self) -> Option<Self> {
+                // glam does not check, a singular map inverts to non-finite elements
+                let det = self.matrix3.determinant();
+                if det == 0.0 || !det.is_finite() {
+                    None
+                } else {
+                    Some(self.inverse())
+                }
+            }
+            #[inline(always)]
+            fn compose(&self, other: &Self) -> Self {
+                *self * *other
+            }
+        }
+    };
+}
+
+macro_rules! impl_rotation3 {
+    ($quat_type:ty, $scalar_type:ty, $vec3_type:ty, $mat3_type:ty) => {
+        impl GenericRotation3 for $quat_type {
+            type Scalar = $scalar_type;
+            type Vector3 = $vec3_type;
+            type Matrix3 = $mat3_type;
+            #[inline(always)]
+            fn from_xyzw(
+                x: $scalar_type,
+                y: $scalar_type,
+                z: $scalar_type,
+                w: $scalar_type,
+            ) -> Self {
+                <$quat_type>::from_xyzw(x, y, z, w)
+            }
+            #[inline(always)]
+            fn to_xyzw(self) -> [$scalar_type; 4] {
+                self.to_array()
+            }
+            #[inline(always)]
+            fn from_axis_angle(axis: $vec3_type, angle: $scalar_type) -> Self {
+                <$quat_type>::from_axis_angle(axis, angle)
+            }
+            #[inline(always)]
+            fn rotate_vector(self, v: $vec3_type) -> $vec3_type {
+                self.mul_vec3(v)
+            }
+            #[inline(always)]
+            fn compose(self, other: Self) -> Self {
+                self * other
+            }
+            #[inline(always)]
+            fn to_matrix(self) -> $mat3_type {
+                <$mat3_type>::from_quat(self)
+            }
+        }
+    };
+}
+
+macro_rules! impl_mask {
+    ($mask_type:ty, $dim:expr, $($bit:expr),+) => {
+        impl GenericMask for $mask_type {
+            const DIM: usize = $dim;
+            #[inline(always)]
+            fn from_bitmask(bits: u32) -> Self {
+                <$mask_type>::new($((bits >> $bit) & 1 != 0),+)
+            }
+            #[inline(always)]
+            fn bitmask(self) -> u32 {
+                <$mask_type>::bitmask(self)
+            }
+        }
+    };
+}
+
+// `Vec2A`, the 2D counterpart of `Vec3A`, and the impls of both
+macro_rules! impl_vec3a {
+    () => {
+        /// A wrapper around `Vec2` with zero runtime cost. Created to facilitate the implementation of the trait
+        /// `GenericVector3` for `Vec3A`. While not an ideal solution, it is the most suitable one identified.
+        /// Note that this type is only as aligned as Vec2 is.
+        #[derive(Debug, Default, Copy, Clone, PartialEq)]
+        pub struct Vec2A(pub Vec2);
+
+        impl Vec2A {
+            /// All zeroes.
+            pub const ZERO: Self = Self(Vec2::ZERO);
+
+            /// Creates a new vector, this is the `const` equivalent of `HasXY::new_2d()`.
+            #[inline(always)]
+            pub const fn new(x: f32, y: f32) -> Self {
+                Self(Vec2::new(x, y))
+            }
+
+            /// Creates a vector with all elements set to `v`.
+            #[inline(always)]
+            pub const fn splat(v: f32) -> Self {
+                Self(Vec2::splat(v))
+            }
+        }
+
+        impl From<Vec2> for Vec2A {
+            fn from(v: Vec2) -> Self {
+                Self(v)
+            }
+        }
+
+        impl From<Vec2A> for Vec2 {
+            fn from(v: Vec2A) -> Self {
+                v.0
+            }
+        }
+
+        // Implement From for (f32, f32)
+        impl From<(f32, f32)> for Vec2A {
+            fn from(tuple: (f32, f32)) -> Self {
+                Vec2A(Vec2::new(tuple.0, tuple.1))
+            }
+        }
+
+        // Implement From for [f32; 2]
+        impl From<[f32; 2]> for Vec2A {
+            fn from(array: [f32; 2]) -> Self {
+                Vec2A(Vec2::new(array[0], array[1]))
+            }
+        }
+
+        impl HasXY for Vec2A {
+            type Scalar = f32;
+            const DIM: usize = 2;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                Self(Vec2::new(x, y))
+            }
+
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.0.x
+            }
+
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.0.x
+            }
+
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.0.x = val;
+            }
+
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.0.y
+            }
+
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.0.y
+            }
+
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.0.y = val
+            }
+
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.0.x),
+                    1 => Some(self.0.y),
+                    _ => None,
+                }
+            }
+
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_2d(self.0.x.canonicalize(), self.0.y.canonicalize())
+            }
+
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_2d(self.0.x.flush_denormal(), self.0.y.flush_denormal())
+            }
+        }
+        impl_approx2!(Vec2A);
+
+        impl AbsDiffEq for Vec2A {
+            type Epsilon = f32;
+
+            #[inline(always)]
+            fn default_epsilon() -> Self::Epsilon {
+                f32::default_epsilon()
+            }
+
+            #[inline(always)]
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                self.is_abs_diff_eq(*other, epsilon)
+            }
+        }
+
+        impl UlpsEq for Vec2A {
+            #[inline(always)]
+            fn default_max_ulps() -> u32 {
+                f32::default_max_ulps()
+            }
+
+            #[inline(always)]
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                self.is_ulps_eq(*other, epsilon, max_ulps)
+            }
+        }
+
+        impl HasXY for Vec3A {
+            type Scalar = f32;
+            const DIM: usize = 3;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                vec3a(x, y, Self::Scalar::ZERO)
+            }
+
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val;
+            }
+
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    2 => Some(self.z),
+                    _ => None,
+                }
+            }
+
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_3d(
+                    self.x.canonicalize(),
+                    self.y.canonicalize(),
+                    self.z.canonicalize(),
+                )
+            }
+
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_3d(
+                    self.x.flush_denormal(),
+                    self.y.flush_denormal(),
+                    self.z.flush_denormal(),
+                )
+            }
+        }
+
+        impl HasXYZ for Vec3A {
+            #[inline(always)]
+            fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+                vec3a(x, y, z)
+            }
+
+            #[inline(always)]
+            fn z(self) -> Self::Scalar {
+                self.z
+            }
+
+            #[inline(always)]
+            fn z_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.z
+            }
+
+            #[inline(always)]
+            fn set_z(&mut self, val: Self::Scalar) {
+                self.z = val
+            }
+        }
+
+        impl GenericVector2 for Vec2A {
+            type Vector3 = Vec3A;
+            type Mask = BVec2;
+            type IntVector = IVec2;
+            #[inline(always)]
+            fn cmplt(self, rhs: Self) -> BVec2 {
+                self.0.cmplt(rhs.0)
+            }
+            #[inline(always)]
+            fn cmple(self, rhs: Self) -> BVec2 {
+                self.0.cmple(rhs.0)
+            }
+            #[inline(always)]
+            fn cmpgt(self, rhs: Self) -> BVec2 {
+                self.0.cmpgt(rhs.0)
+            }
+            #[inline(always)]
+            fn cmpeq(self, rhs: Self) -> BVec2 {
+                self.0.cmpeq(rhs.0)
+            }
+            #[inline(always)]
+            fn select(mask: BVec2, if_true: Self, if_false: Self) -> Self {
+                Self(Vec2::select(mask, if_true.0, if_false.0))
+            }
+            #[inline(always)]
+            fn as_ivec(self) -> IVec2 {
+                self.0.as_ivec2()
+            }
+            #[inline(always)]
+            fn floor_to_int(self) -> IVec2 {
+                self.0.floor().as_ivec2()
+            }
+            #[inline(always)]
+            fn round_to_int(self) -> IVec2 {
+                self.0.round().as_ivec2()
+            }
+
+            #[inline(always)]
+            fn to_3d(self, z: Self::Scalar) -> Self::Vector3 {
+                vec3a(self.0.x, self.0.y, z)
+            }
+
+            #[inline(always)]
+            fn magnitude(self) -> Self::Scalar {
+                strict_or!(crate::math::sqrt(Self::magnitude_sq(self)), self.0.length())
+            }
+
+            #[inline(always)]
+            fn magnitude_sq(self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, self), self.0.length_squared())
+            }
+
+            #[inline(always)]
+            fn dot(self, other: Self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, other), self.0.dot(other.0))
+            }
+
+            #[inline(always)]
+            fn perp_dot(self, rhs: Self) -> Self::Scalar {
+                self.0.perp_dot(rhs.0)
+            }
+
+            #[inline(always)]
+            fn distance(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::distance_sq(self, rhs)),
+                    self.0.distance(rhs.0)
+                )
+            }
+
+            #[inline(always)]
+            fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self - rhs, self - rhs),
+                    self.0.distance_squared(rhs.0)
+                )
+            }
+
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                strict_or!(
+                    self / crate::math::sqrt(Self::magnitude_sq(self)),
+                    Vec2A(self.0.normalize())
+                )
+            }
+
+            #[inline(always)]
+            fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                let l = GenericVector2::magnitude(self);
+                (l > epsilon && l.is_finite()).then(|| Vec2A(self.0 / l))
+            }
+        }
+
+        impl GenericVector3 for Vec3A {
+            type Vector2 = Vec2A;
+            type Mask = BVec3A;
+            type IntVector = IVec3;
+            #[inline(always)]
+            fn cmplt(self, rhs: Self) -> BVec3A {
+                <Vec3A>::cmplt(self, rhs)
+            }
+            #[inline(always)]
+            fn cmple(self, rhs: Self) -> BVec3A {
+                <Vec3A>::cmple(self, rhs)
+            }
+            #[inline(always)]
+            fn cmpgt(self, rhs: Self) -> BVec3A {
+                <Vec3A>::cmpgt(self, rhs)
+            }
+            #[inline(always)]
+            fn cmpeq(self, rhs: Self) -> BVec3A {
+                <Vec3A>::cmpeq(self, rhs)
+            }
+            #[inline(always)]
+            fn select(mask: BVec3A, if_true: Self, if_false: Self) -> Self {
+                <Vec3A>::select(mask, if_true, if_false)
+            }
+            #[inline(always)]
+            fn as_ivec(self) -> IVec3 {
+                <Vec3A>::as_ivec3(&self)
+            }
+            #[inline(always)]
+            fn floor_to_int(self) -> IVec3 {
+                <Vec3A>::floor(self).as_ivec3()
+            }
+            #[inline(always)]
+            fn round_to_int(self) -> IVec3 {
+                <Vec3A>::round(self).as_ivec3()
+            }
+
+            #[inline(always)]
+            fn to_2d(&self) -> Self::Vector2 {
+                Vec2A(vec2(self.x, self.y))
+            }
+
+            #[inline(always)]
+            fn magnitude(self) -> Self::Scalar {
+                strict_or!(crate::math::sqrt(Self::magnitude_sq(self)), self.length())
+            }
+
+            #[inline(always)]
+            fn magnitude_sq(self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, self), self.length_squared())
+            }
+
+            #[inline(always)]
+            fn dot(self, other: Self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, other), self.dot(other))
+            }
+
+            #[inline(always)]
+            fn cross(self, rhs: Self) -> Self {
+                self.cross(rhs)
+            }
+
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                strict_or!(
+                    self / crate::math::sqrt(Self::magnitude_sq(self)),
+                    self.normalize()
+                )
+            }
+
+            #[inline(always)]
+            fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                let l = GenericVector3::magnitude(self);
+                (l > epsilon && l.is_finite()).then(|| self / l)
+            }
+
+            #[inline(always)]
+            fn distance(self, other: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(Self::distance_sq(self, other)),
+                    self.distance(other)
+                )
+            }
+
+            #[inline(always)]
+            fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::dot(self - rhs, self - rhs),
+                    self.distance_squared(rhs)
+                )
+            }
+        }
+
+        impl_approx3!(Vec3A);
+
+        impl Add for Vec2A {
+            type Output = Self;
+
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self::Output {
+                Vec2A(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub for Vec2A {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Vec2A(self.0 - rhs.0)
+            }
+        }
+
+        impl Add<&Vec2A> for Vec2A {
+            type Output = Vec2A;
+
+            #[inline(always)]
+            fn add(self, rhs: &Vec2A) -> Self::Output {
+                Vec2A(self.0 + rhs.0)
+            }
+        }
+
+        impl Add<&Vec2A> for &Vec2A {
+            type Output = Vec2A;
+
+            #[inline(always)]
+            fn add(self, rhs: &Vec2A) -> Self::Output {
+                Vec2A(self.0 + rhs.0)
+            }
+        }
+
+        impl Sub<&Vec2A> for Vec2A {
+            type Output = Vec2A;
+
+            #[inline(always)]
+            fn sub(self, rhs: &Vec2A) -> Self::Output {
+                Vec2A(self.0 - rhs.0)
+            }
+        }
+
+        impl Sub<&Vec2A> for &Vec2A {
+            type Output = Vec2A;
+
+            #[inline(always)]
+            fn sub(self, rhs: &Vec2A) -> Self::Output {
+                Vec2A(self.0 - rhs.0)
+            }
+        }
+
+        impl Sum for Vec2A {
+            #[inline(always)]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                Vec2A(iter.map(|v| v.0).sum())
+            }
+        }
+
+        impl<'a> Sum<&'a Self> for Vec2A {
+            #[inline(always)]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                Vec2A(iter.map(|v| v.0).sum())
+            }
+        }
+
+        impl Index<usize> for Vec2A {
+            type Output = f32;
+
+            #[inline(always)]
+            fn index(&self, index: usize) -> &Self::Output {
+                &self.0[index]
+            }
+        }
+
+        impl Index<Axis2> for Vec2A {
+            type Output = <Self as HasXY>::Scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis2) -> &Self::Output {
+                match axis {
+                    Axis2::X => &self.0.x,
+                    Axis2::Y => &self.0.y,
+                }
+            }
+        }
+
+        impl Index<Axis3> for Vec3A {
+            type Output = <Self as HasXY>::Scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis3) -> &Self::Output {
+                match axis {
+                    Axis3::X => &self.x,
+                    Axis3::Y => &self.y,
+                    Axis3::Z => &self.z,
+                }
+            }
+        }
+
+        impl AddAssign for Vec2A {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl SubAssign for Vec2A {
+            #[inline(always)]
+            fn sub_assign(&mut self, rhs: Self) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl MulAssign<f32> for Vec2A {
+            #[inline(always)]
+            fn mul_assign(&mut self, rhs: f32) {
+                self.0 *= rhs;
+            }
+        }
+
+        impl DivAssign<f32> for Vec2A {
+            #[inline(always)]
+            fn div_assign(&mut self, rhs: f32) {
+                self.0 /= rhs;
+            }
+        }
+
+        impl Mul<f32> for Vec2A {
+            type Output = Self;
+
+            #[inline(always)]
+            fn mul(self, rhs: f32) -> Self::Output {
+                Vec2A(self.0 * rhs)
+            }
+        }
+
+        impl Mul<Vec2A> for f32 {
+            type Output = Vec2A;
+
+            #[inline(always)]
+            fn mul(self, rhs: Vec2A) -> Self::Output {
+                Vec2A(self * rhs.0)
+            }
+        }
+
+        impl Div<f32> for Vec2A {
+            type Output = Self;
+
+            #[inline(always)]
+            fn div(self, rhs: f32) -> Self::Output {
+                Vec2A(self.0 / rhs)
+            }
+        }
+
+        impl Neg for Vec2A {
+            type Output = Self;
+
+            #[inline(always)]
+            fn neg(self) -> Self::Output {
+                Vec2A(-self.0)
+            }
+        }
+    };
+}
@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `HasXY`, `HasXYZ` and `Approx` for `godot::builtin::Vector2` and `Vector3`, so gdext based
+//! games can run the algorithms written against the storage traits directly on engine types.
+//!
+//! The scalar is `godot::builtin::real`, `f32` unless godot is built with its `double-precision`
+//! feature. The generic vector traits are not implemented: they require `Index<usize>`, which the
+//! godot vectors do not implement (they index by `Vector2Axis` and `Vector3Axis`) and this crate
+//! can not provide for a foreign type. `to_vector2()`, `to_vector3()`, `from_vector2()` and
+//! `from_vector3()` convert to and from any vector type.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{Approx, Axis2, Axis3, GenericScalar, HasXY, HasXYZ};
+pub use ::godot::builtin::{real, Vector2, Vector3};
+use std::ops::Index;
+
+impl HasXY for Vector2 {
+    type Scalar = real;
+    const DIM: usize = 2;
+    #[inline(always)]
+    fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+        Vector2::new(x, y)
+    }
+    #[inline(always)]
+    fn x(self) -> Self::Scalar {
+        self.x
+    }
+    #[inline(always)]
+    fn set_x(&mut self, val: Self::Scalar) {
+        self.x = val
+    }
+    #[inline(always)]
+    fn x_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.x
+    }
+    #[inline(always)]
+    fn y(self) -> Self::Scalar {
+        self.y
+    }
+    #[inline(always)]
+    fn set_y(&mut self, val: Self::Scalar) {
+        self.y = val
+    }
+    #[inline(always)]
+    fn y_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.y
+    }
+    #[inline(always)]
+    fn get(self, index: usize) -> Option<Self::Scalar> {
+        match index {
+            0 => Some(self.x),
+            1 => Some(self.y),
+            _ => None,
+        }
+    }
+    #[inline(always)]
+    fn canonicalize(self) -> Self {
+        Self::new_2d(self.x.canonicalize(), self.y.canonicalize())
+    }
+    #[inline(always)]
+    fn flush_denormals(self) -> Self {
+        Self::new_2d(self.x.flush_denormal(), self.y.flush_denormal())
+    }
+}
+
+impl Index<Axis2> for Vector2 {
+    type Output = real;
+
+    #[inline(always)]
+    fn index(&self, axis: Axis2) -> &Self::Output {
+        match axis {
+            Axis2::X => &self.x,
+            Axis2::Y => &self.y,
+        }
+    }
+}
+
+impl Approx for Vector2 {}
+
+impl HasXY for Vector3 {
+    type Scalar = real;
+    const DIM: usize = 3;
+    #[inline(always)]
+    fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+        Vector3::new(x, y, Self::Scalar::ZERO)
+    }
+    #[inline(always)]
+    fn x(self) -> Self::Scalar {
+        self.x
+    }
+    #[inline(always)]
+    fn set_x(&mut self, val: Self::Scalar) {
+        self.x = val
+    }
+    #[inline(always)]
+    fn x_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.x
+    }
+    #[inline(always)]
+    fn y(self) -> Self::Scalar {
+        self.y
+    }
+    #[inline(always)]
+    fn set_y(&mut self, val: Self::Scalar) {
+        self.y = val
+    }
+    #[inline(always)]
+    fn y_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.y
+    }
+    #[inline(always)]
+    fn get(self, index: usize) -> Option<Self::Scalar> {
+        match index {
+            0 => Some(self.x),
+            1 => Some(self.y),
+            2 => Some(self.z),
+            _ => None,
+        }
+    }
+    #[inline(always)]
+    fn canonicalize(self) -> Self {
+        Self::new_3d(
+            self.x.canonicalize(),
+            self.y.canonicalize(),
+            self.z.canonicalize(),
+        )
+    }
+    #[inline(always)]
+    fn flush_denormals(self) -> Self {
+        Self::new_3d(
+            self.x.flush_denormal(),
+            self.y.flush_denormal(),
+            self.z.flush_denormal(),
+        )
+    }
+}
+
+impl HasXYZ for Vector3 {
+    #[inline(always)]
+    fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+        Vector3::new(x, y, z)
+    }
+    #[inline(always)]
+    fn z(self) -> Self::Scalar {
+        self.z
+    }
+    #[inline(always)]
+    fn set_z(&mut self, val: Self::Scalar) {
+        self.z = val
+    }
+    #[inline(always)]
+    fn z_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.z
+    }
+}
+
+impl Index<Axis3> for Vector3 {
+    type Output = real;
+
+    #[inline(always)]
+    fn index(&self, axis: Axis3) -> &Self::Output {
+        match axis {
+            Axis3::X => &self.x,
+            Axis3::Y => &self.y,
+            Axis3::Z => &self.z,
+        }
+    }
+}
+
+impl Approx for Vector3 {}
+
+/// Returns the x and y of `v` as a godot `Vector2`.
+#[inline(always)]
+pub fn to_vector2<V: HasXY<Scalar = real>>(v: V) -> Vector2 {
+    Vector2::new(v.x(), v.y())
+}
+
+/// Returns the x, y and z of `v` as a godot `Vector3`.
+#[inline(always)]
+pub fn to_vector3<V: HasXYZ<Scalar = real>>(v: V) -> Vector3 {
+    Vector3::new(v.x(), v.y(), v.z())
+}
+
+/// Returns the godot `Vector2` as a vector of type `V`.
+#[inline(always)]
+pub fn from_vector2<V: HasXY<Scalar = real>>(v: Vector2) -> V {
+    V::new_2d(v.x, v.y)
+}
+
+/// Returns the godot `Vector3` as a vector of type `V`.
+#[inline(always)]
+pub fn from_vector3<V: HasXYZ<Scalar = real>>(v: Vector3) -> V {
+    V::new_3d(v.x, v.y, v.z)
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{from_vector2, from_vector3, to_vector2, to_vector3, Vector2, Vector3};
+use crate::{closest_point_on_segment, distance_robust, Approx, Axis2, Axis3, HasXYZ};
+
+#[test]
+fn test_godot() {
+    crate::tests::tests::test_xy::<Vector2>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Vector3>(1.0, 2.0);
+    crate::tests::tests::test_xyz::<Vector3>(1.0, 2.0, 3.0);
+
+    let p = Vector3::new_3d(1.0, 2.0, 3.0);
+    assert_eq!((p[Axis3::Z], Vector2::new(5.0, 6.0)[Axis2::Y]), (3.0, 6.0));
+    assert!(p.is_abs_diff_eq(Vector3::new(1.0, 2.0, 3.0 + 1e-7), 1e-6));
+    assert_eq!(distance_robust(p, Vector3::new(4.0, 6.0, 3.0)), 5.0);
+    // the engine operators are enough for the storage trait algorithms
+    let (c, t) =
+        closest_point_on_segment(Vector2::new(1.0, 1.0), Vector2::ZERO, Vector2::RIGHT * 2.0);
+    assert_eq!((c, t), (Vector2::new(1.0, 0.0), 0.5));
+}
+
+#[test]
+fn test_conversions() {
+    let v: Vector3 = from_vector3(Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(to_vector3(v), Vector3::new(1.0, 2.0, 3.0));
+    // a 3D vector converts to a 2D vector by dropping z
+    assert_eq!(to_vector2(v), Vector2::new(1.0, 2.0));
+    let v: Vector3 = from_vector2(Vector2::new(1.0, 2.0));
+    assert_eq!(v, Vector3::new(1.0, 2.0, 0.0));
+}
@@ -0,0 +1,296 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Half precision vector storage, for GPU bound pipelines that upload vertex data as `f16`.
+//!
+//! `half::f16` can not be a vector scalar: `GenericScalar` requires `FloatConst`, `Signed`, the
+//! primitive casts and the rest of the `f32` interface, which the half types do not implement.
+//! `HalfScalar` is the relaxed tier for such types, a storage scalar that is converted to `f32`
+//! for every operation. `F16Vec2` and `F16Vec3` store their components as `f16` in C layout, while
+//! their accessors and operators work in `f32` and round the result back to `f16`.
+//!
+//! The vectors do not implement `HasXY`: `x_mut()` returns a reference to the scalar, and an `f32`
+//! can not be borrowed from `f16` storage. Like the integer storage types they convert to and from
+//! every `HasXY`/`HasXYZ` type instead, with `to_float()` and `from_float()`.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{GenericScalar, HasXY, HasXYZ};
+pub use ::half::{bf16, f16};
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// A storage only scalar, converted to `f32` for arithmetic. Implemented for `f16` and `bf16`.
+pub trait HalfScalar: Copy + Debug + Default + PartialEq + Send + Sync + 'static {
+    /// Converts the value to `f32`, this conversion is exact.
+    fn to_f32(self) -> f32;
+    /// Converts an `f32` to the nearest representable value. Values out of range become infinite.
+    fn from_f32(value: f32) -> Self;
+
+    /// Converts a float scalar, rounding through `f32`.
+    #[inline(always)]
+    fn from_scalar<S: GenericScalar>(value: S) -> Self {
+        Self::from_f32(value.as_())
+    }
+    /// Converts the value to a float scalar, this conversion is exact.
+    #[inline(always)]
+    fn to_scalar<S: GenericScalar>(self) -> S {
+        self.to_f32().into()
+    }
+}
+
+impl HalfScalar for f16 {
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        f16::to_f32(self)
+    }
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        f16::from_f32(value)
+    }
+}
+
+impl HalfScalar for bf16 {
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        bf16::to_f32(self)
+    }
+    #[inline(always)]
+    fn from_f32(value: f32) -> Self {
+        bf16::from_f32(value)
+    }
+}
+
+/// A two-dimensional vector with `f16` storage and C layout.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct F16Vec2 {
+    pub x: f16,
+    pub y: f16,
+}
+
+/// A three-dimensional vector with `f16` storage and C layout.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct F16Vec3 {
+    pub x: f16,
+    pub y: f16,
+    pub z: f16,
+}
+
+impl F16Vec2 {
+    /// All zeroes.
+    pub const ZERO: Self = Self::new(f16::ZERO, f16::ZERO);
+
+    #[inline(always)]
+    pub const fn new(x: f16, y: f16) -> Self {
+        Self { x, y }
+    }
+
+    /// Creates a vector from `f32` components, rounded to the nearest `f16`.
+    #[inline(always)]
+    pub fn from_f32(x: f32, y: f32) -> Self {
+        Self::new(f16::from_f32(x), f16::from_f32(y))
+    }
+
+    #[inline(always)]
+    pub fn x(self) -> f32 {
+        self.x.to_f32()
+    }
+    #[inline(always)]
+    pub fn y(self) -> f32 {
+        self.y.to_f32()
+    }
+    /// Sets the x component, rounded to the nearest `f16`.
+    #[inline(always)]
+    pub fn set_x(&mut self, val: f32) {
+        self.x = f16::from_f32(val)
+    }
+    /// Sets the y component, rounded to the nearest `f16`.
+    #[inline(always)]
+    pub fn set_y(&mut self, val: f32) {
+        self.y = f16::from_f32(val)
+    }
+    /// Returns the component at `index`, or `None` if `index >= 2`.
+    #[inline(always)]
+    pub fn get(self, index: usize) -> Option<f32> {
+        match index {
+            0 => Some(self.x()),
+            1 => Some(self.y()),
+            _ => None,
+        }
+    }
+
+    /// Converts to a float vector, this conversion is exact.
+    #[inline(always)]
+    pub fn to_float<V: HasXY>(self) -> V {
+        V::new_2d(self.x.to_scalar(), self.y.to_scalar())
+    }
+
+    /// Converts from a float vector, each component is rounded to the nearest `f16`. Components
+    /// out of the `f16` range become infinite.
+    #[inline(always)]
+    pub fn from_float<V: HasXY>(v: V) -> Self {
+        Self::new(f16::from_scalar(v.x()), f16::from_scalar(v.y()))
+    }
+}
+
+impl F16Vec3 {
+    /// All zeroes.
+    pub const ZERO: Self = Self::new(f16::ZERO, f16::ZERO, f16::ZERO);
+
+    #[inline(always)]
+    pub const fn new(x: f16, y: f16, z: f16) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Creates a vector from `f32` components, rounded to the nearest `f16`.
+    #[inline(always)]
+    pub fn from_f32(x: f32, y: f32, z: f32) -> Self {
+        Self::new(f16::from_f32(x), f16::from_f32(y), f16::from_f32(z))
+    }
+
+    #[inline(always)]
+    pub fn x(self) -> f32 {
+        self.x.to_f32()
+    }
+    #[inline(always)]
+    pub fn y(self) -> f32 {
+        self.y.to_f32()
+    }
+    #[inline(always)]
+    pub fn z(self) -> f32 {
+        self.z.to_f32()
+    }
+    /// Sets the x component, rounded to the nearest `f16`.
+    #[inline(always)]
+    pub fn set_x(&mut self, val: f32) {
+        self.x = f16::from_f32(val)
+    }
+    /// Sets the y component, rounded to the nearest `f16`.
+    #[inline(always)]
+    pub fn set_y(&mut self, val: f32) {
+        self.y = f16::from_f32(val)
+    }
+    /// Sets the z component, rounded to the nearest `f16`.
+    #[inline(always)]
+    pub fn set_z(&mut self, val: f32) {
+        self.z = f16::from_f32(val)
+    }
+    /// Returns the component at `index`, or `None` if `index >= 3`.
+    #[inline(always)]
+    pub fn get(self, index: usize) -> Option<f32> {
+        match index {
+            0 => Some(self.x()),
+            1 => Some(self.y()),
+            2 => Some(self.z()),
+            _ => None,
+        }
+    }
+
+    /// Converts to a float vector, this conversion is exact.
+    #[inline(always)]
+    pub fn to_float<V: HasXYZ>(self) -> V {
+        V::new_3d(self.x.to_scalar(), self.y.to_scalar(), self.z.to_scalar())
+    }
+
+    /// Converts from a float vector, each component is rounded to the nearest `f16`. Components
+    /// out of the `f16` range become infinite.
+    #[inline(always)]
+    pub fn from_float<V: HasXYZ>(v: V) -> Self {
+        Self::new(
+            f16::from_scalar(v.x()),
+            f16::from_scalar(v.y()),
+            f16::from_scalar(v.z()),
+        )
+    }
+}
+
+// the operators compute in f32 and round the result to f16 once
+macro_rules! impl_half_ops {
+    ($vec_type:ty, $($c:ident),+) => {
+        impl Add for $vec_type {
+            type Output = Self;
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($c: f16::from_f32(self.$c.to_f32() + rhs.$c.to_f32())),+ }
+            }
+        }
+
+        impl Sub for $vec_type {
+            type Output = Self;
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($c: f16::from_f32(self.$c.to_f32() - rhs.$c.to_f32())),+ }
+            }
+        }
+
+        impl Mul<f32> for $vec_type {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, rhs: f32) -> Self {
+                Self { $($c: f16::from_f32(self.$c.to_f32() * rhs)),+ }
+            }
+        }
+
+        impl Div<f32> for $vec_type {
+            type Output = Self;
+            #[inline(always)]
+            fn div(self, rhs: f32) -> Self {
+                Self { $($c: f16::from_f32(self.$c.to_f32() / rhs)),+ }
+            }
+        }
+
+        impl Neg for $vec_type {
+            type Output = Self;
+            #[inline(always)]
+            fn neg(self) -> Self {
+                Self { $($c: -self.$c),+ }
+            }
+        }
+    };
+}
+
+impl_half_ops!(F16Vec2, x, y);
+impl_half_ops!(F16Vec3, x, y, z);
+
+impl From<[f16; 2]> for F16Vec2 {
+    fn from(array: [f16; 2]) -> Self {
+        Self::new(array[0], array[1])
+    }
+}
+
+impl From<[f32; 2]> for F16Vec2 {
+    fn from(array: [f32; 2]) -> Self {
+        Self::from_f32(array[0], array[1])
+    }
+}
+
+impl From<F16Vec2> for [f32; 2] {
+    fn from(v: F16Vec2) -> Self {
+        [v.x(), v.y()]
+    }
+}
+
+impl From<[f16; 3]> for F16Vec3 {
+    fn from(array: [f16; 3]) -> Self {
+        Self::new(array[0], array[1], array[2])
+    }
+}
+
+impl From<[f32; 3]> for F16Vec3 {
+    fn from(array: [f32; 3]) -> Self {
+        Self::from_f32(array[0], array[1], array[2])
+    }
+}
+
+impl From<F16Vec3> for [f32; 3] {
+    fn from(v: F16Vec3) -> Self {
+        [v.x(), v.y(), v.z()]
+    }
+}
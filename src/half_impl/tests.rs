@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{bf16, f16, F16Vec2, F16Vec3, HalfScalar};
+
+#[test]
+fn test_half_scalar() {
+    assert_eq!(HalfScalar::to_f32(f16::from_f32(0.5)), 0.5);
+    assert_eq!(<f16 as HalfScalar>::from_scalar(1.0_f64), f16::ONE);
+    assert_eq!(f16::from_f32(0.1).to_scalar::<f64>(), 0.0999755859375);
+    assert_eq!(<f16 as HalfScalar>::from_f32(1.0e6), f16::INFINITY);
+    assert_eq!(<bf16 as HalfScalar>::from_f32(1.0e6).to_f32(), 999424.0);
+}
+
+#[test]
+fn test_f16_vec2() {
+    assert_eq!(size_of::<F16Vec2>(), 4);
+    let mut v = F16Vec2::from_f32(1.0, 2.0);
+    assert_eq!((v.x(), v.y()), (1.0, 2.0));
+    assert_eq!((v.get(1), v.get(2)), (Some(2.0), None));
+    v.set_y(0.1);
+    assert_eq!(v.y, f16::from_f32(0.1));
+    assert_eq!(v, F16Vec2::from([1.0_f32, 0.1]));
+    assert_eq!(<[f32; 2]>::from(F16Vec2::ZERO), [0.0, 0.0]);
+
+    let a: [f64; 2] = F16Vec2::from_f32(1.5, -2.0).to_float();
+    assert_eq!(a, [1.5, -2.0]);
+    assert_eq!(
+        F16Vec2::from_float((1.5_f64, -2.0)),
+        F16Vec2::from_f32(1.5, -2.0)
+    );
+    // f16 has 11 significant bits, so 2049 rounds to the nearest even value
+    assert_eq!(F16Vec2::from_float([2049.0_f32, 0.0]).x(), 2048.0);
+}
+
+#[test]
+fn test_f16_vec3() {
+    assert_eq!(size_of::<F16Vec3>(), 6);
+    let a = F16Vec3::from_f32(1.0, 2.0, 3.0);
+    let b = F16Vec3::from([f16::ONE, f16::ONE, f16::ONE]);
+    assert_eq!(a + b, F16Vec3::from_f32(2.0, 3.0, 4.0));
+    assert_eq!(a - b, F16Vec3::from_f32(0.0, 1.0, 2.0));
+    assert_eq!(a * 2.0, F16Vec3::from_f32(2.0, 4.0, 6.0));
+    assert_eq!(a / 2.0, F16Vec3::from_f32(0.5, 1.0, 1.5));
+    assert_eq!(-a, F16Vec3::from_f32(-1.0, -2.0, -3.0));
+    // results out of the f16 range become infinite
+    assert_eq!((a * 1.0e5).x(), f32::INFINITY);
+
+    let mut c = F16Vec3::ZERO;
+    c.set_x(4.0);
+    c.set_z(-1.0);
+    assert_eq!(<[f32; 3]>::from(c), [4.0, 0.0, -1.0]);
+    assert_eq!(c.get(2), Some(-1.0));
+    let d: [f32; 3] = c.to_float();
+    assert_eq!(F16Vec3::from_float(d), c);
+}
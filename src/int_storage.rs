@@ -0,0 +1,280 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Integer coordinate storage types, e.g. for FFI with fixed-grid formats.
+//!
+//! `HasXY` requires a floating point scalar, so these types do not implement it. Instead they
+//! convert to and from any `HasXY`/`HasXYZ` type through a grid `scale`, the length of one
+//! integer step in float units.
+//!
+//! They implement `GenericIntVector2` and `GenericIntVector3`, and are the `IntVector` of the
+//! float vectors that have no integer vector of their own, like the cgmath vectors.
+
+use crate::{GenericIntScalar, GenericIntVector2, GenericIntVector3, GenericScalar, HasXY, HasXYZ};
+use num_traits::{Float, NumCast, Signed};
+use std::ops::{Add, AddAssign, Index, Mul, Sub, SubAssign};
+
+/// A trait meant to represent i32 or i64 coordinates.
+pub trait IntScalar: GenericIntScalar + Signed {
+    /// Converts the integer to a float scalar, this conversion can not fail.
+    #[inline(always)]
+    fn to_scalar<S: GenericScalar>(self) -> S {
+        <S as NumCast>::from(self).unwrap()
+    }
+
+    /// Converts a float scalar to the nearest integer.
+    /// Returns `None` if the value is not finite or out of range.
+    #[inline(always)]
+    fn from_scalar<S: GenericScalar>(value: S) -> Option<Self> {
+        <Self as NumCast>::from(Float::round(value))
+    }
+}
+
+impl IntScalar for i32 {}
+impl IntScalar for i64 {}
+
+/// A two-dimensional integer coordinate with C layout.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntVec2<I: IntScalar> {
+    pub x: I,
+    pub y: I,
+}
+
+/// A three-dimensional integer coordinate with C layout.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IntVec3<I: IntScalar> {
+    pub x: I,
+    pub y: I,
+    pub z: I,
+}
+
+impl<I: IntScalar> IntVec2<I> {
+    #[inline(always)]
+    pub const fn new(x: I, y: I) -> Self {
+        Self { x, y }
+    }
+
+    /// Converts to a float vector, each component is multiplied by `scale`.
+    #[inline(always)]
+    pub fn to_float<V: HasXY>(self, scale: V::Scalar) -> V {
+        V::new_2d(
+            self.x.to_scalar::<V::Scalar>() * scale,
+            self.y.to_scalar::<V::Scalar>() * scale,
+        )
+    }
+
+    /// Converts from a float vector, each component is divided by `scale` and rounded to the
+    /// nearest integer. Returns `None` if any component is not finite or out of range.
+    #[inline(always)]
+    pub fn from_float<V: HasXY>(v: V, scale: V::Scalar) -> Option<Self> {
+        Some(Self {
+            x: I::from_scalar(v.x() / scale)?,
+            y: I::from_scalar(v.y() / scale)?,
+        })
+    }
+}
+
+impl<I: IntScalar> IntVec3<I> {
+    #[inline(always)]
+    pub const fn new(x: I, y: I, z: I) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Converts to a float vector, each component is multiplied by `scale`.
+    #[inline(always)]
+    pub fn to_float<V: HasXYZ>(self, scale: V::Scalar) -> V {
+        V::new_3d(
+            self.x.to_scalar::<V::Scalar>() * scale,
+            self.y.to_scalar::<V::Scalar>() * scale,
+            self.z.to_scalar::<V::Scalar>() * scale,
+        )
+    }
+
+    /// Converts from a float vector, each component is divided by `scale` and rounded to the
+    /// nearest integer. Returns `None` if any component is not finite or out of range.
+    #[inline(always)]
+    pub fn from_float<V: HasXYZ>(v: V, scale: V::Scalar) -> Option<Self> {
+        Some(Self {
+            x: I::from_scalar(v.x() / scale)?,
+            y: I::from_scalar(v.y() / scale)?,
+            z: I::from_scalar(v.z() / scale)?,
+        })
+    }
+}
+
+impl<I: IntScalar> From<[I; 2]> for IntVec2<I> {
+    fn from(array: [I; 2]) -> Self {
+        Self::new(array[0], array[1])
+    }
+}
+
+impl<I: IntScalar> From<(I, I)> for IntVec2<I> {
+    fn from(tuple: (I, I)) -> Self {
+        Self::new(tuple.0, tuple.1)
+    }
+}
+
+impl<I: IntScalar> From<[I; 3]> for IntVec3<I> {
+    fn from(array: [I; 3]) -> Self {
+        Self::new(array[0], array[1], array[2])
+    }
+}
+
+impl<I: IntScalar> From<(I, I, I)> for IntVec3<I> {
+    fn from(tuple: (I, I, I)) -> Self {
+        Self::new(tuple.0, tuple.1, tuple.2)
+    }
+}
+
+impl<I: IntScalar> GenericIntVector2 for IntVec2<I> {
+    type Scalar = I;
+    type Vector3 = IntVec3<I>;
+
+    #[inline(always)]
+    fn new_2d(x: I, y: I) -> Self {
+        Self::new(x, y)
+    }
+    #[inline(always)]
+    fn x(self) -> I {
+        self.x
+    }
+    #[inline(always)]
+    fn y(self) -> I {
+        self.y
+    }
+    #[inline(always)]
+    fn dot(self, other: Self) -> I {
+        self.x * other.x + self.y * other.y
+    }
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        Self::new(self.x.min(other.x), self.y.min(other.y))
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        Self::new(self.x.max(other.x), self.y.max(other.y))
+    }
+    #[inline(always)]
+    fn min_element(self) -> I {
+        self.x.min(self.y)
+    }
+    #[inline(always)]
+    fn max_element(self) -> I {
+        self.x.max(self.y)
+    }
+}
+
+impl<I: IntScalar> GenericIntVector3 for IntVec3<I> {
+    type Scalar = I;
+    type Vector2 = IntVec2<I>;
+
+    #[inline(always)]
+    fn new_3d(x: I, y: I, z: I) -> Self {
+        Self::new(x, y, z)
+    }
+    #[inline(always)]
+    fn x(self) -> I {
+        self.x
+    }
+    #[inline(always)]
+    fn y(self) -> I {
+        self.y
+    }
+    #[inline(always)]
+    fn z(self) -> I {
+        self.z
+    }
+    #[inline(always)]
+    fn dot(self, other: Self) -> I {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+    #[inline(always)]
+    fn min(self, other: Self) -> Self {
+        Self::new(
+            self.x.min(other.x),
+            self.y.min(other.y),
+            self.z.min(other.z),
+        )
+    }
+    #[inline(always)]
+    fn max(self, other: Self) -> Self {
+        Self::new(
+            self.x.max(other.x),
+            self.y.max(other.y),
+            self.z.max(other.z),
+        )
+    }
+    #[inline(always)]
+    fn min_element(self) -> I {
+        self.x.min(self.y).min(self.z)
+    }
+    #[inline(always)]
+    fn max_element(self) -> I {
+        self.x.max(self.y).max(self.z)
+    }
+}
+
+/// The arithmetic operators, component by component.
+macro_rules! impl_int_vec_ops {
+    ($vec:ident, $($field:ident = $index:literal),+) => {
+        impl<I: IntScalar> Index<usize> for $vec<I> {
+            type Output = I;
+
+            #[inline(always)]
+            fn index(&self, index: usize) -> &I {
+                match index {
+                    $($index => &self.$field,)+
+                    _ => panic!("index out of bounds: {index}"),
+                }
+            }
+        }
+
+        impl<I: IntScalar> Add for $vec<I> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl<I: IntScalar> Sub for $vec<I> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl<I: IntScalar> Mul<I> for $vec<I> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn mul(self, rhs: I) -> Self {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl<I: IntScalar> AddAssign for $vec<I> {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl<I: IntScalar> SubAssign for $vec<I> {
+            #[inline(always)]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+    };
+}
+
+impl_int_vec_ops!(IntVec2, x = 0, y = 1);
+impl_int_vec_ops!(IntVec3, x = 0, y = 1, z = 2);
@@ -0,0 +1,285 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Integer vector traits, the grid counterpart of `GenericVector2` and `GenericVector3` for voxel
+//! indices, pixel coordinates and the like.
+//!
+//! The traits are implemented for the `glam` integer vectors with the `glam` feature. Integer
+//! arithmetic overflows, so alongside the operators the traits provide wrapping variants, and the
+//! conversions from the float vector traits come in a lossy and a checked flavour.
+
+use crate::{GenericScalar, HasXY, HasXYZ};
+use num_traits::{AsPrimitive, Float, NumCast, PrimInt, WrappingAdd, WrappingMul, WrappingSub};
+use std::{
+    fmt::Debug,
+    hash::Hash,
+    ops::{Add, AddAssign, Index, Mul, Sub, SubAssign},
+};
+
+/// A trait meant to represent the integer scalars `i32`, `i64`, `u32` and `u64`.
+pub trait GenericIntScalar:
+    PrimInt
+    + WrappingAdd
+    + WrappingSub
+    + WrappingMul
+    + AddAssign
+    + SubAssign
+    + Debug
+    + Hash
+    + Default
+    + Sync
+    + Send
+    + 'static
+{
+    /// Returns the absolute value, the value itself for unsigned types. Like `wrapping_abs()`,
+    /// the minimum value of a signed type is returned unchanged.
+    fn abs_value(self) -> Self;
+
+    /// Converts the integer to a float scalar. Integers too large for the mantissa of `S` are
+    /// rounded, e.g. above 2^24 for `f32`.
+    #[inline(always)]
+    fn to_float<S: GenericScalar>(self) -> S {
+        <S as NumCast>::from(self).unwrap()
+    }
+
+    /// Converts a float scalar like an `as` cast: the fraction is truncated, out of range values
+    /// saturate and NaN becomes zero.
+    fn from_float_lossy<S: GenericScalar>(value: S) -> Self;
+
+    /// Converts a float scalar that holds an integer value exactly. Returns `None` if `value`
+    /// has a fraction, is out of range or is not finite.
+    #[inline(always)]
+    fn from_float_checked<S: GenericScalar>(value: S) -> Option<Self> {
+        if Float::fract(value) != S::ZERO {
+            return None;
+        }
+        <Self as NumCast>::from(value)
+    }
+}
+
+macro_rules! impl_int_scalar {
+    ($scalar:ty, $abs:expr) => {
+        impl GenericIntScalar for $scalar {
+            #[inline(always)]
+            fn abs_value(self) -> Self {
+                $abs(self)
+            }
+
+            #[inline(always)]
+            fn from_float_lossy<S: GenericScalar>(value: S) -> Self {
+                AsPrimitive::<$scalar>::as_(value)
+            }
+        }
+    };
+}
+
+impl_int_scalar!(i32, i32::wrapping_abs);
+impl_int_scalar!(i64, i64::wrapping_abs);
+impl_int_scalar!(u32, std::convert::identity);
+impl_int_scalar!(u64, std::convert::identity);
+
+/// A generic two-dimensional integer vector trait.
+///
+/// The component wise methods mirror the ones of the float traits. `dot()` and
+/// `manhattan_length()` use the plain integer operators, so they panic on overflow in debug
+/// builds; use the wrapping methods where overflow is expected.
+pub trait GenericIntVector2:
+    Sync
+    + Send
+    + Copy
+    + Debug
+    + Eq
+    + Hash
+    + Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + Mul<Self::Scalar, Output = Self>
+    + AddAssign
+    + SubAssign
+    + Index<usize, Output = Self::Scalar>
+{
+    type Scalar: GenericIntScalar;
+    type Vector3: GenericIntVector3<Scalar = Self::Scalar, Vector2 = Self>;
+
+    fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self;
+    fn x(self) -> Self::Scalar;
+    fn y(self) -> Self::Scalar;
+    fn dot(self, other: Self) -> Self::Scalar;
+    /// Returns the component wise minimum of the vectors.
+    fn min(self, other: Self) -> Self;
+    /// Returns the component wise maximum of the vectors.
+    fn max(self, other: Self) -> Self;
+    /// Returns the smallest component.
+    fn min_element(self) -> Self::Scalar;
+    /// Returns the largest component.
+    fn max_element(self) -> Self::Scalar;
+
+    #[inline(always)]
+    fn to_3d(self, z: Self::Scalar) -> Self::Vector3 {
+        Self::Vector3::new_3d(self.x(), self.y(), z)
+    }
+    /// Returns the sum of the absolute values of the components, the grid distance from the
+    /// origin.
+    #[inline(always)]
+    fn manhattan_length(self) -> Self::Scalar {
+        self.x().abs_value() + self.y().abs_value()
+    }
+    /// Returns the component wise sum, wrapping around at the bounds of the scalar type.
+    #[inline(always)]
+    fn wrapping_add(self, rhs: Self) -> Self {
+        Self::new_2d(
+            self.x().wrapping_add(&rhs.x()),
+            self.y().wrapping_add(&rhs.y()),
+        )
+    }
+    /// Returns the component wise difference, wrapping around at the bounds of the scalar type.
+    #[inline(always)]
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::new_2d(
+            self.x().wrapping_sub(&rhs.x()),
+            self.y().wrapping_sub(&rhs.y()),
+        )
+    }
+    /// Returns the component wise product, wrapping around at the bounds of the scalar type.
+    #[inline(always)]
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        Self::new_2d(
+            self.x().wrapping_mul(&rhs.x()),
+            self.y().wrapping_mul(&rhs.y()),
+        )
+    }
+    /// Converts to a float vector, rounding components too large for the float mantissa.
+    #[inline(always)]
+    fn to_float_lossy<V: HasXY>(self) -> V {
+        V::new_2d(self.x().to_float(), self.y().to_float())
+    }
+    /// Converts to a float vector, or `None` if a component can not be represented exactly.
+    #[inline(always)]
+    fn to_float_checked<V: HasXY>(self) -> Option<V> {
+        let v = self.to_float_lossy::<V>();
+        (Self::from_float_checked(v)? == self).then_some(v)
+    }
+    /// Converts from a float vector like an `as` cast, see `GenericIntScalar::from_float_lossy()`.
+    #[inline(always)]
+    fn from_float_lossy<V: HasXY>(v: V) -> Self {
+        Self::new_2d(
+            Self::Scalar::from_float_lossy(v.x()),
+            Self::Scalar::from_float_lossy(v.y()),
+        )
+    }
+    /// Converts from a float vector holding integer values, or `None` if a component has a
+    /// fraction, is out of range or is not finite.
+    #[inline(always)]
+    fn from_float_checked<V: HasXY>(v: V) -> Option<Self> {
+        Some(Self::new_2d(
+            Self::Scalar::from_float_checked(v.x())?,
+            Self::Scalar::from_float_checked(v.y())?,
+        ))
+    }
+}
+
+/// A generic three-dimensional integer vector trait, see `GenericIntVector2`.
+pub trait GenericIntVector3:
+    Sync
+    + Send
+    + Copy
+    + Debug
+    + Eq
+    + Hash
+    + Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + Mul<Self::Scalar, Output = Self>
+    + AddAssign
+    + SubAssign
+    + Index<usize, Output = Self::Scalar>
+{
+    type Scalar: GenericIntScalar;
+    type Vector2: GenericIntVector2<Scalar = Self::Scalar, Vector3 = Self>;
+
+    fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self;
+    fn x(self) -> Self::Scalar;
+    fn y(self) -> Self::Scalar;
+    fn z(self) -> Self::Scalar;
+    fn dot(self, other: Self) -> Self::Scalar;
+    /// Returns the component wise minimum of the vectors.
+    fn min(self, other: Self) -> Self;
+    /// Returns the component wise maximum of the vectors.
+    fn max(self, other: Self) -> Self;
+    /// Returns the smallest component.
+    fn min_element(self) -> Self::Scalar;
+    /// Returns the largest component.
+    fn max_element(self) -> Self::Scalar;
+
+    #[inline(always)]
+    fn to_2d(self) -> Self::Vector2 {
+        Self::Vector2::new_2d(self.x(), self.y())
+    }
+    /// Returns the sum of the absolute values of the components, the grid distance from the
+    /// origin.
+    #[inline(always)]
+    fn manhattan_length(self) -> Self::Scalar {
+        self.x().abs_value() + self.y().abs_value() + self.z().abs_value()
+    }
+    /// Returns the component wise sum, wrapping around at the bounds of the scalar type.
+    #[inline(always)]
+    fn wrapping_add(self, rhs: Self) -> Self {
+        Self::new_3d(
+            self.x().wrapping_add(&rhs.x()),
+            self.y().wrapping_add(&rhs.y()),
+            self.z().wrapping_add(&rhs.z()),
+        )
+    }
+    /// Returns the component wise difference, wrapping around at the bounds of the scalar type.
+    #[inline(always)]
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        Self::new_3d(
+            self.x().wrapping_sub(&rhs.x()),
+            self.y().wrapping_sub(&rhs.y()),
+            self.z().wrapping_sub(&rhs.z()),
+        )
+    }
+    /// Returns the component wise product, wrapping around at the bounds of the scalar type.
+    #[inline(always)]
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        Self::new_3d(
+            self.x().wrapping_mul(&rhs.x()),
+            self.y().wrapping_mul(&rhs.y()),
+            self.z().wrapping_mul(&rhs.z()),
+        )
+    }
+    /// Converts to a float vector, rounding components too large for the float mantissa.
+    #[inline(always)]
+    fn to_float_lossy<V: HasXYZ>(self) -> V {
+        V::new_3d(
+            self.x().to_float(),
+            self.y().to_float(),
+            self.z().to_float(),
+        )
+    }
+    /// Converts to a float vector, or `None` if a component can not be represented exactly.
+    #[inline(always)]
+    fn to_float_checked<V: HasXYZ>(self) -> Option<V> {
+        let v = self.to_float_lossy::<V>();
+        (Self::from_float_checked(v)? == self).then_some(v)
+    }
+    /// Converts from a float vector like an `as` cast, see `GenericIntScalar::from_float_lossy()`.
+    #[inline(always)]
+    fn from_float_lossy<V: HasXYZ>(v: V) -> Self {
+        Self::new_3d(
+            Self::Scalar::from_float_lossy(v.x()),
+            Self::Scalar::from_float_lossy(v.y()),
+            Self::Scalar::from_float_lossy(v.z()),
+        )
+    }
+    /// Converts from a float vector holding integer values, or `None` if a component has a
+    /// fraction, is out of range or is not finite.
+    #[inline(always)]
+    fn from_float_checked<V: HasXYZ>(v: V) -> Option<Self> {
+        Some(Self::new_3d(
+            Self::Scalar::from_float_checked(v.x())?,
+            Self::Scalar::from_float_checked(v.y())?,
+            Self::Scalar::from_float_checked(v.z())?,
+        ))
+    }
+}
@@ -0,0 +1,188 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Interval arithmetic, for filtering predicates before falling back to exact computation.
+//!
+//! Rust has no access to the directed rounding modes of the FPU, so every operation rounds to
+//! nearest and then widens the result outwards by at least one ULP. The true result of the
+//! operation on any values inside the operands is always inside the resulting interval.
+
+use crate::GenericScalar;
+use num_traits::Float;
+use std::{
+    cmp::Ordering,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+/// A closed interval `[lower, upper]` of scalar values.
+///
+/// `Interval` does not implement `GenericScalar`, as it has no total order and no bit
+/// representation. It implements the arithmetic operators, so the computation of a predicate
+/// can be written once for scalars and once, unchanged, for intervals.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval<S: GenericScalar> {
+    lower: S,
+    upper: S,
+}
+
+/// Returns a value at least one ULP below `x`.
+#[inline(always)]
+fn round_down<S: GenericScalar>(x: S) -> S {
+    // |x| * EPSILON is at least one ULP of x, the subnormal term handles zero and underflow
+    x - (Float::abs(x) * S::EPSILON + <S as Float>::min_positive_value() * S::EPSILON)
+}
+
+/// Returns a value at least one ULP above `x`.
+#[inline(always)]
+fn round_up<S: GenericScalar>(x: S) -> S {
+    x + (Float::abs(x) * S::EPSILON + <S as Float>::min_positive_value() * S::EPSILON)
+}
+
+impl<S: GenericScalar> Interval<S> {
+    /// Creates the interval `[lower, upper]`.
+    #[inline(always)]
+    pub fn new(lower: S, upper: S) -> Self {
+        debug_assert!(
+            lower <= upper || Float::is_nan(lower) || Float::is_nan(upper),
+            "invalid interval [{}, {}]",
+            lower,
+            upper
+        );
+        Self { lower, upper }
+    }
+
+    /// Creates the interval containing only `value`.
+    #[inline(always)]
+    pub fn point(value: S) -> Self {
+        Self::new(value, value)
+    }
+
+    /// Returns the interval containing every value, the result of operations that are undefined
+    /// for some values of the operands.
+    #[inline(always)]
+    pub fn entire() -> Self {
+        Self::new(S::NEG_INFINITY, S::INFINITY)
+    }
+
+    #[inline(always)]
+    pub fn lower(self) -> S {
+        self.lower
+    }
+
+    #[inline(always)]
+    pub fn upper(self) -> S {
+        self.upper
+    }
+
+    #[inline(always)]
+    pub fn width(self) -> S {
+        self.upper - self.lower
+    }
+
+    /// Returns true if `value` is inside the interval.
+    #[inline(always)]
+    pub fn contains(self, value: S) -> bool {
+        self.lower <= value && value <= self.upper
+    }
+
+    /// Returns the sign of every value in the interval, compared to zero, or `None` if the
+    /// interval contains values of different signs (or is NaN).
+    #[inline(always)]
+    pub fn sign(self) -> Option<Ordering> {
+        if self.lower > S::ZERO {
+            Some(Ordering::Greater)
+        } else if self.upper < S::ZERO {
+            Some(Ordering::Less)
+        } else if self.lower == S::ZERO && self.upper == S::ZERO {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the smallest interval containing all the products, or `entire()` if any of
+    /// them is NaN (zero times infinity).
+    #[inline(always)]
+    fn hull(products: [S; 4]) -> Self {
+        if products.iter().any(|p| Float::is_nan(*p)) {
+            return Self::entire();
+        }
+        let lower = products.iter().copied().fold(S::INFINITY, Float::min);
+        let upper = products.iter().copied().fold(S::NEG_INFINITY, Float::max);
+        Self::new(round_down(lower), round_up(upper))
+    }
+}
+
+impl<S: GenericScalar> From<S> for Interval<S> {
+    #[inline(always)]
+    fn from(value: S) -> Self {
+        Self::point(value)
+    }
+}
+
+impl<S: GenericScalar> Add for Interval<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(
+            round_down(self.lower + rhs.lower),
+            round_up(self.upper + rhs.upper),
+        )
+    }
+}
+
+impl<S: GenericScalar> Sub for Interval<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(
+            round_down(self.lower - rhs.upper),
+            round_up(self.upper - rhs.lower),
+        )
+    }
+}
+
+impl<S: GenericScalar> Mul for Interval<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        Self::hull([
+            self.lower * rhs.lower,
+            self.lower * rhs.upper,
+            self.upper * rhs.lower,
+            self.upper * rhs.upper,
+        ])
+    }
+}
+
+impl<S: GenericScalar> Div for Interval<S> {
+    type Output = Self;
+
+    /// Divides the intervals, the result is `entire()` if `rhs` contains zero.
+    #[inline(always)]
+    fn div(self, rhs: Self) -> Self {
+        if rhs.contains(S::ZERO) {
+            return Self::entire();
+        }
+        Self::hull([
+            self.lower / rhs.lower,
+            self.lower / rhs.upper,
+            self.upper / rhs.lower,
+            self.upper / rhs.upper,
+        ])
+    }
+}
+
+impl<S: GenericScalar> Neg for Interval<S> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self::new(-self.upper, -self.lower)
+    }
+}
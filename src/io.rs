@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Streaming readers and writers for simple text point formats, one point per line.
+//!
+//! `PointFormat::xyz()` reads whitespace separated `.xyz` files and `PointFormat::csv()` comma
+//! separated files. The columns holding x, y and z are configurable, other columns are ignored.
+//! Empty lines and lines starting with the comment character are skipped. Quoted CSV fields are
+//! not supported.
+//!
+//! `write_ply()` and `write_obj()` dump vertex positions as PLY and OBJ files, for looking at
+//! intermediate geometry in a mesh viewer.
+//!
+//! The text writers print the shortest representation that parses back to the same value, switching
+//! to exponent notation for very large and small values, so points survive a write and read round
+//! trip exactly.
+
+#[cfg(all(test, feature = "glam"))]
+mod tests;
+
+use crate::{GenericScalar, GenericVector2, GenericVector3};
+use num_traits::AsPrimitive;
+use std::{
+    fmt::{Display, Formatter},
+    io::{BufRead, Write},
+};
+
+/// How the fields of a line are separated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+    /// Any run of whitespace, leading and trailing whitespace is ignored.
+    Whitespace,
+    /// A single character, the fields are trimmed of surrounding whitespace.
+    Char(char),
+}
+
+/// The layout of a text point file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PointFormat {
+    pub delimiter: Delimiter,
+    /// The zero based columns of x, y and z. The z column is not used for 2D points.
+    pub columns: [usize; 3],
+    /// The number of lines to skip at the start of the input, e.g. a CSV header.
+    pub header_lines: usize,
+    /// Lines starting with this character, after leading whitespace, are skipped.
+    pub comment: Option<char>,
+}
+
+impl PointFormat {
+    /// Whitespace separated x, y and z columns, with `#` comments.
+    pub const fn xyz() -> Self {
+        Self {
+            delimiter: Delimiter::Whitespace,
+            columns: [0, 1, 2],
+            header_lines: 0,
+            comment: Some('#'),
+        }
+    }
+
+    /// Comma separated x, y and z columns, with `#` comments.
+    pub const fn csv() -> Self {
+        Self {
+            delimiter: Delimiter::Char(','),
+            columns: [0, 1, 2],
+            header_lines: 0,
+            comment: Some('#'),
+        }
+    }
+
+    /// Returns the format reading x, y and z from the given zero based columns.
+    pub const fn with_columns(mut self, x: usize, y: usize, z: usize) -> Self {
+        self.columns = [x, y, z];
+        self
+    }
+
+    /// Returns the format skipping the first `lines` lines of the input.
+    pub const fn with_header_lines(mut self, lines: usize) -> Self {
+        self.header_lines = lines;
+        self
+    }
+
+    fn separator(&self) -> char {
+        match self.delimiter {
+            Delimiter::Whitespace => ' ',
+            Delimiter::Char(c) => c,
+        }
+    }
+
+    /// Returns the field of `line` at `column`, if there is one.
+    fn field<'a>(&self, line: &'a str, column: usize) -> Option<&'a str> {
+        match self.delimiter {
+            Delimiter::Whitespace => line.split_whitespace().nth(column),
+            Delimiter::Char(c) => line.split(c).nth(column).map(str::trim),
+        }
+    }
+}
+
+/// What went wrong in a `PointReadError`.
+#[derive(Debug)]
+pub enum PointReadErrorKind {
+    /// Reading from the underlying reader failed.
+    Io(std::io::Error),
+    /// The line has no field at `column`.
+    MissingColumn { column: usize },
+    /// The field at `column` is not a number.
+    InvalidNumber { column: usize, text: String },
+}
+
+/// An error reading a point file, with the one based line number where it happened.
+#[derive(Debug)]
+pub struct PointReadError {
+    pub line: usize,
+    pub kind: PointReadErrorKind,
+}
+
+impl Display for PointReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            PointReadErrorKind::Io(err) => write!(f, "line {}: {}", self.line, err),
+            PointReadErrorKind::MissingColumn { column } => {
+                write!(f, "line {}: missing column {}", self.line, column)
+            }
+            PointReadErrorKind::InvalidNumber { column, text } => write!(
+                f,
+                "line {}: column {} is not a number: {:?}",
+                self.line, column, text
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PointReadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            PointReadErrorKind::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Reads the first `dim` mapped columns of every data line of `reader`.
+fn read_rows<S: GenericScalar, R: BufRead>(
+    reader: R,
+    format: PointFormat,
+    dim: usize,
+) -> impl Iterator<Item = Result<[S; 3], PointReadError>> {
+    reader
+        .lines()
+        .enumerate()
+        .skip(format.header_lines)
+        .filter_map(move |(i, line)| {
+            let line_number = i + 1;
+            let error = |kind| {
+                Some(Err(PointReadError {
+                    line: line_number,
+                    kind,
+                }))
+            };
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => return error(PointReadErrorKind::Io(err)),
+            };
+            let trimmed = line.trim_start();
+            if trimmed.is_empty() || format.comment.is_some_and(|c| trimmed.starts_with(c)) {
+                return None;
+            }
+            let mut row = [S::ZERO; 3];
+            for (value, &column) in row.iter_mut().zip(format.columns.iter()).take(dim) {
+                let Some(text) = format.field(&line, column) else {
+                    return error(PointReadErrorKind::MissingColumn { column });
+                };
+                match text.parse::<S>() {
+                    Ok(v) => *value = v,
+                    Err(_) => {
+                        return error(PointReadErrorKind::InvalidNumber {
+                            column,
+                            text: text.to_string(),
+                        })
+                    }
+                }
+            }
+            Some(Ok(row))
+        })
+}
+
+/// Returns an iterator over the points of `reader`, reading x and y from the mapped columns.
+///
+/// The iterator keeps going after an error, so a caller may skip bad lines or stop at the first
+/// one, e.g. by collecting into a `Result<Vec<_>, _>`.
+pub fn read_points_2d<T: GenericVector2, R: BufRead>(
+    reader: R,
+    format: PointFormat,
+) -> impl Iterator<Item = Result<T, PointReadError>> {
+    read_rows(reader, format, 2).map(|row| row.map(|p| T::new_2d(p[0], p[1])))
+}
+
+/// Returns an iterator over the points of `reader`, reading x, y and z from the mapped columns.
+///
+/// The iterator keeps going after an error, so a caller may skip bad lines or stop at the first
+/// one, e.g. by collecting into a `Result<Vec<_>, _>`.
+pub fn read_points_3d<T: GenericVector3, R: BufRead>(
+    reader: R,
+    format: PointFormat,
+) -> impl Iterator<Item = Result<T, PointReadError>> {
+    read_rows(reader, format, 3).map(|row| row.map(|p| T::new_3d(p[0], p[1], p[2])))
+}
+
+/// Writes one line of x and y per point, separated by the delimiter of `format`.
+///
+/// The column mapping and header lines of `format` only apply to reading.
+pub fn write_points_2d<T: GenericVector2, W: Write>(
+    mut writer: W,
+    points: impl IntoIterator<Item = T>,
+    format: PointFormat,
+) -> std::io::Result<()> {
+    let sep = format.separator();
+    for p in points {
+        writeln!(writer, "{:?}{}{:?}", p.x(), sep, p.y())?;
+    }
+    Ok(())
+}
+
+/// Writes one line of x, y and z per point, separated by the delimiter of `format`.
+///
+/// The column mapping and header lines of `format` only apply to reading.
+pub fn write_points_3d<T: GenericVector3, W: Write>(
+    mut writer: W,
+    points: impl IntoIterator<Item = T>,
+    format: PointFormat,
+) -> std::io::Result<()> {
+    let sep = format.separator();
+    for p in points {
+        writeln!(writer, "{:?}{}{:?}{}{:?}", p.x(), sep, p.y(), sep, p.z())?;
+    }
+    Ok(())
+}
+
+/// Writes the points as the vertices of a binary little endian PLY file, without faces.
+///
+/// The vertex properties are `float` for `f32` scalars and `double` for `f64` scalars. 2D points
+/// can be written with `GenericVector2::to_3d()`.
+pub fn write_ply<T: GenericVector3, W: Write>(
+    mut writer: W,
+    points: impl IntoIterator<Item = T, IntoIter: ExactSizeIterator>,
+) -> std::io::Result<()> {
+    let points = points.into_iter();
+    let double = size_of::<T::Scalar>() == size_of::<f64>();
+    let kind = if double { "double" } else { "float" };
+    write!(
+        writer,
+        "ply\nformat binary_little_endian 1.0\nelement vertex {}\n\
+         property {kind} x\nproperty {kind} y\nproperty {kind} z\nend_header\n",
+        points.len()
+    )?;
+    for p in points {
+        for v in [p.x(), p.y(), p.z()] {
+            if double {
+                writer.write_all(&AsPrimitive::<f64>::as_(v).to_le_bytes())?;
+            } else {
+                writer.write_all(&AsPrimitive::<f32>::as_(v).to_le_bytes())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Writes the points as the `v` lines of an OBJ file. 2D points can be written with
+/// `GenericVector2::to_3d()`.
+pub fn write_obj<T: GenericVector3, W: Write>(
+    mut writer: W,
+    points: impl IntoIterator<Item = T>,
+) -> std::io::Result<()> {
+    for p in points {
+        writeln!(writer, "v {:?} {:?} {:?}", p.x(), p.y(), p.z())?;
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{
+    read_points_2d, read_points_3d, write_obj, write_ply, write_points_2d, write_points_3d,
+    PointFormat, PointReadErrorKind,
+};
+use crate::GenericVector2;
+use glam::{dvec3, vec2, vec3, DVec3, Vec2};
+
+#[test]
+fn test_xyz_round_trip() {
+    let points = vec![
+        dvec3(0.1, -2.5e-300, 3.0),
+        dvec3(1.0 / 3.0, 1e300, -0.0),
+        dvec3(f64::MAX, f64::MIN_POSITIVE, 7.0),
+    ];
+    let mut buffer = Vec::new();
+    write_points_3d(&mut buffer, points.iter().copied(), PointFormat::xyz()).unwrap();
+    let read: Vec<DVec3> = read_points_3d(buffer.as_slice(), PointFormat::xyz())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(read, points);
+
+    let mut buffer = Vec::new();
+    let points = vec![vec2(0.1, 0.2), vec2(-1e-40, 3.5)];
+    write_points_2d(&mut buffer, points.iter().copied(), PointFormat::csv()).unwrap();
+    assert_eq!(
+        String::from_utf8(buffer.clone()).unwrap(),
+        "0.1,0.2\n-1e-40,3.5\n"
+    );
+    let read: Vec<Vec2> = read_points_2d(buffer.as_slice(), PointFormat::csv())
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(read, points);
+}
+
+#[test]
+fn test_csv_columns() {
+    let text = "id, z, x, y, label\n\
+                # a comment\n\
+                1, 3.0, 1.0, 2.0, a\n\
+                \n\
+                2, 6 , 4,5 ,b\n";
+    let format = PointFormat::csv()
+        .with_columns(2, 3, 1)
+        .with_header_lines(1);
+    let read: Vec<DVec3> = read_points_3d(text.as_bytes(), format)
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(read, vec![dvec3(1.0, 2.0, 3.0), dvec3(4.0, 5.0, 6.0)]);
+}
+
+#[test]
+fn test_read_errors() {
+    let text = "1 2 3\n4 five 6\n7 8\n\t 9 10 11 12\n";
+    let results: Vec<_> = read_points_3d::<DVec3, _>(text.as_bytes(), PointFormat::xyz()).collect();
+    assert_eq!(results.len(), 4);
+    assert_eq!(*results[0].as_ref().unwrap(), dvec3(1.0, 2.0, 3.0));
+    let err = results[1].as_ref().unwrap_err();
+    assert_eq!(err.line, 2);
+    assert!(
+        matches!(&err.kind, PointReadErrorKind::InvalidNumber { column: 1, text } if text == "five")
+    );
+    assert_eq!(
+        err.to_string(),
+        "line 2: column 1 is not a number: \"five\""
+    );
+    let err = results[2].as_ref().unwrap_err();
+    assert_eq!(err.line, 3);
+    assert!(matches!(
+        err.kind,
+        PointReadErrorKind::MissingColumn { column: 2 }
+    ));
+    assert_eq!(*results[3].as_ref().unwrap(), dvec3(9.0, 10.0, 11.0));
+}
+
+#[test]
+fn test_ply() {
+    let mut buffer = Vec::new();
+    write_ply(
+        &mut buffer,
+        [vec3(1.0, 2.0, 3.0), vec2(-0.5, 4.0).to_3d(0.0)],
+    )
+    .unwrap();
+    let header = "ply\nformat binary_little_endian 1.0\nelement vertex 2\n\
+                  property float x\nproperty float y\nproperty float z\nend_header\n";
+    assert_eq!(&buffer[..header.len()], header.as_bytes());
+    let body: Vec<f32> = buffer[header.len()..]
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes(b.try_into().unwrap()))
+        .collect();
+    assert_eq!(body, vec![1.0, 2.0, 3.0, -0.5, 4.0, 0.0]);
+
+    let mut buffer = Vec::new();
+    write_ply(&mut buffer, vec![dvec3(0.1, 0.2, 0.3)]).unwrap();
+    let text = String::from_utf8_lossy(&buffer);
+    assert!(text.contains("property double z\nend_header\n"));
+    let body = &buffer[buffer.len() - 24..];
+    assert_eq!(f64::from_le_bytes(body[8..16].try_into().unwrap()), 0.2);
+}
+
+#[test]
+fn test_obj() {
+    let mut buffer = Vec::new();
+    write_obj(
+        &mut buffer,
+        [dvec3(1.0, -2.5, 0.1), dvec3(0.0, 0.0, 1e-300)],
+    )
+    .unwrap();
+    assert_eq!(
+        String::from_utf8(buffer).unwrap(),
+        "v 1.0 -2.5 0.1\nv 0.0 0.0 1e-300\n"
+    );
+}
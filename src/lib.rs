@@ -16,7 +16,115 @@
 //! - Generic scalar trait `GenericScalar` for a flexible scalar type handling.
 //! - Basic vector traits `HasXY` and `HasXYZ` for down to metal, custom vector storage types, e.g., FFI types.
 //! - Seamless transition between different vector libraries like `cgmath` and `glam`.
-//! - Ability to switch between different scalar types (`f32`, `f64`) effortlessly.
+//! - Ability to switch between different scalar types (`f32`, `f64`) effortlessly, also at runtime
+//!   with `ScalarKind`, the `dispatch_scalar!` macro and the `DynVector2`/`DynVector3` enums.
+//!
+//! ### Traits and types
+//!
+//! - Default `Approx` methods, so custom storage types get approximate comparison with an empty impl.
+//! - Typed axis enums `Axis2` and `Axis3` for indexing vector components.
+//! - Per component comparisons returning a `GenericMask`, with `select()`, for branch free
+//!   generic code.
+//! - `as_ivec()`, `floor_to_int()` and `round_to_int()`, converting float vectors to the `i32` grid
+//!   vector of the backend.
+//! - Integer coordinate storage types `IntVec2` and `IntVec3` with scaled float conversions.
+//! - `GenericIntVector2` and `GenericIntVector3` traits for integer grid vectors, with wrapping
+//!   operations and lossy or checked conversions to and from the float vectors.
+//! - A const generic `GenericVectorN<N>` trait and the `VecN` vector, for any number of dimensions.
+//! - The object safe `ObjectVector2` and `ObjectVector3` traits, implemented for every vector, to
+//!   pass vectors of any backend as `&dyn`.
+//! - A `prelude` with the traits and the `Vector2Ops<S>`/`Vector3Ops<S>` aliases of the common
+//!   bounds.
+//! - The `impl_generic_vector2!` and `impl_generic_vector3!` macros, implementing the vector traits
+//!   for a new vector type in terms of its accessors and operators.
+//! - `#[derive(HasXY, HasXYZ)]` for user defined structs, with the `derive` feature.
+//! - The `AnyVector2` and `AnyVector3` enums of the vectors of every enabled backend, for data whose
+//!   backend is only known at runtime.
+//! - `IntoFlat` and `FromFlat` conversions to and from flat `[S; 2]`/`[S; 3]` arrays and scalar
+//!   runs, for `vecmath` and C APIs.
+//! - A `CgmathCompat` extension trait with the `cgmath` method names, for gradual migration.
+//! - A `GenericMatrix3` trait for the 3x3 matrices of `glam`, `bevy_math`, `cgmath` and
+//!   `nalgebra`, as 2D affine maps and 3D linear maps.
+//! - A `GenericMatrix2` trait for the 2x2 matrices of `glam`, `bevy_math`, `cgmath` and
+//!   `nalgebra`, as 2D linear maps.
+//! - A `GenericMatrix4` trait for the 4x4 matrices of `glam`, `bevy_math`, `cgmath` and
+//!   `nalgebra`, as 3D affine and projective maps.
+//! - `GenericAffine2` and `GenericAffine3` traits for 2D and 3D placements, implemented for the
+//!   `glam` and `bevy_math` affine types, cgmath `Decomposed`, nalgebra `Affine2` and `Affine3` and,
+//!   in 2D, every `GenericMatrix3` wrapped in `AffineMatrix3`.
+//! - A `GenericRotation3` trait for the quaternions of `glam`, `bevy_math`, `cgmath` and `nalgebra`,
+//!   and for the cgmath rotation matrix `Basis3`.
+//! - `GenericVector2xN` and `GenericVector3xN` structure of arrays batches of vectors with lane
+//!   access and batched dot, cross and normalize, implemented by `Vec2xN` and `Vec3xN` over any
+//!   `ScalarBatch`: the portable `ArrayBatch`, or `wide::f32x8` and `f64x4` of the optional `wide`
+//!   feature, with dot products and distances over slices.
+//!
+//! ### Geometry
+//!
+//! - Robust `orient2d` and `segments_intersect` predicates, exact for any finite input.
+//! - `GenericVector2::orientation()` and a tolerance based `orientation_eps()` classification.
+//! - Total `sweep_cmp` and `sweep_cmp_x` point orderings for plane sweep event queues.
+//! - Closest points and distances to segments, and signed distances to lines.
+//! - `EulerAngles` with an explicit `EulerOrder`, and yaw/pitch direction conversions.
+//! - `project_onto_plane`, `clamp_to_aabb` and `signed_angle_about_axis` helpers for 3D vectors.
+//! - `look_rotation` and `direction_to` helpers for camera and tool orientation frames.
+//! - Octahedral and stereographic mappings between unit directions and the plane.
+//! - Angle wrap-around helpers `normalize_angle_0_tau`, `normalize_angle_pm_pi` and `shortest_angle_delta`.
+//! - Exact `rotate_90_ccw`, `rotate_90_cw` and `rotate_180` rotations of 2D vectors.
+//! - Exact `flip_x`, `flip_y`, `flip_z` and `mirror_across_axis` sign flips.
+//! - `drop_dominant_axis` projection of 3D polygons to 2D, and the inverse `insert_axis`.
+//! - Idempotent grid snapping with `snap()`, free of seam cracks at cell boundaries.
+//!
+//! ### Point sets
+//!
+//! - Seeded, reproducible grid, jittered grid, Poisson-disk, Halton and Sobol point sets.
+//! - `linspace_points`, `meshgrid_2d` and `meshgrid_3d` iterators over evenly spaced points.
+//! - Bounding spheres of point sets, with Ritter's approximation or Welzl's exact algorithm.
+//! - Centroid, covariance and principal axes of point clouds, without external dependencies.
+//! - A `Plane` type and least-squares `fit_plane`, reporting why degenerate input has no plane.
+//! - Grid downsampling of point clouds, keeping the first point or the centroid of every cell.
+//! - Vertex welding by distance, returning the merged points and an index remap.
+//! - A single pass `RunningStats` accumulator of the mean, extent and variance of streamed points.
+//!
+//! ### Precision and determinism
+//!
+//! - An `Interval` arithmetic type and the `orient2d_interval` filter built on it.
+//! - Compensated summation of squared distances and mean squared errors.
+//! - `convex_combination` weighted averages with normalized, validated weights.
+//! - Checked vector arithmetic reporting where NaN or infinity first appears.
+//! - `dot_with_error`, `perp_dot_with_error` and `cross_with_error` returning a forward error bound.
+//! - Overflow safe `distance_robust` and `distance_sq_scaled` for extreme coordinates.
+//! - A `deterministic` feature that routes `sqrt`, trigonometry and normalization through `libm`.
+//! - A `strict-fp` feature that evaluates dot products, magnitudes and distances in a fixed order,
+//!   without the `rsqrt` or fused multiply-add shortcuts a backend may take.
+//!
+//! ### Scalar types
+//!
+//! - Optional `half::f16` storage with the `F16Vec2` and `F16Vec3` types, which convert to and
+//!   from any vector type and compute in `f32`.
+//! - Optional fixed point `FixedVec2` and `FixedVec3` vectors for deterministic lockstep
+//!   simulations, over the float-agnostic `ScalarCore` below `GenericScalar`.
+//! - Optional software float `SoftF32` and `SoftF64` scalars, with arithmetic evaluated in integer
+//!   code for bit identical lockstep and consensus results, and their `SoftFloatVec2` and
+//!   `SoftFloatVec3` vectors.
+//! - Optional double-double `TwoFloatVec2` and `TwoFloatVec3` vectors with about 106 bits of
+//!   precision, for CAD geometry where `f64` is not robust enough.
+//! - Optional arbitrary precision `BigVec2` and `BigVec3` vectors over `dashu_float::FBig`, with
+//!   exact dot products, determinants and squared distances for verification passes.
+//! - Optional exact `num_rational::BigRational` scalars, converted from the float bits, with the
+//!   `orient2d_rational` predicate that escalates to exact arithmetic.
+//!
+//! ### Interoperability
+//!
+//! - An optional `io` module with streaming `.xyz` and CSV point readers and writers, and PLY and
+//!   OBJ vertex dumps.
+//! - An optional `uom` bridge with dimension checked dot and cross products of quantity vectors.
+//! - Optional `HasXY` support for `num_complex::Complex`, with the real part as x.
+//! - Optional `HasXY` support for `egui::Vec2` and `egui::Pos2`, for drawing computed geometry.
+//! - Optional `HasXY` and `HasXYZ` support for the godot-rust `Vector2` and `Vector3`.
+//! - Optional `HasXY` support for `geo_types::Coord` and `geo_types::Point`, for GIS pipelines.
+//! - Optional `HasXY` support for the `lyon_geom` and `euclid` 2D points and vectors.
+//! - An optional `rstar` bridge, putting any vector type into an R-tree.
 //!
 //! ## Supported Vector Implementations
 //!
@@ -26,8 +134,32 @@
 //! - `glam::DVec2`
 //! - `glam::Vec3`
 //! - `glam::DVec3`
+//! - `glam::Mat2`, `Mat3`, `Mat4`, `Affine2`, `Affine3A` and `Quat` and their f64 counterparts, as
+//!   matrix, affine and rotation types
+//! - `glam::IVec2`, `UVec2`, `I64Vec2`, `U64Vec2` and their 3D counterparts, as integer vectors
+//! - `glam::Vec4` and `glam::DVec4`, as 4D vectors implementing `GenericVector4`
 //! - `cgmath::Vector2`
 //! - `cgmath::Vector3`
+//! - `cgmath::Vector4`, as a 4D vector implementing `GenericVector4`
+//! - `cgmath::Matrix2`, `Matrix3`, `Matrix4`, `Quaternion`, `Basis3` and `Decomposed`, as
+//!   matrix, rotation and affine types
+//! - `cgmath::Point2` and `cgmath::Point3`, as `HasXY` and `HasXYZ` storage types
+//! - the same `cgmath` types of cgmath 0.17, with the `cgmath_017` feature
+//! - `nalgebra::Vector2` and `nalgebra::Vector3`
+//! - `nalgebra::Vector4`, as a 4D vector implementing `GenericVector4`
+//! - `nalgebra::Matrix2`, `Matrix3`, `Matrix4`, `Affine2`, `Affine3` and `UnitQuaternion`, as
+//!   matrix, affine and rotation types
+//! - `nalgebra::Point2` and `nalgebra::Point3`, as `HasXY` and `HasXYZ` storage types
+//! - `mint::Vector2`, `Vector3`, `Point2` and `Point3`, as `HasXY` and `HasXYZ` storage types
+//! - `bevy_math::Vec2`, `Vec3`, `Vec3A`, `Vec4`, `DVec2`, `DVec3` and `DVec4`, of the glam version
+//!   linked by Bevy, and read only views of `Dir2`, `Dir3` and `Dir3A`
+//! - `egui::Vec2` and `egui::Pos2`, as `HasXY` storage types
+//! - `[S; 2]` and `[S; 3]`, as `HasXY` and `HasXYZ` storage types, and the `ArrayVec2` and
+//!   `ArrayVec3` newtypes over them
+//! - `(S, S)` and `(S, S, S)`, as `HasXY` and `HasXYZ` storage types
+//! - `godot::builtin::Vector2` and `Vector3`, as `HasXY` and `HasXYZ` storage types
+//! - `geo_types::Coord` and `geo_types::Point`, as `HasXY` storage types
+//! - `lyon_geom::Point` and `lyon_geom::Vector`, as `HasXY` storage types
 //!
 //! ## Usage
 //!
@@ -38,6 +170,21 @@
 //! vector-traits = { version = "0.3.2", features = ["glam", "cgmath"] }  # only use what you need
 //! ```
 //!
+//! ## Determinism
+//!
+//! The basic IEEE 754 operations (`+`, `-`, `*`, `/` and `sqrt`) are correctly rounded on every
+//! conforming platform, so with the `strict-fp` feature, which fixes the evaluation order and rules
+//! out fused multiply-adds, and the `deterministic` feature, which routes the remaining functions
+//! through `libm`, the vector methods give bit identical results across platforms. This covers
+//! lockstep simulation and consensus use as long as the inputs are identical and denormals are not
+//! flushed by the environment.
+//!
+//! Where even that is not enough, the `softfloat` feature adds the `SoftF32` and `SoftF64`
+//! scalars, which evaluate the basic operations in integer code. `GenericScalar` requires the full
+//! `Float`, `FloatCore`, primitive cast and `approx` interface of `f32` and `f64`, so they, like
+//! fixed point numbers, implement the smaller `ScalarCore` and use the `CoreVec2` and `CoreVec3`
+//! vectors.
+//!
 //! ## Contributing
 //!
 //! Contributions are welcome! Feel free to open an issue or submit a pull request.
@@ -70,30 +217,286 @@
 )]
 #![warn(clippy::explicit_into_iter_loop)]
 
-use num_traits::{float::FloatCore, AsPrimitive, Float, FromPrimitive, Signed, ToPrimitive};
+use num_traits::{
+    float::FloatCore, AsPrimitive, Float, FloatConst, FromPrimitive, Signed, ToPrimitive,
+};
 use std::{
     fmt::{Debug, Display, LowerExp},
     hash::Hash,
     ops::{Add, AddAssign, DivAssign, Index, MulAssign, Neg, Sub, SubAssign},
 };
 
+/// Expands to `$det` when the `deterministic` feature is enabled, and to `$fast` otherwise.
+macro_rules! deterministic_or {
+    ($det:expr, $fast:expr) => {{
+        #[cfg(feature = "deterministic")]
+        {
+            $det
+        }
+        #[cfg(not(feature = "deterministic"))]
+        {
+            $fast
+        }
+    }};
+}
+
+/// Expands to `$strict` when the `strict-fp` feature is enabled, and to `$fast` otherwise.
+#[allow(unused_macros)]
+macro_rules! strict_or {
+    ($strict:expr, $fast:expr) => {{
+        #[cfg(feature = "strict-fp")]
+        {
+            $strict
+        }
+        #[cfg(not(feature = "strict-fp"))]
+        {
+            $fast
+        }
+    }};
+}
+
+#[cfg(all(feature = "strict-fp", feature = "glam-fast-math"))]
+compile_error!("the `strict-fp` and `glam-fast-math` features are mutually exclusive");
+
+#[cfg(any(feature = "glam", feature = "bevy_math"))]
+#[macro_use]
+mod glam_macros;
+
+#[cfg(any(feature = "cgmath", feature = "cgmath_017"))]
+#[macro_use]
+mod cgmath_macros;
+
+pub mod affine;
+pub mod angle;
+pub mod any_vector;
+pub mod approx_utils;
+pub mod array_impl;
+pub mod axis;
+pub mod batch;
+#[cfg(feature = "bevy_math")]
+pub mod bevy_math_impl;
+pub mod big_vector;
+pub mod bounding;
+#[cfg(feature = "cgmath_017")]
+pub mod cgmath_017_impl;
 #[cfg(feature = "cgmath")]
 pub mod cgmath_impl;
+pub mod checked;
+pub mod compat;
+pub mod core_vector;
+pub mod covariance;
+#[cfg(feature = "dashu")]
+pub mod dashu_impl;
+pub mod direction;
+pub mod dispatch;
+pub mod distance;
+#[cfg(feature = "egui")]
+pub mod egui_impl;
+pub mod error_bound;
+pub mod euler;
+#[cfg(feature = "fixed")]
+pub mod fixed_impl;
+pub mod flat;
+pub mod frame;
+#[cfg(feature = "geo")]
+pub mod geo_impl;
 #[cfg(feature = "glam")]
 pub mod glam_impl;
+#[cfg(feature = "godot")]
+pub mod godot_impl;
+#[cfg(feature = "half")]
+pub mod half_impl;
+pub mod int_storage;
+pub mod int_vector;
+pub mod interval;
+#[cfg(feature = "io")]
+pub mod io;
+#[cfg(feature = "lyon")]
+pub mod lyon_impl;
+pub mod mask;
+pub mod math;
+pub mod matrix;
+#[cfg(feature = "mint")]
+pub mod mint_impl;
+#[cfg(feature = "nalgebra")]
+pub mod nalgebra_impl;
+#[cfg(feature = "num-complex")]
+pub mod num_complex_impl;
+#[cfg(feature = "num-rational")]
+pub mod num_rational_impl;
+pub mod object;
+pub mod plane;
+pub mod predicates;
+pub mod prelude;
+pub mod rotation;
+#[cfg(feature = "rstar")]
+pub mod rstar_impl;
+pub mod sampling;
+pub mod segment;
+#[cfg(feature = "softfloat")]
+pub mod softfloat_impl;
+pub mod spatial;
+pub mod stats;
+pub mod summation;
+pub mod tuple_impl;
+#[cfg(feature = "twofloat")]
+pub mod twofloat_impl;
+#[cfg(feature = "uom")]
+pub mod uom_impl;
+pub mod vector_macros;
+pub mod vector_n;
+#[cfg(feature = "wide")]
+pub mod wide_impl;
 
+pub use affine::{AffineMatrix3, GenericAffine2, GenericAffine3};
+pub use angle::{normalize_angle_0_tau, normalize_angle_pm_pi, shortest_angle_delta};
+pub use any_vector::{AnyVector2, AnyVector3};
+pub use approx_utils::{slices_abs_diff_eq, slices_ulps_eq, SliceMismatch};
+pub use array_impl::{ArrayVec2, ArrayVec3};
+pub use axis::{Axis2, Axis3};
+pub use batch::{
+    batch_distances_2d, batch_distances_3d, batch_dots_2d, batch_dots_3d, batches_2d, batches_3d,
+    ArrayBatch, GenericVector2xN, GenericVector3xN, ScalarBatch, Vec2xN, Vec3xN,
+};
+pub use big_vector::{BigVec2, BigVec3};
+pub use bounding::{
+    bounding_sphere_2d, bounding_sphere_3d, bounding_sphere_exact_2d, bounding_sphere_exact_3d,
+};
+pub use checked::{CheckedOp, CheckedVector, NonFiniteError};
+pub use compat::CgmathCompat;
+pub use core_vector::{CoreVec2, CoreVec3};
+pub use covariance::{centroid3, covariance3, principal_axes, symmetric_eigen3, PrincipalAxes};
+#[cfg(feature = "dashu")]
+pub use dashu_impl::{FBigVec2, FBigVec3};
+pub use direction::{
+    octahedral_decode, octahedral_encode, stereographic_project, stereographic_unproject,
+};
+pub use dispatch::{DynScalar, DynVector2, DynVector3, ParseScalarKindError, ScalarKind};
+pub use distance::{distance_robust, distance_sq_scaled};
+pub use error_bound::{cross_with_error, dot_with_error, perp_dot_with_error};
+pub use euler::{EulerAngles, EulerOrder};
+#[cfg(feature = "fixed")]
+pub use fixed_impl::{FixedVec2, FixedVec3};
+pub use flat::{
+    from_flat_arrays, from_flat_scalars, to_flat_arrays, to_flat_scalars, FromFlat, IntoFlat,
+};
+pub use frame::{look_frame, look_rotation};
 #[cfg(feature = "glam")]
 pub use glam_impl::Vec2A;
+#[cfg(feature = "half")]
+pub use half_impl::{F16Vec2, F16Vec3, HalfScalar};
+pub use int_storage::{IntScalar, IntVec2, IntVec3};
+pub use int_vector::{GenericIntScalar, GenericIntVector2, GenericIntVector3};
+pub use interval::Interval;
+pub use mask::{GenericMask, Mask2, Mask3};
+pub use matrix::{GenericMatrix2, GenericMatrix3, GenericMatrix4};
+#[cfg(feature = "num-rational")]
+pub use num_rational_impl::{orient2d_rational, to_rational, RationalVec2};
+pub use object::{ObjectVector2, ObjectVector3};
+pub use plane::{fit_plane, fit_plane_checked, Plane, PlaneFit, PlaneFitError};
+pub use predicates::{
+    orient2d, orient2d_interval, segments_intersect, sweep_cmp, sweep_cmp_x, IntersectionKind,
+    Orientation,
+};
+pub use rotation::GenericRotation3;
+pub use sampling::{
+    grid_2d, grid_3d, halton_2d, halton_3d, jittered_grid_2d, jittered_grid_3d, linspace_points,
+    meshgrid_2d, meshgrid_3d, poisson_disk_2d, poisson_disk_3d, sobol_2d, sobol_3d, SampleRng,
+};
+pub use segment::{
+    closest_point_on_segment, distance_to_line_2d, distance_to_line_3d, distance_to_segment,
+};
+#[cfg(feature = "softfloat")]
+pub use softfloat_impl::{SoftF32, SoftF64, SoftFloatVec2, SoftFloatVec3};
+pub use spatial::{
+    grid_downsample_2d, grid_downsample_3d, weld_vertices_2d, weld_vertices_3d, CellRepresentative,
+};
+pub use stats::RunningStats;
+pub use summation::{
+    convex_combination, mean_squared_distance, mean_squared_error, sum_squared_distances,
+    CompensatedSum,
+};
+#[cfg(feature = "twofloat")]
+pub use twofloat_impl::{TwoFloatVec2, TwoFloatVec3};
+pub use vector_n::{GenericVectorN, VecN};
+#[cfg(feature = "derive")]
+pub use vector_traits_derive::{HasXY, HasXYZ};
 
 #[cfg(test)]
 mod tests;
 
+/// The float-agnostic core of a scalar: the arithmetic operators, zero and one, `abs`, `sqrt`
+/// and a lossy round trip through `f64`.
+///
+/// `GenericScalar` extends this trait with the `f32`/`f64` interface. Types that are no floats,
+/// like the fixed point numbers of the `fixed` feature, implement only the core, and code written
+/// against `ScalarCore`, e.g. with the `CoreVec2` and `CoreVec3` vectors, works with both.
+pub trait ScalarCore:
+    Copy
+    + Debug
+    + Display
+    + Default
+    + PartialOrd
+    + num_traits::Zero
+    + num_traits::One
+    + Sub<Output = Self>
+    + std::ops::Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+    + Sync
+    + Send
+    + 'static
+{
+    /// Returns the absolute value.
+    fn abs_value(self) -> Self;
+    /// Returns the square root. Negative input returns NaN, or a type specific value for types
+    /// without NaN.
+    fn sqrt_value(self) -> Self;
+    /// Converts the value to `f64`, rounding if `f64` can not represent it exactly.
+    fn to_f64_lossy(self) -> f64;
+    /// Converts an `f64` to the nearest representable value. Types without infinities saturate at
+    /// their bounds and map NaN to zero.
+    fn from_f64_lossy(value: f64) -> Self;
+}
+
+/// An arbitrary precision scalar, the tier below `ScalarCore` for types that are not `Copy`.
+///
+/// Only the ring operations are required, which such types evaluate without rounding, so code
+/// written against `BigScalar`, e.g. with the `BigVec2` and `BigVec3` vectors, computes exact dot
+/// products, determinants and squared distances of `f64` input. The `dashu` feature implements it
+/// for `dashu_float::FBig`, and the `num-rational` feature for `num_rational::BigRational`.
+pub trait BigScalar:
+    Clone
+    + Debug
+    + Display
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + std::ops::Mul<Output = Self>
+    + Neg<Output = Self>
+    + Sync
+    + Send
+    + 'static
+{
+    /// Returns zero.
+    fn zero_value() -> Self;
+    /// Converts an `f64` exactly, or returns `None` if the value is not finite.
+    fn from_f64_exact(value: f64) -> Option<Self>;
+    /// Converts the value to the nearest `f64`.
+    fn to_f64_lossy(&self) -> f64;
+}
+
 /// A trait meant to to represent f32 or f64
 pub trait GenericScalar
 where
-    Self: Display
+    Self: ScalarCore
+        + Display
         + Debug
         + Float
+        + FloatConst
         + FloatCore
         + FromPrimitive
         + ToPrimitive
@@ -126,7 +529,9 @@ where
         + AsPrimitive<i16>
         + AsPrimitive<u8>
         + AsPrimitive<i8>
-        + approx::UlpsEq<Epsilon = Self>,
+        + approx::UlpsEq<Epsilon = Self>
+        + num_traits::float::TotalOrder
+        + math::ScalarMath,
 {
     /// The type of the to_bits() and from_bits() methods
     type BitsType: Hash + Eq + Ord + Display + Debug;
@@ -140,6 +545,56 @@ where
     fn to_bits(self) -> Self::BitsType;
     fn from_bits(bits: Self::BitsType) -> Self;
     fn clamp(self, min: Self, max: Self) -> Self;
+    /// Maps -0.0 to +0.0 and every NaN to a single NaN bit pattern, leaving other values as they are.
+    /// This makes hashing and deduplication built on `to_bits()` behave predictably.
+    #[inline(always)]
+    fn canonicalize(self) -> Self {
+        if Float::is_nan(self) {
+            <Self as Float>::nan()
+        } else if self == Self::ZERO {
+            Self::ZERO
+        } else {
+            self
+        }
+    }
+    /// Returns true if the value is denormal (subnormal), i.e. non-zero but smaller in magnitude than
+    /// the smallest normal value. Arithmetic on denormals is slow on many CPUs and loses precision.
+    #[inline(always)]
+    fn is_denormal(self) -> bool {
+        Float::is_subnormal(self)
+    }
+    /// Returns zero, with the sign kept, if the value is denormal. Other values are returned as they are.
+    #[inline(always)]
+    fn flush_denormal(self) -> Self {
+        if self.is_denormal() {
+            Float::copysign(Self::ZERO, self)
+        } else {
+            self
+        }
+    }
+    /// Snaps the value to the nearest multiple of `cell`, which must be positive.
+    ///
+    /// Ties are rounded up (towards positive infinity), so every cell covers the same half open
+    /// range `[(k - 0.5) * cell, (k + 0.5) * cell)` on both sides of zero. The result is idempotent:
+    /// `s.snap(cell).snap(cell) == s.snap(cell)`. Values too large for the grid to be finer than
+    /// the float spacing, and non-finite values, are returned as they are.
+    #[inline(always)]
+    fn snap(self, cell: Self) -> Self {
+        let q = self / cell;
+        // below this bound the rounding error of k * cell / cell is less than 0.5, so snapping
+        // a snapped value reproduces the same k
+        if Float::is_nan(q) || Float::abs(q) >= Self::ONE / (Self::EPSILON * Self::TWO) {
+            return self;
+        }
+        // q - floor(q) is exact, or rounds towards one for q in (-0.5, 0), so unlike
+        // floor(q + 0.5) it never moves a value just below a tie into the next cell
+        let k = Float::floor(q);
+        if q - k >= 0.5.into() {
+            (k + Self::ONE) * cell
+        } else {
+            k * cell
+        }
+    }
 }
 
 /// A workaround for Rust's limitations where external traits cannot be implemented for external types.
@@ -152,27 +607,68 @@ where
 /// comparisons. The methods in this trait are wrappers around the corresponding methods provided
 /// by the `approx` crate.
 ///
+/// Every method has a default implementation comparing the `HasXY::DIM` components of
+/// `HasXY::get()` one by one, so a custom storage type only needs an empty
+/// `impl Approx for MyType {}`.
+///
 pub trait Approx: HasXY {
     /// Checks if two instances are nearly equal within a specified tolerance in ULPs (Units in the Last Place).
     ///
     /// This method delegates to the `approx::UlpsEq::ulps_eq` method, performing approximate equality checks
     /// one time per coordinate axis.
+    #[inline(always)]
     fn is_ulps_eq(
         self,
         other: Self,
         epsilon: <Self::Scalar as approx::AbsDiffEq>::Epsilon,
         max_ulps: u32,
-    ) -> bool;
+    ) -> bool {
+        (0..Self::DIM).all(|i| match (self.get(i), other.get(i)) {
+            (Some(a), Some(b)) => approx::UlpsEq::ulps_eq(&a, &b, epsilon, max_ulps),
+            _ => false,
+        })
+    }
 
     /// Checks if two instances are nearly equal within a specified absolute difference tolerance.
     ///
     /// This method delegates to the `approx::AbsDiffEq::abs_diff_eq` method, performing approximate equality checks
     /// one time per coordinate axis.
+    #[inline(always)]
     fn is_abs_diff_eq(
         self,
         other: Self,
         epsilon: <Self::Scalar as approx::AbsDiffEq>::Epsilon,
-    ) -> bool;
+    ) -> bool {
+        (0..Self::DIM).all(|i| match (self.get(i), other.get(i)) {
+            (Some(a), Some(b)) => approx::AbsDiffEq::abs_diff_eq(&a, &b, epsilon),
+            _ => false,
+        })
+    }
+
+    /// Checks if two instances are nearly equal using `is_ulps_eq()` with the default epsilon
+    /// and max ULPs of the scalar type.
+    #[inline(always)]
+    fn is_approx_eq(self, other: Self) -> bool {
+        self.is_ulps_eq(
+            other,
+            <Self::Scalar as approx::AbsDiffEq>::default_epsilon(),
+            <Self::Scalar as approx::UlpsEq>::default_max_ulps(),
+        )
+    }
+
+    /// Checks if the distance between two instances is at most `rel_eps` times the larger of their
+    /// magnitudes. Unlike the per-component checks this behaves well when the components have very
+    /// different magnitudes. Two zero vectors compare as equal.
+    fn is_eq_relative_to_norm(self, other: Self, rel_eps: Self::Scalar) -> bool {
+        let (mut diff_sq, mut self_sq, mut other_sq) =
+            (Self::Scalar::ZERO, Self::Scalar::ZERO, Self::Scalar::ZERO);
+        for (a, b) in (0..Self::DIM).filter_map(|i| Some((self.get(i)?, other.get(i)?))) {
+            diff_sq += (a - b) * (a - b);
+            self_sq += a * a;
+            other_sq += b * b;
+        }
+        Float::sqrt(diff_sq) <= rel_eps * Float::sqrt(Float::max(self_sq, other_sq))
+    }
 }
 
 /// A generic two-dimensional vector trait, designed for flexibility in precision.
@@ -195,23 +691,240 @@ pub trait GenericVector2:
     + Approx
     + PartialEq
     + AddAssign
+    + SubAssign
+    + MulAssign<Self::Scalar>
+    + DivAssign<Self::Scalar>
     + Neg<Output = Self>
     + Sub<Self, Output = Self>
     + std::ops::Mul<Self::Scalar, Output = Self>
     + std::ops::Div<Self::Scalar, Output = Self>
     + Add<Self, Output = Self>
     + Index<usize, Output = Self::Scalar>
+    + Index<Axis2, Output = Self::Scalar>
+    + std::iter::Sum<Self>
+    + for<'a> std::iter::Sum<&'a Self>
 {
     type Vector3: GenericVector3<Scalar = Self::Scalar, Vector2 = Self>;
+    /// The per component result of the comparisons, see `GenericMask`.
+    type Mask: GenericMask;
+    /// The `i32` grid vector of `as_ivec()`, `floor_to_int()` and `round_to_int()`.
+    type IntVector: GenericIntVector2<Scalar = i32>;
     fn to_3d(self, z: Self::Scalar) -> Self::Vector3;
     fn magnitude(self) -> Self::Scalar;
     fn magnitude_sq(self) -> Self::Scalar;
+    /// Returns the length of the vector computed with `hypot`, free of intermediate overflow and
+    /// underflow. This is slower than `magnitude()`, use it where robustness matters more than speed.
+    #[inline(always)]
+    fn magnitude_hypot(self) -> Self::Scalar {
+        math::hypot(self.x(), self.y())
+    }
     fn dot(self, other: Self) -> Self::Scalar;
     fn perp_dot(self, rhs: Self) -> Self::Scalar;
     fn distance(self, rhs: Self) -> Self::Scalar;
     fn distance_sq(self, rhs: Self) -> Self::Scalar;
     fn normalize(self) -> Self;
-    fn safe_normalize(self) -> Option<Self>;
+    /// Returns the normalized vector, or `None` if the length is zero, subnormal or not finite.
+    /// Short vectors of normal length are kept, use `safe_normalize_eps()` for a coarser cutoff.
+    #[inline(always)]
+    fn safe_normalize(self) -> Option<Self> {
+        self.safe_normalize_eps(Float::min_positive_value())
+    }
+    /// Returns the normalized vector, or `None` if the length is not finite or not larger than `epsilon`.
+    fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self>;
+    /// Returns the unsigned angle between the vectors, in radians in the range [0, pi].
+    ///
+    /// This is computed as `atan2(|perp_dot|, dot)`, so the result is never NaN for finite input,
+    /// and it stays accurate for nearly parallel vectors, where an `acos` based version loses precision
+    /// or NaNs out. The angle is zero if either vector has zero length.
+    #[inline(always)]
+    fn angle_between(self, other: Self) -> Self::Scalar {
+        math::atan2(Float::abs(self.perp_dot(other)), self.dot(other))
+    }
+    /// Snaps every component to the nearest multiple of `cell`, see `GenericScalar::snap()`.
+    /// Snapping is idempotent, so snapped points never drift when they are snapped again.
+    #[inline(always)]
+    fn snap(self, cell: Self::Scalar) -> Self {
+        Self::new_2d(self.x().snap(cell), self.y().snap(cell))
+    }
+    /// Returns the vector rotated 90 degrees counterclockwise, `(-y, x)`. The result is exact.
+    #[inline(always)]
+    fn rotate_90_ccw(self) -> Self {
+        Self::new_2d(-self.y(), self.x())
+    }
+    /// Returns the vector rotated 90 degrees clockwise, `(y, -x)`. The result is exact.
+    #[inline(always)]
+    fn rotate_90_cw(self) -> Self {
+        Self::new_2d(self.y(), -self.x())
+    }
+    /// Returns the vector rotated 180 degrees, `(-x, -y)`. The result is exact.
+    #[inline(always)]
+    fn rotate_180(self) -> Self {
+        Self::new_2d(-self.x(), -self.y())
+    }
+    /// Returns the vector with the x component negated, the mirror image across the y axis.
+    /// The result is exact.
+    #[inline(always)]
+    fn flip_x(self) -> Self {
+        Self::new_2d(-self.x(), self.y())
+    }
+    /// Returns the vector with the y component negated, the mirror image across the x axis.
+    /// The result is exact.
+    #[inline(always)]
+    fn flip_y(self) -> Self {
+        Self::new_2d(self.x(), -self.y())
+    }
+    /// Returns the mirror image of the vector across the line of `axis`, keeping the component
+    /// along the axis and negating the other. The result is exact.
+    #[inline(always)]
+    fn mirror_across_axis(self, axis: Axis2) -> Self {
+        match axis {
+            Axis2::X => self.flip_y(),
+            Axis2::Y => self.flip_x(),
+        }
+    }
+    /// Returns the 3D vector with `value` inserted as the component along `axis`, the inverse of
+    /// `GenericVector3::drop_dominant_axis()`.
+    #[inline(always)]
+    fn insert_axis(self, axis: Axis3, value: Self::Scalar) -> Self::Vector3 {
+        match axis {
+            Axis3::X => Self::Vector3::new_3d(value, self.x(), self.y()),
+            Axis3::Y => Self::Vector3::new_3d(self.y(), value, self.x()),
+            Axis3::Z => Self::Vector3::new_3d(self.x(), self.y(), value),
+        }
+    }
+    /// Returns the vector as a `mint::Vector2`.
+    #[cfg(feature = "mint")]
+    #[inline(always)]
+    fn to_mint(self) -> mint::Vector2<Self::Scalar> {
+        mint::Vector2 {
+            x: self.x(),
+            y: self.y(),
+        }
+    }
+    /// Creates a vector from a `mint::Vector2`.
+    #[cfg(feature = "mint")]
+    #[inline(always)]
+    fn from_mint(v: mint::Vector2<Self::Scalar>) -> Self {
+        Self::new_2d(v.x, v.y)
+    }
+    /// Returns the exact orientation of the points `a`, `b` and `c`, see `predicates::orient2d()`.
+    #[inline(always)]
+    fn orientation(a: Self, b: Self, c: Self) -> Orientation {
+        orient2d(a, b, c)
+    }
+    /// Returns the orientation of the points `a`, `b` and `c`, treating them as collinear when
+    /// `c` is within `epsilon` of the line through `a` and `b`, or when `a` and `b` coincide.
+    ///
+    /// Unlike `orientation()` this is not exact, so different triplets of the same points can
+    /// give inconsistent answers close to the tolerance.
+    #[inline(always)]
+    fn orientation_eps(a: Self, b: Self, c: Self, epsilon: Self::Scalar) -> Orientation {
+        let ab = b - a;
+        let cross = ab.perp_dot(c - a);
+        if Float::abs(cross) <= epsilon * ab.magnitude() {
+            Orientation::Collinear
+        } else if cross > Self::Scalar::ZERO {
+            Orientation::CounterClockwise
+        } else {
+            Orientation::Clockwise
+        }
+    }
+    /// Returns the mask of the components of `self` less than those of `rhs`. As with the scalar
+    /// comparisons, NaN components compare false.
+    #[inline(always)]
+    fn cmplt(self, rhs: Self) -> Self::Mask {
+        Self::Mask::from_bitmask((self.x() < rhs.x()) as u32 | ((self.y() < rhs.y()) as u32) << 1)
+    }
+    /// Returns the mask of the components of `self` less than or equal to those of `rhs`.
+    #[inline(always)]
+    fn cmple(self, rhs: Self) -> Self::Mask {
+        Self::Mask::from_bitmask((self.x() <= rhs.x()) as u32 | ((self.y() <= rhs.y()) as u32) << 1)
+    }
+    /// Returns the mask of the components of `self` greater than those of `rhs`.
+    #[inline(always)]
+    fn cmpgt(self, rhs: Self) -> Self::Mask {
+        rhs.cmplt(self)
+    }
+    /// Returns the mask of the components of `self` equal to those of `rhs`.
+    #[inline(always)]
+    fn cmpeq(self, rhs: Self) -> Self::Mask {
+        Self::Mask::from_bitmask((self.x() == rhs.x()) as u32 | ((self.y() == rhs.y()) as u32) << 1)
+    }
+    /// Returns the vector with the components of `if_true` where `mask` is set, and those of
+    /// `if_false` elsewhere.
+    #[inline(always)]
+    fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self {
+        let pick = |i: usize| {
+            if mask.test(i) {
+                if_true[i]
+            } else {
+                if_false[i]
+            }
+        };
+        Self::new_2d(pick(0), pick(1))
+    }
+    /// Converts the components to `i32` like an `as` cast: the fraction is truncated, out of
+    /// range values saturate and NaN becomes zero.
+    #[inline(always)]
+    fn as_ivec(self) -> Self::IntVector {
+        Self::IntVector::from_float_lossy(self)
+    }
+    /// Converts the components rounded down to `i32`, the index of the unit grid cell holding the
+    /// point. Saturates like `as_ivec()`.
+    #[inline(always)]
+    fn floor_to_int(self) -> Self::IntVector {
+        Self::IntVector::new_2d(
+            i32::from_float_lossy(Float::floor(self.x())),
+            i32::from_float_lossy(Float::floor(self.y())),
+        )
+    }
+    /// Converts the components rounded to the nearest `i32`, with ties away from zero. Saturates
+    /// like `as_ivec()`.
+    #[inline(always)]
+    fn round_to_int(self) -> Self::IntVector {
+        Self::IntVector::new_2d(
+            i32::from_float_lossy(Float::round(self.x())),
+            i32::from_float_lossy(Float::round(self.y())),
+        )
+    }
+}
+
+impl ScalarCore for f32 {
+    #[inline(always)]
+    fn abs_value(self) -> Self {
+        f32::abs(self)
+    }
+    #[inline(always)]
+    fn sqrt_value(self) -> Self {
+        math::sqrt(self)
+    }
+    #[inline(always)]
+    fn to_f64_lossy(self) -> f64 {
+        self.into()
+    }
+    #[inline(always)]
+    fn from_f64_lossy(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl ScalarCore for f64 {
+    #[inline(always)]
+    fn abs_value(self) -> Self {
+        f64::abs(self)
+    }
+    #[inline(always)]
+    fn sqrt_value(self) -> Self {
+        math::sqrt(self)
+    }
+    #[inline(always)]
+    fn to_f64_lossy(self) -> f64 {
+        self
+    }
+    #[inline(always)]
+    fn from_f64_lossy(value: f64) -> Self {
+        value
+    }
 }
 
 impl GenericScalar for f32 {
@@ -277,6 +990,12 @@ impl GenericScalar for f64 {
 ///
 pub trait HasXY: Sync + Send + Copy + Debug + Sized {
     type Scalar: GenericScalar;
+    /// The number of dimensions of the type, 2 for 2d types, 3 for types implementing `HasXYZ`
+    /// and 4 for types implementing `HasXYZW`.
+    ///
+    /// `DIM`, `get()`, `canonicalize()` and `flush_denormals()` have no defaults, so a 3d or 4d
+    /// type can not leave out its z and w components by accident.
+    const DIM: usize;
     /// create a new instance of Self, note that this
     /// creates a 3d vector if the instanced type is a 3d type
     fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self;
@@ -286,6 +1005,53 @@ pub trait HasXY: Sync + Send + Copy + Debug + Sized {
     fn y(self) -> Self::Scalar;
     fn y_mut(&mut self) -> &mut Self::Scalar;
     fn set_y(&mut self, val: Self::Scalar);
+    /// Returns the component at `index`, or `None` if `index >= Self::DIM`.
+    /// This is the non-panicking complement of `Index<usize>`.
+    fn get(self, index: usize) -> Option<Self::Scalar>;
+    /// Returns a copy where every component is canonicalized, see `GenericScalar::canonicalize()`.
+    fn canonicalize(self) -> Self;
+    /// Returns a copy where every denormal component is flushed to zero, see
+    /// `GenericScalar::flush_denormal()`.
+    fn flush_denormals(self) -> Self;
+    /// Returns true if any component is denormal, see `GenericScalar::is_denormal()`.
+    #[inline(always)]
+    fn is_denormal(self) -> bool {
+        (0..Self::DIM)
+            .filter_map(|i| self.get(i))
+            .any(GenericScalar::is_denormal)
+    }
+    /// Sets the x and y components in one call.
+    #[inline(always)]
+    fn set_xy(&mut self, x: Self::Scalar, y: Self::Scalar) {
+        self.set_x(x);
+        self.set_y(y);
+    }
+    /// Sets the x and y components from an array.
+    #[inline(always)]
+    fn set_from_array(&mut self, array: [Self::Scalar; 2]) {
+        self.set_xy(array[0], array[1]);
+    }
+    /// Returns the component along `axis`.
+    #[inline(always)]
+    fn axis(self, axis: Axis2) -> Self::Scalar {
+        match axis {
+            Axis2::X => self.x(),
+            Axis2::Y => self.y(),
+        }
+    }
+    /// Returns a mutable reference to the component along `axis`.
+    #[inline(always)]
+    fn axis_mut(&mut self, axis: Axis2) -> &mut Self::Scalar {
+        match axis {
+            Axis2::X => self.x_mut(),
+            Axis2::Y => self.y_mut(),
+        }
+    }
+    /// Sets the component along `axis`.
+    #[inline(always)]
+    fn set_axis(&mut self, axis: Axis2, val: Self::Scalar) {
+        *self.axis_mut(axis) = val;
+    }
 }
 
 /// A basic three-dimensional vector trait, designed for flexibility in precision.
@@ -305,6 +1071,64 @@ pub trait HasXYZ: HasXY {
     fn z(self) -> Self::Scalar;
     fn z_mut(&mut self) -> &mut Self::Scalar;
     fn set_z(&mut self, val: Self::Scalar);
+    /// Sets the x, y and z components in one call.
+    #[inline(always)]
+    fn set_xyz(&mut self, x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) {
+        self.set_x(x);
+        self.set_y(y);
+        self.set_z(z);
+    }
+    /// Sets the x, y and z components from an array.
+    #[inline(always)]
+    fn set_from_array3(&mut self, array: [Self::Scalar; 3]) {
+        self.set_xyz(array[0], array[1], array[2]);
+    }
+    /// Returns the component along `axis`.
+    #[inline(always)]
+    fn axis3(self, axis: Axis3) -> Self::Scalar {
+        match axis {
+            Axis3::X => self.x(),
+            Axis3::Y => self.y(),
+            Axis3::Z => self.z(),
+        }
+    }
+    /// Returns a mutable reference to the component along `axis`.
+    #[inline(always)]
+    fn axis3_mut(&mut self, axis: Axis3) -> &mut Self::Scalar {
+        match axis {
+            Axis3::X => self.x_mut(),
+            Axis3::Y => self.y_mut(),
+            Axis3::Z => self.z_mut(),
+        }
+    }
+    /// Sets the component along `axis`.
+    #[inline(always)]
+    fn set_axis3(&mut self, axis: Axis3, val: Self::Scalar) {
+        *self.axis3_mut(axis) = val;
+    }
+}
+
+/// A basic four-dimensional vector trait, the `HasXYZ` counterpart for homogeneous coordinates
+/// and other 4-component vectors.
+///
+/// `new_2d()` and `new_3d()` set the missing components to zero.
+///
+pub trait HasXYZW: HasXYZ {
+    fn new_4d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar, w: Self::Scalar) -> Self;
+    fn w(self) -> Self::Scalar;
+    fn w_mut(&mut self) -> &mut Self::Scalar;
+    fn set_w(&mut self, val: Self::Scalar);
+    /// Sets the x, y, z and w components in one call.
+    #[inline(always)]
+    fn set_xyzw(&mut self, x: Self::Scalar, y: Self::Scalar, z: Self::Scalar, w: Self::Scalar) {
+        self.set_xyz(x, y, z);
+        self.set_w(w);
+    }
+    /// Sets the x, y, z and w components from an array.
+    #[inline(always)]
+    fn set_from_array4(&mut self, array: [Self::Scalar; 4]) {
+        self.set_xyzw(array[0], array[1], array[2], array[3]);
+    }
 }
 
 /// A generic three-dimensional vector trait, designed for flexibility in precision.
@@ -327,28 +1151,368 @@ pub trait GenericVector3:
     + Approx
     + PartialEq
     + AddAssign
+    + SubAssign
+    + MulAssign<Self::Scalar>
+    + DivAssign<Self::Scalar>
     + Neg<Output = Self>
     + Sub<Self, Output = Self>
     + std::ops::Mul<Self::Scalar, Output = Self>
     + std::ops::Div<Self::Scalar, Output = Self>
     + Add<Self, Output = Self>
     + Index<usize, Output = Self::Scalar>
+    + Index<Axis3, Output = Self::Scalar>
+    + std::iter::Sum<Self>
+    + for<'a> std::iter::Sum<&'a Self>
 {
     type Vector2: GenericVector2<Scalar = Self::Scalar, Vector3 = Self>;
+    /// The per component result of the comparisons, see `GenericMask`.
+    type Mask: GenericMask;
+    /// The `i32` grid vector of `as_ivec()`, `floor_to_int()` and `round_to_int()`.
+    type IntVector: GenericIntVector3<Scalar = i32>;
     fn to_2d(&self) -> Self::Vector2;
     fn magnitude(self) -> Self::Scalar;
     fn magnitude_sq(self) -> Self::Scalar;
+    /// Returns the length of the vector computed with `hypot`, free of intermediate overflow and
+    /// underflow. This is slower than `magnitude()`, use it where robustness matters more than speed.
+    #[inline(always)]
+    fn magnitude_hypot(self) -> Self::Scalar {
+        math::hypot(math::hypot(self.x(), self.y()), self.z())
+    }
     fn dot(self, other: Self) -> Self::Scalar;
     fn cross(self, rhs: Self) -> Self;
     fn normalize(self) -> Self;
-    fn safe_normalize(self) -> Option<Self>;
+    /// Returns the normalized vector, or `None` if the length is zero, subnormal or not finite.
+    /// Short vectors of normal length are kept, use `safe_normalize_eps()` for a coarser cutoff.
+    #[inline(always)]
+    fn safe_normalize(self) -> Option<Self> {
+        self.safe_normalize_eps(Float::min_positive_value())
+    }
+    /// Returns the normalized vector, or `None` if the length is not finite or not larger than `epsilon`.
+    fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self>;
+    /// Returns the unsigned angle between the vectors, in radians in the range [0, pi].
+    ///
+    /// This is computed as `atan2(|cross|, dot)`, so the result is never NaN for finite input,
+    /// and it stays accurate for nearly parallel vectors, where an `acos` based version loses precision
+    /// or NaNs out. The angle is zero if either vector has zero length.
+    #[inline(always)]
+    fn angle_between(self, other: Self) -> Self::Scalar {
+        math::atan2(self.cross(other).magnitude(), self.dot(other))
+    }
+    /// Returns the signed angle of the rotation about `axis` that takes `self` to `other`, in
+    /// radians in the range [-pi, pi]. The angle is positive for a counterclockwise rotation seen
+    /// from the tip of the axis looking towards the origin (the right hand rule).
+    ///
+    /// Only the components perpendicular to the axis are compared, so the vectors do not have to be
+    /// perpendicular to it. The axis does not have to be normalized. The angle is zero if the axis
+    /// can not be normalized, or if either vector is parallel to the axis.
+    #[inline(always)]
+    fn signed_angle_about_axis(self, other: Self, axis: Self) -> Self::Scalar {
+        let Some(axis) = axis.safe_normalize() else {
+            return Self::Scalar::ZERO;
+        };
+        let a = self - axis * self.dot(axis);
+        let b = other - axis * other.dot(axis);
+        math::atan2(a.cross(b).dot(axis), a.dot(b))
+    }
+    /// Snaps every component to the nearest multiple of `cell`, see `GenericScalar::snap()`.
+    /// Snapping is idempotent, so snapped points never drift when they are snapped again.
+    #[inline(always)]
+    fn snap(self, cell: Self::Scalar) -> Self {
+        Self::new_3d(
+            self.x().snap(cell),
+            self.y().snap(cell),
+            self.z().snap(cell),
+        )
+    }
+    /// Returns the unit direction with the given `yaw` and `pitch`, in radians.
+    ///
+    /// Yaw is the counterclockwise angle about the z axis, measured from the x axis, and pitch
+    /// is the elevation above the xy plane, so `from_yaw_pitch(0, 0)` is the x axis and a pitch
+    /// of pi/2 is the z axis.
+    #[inline(always)]
+    fn from_yaw_pitch(yaw: Self::Scalar, pitch: Self::Scalar) -> Self {
+        let cos_pitch = math::cos(pitch);
+        Self::new_3d(
+            cos_pitch * math::cos(yaw),
+            cos_pitch * math::sin(yaw),
+            math::sin(pitch),
+        )
+    }
+    /// Returns the vector projected onto the plane through the origin with the given `normal`, by
+    /// removing the component along the normal. The normal does not have to be normalized, and a
+    /// zero normal returns the vector unchanged.
+    #[inline(always)]
+    fn project_onto_plane(self, normal: Self) -> Self {
+        let n_sq = normal.dot(normal);
+        if n_sq == Self::Scalar::ZERO {
+            return self;
+        }
+        self - normal * (self.dot(normal) / n_sq)
+    }
+    /// Returns the point in the axis aligned box from `min` to `max` closest to `self`, by clamping
+    /// every component. A NaN component is passed through.
+    #[inline(always)]
+    fn clamp_to_aabb(self, min: Self, max: Self) -> Self {
+        let clamp = |v: Self::Scalar, lo: Self::Scalar, hi: Self::Scalar| {
+            if v < lo {
+                lo
+            } else if v > hi {
+                hi
+            } else {
+                v
+            }
+        };
+        Self::new_3d(
+            clamp(self.x(), min.x(), max.x()),
+            clamp(self.y(), min.y(), max.y()),
+            clamp(self.z(), min.z(), max.z()),
+        )
+    }
+    /// Returns the vector with the x component negated, the mirror image across the yz plane.
+    /// The result is exact.
+    #[inline(always)]
+    fn flip_x(self) -> Self {
+        Self::new_3d(-self.x(), self.y(), self.z())
+    }
+    /// Returns the vector with the y component negated, the mirror image across the xz plane.
+    /// The result is exact.
+    #[inline(always)]
+    fn flip_y(self) -> Self {
+        Self::new_3d(self.x(), -self.y(), self.z())
+    }
+    /// Returns the vector with the z component negated, the mirror image across the xy plane.
+    /// The result is exact.
+    #[inline(always)]
+    fn flip_z(self) -> Self {
+        Self::new_3d(self.x(), self.y(), -self.z())
+    }
+    /// Returns the mirror image of the vector across the line of `axis`, keeping the component
+    /// along the axis and negating the other two, which is a rotation of 180 degrees about the
+    /// axis. Use the `flip_*()` methods to mirror across the coordinate planes. The result is exact.
+    #[inline(always)]
+    fn mirror_across_axis(self, axis: Axis3) -> Self {
+        match axis {
+            Axis3::X => Self::new_3d(self.x(), -self.y(), -self.z()),
+            Axis3::Y => Self::new_3d(-self.x(), self.y(), -self.z()),
+            Axis3::Z => Self::new_3d(-self.x(), -self.y(), self.z()),
+        }
+    }
+    /// Returns the vector with the component of the largest magnitude removed, and the axis of
+    /// that component, see `Axis3::dominant()`.
+    ///
+    /// Applied to the vertices of a planar polygon with the dominant axis of its normal, this
+    /// projects the polygon to 2D with the least distortion, e.g. for triangulation. The remaining
+    /// components keep their cyclic order, `(y, z)`, `(z, x)` or `(x, y)`, so the winding of the
+    /// projected polygon is preserved when the dropped component of the normal is positive, and
+    /// reversed when it is negative. `GenericVector2::insert_axis()` puts the component back.
+    #[inline(always)]
+    fn drop_dominant_axis(self) -> (Self::Vector2, Axis3) {
+        let axis = Axis3::dominant(self);
+        (self.drop_axis(axis), axis)
+    }
+    /// Returns the vector with the component along `axis` removed, keeping the cyclic order of the
+    /// other two as `drop_dominant_axis()` does.
+    #[inline(always)]
+    fn drop_axis(self, axis: Axis3) -> Self::Vector2 {
+        match axis {
+            Axis3::X => Self::Vector2::new_2d(self.y(), self.z()),
+            Axis3::Y => Self::Vector2::new_2d(self.z(), self.x()),
+            Axis3::Z => Self::Vector2::new_2d(self.x(), self.y()),
+        }
+    }
+    /// Returns the vector as a `mint::Vector3`.
+    #[cfg(feature = "mint")]
+    #[inline(always)]
+    fn to_mint(self) -> mint::Vector3<Self::Scalar> {
+        mint::Vector3 {
+            x: self.x(),
+            y: self.y(),
+            z: self.z(),
+        }
+    }
+    /// Creates a vector from a `mint::Vector3`.
+    #[cfg(feature = "mint")]
+    #[inline(always)]
+    fn from_mint(v: mint::Vector3<Self::Scalar>) -> Self {
+        Self::new_3d(v.x, v.y, v.z)
+    }
+    /// Returns the unit direction from `self` towards `to`, or `None` if the points coincide
+    /// (see `safe_normalize()`).
+    #[inline(always)]
+    fn direction_to(self, to: Self) -> Option<Self> {
+        (to - self).safe_normalize()
+    }
+    /// Returns the `(yaw, pitch)` of the direction of the vector, the inverse of `from_yaw_pitch()`.
+    /// The length of the vector is ignored, the zero vector returns zero angles.
+    #[inline(always)]
+    fn to_yaw_pitch(self) -> (Self::Scalar, Self::Scalar) {
+        (
+            math::atan2(self.y(), self.x()),
+            math::atan2(self.z(), math::hypot(self.x(), self.y())),
+        )
+    }
+    fn distance(self, other: Self) -> Self::Scalar;
+    fn distance_sq(self, rhs: Self) -> Self::Scalar;
+    /// Returns the mask of the components of `self` less than those of `rhs`. As with the scalar
+    /// comparisons, NaN components compare false.
+    #[inline(always)]
+    fn cmplt(self, rhs: Self) -> Self::Mask {
+        Self::Mask::from_bitmask(
+            (self.x() < rhs.x()) as u32
+                | ((self.y() < rhs.y()) as u32) << 1
+                | ((self.z() < rhs.z()) as u32) << 2,
+        )
+    }
+    /// Returns the mask of the components of `self` less than or equal to those of `rhs`.
+    #[inline(always)]
+    fn cmple(self, rhs: Self) -> Self::Mask {
+        Self::Mask::from_bitmask(
+            (self.x() <= rhs.x()) as u32
+                | ((self.y() <= rhs.y()) as u32) << 1
+                | ((self.z() <= rhs.z()) as u32) << 2,
+        )
+    }
+    /// Returns the mask of the components of `self` greater than those of `rhs`.
+    #[inline(always)]
+    fn cmpgt(self, rhs: Self) -> Self::Mask {
+        rhs.cmplt(self)
+    }
+    /// Returns the mask of the components of `self` equal to those of `rhs`.
+    #[inline(always)]
+    fn cmpeq(self, rhs: Self) -> Self::Mask {
+        Self::Mask::from_bitmask(
+            (self.x() == rhs.x()) as u32
+                | ((self.y() == rhs.y()) as u32) << 1
+                | ((self.z() == rhs.z()) as u32) << 2,
+        )
+    }
+    /// Returns the vector with the components of `if_true` where `mask` is set, and those of
+    /// `if_false` elsewhere.
+    #[inline(always)]
+    fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self {
+        let pick = |i: usize| {
+            if mask.test(i) {
+                if_true[i]
+            } else {
+                if_false[i]
+            }
+        };
+        Self::new_3d(pick(0), pick(1), pick(2))
+    }
+    /// Converts the components to `i32` like an `as` cast: the fraction is truncated, out of
+    /// range values saturate and NaN becomes zero.
+    #[inline(always)]
+    fn as_ivec(self) -> Self::IntVector {
+        Self::IntVector::from_float_lossy(self)
+    }
+    /// Converts the components rounded down to `i32`, the index of the unit grid cell holding the
+    /// point. Saturates like `as_ivec()`.
+    #[inline(always)]
+    fn floor_to_int(self) -> Self::IntVector {
+        Self::IntVector::new_3d(
+            i32::from_float_lossy(Float::floor(self.x())),
+            i32::from_float_lossy(Float::floor(self.y())),
+            i32::from_float_lossy(Float::floor(self.z())),
+        )
+    }
+    /// Converts the components rounded to the nearest `i32`, with ties away from zero. Saturates
+    /// like `as_ivec()`.
+    #[inline(always)]
+    fn round_to_int(self) -> Self::IntVector {
+        Self::IntVector::new_3d(
+            i32::from_float_lossy(Float::round(self.x())),
+            i32::from_float_lossy(Float::round(self.y())),
+            i32::from_float_lossy(Float::round(self.z())),
+        )
+    }
+}
+
+/// A generic four-dimensional vector trait, designed for flexibility in precision.
+///
+/// The `GenericVector4` trait abstracts over four-dimensional vectors, e.g. homogeneous
+/// coordinates and RGBA colors. It provides the operations that are meaningful in any dimension,
+/// there is no cross product.
+///
+/// The associated `Vector3` type is the corresponding three-dimensional vector type, returned by
+/// `truncate()`.
+pub trait GenericVector4:
+    HasXYZW
+    + Approx
+    + PartialEq
+    + AddAssign
+    + SubAssign
+    + MulAssign<Self::Scalar>
+    + DivAssign<Self::Scalar>
+    + Neg<Output = Self>
+    + Sub<Self, Output = Self>
+    + std::ops::Mul<Self::Scalar, Output = Self>
+    + std::ops::Div<Self::Scalar, Output = Self>
+    + Add<Self, Output = Self>
+    + Index<usize, Output = Self::Scalar>
+    + std::iter::Sum<Self>
+    + for<'a> std::iter::Sum<&'a Self>
+{
+    type Vector3: GenericVector3<Scalar = Self::Scalar>;
+    /// Returns the x, y and z components, dropping w.
+    fn truncate(self) -> Self::Vector3;
+    /// Creates a vector from a three-dimensional vector and a w component.
+    #[inline(always)]
+    fn extend(v: Self::Vector3, w: Self::Scalar) -> Self {
+        Self::new_4d(v.x(), v.y(), v.z(), w)
+    }
+    fn magnitude(self) -> Self::Scalar;
+    fn magnitude_sq(self) -> Self::Scalar;
+    fn dot(self, other: Self) -> Self::Scalar;
+    fn normalize(self) -> Self;
+    /// Returns the normalized vector, or `None` if the length is zero, subnormal or not finite.
+    /// Short vectors of normal length are kept, use `safe_normalize_eps()` for a coarser cutoff.
+    #[inline(always)]
+    fn safe_normalize(self) -> Option<Self> {
+        self.safe_normalize_eps(Float::min_positive_value())
+    }
+    /// Returns the normalized vector, or `None` if the length is not finite or not larger than `epsilon`.
+    fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self>;
     fn distance(self, other: Self) -> Self::Scalar;
     fn distance_sq(self, rhs: Self) -> Self::Scalar;
 }
 
 pub use approx;
+#[cfg(feature = "bevy_math")]
+pub use bevy_math;
 #[cfg(feature = "cgmath")]
 pub use cgmath;
+#[cfg(feature = "cgmath_017")]
+pub use cgmath_017;
+#[cfg(feature = "softfloat")]
+pub use const_soft_float;
+#[cfg(feature = "dashu")]
+pub use dashu_float;
+#[cfg(feature = "egui")]
+pub use emath;
+#[cfg(feature = "fixed")]
+pub use fixed;
+#[cfg(feature = "geo")]
+pub use geo_types;
 #[cfg(feature = "glam")]
 pub use glam;
+#[cfg(feature = "godot")]
+pub use godot;
+#[cfg(feature = "half")]
+pub use half;
+#[cfg(feature = "lyon")]
+pub use lyon_geom;
+#[cfg(feature = "mint")]
+pub use mint;
+#[cfg(feature = "nalgebra")]
+pub use nalgebra;
+#[cfg(feature = "num-complex")]
+pub use num_complex;
+#[cfg(feature = "num-rational")]
+pub use num_rational;
 pub use num_traits;
+#[cfg(feature = "rstar")]
+pub use rstar;
+#[cfg(feature = "twofloat")]
+pub use twofloat;
+#[cfg(feature = "wide")]
+pub use wide;
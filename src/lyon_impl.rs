@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `HasXY` and `Approx` for `lyon_geom::Point` and `lyon_geom::Vector`, so tessellation input
+//! and output can flow through the algorithms written against the storage traits.
+//!
+//! The two types are `euclid::Point2D` and `euclid::Vector2D` with the default unit, and the
+//! impls cover every euclid unit, with `f32` or `f64` scalars. `GenericVector2` is not
+//! implemented: it requires `Index<usize>`, which euclid does not implement and this crate can not
+//! provide for a foreign type. `to_lyon_point()`, `to_lyon_vector()`, `from_lyon_point()` and
+//! `from_lyon_vector()` convert to and from any vector type.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{Approx, Axis2, GenericScalar, HasXY};
+use ::lyon_geom::euclid::{Point2D, Vector2D};
+pub use ::lyon_geom::{Point, Vector};
+use std::{fmt::Debug, ops::Index};
+
+macro_rules! impl_euclid {
+    ($vec:ident, $scalar:ty) => {
+        impl<U: Debug + Send + Sync> HasXY for $vec<$scalar, U> {
+            type Scalar = $scalar;
+            const DIM: usize = 2;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                $vec::new(x, y)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_2d(self.x.canonicalize(), self.y.canonicalize())
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_2d(self.x.flush_denormal(), self.y.flush_denormal())
+            }
+        }
+
+        impl<U> Index<Axis2> for $vec<$scalar, U> {
+            type Output = $scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis2) -> &Self::Output {
+                match axis {
+                    Axis2::X => &self.x,
+                    Axis2::Y => &self.y,
+                }
+            }
+        }
+
+        impl<U: Debug + Send + Sync> Approx for $vec<$scalar, U> {}
+    };
+}
+
+impl_euclid!(Point2D, f32);
+impl_euclid!(Point2D, f64);
+impl_euclid!(Vector2D, f32);
+impl_euclid!(Vector2D, f64);
+
+/// Returns the x and y of `v` as a `lyon_geom::Point`.
+#[inline(always)]
+pub fn to_lyon_point<V: HasXY>(v: V) -> Point<V::Scalar> {
+    Point::new(v.x(), v.y())
+}
+
+/// Returns the x and y of `v` as a `lyon_geom::Vector`.
+#[inline(always)]
+pub fn to_lyon_vector<V: HasXY>(v: V) -> Vector<V::Scalar> {
+    Vector::new(v.x(), v.y())
+}
+
+/// Returns the `lyon_geom::Point` as a vector of type `V`.
+#[inline(always)]
+pub fn from_lyon_point<V: HasXY>(p: Point<V::Scalar>) -> V {
+    V::new_2d(p.x, p.y)
+}
+
+/// Returns the `lyon_geom::Vector` as a vector of type `V`.
+#[inline(always)]
+pub fn from_lyon_vector<V: HasXY>(v: Vector<V::Scalar>) -> V {
+    V::new_2d(v.x, v.y)
+}
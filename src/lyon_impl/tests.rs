@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{from_lyon_point, from_lyon_vector, to_lyon_point, to_lyon_vector, Point, Vector};
+use crate::{distance_robust, Approx, Axis2, HasXY, RunningStats};
+
+#[test]
+fn test_lyon() {
+    crate::tests::tests::test_xy::<Point<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Point<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Vector<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Vector<f64>>(1.0, 2.0);
+
+    let p = Point::new_2d(3.0, 4.0);
+    assert_eq!((p[Axis2::Y], Vector::new(5.0, 6.0)[Axis2::X]), (4.0, 5.0));
+    assert!(p.is_abs_diff_eq(Point::new(3.0, 4.0 + 1e-6), 1e-5));
+    assert_eq!(distance_robust(p, Point::origin()), 5.0);
+    // the euclid vector operators are enough for the storage trait algorithms
+    let stats: RunningStats<Vector<f32>> = [Vector::new(1.0, 2.0), Vector::new(3.0, 4.0)]
+        .into_iter()
+        .collect();
+    assert_eq!(stats.mean(), Some(Vector::new(2.0, 3.0)));
+}
+
+#[test]
+fn test_conversions() {
+    let v: Vector<f64> = from_lyon_point(Point::new(1.0, 2.0));
+    assert_eq!(v, Vector::new(1.0, 2.0));
+    assert_eq!(to_lyon_point(v), Point::new(1.0, 2.0));
+    let p: Point<f32> = from_lyon_vector(Vector::new(3.0, 4.0));
+    assert_eq!(to_lyon_vector(p), Vector::new(3.0, 4.0));
+}
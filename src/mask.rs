@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Boolean masks, the per component results of the comparisons of `GenericVector2` and
+//! `GenericVector3`, for branch free generic code.
+//!
+//! The glam vectors use `glam::BVec2`, `BVec3` and `BVec3A`, the other backends the plain
+//! `Mask2` and `Mask3` structs. `GenericVector2::select()` and `GenericVector3::select()` pick the
+//! components of one of two vectors by a mask.
+
+use std::{
+    fmt::Debug,
+    ops::{BitAnd, BitOr, Not},
+};
+
+/// A boolean per component of a vector.
+pub trait GenericMask:
+    Copy
+    + Debug
+    + PartialEq
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + Not<Output = Self>
+    + Sync
+    + Send
+    + 'static
+{
+    /// The number of components.
+    const DIM: usize;
+
+    /// Returns the mask with component `i` set to bit `i` of `bits`. Higher bits are ignored.
+    fn from_bitmask(bits: u32) -> Self;
+
+    /// Returns the components as the low bits of a `u32`, component `i` in bit `i`.
+    fn bitmask(self) -> u32;
+
+    /// Returns the mask with every component set to `value`.
+    #[inline(always)]
+    fn splat(value: bool) -> Self {
+        Self::from_bitmask(if value { u32::MAX } else { 0 })
+    }
+
+    /// Returns component `index`.
+    ///
+    /// # Panics
+    /// If `index >= DIM`.
+    #[inline(always)]
+    fn test(self, index: usize) -> bool {
+        assert!(index < Self::DIM, "mask index {index} out of range");
+        (self.bitmask() >> index) & 1 != 0
+    }
+
+    /// Returns true if any component is set.
+    #[inline(always)]
+    fn any(self) -> bool {
+        self.bitmask() != 0
+    }
+
+    /// Returns true if every component is set.
+    #[inline(always)]
+    fn all(self) -> bool {
+        self.bitmask() == (1 << Self::DIM) - 1
+    }
+}
+
+/// A mask of two components, for the 2D vectors of the backends without their own mask type.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mask2 {
+    pub x: bool,
+    pub y: bool,
+}
+
+/// A mask of three components, for the 3D vectors of the backends without their own mask type.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Mask3 {
+    pub x: bool,
+    pub y: bool,
+    pub z: bool,
+}
+
+macro_rules! impl_mask {
+    ($mask_type:ident, $dim:expr, $($c:ident = $bit:expr),+) => {
+        impl GenericMask for $mask_type {
+            const DIM: usize = $dim;
+            #[inline(always)]
+            fn from_bitmask(bits: u32) -> Self {
+                Self { $($c: (bits >> $bit) & 1 != 0),+ }
+            }
+            #[inline(always)]
+            fn bitmask(self) -> u32 {
+                0 $(| (self.$c as u32) << $bit)+
+            }
+        }
+
+        impl BitAnd for $mask_type {
+            type Output = Self;
+            #[inline(always)]
+            fn bitand(self, rhs: Self) -> Self {
+                Self { $($c: self.$c & rhs.$c),+ }
+            }
+        }
+
+        impl BitOr for $mask_type {
+            type Output = Self;
+            #[inline(always)]
+            fn bitor(self, rhs: Self) -> Self {
+                Self { $($c: self.$c | rhs.$c),+ }
+            }
+        }
+
+        impl Not for $mask_type {
+            type Output = Self;
+            #[inline(always)]
+            fn not(self) -> Self {
+                Self { $($c: !self.$c),+ }
+            }
+        }
+    };
+}
+
+impl_mask!(Mask2, 2, x = 0, y = 1);
+impl_mask!(Mask3, 3, x = 0, y = 1, z = 2);
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Scalar math functions that are routed through `libm` when the `deterministic` feature is enabled.
+//!
+//! With the feature enabled these functions, and the vector methods built on them (`magnitude()`,
+//! `distance()`, `normalize()` and `safe_normalize()`), give identical results across platforms
+//! and compilers. Without the feature they forward to `std`.
+
+use crate::{GenericScalar, HasXY};
+use std::ops::Mul;
+
+/// The per type implementation of the functions in this module.
+/// This trait is implemented for `f32` and `f64`, use the free functions instead of calling it directly.
+pub trait ScalarMath: Copy {
+    fn math_sqrt(self) -> Self;
+    fn math_sin(self) -> Self;
+    fn math_cos(self) -> Self;
+    fn math_acos(self) -> Self;
+    fn math_asin(self) -> Self;
+    fn math_atan2(self, x: Self) -> Self;
+    fn math_hypot(self, other: Self) -> Self;
+}
+
+macro_rules! impl_scalar_math {
+    ($scalar_type:ty, $sqrt:ident, $sin:ident, $cos:ident, $acos:ident, $asin:ident, $atan2:ident, $hypot:ident) => {
+        impl ScalarMath for $scalar_type {
+            #[inline(always)]
+            fn math_sqrt(self) -> Self {
+                deterministic_or!(libm::$sqrt(self), self.sqrt())
+            }
+            #[inline(always)]
+            fn math_sin(self) -> Self {
+                deterministic_or!(libm::$sin(self), self.sin())
+            }
+            #[inline(always)]
+            fn math_cos(self) -> Self {
+                deterministic_or!(libm::$cos(self), self.cos())
+            }
+            #[inline(always)]
+            fn math_acos(self) -> Self {
+                deterministic_or!(libm::$acos(self), self.acos())
+            }
+            #[inline(always)]
+            fn math_asin(self) -> Self {
+                deterministic_or!(libm::$asin(self), self.asin())
+            }
+            #[inline(always)]
+            fn math_atan2(self, x: Self) -> Self {
+                deterministic_or!(libm::$atan2(self, x), self.atan2(x))
+            }
+            #[inline(always)]
+            fn math_hypot(self, other: Self) -> Self {
+                deterministic_or!(libm::$hypot(self, other), self.hypot(other))
+            }
+        }
+    };
+}
+
+impl_scalar_math!(f32, sqrtf, sinf, cosf, acosf, asinf, atan2f, hypotf);
+impl_scalar_math!(f64, sqrt, sin, cos, acos, asin, atan2, hypot);
+
+/// Returns the square root of `s`.
+#[inline(always)]
+pub fn sqrt<S: GenericScalar>(s: S) -> S {
+    s.math_sqrt()
+}
+
+/// Returns the sine of `s` (in radians).
+#[inline(always)]
+pub fn sin<S: GenericScalar>(s: S) -> S {
+    s.math_sin()
+}
+
+/// Returns the cosine of `s` (in radians).
+#[inline(always)]
+pub fn cos<S: GenericScalar>(s: S) -> S {
+    s.math_cos()
+}
+
+/// Returns the arccosine of `s` in the range [0, pi], or NaN if `s` is outside [-1, 1].
+#[inline(always)]
+pub fn acos<S: GenericScalar>(s: S) -> S {
+    s.math_acos()
+}
+
+/// Returns the arcsine of `s` in the range [-pi/2, pi/2], or NaN if `s` is outside [-1, 1].
+#[inline(always)]
+pub fn asin<S: GenericScalar>(s: S) -> S {
+    s.math_asin()
+}
+
+/// Returns the four quadrant arctangent of `y` and `x` in the range [-pi, pi].
+#[inline(always)]
+pub fn atan2<S: GenericScalar>(y: S, x: S) -> S {
+    y.math_atan2(x)
+}
+
+/// Returns `sqrt(a * a + b * b)` without intermediate overflow or underflow.
+#[inline(always)]
+pub fn hypot<S: GenericScalar>(a: S, b: S) -> S {
+    a.math_hypot(b)
+}
+
+/// Returns the dot product of `a` and `b`, summed left to right as `x * x' + y * y' (+ z * z')`.
+///
+/// Every product and sum is rounded separately, so the result does not depend on the
+/// optimization level or target features. This is what the vector methods use when the
+/// `strict-fp` feature is enabled.
+#[inline(always)]
+pub fn dot<T: HasXY>(a: T, b: T) -> T::Scalar {
+    (1..T::DIM)
+        .filter_map(|i| Some(a.get(i)? * b.get(i)?))
+        .fold(a.x() * b.x(), |sum, p| sum + p)
+}
+
+/// Returns `v` scaled by `s`, the scalar on the left as in `s * v`.
+///
+/// The foreign vector types implement `Mul<V>` for their own scalar type, but a generic scalar
+/// can not be multiplied from the left in generic code, so formulas written in mathematical
+/// order can use this instead.
+#[inline(always)]
+pub fn scale<T: HasXY + Mul<T::Scalar, Output = T>>(s: T::Scalar, v: T) -> T {
+    v * s
+}
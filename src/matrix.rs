@@ -0,0 +1,461 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `GenericMatrix3`, the 3x3 matrices of the backends, as 3D linear maps and as 2D affine maps,
+//! `GenericMatrix2`, the 2x2 matrices as 2D linear maps, and `GenericMatrix4`, the 4x4 matrices as
+//! 3D affine and projective maps.
+//!
+//! The matrices are column major, like glam, cgmath and nalgebra: `col(2)` is the translation of a
+//! 2D affine map, `col(3)` that of a 3D affine map. The backends provide the columns and the
+//! matrix product, the other operations are default methods over the vector traits, and evaluate
+//! the same on every backend.
+//! `from_rows()` and `to_rows()` convert to and from the row major `[[S; 3]; 3]` arrays of
+//! `EulerAngles::to_matrix()` and `frame::look_rotation()`, the `from_euler()` and
+//! `look_rotation()` methods return the matrix type directly.
+
+use crate::{
+    frame, math, EulerAngles, GenericScalar, GenericVector2, GenericVector3, GenericVector4, HasXY,
+    HasXYZ, HasXYZW,
+};
+use num_traits::Float;
+use std::{fmt::Debug, ops::Mul};
+
+/// A 3x3 matrix of column vectors.
+pub trait GenericMatrix3:
+    Copy + Debug + PartialEq + Mul<Self, Output = Self> + Sync + Send + 'static
+{
+    type Scalar: GenericScalar;
+    type Vector2: GenericVector2<Scalar = Self::Scalar, Vector3 = Self::Vector3>;
+    type Vector3: GenericVector3<Scalar = Self::Scalar, Vector2 = Self::Vector2>;
+
+    /// Returns the matrix with the columns `x`, `y` and `z`.
+    fn from_cols(x: Self::Vector3, y: Self::Vector3, z: Self::Vector3) -> Self;
+
+    /// Returns column `index`.
+    ///
+    /// # Panics
+    /// If `index > 2`.
+    fn col(&self, index: usize) -> Self::Vector3;
+
+    #[inline(always)]
+    fn identity() -> Self {
+        let (o, l) = (Self::Scalar::ZERO, Self::Scalar::ONE);
+        Self::from_cols(
+            Self::Vector3::new_3d(l, o, o),
+            Self::Vector3::new_3d(o, l, o),
+            Self::Vector3::new_3d(o, o, l),
+        )
+    }
+
+    /// Returns the matrix of a row major array.
+    #[inline(always)]
+    fn from_rows(rows: [[Self::Scalar; 3]; 3]) -> Self {
+        let col = |i: usize| Self::Vector3::new_3d(rows[0][i], rows[1][i], rows[2][i]);
+        Self::from_cols(col(0), col(1), col(2))
+    }
+
+    /// Returns the matrix as a row major array.
+    #[inline(always)]
+    fn to_rows(&self) -> [[Self::Scalar; 3]; 3] {
+        let (x, y, z) = (self.col(0), self.col(1), self.col(2));
+        [
+            [x.x(), y.x(), z.x()],
+            [x.y(), y.y(), z.y()],
+            [x.z(), y.z(), z.z()],
+        ]
+    }
+
+    /// Returns the rotation matrix of the Euler angles.
+    #[inline(always)]
+    fn from_euler(euler: &EulerAngles<Self::Scalar>) -> Self {
+        Self::from_rows(euler.to_matrix())
+    }
+
+    /// Returns the rotation matrix of `frame::look_rotation()`, with the forward, left and up
+    /// directions as columns. Returns `None` if either vector has zero length or if they are
+    /// parallel.
+    #[inline(always)]
+    fn look_rotation(forward: Self::Vector3, up: Self::Vector3) -> Option<Self> {
+        let [f, l, u] = frame::look_frame(forward, up)?;
+        Some(Self::from_cols(f, l, u))
+    }
+
+    /// Returns the 2D affine map translating by `translation`.
+    #[inline(always)]
+    fn from_translation(translation: Self::Vector2) -> Self {
+        let (o, l) = (Self::Scalar::ZERO, Self::Scalar::ONE);
+        Self::from_cols(
+            Self::Vector3::new_3d(l, o, o),
+            Self::Vector3::new_3d(o, l, o),
+            translation.to_3d(l),
+        )
+    }
+
+    /// Returns the 2D affine map rotating counterclockwise by `angle` radians.
+    #[inline(always)]
+    fn from_angle(angle: Self::Scalar) -> Self {
+        let (s, c) = (math::sin(angle), math::cos(angle));
+        let (o, l) = (Self::Scalar::ZERO, Self::Scalar::ONE);
+        Self::from_cols(
+            Self::Vector3::new_3d(c, s, o),
+            Self::Vector3::new_3d(-s, c, o),
+            Self::Vector3::new_3d(o, o, l),
+        )
+    }
+
+    /// Returns the 2D affine map scaling the x and y axes by the components of `scale`.
+    #[inline(always)]
+    fn from_scale(scale: Self::Vector2) -> Self {
+        let (o, l) = (Self::Scalar::ZERO, Self::Scalar::ONE);
+        Self::from_cols(
+            Self::Vector3::new_3d(scale.x(), o, o),
+            Self::Vector3::new_3d(o, scale.y(), o),
+            Self::Vector3::new_3d(o, o, l),
+        )
+    }
+
+    #[inline(always)]
+    fn transpose(&self) -> Self {
+        let (x, y, z) = (self.col(0), self.col(1), self.col(2));
+        Self::from_cols(
+            Self::Vector3::new_3d(x.x(), y.x(), z.x()),
+            Self::Vector3::new_3d(x.y(), y.y(), z.y()),
+            Self::Vector3::new_3d(x.z(), y.z(), z.z()),
+        )
+    }
+
+    #[inline(always)]
+    fn determinant(&self) -> Self::Scalar {
+        self.col(2).dot(self.col(0).cross(self.col(1)))
+    }
+
+    /// Returns the inverse matrix, or `None` if the determinant is zero or not finite.
+    #[inline(always)]
+    fn try_inverse(&self) -> Option<Self> {
+        let (x, y, z) = (self.col(0), self.col(1), self.col(2));
+        let (yz, zx, xy) = (y.cross(z), z.cross(x), x.cross(y));
+        let det = z.dot(xy);
+        if det == Self::Scalar::ZERO || !det.is_finite() {
+            return None;
+        }
+        let inv_det = Self::Scalar::ONE / det;
+        Some(Self::from_cols(yz * inv_det, zx * inv_det, xy * inv_det).transpose())
+    }
+
+    /// Returns the matrix times the column vector `v`.
+    #[inline(always)]
+    fn mul_vec3(&self, v: Self::Vector3) -> Self::Vector3 {
+        self.col(0) * v.x() + self.col(1) * v.y() + self.col(2) * v.z()
+    }
+
+    /// Transforms the 2D point `p` by the affine map: applies the upper left 2x2 matrix and the
+    /// translation `col(2)`. The bottom row is assumed to be `0 0 1`, and is ignored.
+    #[inline(always)]
+    fn transform_point2(&self, p: Self::Vector2) -> Self::Vector2 {
+        self.col(0).to_2d() * p.x() + self.col(1).to_2d() * p.y() + self.col(2).to_2d()
+    }
+
+    /// Transforms the 2D direction `v` by the affine map, without the translation. The bottom row
+    /// is assumed to be `0 0 1`, and is ignored.
+    #[inline(always)]
+    fn transform_vector2(&self, v: Self::Vector2) -> Self::Vector2 {
+        self.col(0).to_2d() * v.x() + self.col(1).to_2d() * v.y()
+    }
+}
+
+/// A 2x2 matrix of column vectors, a 2D linear map.
+pub trait GenericMatrix2:
+    Copy + Debug + PartialEq + Mul<Self, Output = Self> + Sync + Send + 'static
+{
+    type Scalar: GenericScalar;
+    type Vector2: GenericVector2<Scalar = Self::Scalar>;
+
+    /// Returns the matrix with the columns `x` and `y`.
+    fn from_cols(x: Self::Vector2, y: Self::Vector2) -> Self;
+
+    /// Returns column `index`.
+    ///
+    /// # Panics
+    /// If `index > 1`.
+    fn col(&self, index: usize) -> Self::Vector2;
+
+    #[inline(always)]
+    fn identity() -> Self {
+        let (o, l) = (Self::Scalar::ZERO, Self::Scalar::ONE);
+        Self::from_cols(Self::Vector2::new_2d(l, o), Self::Vector2::new_2d(o, l))
+    }
+
+    /// Returns the matrix of a row major array.
+    #[inline(always)]
+    fn from_rows(rows: [[Self::Scalar; 2]; 2]) -> Self {
+        let col = |i: usize| Self::Vector2::new_2d(rows[0][i], rows[1][i]);
+        Self::from_cols(col(0), col(1))
+    }
+
+    /// Returns the matrix as a row major array.
+    #[inline(always)]
+    fn to_rows(&self) -> [[Self::Scalar; 2]; 2] {
+        let (x, y) = (self.col(0), self.col(1));
+        [[x.x(), y.x()], [x.y(), y.y()]]
+    }
+
+    /// Returns the rotation counterclockwise by `angle` radians.
+    #[inline(always)]
+    fn from_angle(angle: Self::Scalar) -> Self {
+        let (s, c) = (math::sin(angle), math::cos(angle));
+        Self::from_cols(Self::Vector2::new_2d(c, s), Self::Vector2::new_2d(-s, c))
+    }
+
+    /// Returns the map scaling the x and y axes by the components of `scale`.
+    #[inline(always)]
+    fn from_scale(scale: Self::Vector2) -> Self {
+        let o = Self::Scalar::ZERO;
+        Self::from_cols(
+            Self::Vector2::new_2d(scale.x(), o),
+            Self::Vector2::new_2d(o, scale.y()),
+        )
+    }
+
+    #[inline(always)]
+    fn transpose(&self) -> Self {
+        let (x, y) = (self.col(0), self.col(1));
+        Self::from_cols(
+            Self::Vector2::new_2d(x.x(), y.x()),
+            Self::Vector2::new_2d(x.y(), y.y()),
+        )
+    }
+
+    #[inline(always)]
+    fn determinant(&self) -> Self::Scalar {
+        self.col(0).perp_dot(self.col(1))
+    }
+
+    /// Returns the inverse matrix, or `None` if the determinant is zero or not finite.
+    #[inline(always)]
+    fn try_inverse(&self) -> Option<Self> {
+        let (x, y) = (self.col(0), self.col(1));
+        let det = x.perp_dot(y);
+        if det == Self::Scalar::ZERO || !det.is_finite() {
+            return None;
+        }
+        let inv_det = Self::Scalar::ONE / det;
+        Some(Self::from_cols(
+            Self::Vector2::new_2d(y.y(), -x.y()) * inv_det,
+            Self::Vector2::new_2d(-y.x(), x.x()) * inv_det,
+        ))
+    }
+
+    /// Returns the matrix times the column vector `v`.
+    #[inline(always)]
+    fn mul_vec2(&self, v: Self::Vector2) -> Self::Vector2 {
+        self.col(0) * v.x() + self.col(1) * v.y()
+    }
+}
+
+/// A 4x4 matrix of column vectors, a 3D affine or projective map in homogeneous coordinates.
+pub trait GenericMatrix4:
+    Copy + Debug + PartialEq + Mul<Self, Output = Self> + Sync + Send + 'static
+{
+    type Scalar: GenericScalar;
+    type Vector3: GenericVector3<Scalar = Self::Scalar>;
+    type Vector4: GenericVector4<Scalar = Self::Scalar, Vector3 = Self::Vector3>;
+    type Matrix3: GenericMatrix3<Scalar = Self::Scalar, Vector3 = Self::Vector3>;
+
+    /// Returns the matrix with the columns `x`, `y`, `z` and `w`.
+    fn from_cols(x: Self::Vector4, y: Self::Vector4, z: Self::Vector4, w: Self::Vector4) -> Self;
+
+    /// Returns column `index`.
+    ///
+    /// # Panics
+    /// If `index > 3`.
+    fn col(&self, index: usize) -> Self::Vector4;
+
+    #[inline(always)]
+    fn identity() -> Self {
+        let (o, l) = (Self::Scalar::ZERO, Self::Scalar::ONE);
+        Self::from_cols(
+            Self::Vector4::new_4d(l, o, o, o),
+            Self::Vector4::new_4d(o, l, o, o),
+            Self::Vector4::new_4d(o, o, l, o),
+            Self::Vector4::new_4d(o, o, o, l),
+        )
+    }
+
+    /// Returns the matrix of a row major array.
+    #[inline(always)]
+    fn from_rows(rows: [[Self::Scalar; 4]; 4]) -> Self {
+        let col = |i: usize| Self::Vector4::new_4d(rows[0][i], rows[1][i], rows[2][i], rows[3][i]);
+        Self::from_cols(col(0), col(1), col(2), col(3))
+    }
+
+    /// Returns the matrix as a row major array.
+    #[inline(always)]
+    fn to_rows(&self) -> [[Self::Scalar; 4]; 4] {
+        let cols = [self.col(0), self.col(1), self.col(2), self.col(3)];
+        [
+            cols.map(|c| c.x()),
+            cols.map(|c| c.y()),
+            cols.map(|c| c.z()),
+            cols.map(|c| c.w()),
+        ]
+    }
+
+    /// Returns the 3D affine map translating by `translation`.
+    #[inline(always)]
+    fn from_translation(translation: Self::Vector3) -> Self {
+        let mut m = Self::identity().to_rows();
+        (0..3).for_each(|i| m[i][3] = translation[i]);
+        Self::from_rows(m)
+    }
+
+    /// Returns the 3D affine map scaling the x, y and z axes by the components of `scale`.
+    #[inline(always)]
+    fn from_scale(scale: Self::Vector3) -> Self {
+        let mut m = Self::identity().to_rows();
+        (0..3).for_each(|i| m[i][i] = scale[i]);
+        Self::from_rows(m)
+    }
+
+    /// Returns the 3D affine map of the 3x3 linear map `m` and the translation `translation`.
+    #[inline(always)]
+    fn from_matrix3_translation(m: &Self::Matrix3, translation: Self::Vector3) -> Self {
+        let col = |i: usize| Self::Vector4::extend(m.col(i), Self::Scalar::ZERO);
+        Self::from_cols(
+            col(0),
+            col(1),
+            col(2),
+            Self::Vector4::extend(translation, Self::Scalar::ONE),
+        )
+    }
+
+    /// Returns the upper left 3x3 matrix, the linear part of a 3D affine map.
+    #[inline(always)]
+    fn to_matrix3(&self) -> Self::Matrix3 {
+        Self::Matrix3::from_cols(
+            self.col(0).truncate(),
+            self.col(1).truncate(),
+            self.col(2).truncate(),
+        )
+    }
+
+    #[inline(always)]
+    fn transpose(&self) -> Self {
+        Self::from_cols(self.row(0), self.row(1), self.row(2), self.row(3))
+    }
+
+    /// Returns row `index` as a vector.
+    ///
+    /// # Panics
+    /// If `index > 3`.
+    #[inline(always)]
+    fn row(&self, index: usize) -> Self::Vector4 {
+        let r = |c: Self::Vector4| c[index];
+        Self::Vector4::new_4d(
+            r(self.col(0)),
+            r(self.col(1)),
+            r(self.col(2)),
+            r(self.col(3)),
+        )
+    }
+
+    #[inline(always)]
+    fn determinant(&self) -> Self::Scalar {
+        let (s, c) = sub_determinants4(&self.to_rows());
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// Returns the inverse matrix, or `None` if the determinant is zero or not finite.
+    fn try_inverse(&self) -> Option<Self> {
+        let m = self.to_rows();
+        let (s, c) = sub_determinants4(&m);
+        let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+        if det == Self::Scalar::ZERO || !det.is_finite() {
+            return None;
+        }
+        let inv_det = Self::Scalar::ONE / det;
+        // the adjugate, the transposed matrix of cofactors
+        let adjugate = [
+            [
+                m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+                -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+                m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+                -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3],
+            ],
+            [
+                -m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+                m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+                -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+                m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1],
+            ],
+            [
+                m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+                -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+                m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+                -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0],
+            ],
+            [
+                -m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+                m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+                -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+                m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0],
+            ],
+        ];
+        Some(Self::from_rows(
+            adjugate.map(|row| row.map(|v| v * inv_det)),
+        ))
+    }
+
+    /// Returns the matrix times the column vector `v`.
+    #[inline(always)]
+    fn mul_vec4(&self, v: Self::Vector4) -> Self::Vector4 {
+        self.col(0) * v.x() + self.col(1) * v.y() + self.col(2) * v.z() + self.col(3) * v.w()
+    }
+
+    /// Transforms the 3D point `p` by the affine map: applies the upper left 3x3 matrix and the
+    /// translation `col(3)`. The bottom row is assumed to be `0 0 0 1`, and is ignored.
+    #[inline(always)]
+    fn transform_point3(&self, p: Self::Vector3) -> Self::Vector3 {
+        (self.col(0) * p.x() + self.col(1) * p.y() + self.col(2) * p.z() + self.col(3)).truncate()
+    }
+
+    /// Transforms the 3D direction `v` by the affine map, without the translation. The bottom row
+    /// is assumed to be `0 0 0 1`, and is ignored.
+    #[inline(always)]
+    fn transform_vector3(&self, v: Self::Vector3) -> Self::Vector3 {
+        (self.col(0) * v.x() + self.col(1) * v.y() + self.col(2) * v.z()).truncate()
+    }
+
+    /// Transforms the 3D point `p` by the projective map, dividing by the resulting w.
+    #[inline(always)]
+    fn project_point3(&self, p: Self::Vector3) -> Self::Vector3 {
+        let r = self.mul_vec4(Self::Vector4::extend(p, Self::Scalar::ONE));
+        r.truncate() / r.w()
+    }
+}
+
+/// Returns the 2x2 determinants of the top two rows and of the bottom two rows of `m`, both in
+/// the column pair order (0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3).
+#[inline(always)]
+fn sub_determinants4<S: GenericScalar>(m: &[[S; 4]; 4]) -> ([S; 6], [S; 6]) {
+    let det = |r0: usize, r1: usize, i: usize, j: usize| m[r0][i] * m[r1][j] - m[r1][i] * m[r0][j];
+    (
+        [
+            det(0, 1, 0, 1),
+            det(0, 1, 0, 2),
+            det(0, 1, 0, 3),
+            det(0, 1, 1, 2),
+            det(0, 1, 1, 3),
+            det(0, 1, 2, 3),
+        ],
+        [
+            det(2, 3, 0, 1),
+            det(2, 3, 0, 2),
+            det(2, 3, 0, 3),
+            det(2, 3, 1, 2),
+            det(2, 3, 1, 3),
+            det(2, 3, 2, 3),
+        ],
+    )
+}
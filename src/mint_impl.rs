@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `HasXY`, `HasXYZ` and `Approx` for the `mint` interchange types `Vector2`, `Vector3`, `Point2`
+//! and `Point3`, with `f32` and `f64` components.
+//!
+//! Most math libraries convert to and from `mint`, so this connects them to the algorithms written
+//! against the storage traits. `GenericVector2::to_mint()` and `from_mint()` (and the 3D
+//! equivalents) convert the generic vector types.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{Approx, Axis2, Axis3, GenericScalar, HasXY, HasXYZ};
+use mint::{Point2, Point3, Vector2, Vector3};
+use std::ops::Index;
+
+macro_rules! impl_mint {
+    ($vec2_type:ident, $vec3_type:ident, $scalar:ty) => {
+        impl HasXY for $vec2_type<$scalar> {
+            type Scalar = $scalar;
+            const DIM: usize = 2;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                $vec2_type { x, y }
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_2d(self.x.canonicalize(), self.y.canonicalize())
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_2d(self.x.flush_denormal(), self.y.flush_denormal())
+            }
+        }
+
+        impl Index<Axis2> for $vec2_type<$scalar> {
+            type Output = $scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis2) -> &Self::Output {
+                match axis {
+                    Axis2::X => &self.x,
+                    Axis2::Y => &self.y,
+                }
+            }
+        }
+
+        impl Approx for $vec2_type<$scalar> {}
+
+        impl HasXY for $vec3_type<$scalar> {
+            type Scalar = $scalar;
+            const DIM: usize = 3;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                $vec3_type {
+                    x,
+                    y,
+                    z: Self::Scalar::ZERO,
+                }
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    2 => Some(self.z),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_3d(
+                    self.x.canonicalize(),
+                    self.y.canonicalize(),
+                    self.z.canonicalize(),
+                )
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_3d(
+                    self.x.flush_denormal(),
+                    self.y.flush_denormal(),
+                    self.z.flush_denormal(),
+                )
+            }
+        }
+
+        impl HasXYZ for $vec3_type<$scalar> {
+            #[inline(always)]
+            fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+                $vec3_type { x, y, z }
+            }
+            #[inline(always)]
+            fn z(self) -> Self::Scalar {
+                self.z
+            }
+            #[inline(always)]
+            fn set_z(&mut self, val: Self::Scalar) {
+                self.z = val
+            }
+            #[inline(always)]
+            fn z_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.z
+            }
+        }
+
+        impl Index<Axis3> for $vec3_type<$scalar> {
+            type Output = $scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis3) -> &Self::Output {
+                match axis {
+                    Axis3::X => &self.x,
+                    Axis3::Y => &self.y,
+                    Axis3::Z => &self.z,
+                }
+            }
+        }
+
+        impl Approx for $vec3_type<$scalar> {}
+    };
+}
+
+impl_mint!(Vector2, Vector3, f32);
+impl_mint!(Vector2, Vector3, f64);
+impl_mint!(Point2, Point3, f32);
+impl_mint!(Point2, Point3, f64);
@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use crate::{distance_robust, Approx, Axis3, HasXYZ};
+use mint::{Point2, Point3, Vector2, Vector3};
+
+#[test]
+fn test_mint() {
+    crate::tests::tests::test_xy::<Vector2<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Vector2<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Point2<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Point2<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Vector3<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Point3<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xyz::<Vector3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_xyz::<Vector3<f64>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_xyz::<Point3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_xyz::<Point3<f64>>(1.0, 2.0, 3.0);
+
+    let p = Point3::<f64>::new_3d(1.0, 2.0, 3.0);
+    assert_eq!(p[Axis3::Z], 3.0);
+    assert!(p.is_abs_diff_eq(Point3::new_3d(1.0, 2.0, 3.0 + 1e-9), 1e-6));
+    assert_eq!(distance_robust(p, Point3::new_3d(4.0, 6.0, 3.0)), 5.0);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_to_from_mint() {
+    use crate::{GenericVector2, GenericVector3};
+    let v = glam::dvec3(1.0, 2.0, 3.0);
+    assert_eq!(v.to_mint(), Vector3::from([1.0, 2.0, 3.0]));
+    assert_eq!(glam::DVec3::from_mint(v.to_mint()), v);
+    let v = glam::vec2(1.0, 2.0);
+    assert_eq!(
+        <glam::Vec2 as GenericVector2>::from_mint(Vector2::from([1.0, 2.0])),
+        v
+    );
+    assert_eq!(GenericVector2::to_mint(v), Vector2::from([1.0, 2.0]));
+}
@@ -0,0 +1,806 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! The vector traits for `nalgebra::Vector2` and `Vector3`, and `HasXY`, `HasXYZ` and `Approx`
+//! for `nalgebra::Point2` and `Point3`, so data stored by nalgebra based code can go through the
+//! algorithms written against the traits.
+//!
+//! The points are storage only: in nalgebra the difference of two points is a vector and points
+//! can not be added, so they do not implement the generic vector traits. `to_point2()`,
+//! `to_point3()`, `from_point2()` and `from_point3()` convert points to and from any vector type,
+//! the nalgebra vectors included.
+//!
+//! `nalgebra::Matrix2`, `Matrix3` and `Matrix4` implement the matrix traits, and `Vector4`, the
+//! columns of `Matrix4`, implements `GenericVector4`. The matrix backed `Affine2` and `Affine3`
+//! implement `GenericAffine2` and `GenericAffine3`, so a zero scale is a valid map and
+//! `try_inverse()` returns `None` for it. `Isometry2`, `Isometry3`, `Similarity2` and
+//! `Similarity3` convert to them with `nalgebra::convert()`, e.g.
+//! `let a: Affine3<f64> = nalgebra::convert(similarity)`. They have no impls of their own, since
+//! nalgebra panics when a similarity is built with a zero scale. `UnitQuaternion` implements
+//! `GenericRotation3`, and its `rotate_xyz()` rotates the vectors of any backend.
+//!
+//! nalgebra has inherent methods like `dot()`, `normalize()` and `magnitude()` that take their
+//! arguments by reference, and method call syntax on a concrete nalgebra vector picks those. Call
+//! the trait methods through the trait, or from code generic over the trait.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    Approx, Axis2, Axis3, GenericAffine2, GenericAffine3, GenericMatrix2, GenericMatrix3,
+    GenericMatrix4, GenericRotation3, GenericScalar, GenericVector2, GenericVector3,
+    GenericVector4, HasXY, HasXYZ, HasXYZW, IntVec2, IntVec3, Mask2, Mask3,
+};
+pub use ::nalgebra::{
+    Affine2, Affine3, Matrix2, Matrix3, Matrix4, Point2, Point3, UnitQuaternion, Vector2, Vector3,
+    Vector4,
+};
+use std::ops::Index;
+
+macro_rules! impl_nalgebra_point {
+    ($scalar:ty) => {
+        impl HasXY for Point2<$scalar> {
+            type Scalar = $scalar;
+            const DIM: usize = 2;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                Point2::new(x, y)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_2d(self.x.canonicalize(), self.y.canonicalize())
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_2d(self.x.flush_denormal(), self.y.flush_denormal())
+            }
+        }
+
+        impl Index<Axis2> for Point2<$scalar> {
+            type Output = $scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis2) -> &Self::Output {
+                &self.coords[axis.index()]
+            }
+        }
+
+        impl Approx for Point2<$scalar> {}
+
+        impl HasXY for Point3<$scalar> {
+            type Scalar = $scalar;
+            const DIM: usize = 3;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                Point3::new(x, y, Self::Scalar::ZERO)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    2 => Some(self.z),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_3d(
+                    self.x.canonicalize(),
+                    self.y.canonicalize(),
+                    self.z.canonicalize(),
+                )
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_3d(
+                    self.x.flush_denormal(),
+                    self.y.flush_denormal(),
+                    self.z.flush_denormal(),
+                )
+            }
+        }
+
+        impl HasXYZ for Point3<$scalar> {
+            #[inline(always)]
+            fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+                Point3::new(x, y, z)
+            }
+            #[inline(always)]
+            fn z(self) -> Self::Scalar {
+                self.z
+            }
+            #[inline(always)]
+            fn set_z(&mut self, val: Self::Scalar) {
+                self.z = val
+            }
+            #[inline(always)]
+            fn z_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.z
+            }
+        }
+
+        impl Index<Axis3> for Point3<$scalar> {
+            type Output = $scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis3) -> &Self::Output {
+                &self.coords[axis.index()]
+            }
+        }
+
+        impl Approx for Point3<$scalar> {}
+    };
+}
+
+macro_rules! impl_nalgebra_vector {
+    ($scalar:ty) => {
+        impl HasXY for Vector2<$scalar> {
+            type Scalar = $scalar;
+            const DIM: usize = 2;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                Vector2::new(x, y)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_2d(self.x.canonicalize(), self.y.canonicalize())
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_2d(self.x.flush_denormal(), self.y.flush_denormal())
+            }
+        }
+
+        impl Index<Axis2> for Vector2<$scalar> {
+            type Output = $scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis2) -> &Self::Output {
+                &self[axis.index()]
+            }
+        }
+
+        impl Approx for Vector2<$scalar> {}
+
+        impl GenericVector2 for Vector2<$scalar> {
+            type Vector3 = Vector3<$scalar>;
+            type Mask = Mask2;
+            type IntVector = IntVec2<i32>;
+
+            #[inline(always)]
+            fn to_3d(self, z: Self::Scalar) -> Self::Vector3 {
+                Vector3::new(self.x, self.y, z)
+            }
+            #[inline(always)]
+            fn magnitude(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(GenericVector2::magnitude_sq(self)),
+                    self.norm()
+                )
+            }
+            #[inline(always)]
+            fn magnitude_sq(self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, self), self.norm_squared())
+            }
+            #[inline(always)]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, rhs), Vector2::dot(&self, &rhs))
+            }
+            #[inline(always)]
+            fn perp_dot(self, rhs: Self) -> Self::Scalar {
+                self.x * rhs.y - self.y * rhs.x
+            }
+            #[inline(always)]
+            fn distance(self, rhs: Self) -> Self::Scalar {
+                crate::math::sqrt(GenericVector2::distance_sq(self, rhs))
+            }
+            #[inline(always)]
+            fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                GenericVector2::magnitude_sq(self - rhs)
+            }
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                self / GenericVector2::magnitude(self)
+            }
+            #[inline(always)]
+            fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                let l = GenericVector2::magnitude(self);
+                (l > epsilon && l.is_finite()).then(|| self / l)
+            }
+        }
+
+        impl HasXY for Vector3<$scalar> {
+            type Scalar = $scalar;
+            const DIM: usize = 3;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                Vector3::new(x, y, Self::Scalar::ZERO)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    2 => Some(self.z),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_3d(
+                    self.x.canonicalize(),
+                    self.y.canonicalize(),
+                    self.z.canonicalize(),
+                )
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_3d(
+                    self.x.flush_denormal(),
+                    self.y.flush_denormal(),
+                    self.z.flush_denormal(),
+                )
+            }
+        }
+
+        impl HasXYZ for Vector3<$scalar> {
+            #[inline(always)]
+            fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+                Vector3::new(x, y, z)
+            }
+            #[inline(always)]
+            fn z(self) -> Self::Scalar {
+                self.z
+            }
+            #[inline(always)]
+            fn set_z(&mut self, val: Self::Scalar) {
+                self.z = val
+            }
+            #[inline(always)]
+            fn z_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.z
+            }
+        }
+
+        impl Index<Axis3> for Vector3<$scalar> {
+            type Output = $scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: Axis3) -> &Self::Output {
+                &self[axis.index()]
+            }
+        }
+
+        impl Approx for Vector3<$scalar> {}
+
+        impl GenericVector3 for Vector3<$scalar> {
+            type Vector2 = Vector2<$scalar>;
+            type Mask = Mask3;
+            type IntVector = IntVec3<i32>;
+
+            #[inline(always)]
+            fn to_2d(&self) -> Self::Vector2 {
+                Vector2::new(self.x, self.y)
+            }
+            #[inline(always)]
+            fn magnitude(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(GenericVector3::magnitude_sq(self)),
+                    self.norm()
+                )
+            }
+            #[inline(always)]
+            fn magnitude_sq(self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, self), self.norm_squared())
+            }
+            #[inline(always)]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, rhs), Vector3::dot(&self, &rhs))
+            }
+            #[inline(always)]
+            fn cross(self, rhs: Self) -> Self {
+                Vector3::cross(&self, &rhs)
+            }
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                self / GenericVector3::magnitude(self)
+            }
+            #[inline(always)]
+            fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                let l = GenericVector3::magnitude(self);
+                (l > epsilon && l.is_finite()).then(|| self / l)
+            }
+            #[inline(always)]
+            fn distance(self, rhs: Self) -> Self::Scalar {
+                crate::math::sqrt(GenericVector3::distance_sq(self, rhs))
+            }
+            #[inline(always)]
+            fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                GenericVector3::magnitude_sq(self - rhs)
+            }
+        }
+    };
+}
+
+// the homogeneous coordinates of `Matrix4`
+macro_rules! impl_nalgebra_vector4 {
+    ($scalar:ty) => {
+        impl HasXY for Vector4<$scalar> {
+            type Scalar = $scalar;
+            const DIM: usize = 4;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                Vector4::new(x, y, Self::Scalar::ZERO, Self::Scalar::ZERO)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.x
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.x = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.x
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.y
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.y = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.y
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.x),
+                    1 => Some(self.y),
+                    2 => Some(self.z),
+                    3 => Some(self.w),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Self::new_4d(
+                    self.x.canonicalize(),
+                    self.y.canonicalize(),
+                    self.z.canonicalize(),
+                    self.w.canonicalize(),
+                )
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Self::new_4d(
+                    self.x.flush_denormal(),
+                    self.y.flush_denormal(),
+                    self.z.flush_denormal(),
+                    self.w.flush_denormal(),
+                )
+            }
+        }
+
+        impl HasXYZ for Vector4<$scalar> {
+            #[inline(always)]
+            fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+                Vector4::new(x, y, z, Self::Scalar::ZERO)
+            }
+            #[inline(always)]
+            fn z(self) -> Self::Scalar {
+                self.z
+            }
+            #[inline(always)]
+            fn set_z(&mut self, val: Self::Scalar) {
+                self.z = val
+            }
+            #[inline(always)]
+            fn z_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.z
+            }
+        }
+
+        impl HasXYZW for Vector4<$scalar> {
+            #[inline(always)]
+            fn new_4d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar, w: Self::Scalar) -> Self {
+                Vector4::new(x, y, z, w)
+            }
+            #[inline(always)]
+            fn w(self) -> Self::Scalar {
+                self.w
+            }
+            #[inline(always)]
+            fn set_w(&mut self, val: Self::Scalar) {
+                self.w = val
+            }
+            #[inline(always)]
+            fn w_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.w
+            }
+        }
+
+        impl Approx for Vector4<$scalar> {}
+
+        impl GenericVector4 for Vector4<$scalar> {
+            type Vector3 = Vector3<$scalar>;
+            #[inline(always)]
+            fn truncate(self) -> Self::Vector3 {
+                self.xyz()
+            }
+            #[inline(always)]
+            fn magnitude(self) -> Self::Scalar {
+                strict_or!(
+                    crate::math::sqrt(GenericVector4::magnitude_sq(self)),
+                    self.norm()
+                )
+            }
+            #[inline(always)]
+            fn magnitude_sq(self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, self), self.norm_squared())
+            }
+            #[inline(always)]
+            fn dot(self, rhs: Self) -> Self::Scalar {
+                strict_or!(crate::math::dot(self, rhs), Vector4::dot(&self, &rhs))
+            }
+            #[inline(always)]
+            fn normalize(self) -> Self {
+                self / GenericVector4::magnitude(self)
+            }
+            #[inline(always)]
+            fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                let l = GenericVector4::magnitude(self);
+                (l > epsilon && l.is_finite()).then(|| self / l)
+            }
+            #[inline(always)]
+            fn distance(self, rhs: Self) -> Self::Scalar {
+                crate::math::sqrt(GenericVector4::distance_sq(self, rhs))
+            }
+            #[inline(always)]
+            fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                GenericVector4::magnitude_sq(self - rhs)
+            }
+        }
+    };
+}
+
+macro_rules! impl_nalgebra_matrix {
+    ($scalar:ty) => {
+        impl GenericMatrix3 for Matrix3<$scalar> {
+            type Scalar = $scalar;
+            type Vector2 = Vector2<$scalar>;
+            type Vector3 = Vector3<$scalar>;
+            #[inline(always)]
+            fn from_cols(x: Vector3<$scalar>, y: Vector3<$scalar>, z: Vector3<$scalar>) -> Self {
+                Matrix3::from_columns(&[x, y, z])
+            }
+            #[inline(always)]
+            fn col(&self, index: usize) -> Vector3<$scalar> {
+                self.column(index).into_owned()
+            }
+        }
+
+        impl GenericMatrix2 for Matrix2<$scalar> {
+            type Scalar = $scalar;
+            type Vector2 = Vector2<$scalar>;
+            #[inline(always)]
+            fn from_cols(x: Vector2<$scalar>, y: Vector2<$scalar>) -> Self {
+                Matrix2::from_columns(&[x, y])
+            }
+            #[inline(always)]
+            fn col(&self, index: usize) -> Vector2<$scalar> {
+                self.column(index).into_owned()
+            }
+        }
+
+        impl GenericMatrix4 for Matrix4<$scalar> {
+            type Scalar = $scalar;
+            type Vector3 = Vector3<$scalar>;
+            type Vector4 = Vector4<$scalar>;
+            type Matrix3 = Matrix3<$scalar>;
+            #[inline(always)]
+            fn from_cols(
+                x: Vector4<$scalar>,
+                y: Vector4<$scalar>,
+                z: Vector4<$scalar>,
+                w: Vector4<$scalar>,
+            ) -> Self {
+                Matrix4::from_columns(&[x, y, z, w])
+            }
+            #[inline(always)]
+            fn col(&self, index: usize) -> Vector4<$scalar> {
+                self.column(index).into_owned()
+            }
+        }
+    };
+}
+
+// the matrix backed affine maps, which unlike `Similarity2` and `Similarity3` allow a zero scale
+macro_rules! impl_nalgebra_affine {
+    ($scalar:ty) => {
+        impl GenericAffine2 for Affine2<$scalar> {
+            type Scalar = $scalar;
+            type Vector2 = Vector2<$scalar>;
+            #[inline(always)]
+            fn identity() -> Self {
+                Affine2::identity()
+            }
+            #[inline(always)]
+            fn from_scale_angle_translation(
+                scale: $scalar,
+                angle: $scalar,
+                translation: Vector2<$scalar>,
+            ) -> Self {
+                let (s, c) = (
+                    crate::math::sin(angle) * scale,
+                    crate::math::cos(angle) * scale,
+                );
+                Affine2::from_matrix_unchecked(Matrix3::new(
+                    c,
+                    -s,
+                    translation.x,
+                    s,
+                    c,
+                    translation.y,
+                    0.0,
+                    0.0,
+                    1.0,
+                ))
+            }
+            #[inline(always)]
+            fn translation(&self) -> Vector2<$scalar> {
+                let m = self.matrix();
+                Vector2::new(m[(0, 2)], m[(1, 2)])
+            }
+            #[inline(always)]
+            fn transform_point2(&self, p: Vector2<$scalar>) -> Vector2<$scalar> {
+                self.transform_point(&Point2::from(p)).coords
+            }
+            #[inline(always)]
+            fn transform_vector2(&self, v: Vector2<$scalar>) -> Vector2<$scalar> {
+                self.transform_vector(&v)
+            }
+            #[inline(always)]
+            fn try_inverse(&self) -> Option<Self> {
+                Affine2::try_inverse(*self)
+            }
+            #[inline(always)]
+            fn compose(&self, other: &Self) -> Self {
+                self * other
+            }
+        }
+
+        impl GenericAffine3 for Affine3<$scalar> {
+            type Scalar = $scalar;
+            type Vector3 = Vector3<$scalar>;
+            #[inline(always)]
+            fn identity() -> Self {
+                Affine3::identity()
+            }
+            #[inline(always)]
+            fn from_scale_axis_angle_translation(
+                scale: $scalar,
+                axis: Vector3<$scalar>,
+                angle: $scalar,
+                translation: Vector3<$scalar>,
+            ) -> Self {
+                let rotation =
+                    UnitQuaternion::from_axis_angle(&::nalgebra::Unit::new_unchecked(axis), angle);
+                let mut m = (rotation.to_rotation_matrix().into_inner() * scale).to_homogeneous();
+                m.fixed_view_mut::<3, 1>(0, 3).copy_from(&translation);
+                Affine3::from_matrix_unchecked(m)
+            }
+            #[inline(always)]
+            fn translation(&self) -> Vector3<$scalar> {
+                self.matrix().fixed_view::<3, 1>(0, 3).into_owned()
+            }
+            #[inline(always)]
+            fn transform_point3(&self, p: Vector3<$scalar>) -> Vector3<$scalar> {
+                self.transform_point(&Point3::from(p)).coords
+            }
+            #[inline(always)]
+            fn transform_vector3(&self, v: Vector3<$scalar>) -> Vector3<$scalar> {
+                self.transform_vector(&v)
+            }
+            #[inline(always)]
+            fn try_inverse(&self) -> Option<Self> {
+                Affine3::try_inverse(*self)
+            }
+            #[inline(always)]
+            fn compose(&self, other: &Self) -> Self {
+                self * other
+            }
+        }
+    };
+}
+
+macro_rules! impl_nalgebra_rotation3 {
+    ($scalar:ty) => {
+        impl GenericRotation3 for UnitQuaternion<$scalar> {
+            type Scalar = $scalar;
+            type Vector3 = Vector3<$scalar>;
+            type Matrix3 = Matrix3<$scalar>;
+            #[inline(always)]
+            fn from_xyzw(x: $scalar, y: $scalar, z: $scalar, w: $scalar) -> Self {
+                UnitQuaternion::new_unchecked(::nalgebra::Quaternion::new(w, x, y, z))
+            }
+            #[inline(always)]
+            fn to_xyzw(self) -> [$scalar; 4] {
+                let c = self.coords;
+                [c.x, c.y, c.z, c.w]
+            }
+            #[inline(always)]
+            fn from_axis_angle(axis: Vector3<$scalar>, angle: $scalar) -> Self {
+                UnitQuaternion::from_axis_angle(&::nalgebra::Unit::new_unchecked(axis), angle)
+            }
+            #[inline(always)]
+            fn rotate_vector(self, v: Vector3<$scalar>) -> Vector3<$scalar> {
+                self * v
+            }
+            #[inline(always)]
+            fn compose(self, other: Self) -> Self {
+                self * other
+            }
+            #[inline(always)]
+            fn to_matrix(self) -> Matrix3<$scalar> {
+                self.to_rotation_matrix().into_inner()
+            }
+        }
+    };
+}
+
+impl_nalgebra_point!(f32);
+impl_nalgebra_point!(f64);
+impl_nalgebra_vector!(f32);
+impl_nalgebra_vector!(f64);
+impl_nalgebra_vector4!(f32);
+impl_nalgebra_vector4!(f64);
+impl_nalgebra_matrix!(f32);
+impl_nalgebra_matrix!(f64);
+impl_nalgebra_affine!(f32);
+impl_nalgebra_affine!(f64);
+impl_nalgebra_rotation3!(f32);
+impl_nalgebra_rotation3!(f64);
+
+/// Returns the x and y of `v` as a nalgebra point.
+#[inline(always)]
+pub fn to_point2<V: HasXY>(v: V) -> Point2<V::Scalar> {
+    Point2::new(v.x(), v.y())
+}
+
+/// Returns the x, y and z of `v` as a nalgebra point.
+#[inline(always)]
+pub fn to_point3<V: HasXYZ>(v: V) -> Point3<V::Scalar> {
+    Point3::new(v.x(), v.y(), v.z())
+}
+
+/// Returns the nalgebra point as a vector of type `V`.
+#[inline(always)]
+pub fn from_point2<V: HasXY>(p: Point2<V::Scalar>) -> V {
+    V::new_2d(p.x, p.y)
+}
+
+/// Returns the nalgebra point as a vector of type `V`.
+#[inline(always)]
+pub fn from_point3<V: HasXYZ>(p: Point3<V::Scalar>) -> V {
+    V::new_3d(p.x, p.y, p.z)
+}
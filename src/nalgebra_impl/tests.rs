@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{
+    from_point2, from_point3, to_point2, to_point3, Affine2, Affine3, Matrix2, Matrix3, Matrix4,
+    Point2, Point3, UnitQuaternion, Vector2, Vector3, Vector4,
+};
+use crate::{distance_robust, Approx, Axis2, Axis3, HasXYZ};
+
+#[test]
+fn test_points() {
+    crate::tests::tests::test_xy::<Point2<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Point2<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Point3<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Point3<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xyz::<Point3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_xyz::<Point3<f64>>(1.0, 2.0, 3.0);
+
+    let p = Point3::new_3d(1.0, 2.0, 3.0);
+    assert_eq!((p[Axis3::Y], Point2::new(5.0, 6.0)[Axis2::X]), (2.0, 5.0));
+    assert!(p.is_abs_diff_eq(Point3::new(1.0, 2.0, 3.0 + 1e-9), 1e-6));
+    assert_eq!(distance_robust(p, Point3::new(4.0, 6.0, 3.0)), 5.0);
+}
+
+#[test]
+fn test_xy() {
+    crate::tests::tests::test_xy::<Vector2<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Vector2<f64>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Vector3<f32>>(1.0, 2.0);
+    crate::tests::tests::test_xy::<Vector3<f64>>(1.0, 2.0);
+}
+
+#[test]
+fn test_xyz() {
+    crate::tests::tests::test_xyz::<Vector3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_xyz::<Vector3<f64>>(1.0, 2.0, 3.0);
+}
+
+#[test]
+fn test_gxy() {
+    crate::tests::tests::test_gxy::<Vector2<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_gxy::<Vector2<f64>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_generic_xy::<Vector2<f32>>(1.0, 2.0, 3.0, 0.00000001);
+    crate::tests::tests::test_generic_xy::<Vector2<f64>>(1.0, 2.0, 3.0, 0.0000000000001);
+}
+
+#[test]
+fn test_gxyz() {
+    crate::tests::tests::test_gxyz::<Vector3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_gxyz::<Vector3<f64>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_generic_xyz::<Vector3<f32>>(1.0, 2.0, 3.0, 4.0, 0.0001);
+    crate::tests::tests::test_generic_xyz::<Vector3<f64>>(1.0, 2.0, 3.0, 4.0, 0.0000000000001);
+}
+
+#[test]
+fn test_axis() {
+    crate::tests::tests::test_axis::<Vector3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_axis::<Vector3<f64>>(1.0, 2.0, 3.0);
+}
+
+#[test]
+fn test_summation() {
+    crate::tests::tests::test_summation::<Vector2<f32>>();
+    crate::tests::tests::test_summation::<Vector2<f64>>();
+}
+
+#[test]
+fn test_distance_robust() {
+    crate::tests::tests::test_distance_robust::<Vector3<f32>>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_distance_robust::<Vector3<f64>>(1.0, 2.0, 3.0);
+}
+
+#[test]
+fn test_matrix3() {
+    crate::tests::tests::test_matrix3::<Matrix3<f32>>();
+    crate::tests::tests::test_matrix3::<Matrix3<f64>>();
+    crate::tests::tests::test_affine2::<crate::AffineMatrix3<Matrix3<f32>>>();
+    crate::tests::tests::test_affine2::<crate::AffineMatrix3<Matrix3<f64>>>();
+}
+
+#[test]
+fn test_matrix2_matrix4() {
+    crate::tests::tests::test_vector4::<Vector4<f32>>();
+    crate::tests::tests::test_vector4::<Vector4<f64>>();
+    crate::tests::tests::test_matrix2::<Matrix2<f32>>();
+    crate::tests::tests::test_matrix2::<Matrix2<f64>>();
+    crate::tests::tests::test_matrix4::<Matrix4<f32>>();
+    crate::tests::tests::test_matrix4::<Matrix4<f64>>();
+}
+
+#[test]
+fn test_affine() {
+    crate::tests::tests::test_affine2::<Affine2<f32>>();
+    crate::tests::tests::test_affine2::<Affine2<f64>>();
+    crate::tests::tests::test_affine3::<Affine3<f32>>();
+    crate::tests::tests::test_affine3::<Affine3<f64>>();
+}
+
+#[test]
+fn test_rotation3() {
+    crate::tests::tests::test_rotation3::<UnitQuaternion<f32>>();
+    crate::tests::tests::test_rotation3::<UnitQuaternion<f64>>();
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_rotate_glam() {
+    use crate::GenericRotation3;
+    let q = <UnitQuaternion<f64> as GenericRotation3>::from_axis_angle(
+        Vector3::new(0.0, 0.0, 1.0),
+        std::f64::consts::FRAC_PI_2,
+    );
+    let v = q.rotate_xyz(glam::dvec3(1.0, 0.0, 2.0));
+    assert!(v.abs_diff_eq(glam::dvec3(0.0, 1.0, 2.0), 1e-12));
+}
+
+#[test]
+fn test_similarity_conversion() {
+    use crate::GenericAffine3;
+    let similarity = nalgebra::Similarity3::new(
+        Vector3::new(1.0, 2.0, 3.0),
+        Vector3::new(0.0, 0.0, std::f64::consts::FRAC_PI_2),
+        2.0,
+    );
+    let a: Affine3<f64> = nalgebra::convert(similarity);
+    let p = Vector3::new(1.0, 0.0, 1.0);
+    assert!((a.transform_point3(p) - Vector3::new(1.0, 4.0, 5.0)).norm() < 1e-12);
+    let isometry = nalgebra::Isometry2::new(Vector2::new(1.0, 2.0), 0.0);
+    let a: Affine2<f64> = nalgebra::convert(isometry);
+    assert_eq!(
+        crate::GenericAffine2::translation(&a),
+        Vector2::new(1.0, 2.0)
+    );
+}
+
+#[test]
+fn test_point_vector_conversions() {
+    let v: Vector3<f64> = from_point3(Point3::new(1.0, 2.0, 3.0));
+    assert_eq!(v, Vector3::new(1.0, 2.0, 3.0));
+    assert_eq!(to_point3(v), Point3::new(1.0, 2.0, 3.0));
+    let v: Vector2<f32> = from_point2(Point2::new(1.0, 2.0));
+    assert_eq!(to_point2(v), Point2::new(1.0, 2.0));
+    // a 3D vector converts to a 2D point by dropping z
+    assert_eq!(
+        to_point2(Vector3::new(1.0, 2.0, 3.0)),
+        Point2::new(1.0, 2.0)
+    );
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_conversions() {
+    let v: glam::DVec3 = from_point3(Point3::new(1.0, 2.0, 3.0));
+    assert_eq!(v, glam::dvec3(1.0, 2.0, 3.0));
+    assert_eq!(to_point3(v), Point3::new(1.0, 2.0, 3.0));
+    let v: glam::Vec2 = from_point2(Point2::new(1.0, 2.0));
+    assert_eq!(to_point2(v), Point2::new(1.0, 2.0));
+}
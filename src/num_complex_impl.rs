@@ -0,0 +1,77 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `HasXY` and `Approx` for `num_complex::Complex<f32>` and `Complex<f64>`, with the real part as
+//! x and the imaginary part as y.
+//!
+//! This lets complex numbers go through the algorithms written against `HasXY` and the vector
+//! operators, e.g. `closest_point_on_segment()`, `distance_robust()` or `RunningStats`.
+//! `GenericVector2` is not implemented: it requires `Index` and a matching 3D vector type,
+//! neither of which this crate can provide for a foreign type. Use
+//! `GenericVector2::new_2d(c.re, c.im)` to move to a vector type when those methods are needed.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{Approx, GenericScalar, HasXY};
+use num_complex::Complex;
+
+macro_rules! impl_complex {
+    ($scalar:ty) => {
+        impl HasXY for Complex<$scalar> {
+            type Scalar = $scalar;
+            const DIM: usize = 2;
+            #[inline(always)]
+            fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                Complex::new(x, y)
+            }
+            #[inline(always)]
+            fn x(self) -> Self::Scalar {
+                self.re
+            }
+            #[inline(always)]
+            fn set_x(&mut self, val: Self::Scalar) {
+                self.re = val
+            }
+            #[inline(always)]
+            fn x_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.re
+            }
+            #[inline(always)]
+            fn y(self) -> Self::Scalar {
+                self.im
+            }
+            #[inline(always)]
+            fn set_y(&mut self, val: Self::Scalar) {
+                self.im = val
+            }
+            #[inline(always)]
+            fn y_mut(&mut self) -> &mut Self::Scalar {
+                &mut self.im
+            }
+            #[inline(always)]
+            fn get(self, index: usize) -> Option<Self::Scalar> {
+                match index {
+                    0 => Some(self.re),
+                    1 => Some(self.im),
+                    _ => None,
+                }
+            }
+            #[inline(always)]
+            fn canonicalize(self) -> Self {
+                Complex::new(self.re.canonicalize(), self.im.canonicalize())
+            }
+            #[inline(always)]
+            fn flush_denormals(self) -> Self {
+                Complex::new(self.re.flush_denormal(), self.im.flush_denormal())
+            }
+        }
+
+        impl Approx for Complex<$scalar> {}
+    };
+}
+
+impl_complex!(f32);
+impl_complex!(f64);
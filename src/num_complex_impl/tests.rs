@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use crate::{
+    closest_point_on_segment, distance_robust, math, Approx, CgmathCompat, HasXY, RunningStats,
+};
+use num_complex::Complex;
+
+#[test]
+fn test_complex_has_xy() {
+    let mut c = Complex::<f64>::new_2d(3.0, 4.0);
+    assert_eq!((c.x(), c.y()), (3.0, 4.0));
+    assert_eq!((c.get(0), c.get(1), c.get(2)), (Some(3.0), Some(4.0), None));
+    c.set_y(-4.0);
+    *c.x_mut() = -3.0;
+    assert_eq!(c, Complex::new(-3.0, -4.0));
+    assert_eq!(
+        Complex::<f32>::new_2d(-0.0, 1.0)
+            .canonicalize()
+            .re
+            .to_bits(),
+        0
+    );
+
+    assert!(c.is_ulps_eq(Complex::new(-3.0, -4.0 + 1e-15), f64::EPSILON, 4));
+    assert!(!c.is_abs_diff_eq(Complex::new(-3.0, -4.1), 0.01));
+}
+
+#[test]
+fn test_complex_algorithms() {
+    // the vector algorithms agree with the complex arithmetic
+    let (a, b) = (Complex::new(1.0_f32, 2.0), Complex::new(4.0, -2.0));
+    assert_eq!(math::dot(a, a), a.norm_sqr());
+    assert_eq!(distance_robust(a, b), (a - b).norm());
+    assert_eq!(a.magnitude2(), a.norm_sqr());
+
+    let (p, t) = closest_point_on_segment(
+        Complex::new(0.0, 0.0),
+        Complex::new(-1.0, 1.0),
+        Complex::new(1.0, 1.0),
+    );
+    assert_eq!((p, t), (Complex::new(0.0, 1.0), 0.5));
+
+    let stats: RunningStats<Complex<f64>> = [Complex::new(1.0, 0.0), Complex::new(3.0, 2.0)]
+        .into_iter()
+        .collect();
+    assert_eq!(stats.mean(), Some(Complex::new(2.0, 1.0)));
+}
@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Exact rational scalars with `num_rational::BigRational`, for robust predicates.
+//!
+//! `BigRational` implements `BigScalar`, the tier for exact types that are not `Copy`, so the
+//! `BigVec2` and `BigVec3` vectors work over it. Every finite float is a rational number, and
+//! `to_rational()` converts it exactly by decoding the mantissa and exponent from `to_bits()`.
+//! `orient2d_rational()` shows the intended use: evaluate a predicate with a cheap filter in the
+//! float type, and escalate to exact rational arithmetic only when the filter can not decide.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{orient2d_interval, BigScalar, BigVec2, GenericScalar, HasXY, Orientation};
+use num_bigint::BigInt;
+pub use num_rational::BigRational;
+use num_traits::{One, ToPrimitive, Zero};
+
+/// Returns the exact value of a finite float as a rational number, or `None` for infinities and
+/// NaN.
+pub fn to_rational<S: GenericScalar>(value: S) -> Option<BigRational> {
+    // f32 widens to f64 exactly
+    let value: f64 = value.into();
+    if !value.is_finite() {
+        return None;
+    }
+    let bits = value.to_bits();
+    let negative = bits >> 63 != 0;
+    let biased_exponent = ((bits >> 52) & 0x7ff) as i64;
+    let fraction = bits & ((1_u64 << 52) - 1);
+    // value = mantissa * 2^exponent, denormals have no implicit leading bit
+    let (mantissa, exponent) = if biased_exponent == 0 {
+        (fraction, -1074)
+    } else {
+        (fraction | (1_u64 << 52), biased_exponent - 1075)
+    };
+    let mantissa = BigInt::from(mantissa);
+    let r = if exponent >= 0 {
+        BigRational::from_integer(mantissa << exponent as usize)
+    } else {
+        BigRational::new(mantissa, BigInt::one() << (-exponent) as usize)
+    };
+    Some(if negative { -r } else { r })
+}
+
+impl BigScalar for BigRational {
+    #[inline(always)]
+    fn zero_value() -> Self {
+        BigRational::zero()
+    }
+    #[inline(always)]
+    fn from_f64_exact(value: f64) -> Option<Self> {
+        to_rational(value)
+    }
+    #[inline(always)]
+    fn to_f64_lossy(&self) -> f64 {
+        ToPrimitive::to_f64(self).unwrap_or(f64::NAN)
+    }
+}
+
+/// A two-dimensional vector over `BigRational`.
+pub type RationalVec2 = BigVec2<BigRational>;
+
+/// Returns the orientation of the points `a`, `b` and `c`, like `orient2d()`.
+///
+/// The interval filter of `orient2d_interval()` decides most inputs in the scalar type of the
+/// points. Nearly collinear points escalate to the exact determinant in rational arithmetic.
+/// Points with a non-finite component are reported as collinear. Only the x and y components
+/// are considered.
+pub fn orient2d_rational<T: HasXY>(a: T, b: T, c: T) -> Orientation {
+    if let Some(orientation) = orient2d_interval(a, b, c) {
+        return orientation;
+    }
+    match (
+        RationalVec2::from_float(a),
+        RationalVec2::from_float(b),
+        RationalVec2::from_float(c),
+    ) {
+        (Some(a), Some(b), Some(c)) => RationalVec2::orient2d(&a, &b, &c),
+        _ => Orientation::Collinear,
+    }
+}
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{orient2d_rational, to_rational, BigRational, RationalVec2};
+use crate::{orient2d, BigScalar, Orientation};
+
+#[test]
+fn test_to_rational() {
+    let r = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+    assert_eq!(to_rational(0.75_f64), Some(r(3, 4)));
+    assert_eq!(to_rational(-0.75_f32), Some(r(-3, 4)));
+    assert_eq!(to_rational(-0.0_f64), Some(r(0, 1)));
+    assert_eq!(to_rational(6.0e3_f64), Some(r(6000, 1)));
+    assert_eq!(to_rational(f64::NAN), None);
+    assert_eq!(to_rational(f32::NEG_INFINITY), None);
+    // 0.1 is not a tenth, but the nearest float with a power of two denominator
+    assert_eq!(
+        to_rational(0.1_f64),
+        Some(BigRational::new(
+            3602879701896397_i64.into(),
+            36028797018963968_i64.into()
+        ))
+    );
+    for v in [
+        f64::MIN_POSITIVE,
+        f64::MIN_POSITIVE / 3.0,
+        f64::from_bits(1),
+        f64::MAX,
+        -1.0e-300,
+        1.0 / 3.0,
+    ] {
+        assert_eq!(to_rational(v).unwrap().to_f64_lossy(), v);
+    }
+}
+
+#[test]
+fn test_orient2d_rational() {
+    let eps = f64::EPSILON;
+    let cases = [
+        ([0.0, 0.0], [1.0, 0.0], [0.0, 1.0]),
+        ([0.5, 0.5], [12.0, 12.0], [24.0, 24.0 + eps * 16.0]),
+        ([0.5, 0.5], [12.0, 12.0], [24.0, 24.0]),
+        ([0.5, 0.5], [12.0, 12.0], [24.0, 24.0 - eps * 16.0]),
+        ([1.0 + eps, 1.0], [1.0, 1.0 - eps], [0.0, 0.0]),
+    ];
+    for (a, b, c) in cases {
+        assert_eq!(orient2d_rational(a, b, c), orient2d(a, b, c));
+    }
+    assert_eq!(
+        orient2d_rational([0.5, 0.5], [12.0, 12.0], [24.0, 24.0]),
+        Orientation::Collinear
+    );
+    assert_eq!(
+        orient2d_rational([0.0_f32, 0.0], [1.0, f32::NAN], [0.0, 1.0]),
+        Orientation::Collinear
+    );
+
+    // rationals divide exactly as well
+    let a = RationalVec2::from_float([1.0_f64, 3.0]).unwrap();
+    let third = BigRational::new(1.into(), 3.into());
+    let b = a.clone() * third;
+    assert_eq!(b.y, BigRational::from_integer(1.into()));
+    assert_eq!((b * BigRational::from_integer(3.into())), a);
+}
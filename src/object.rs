@@ -0,0 +1,247 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `ObjectVector2` and `ObjectVector3`, object safe companions of `GenericVector2` and
+//! `GenericVector3`, for plugins and other code that passes vectors around as `&dyn` instead of
+//! being generic over the backend.
+//!
+//! The vector traits can not be trait objects: they have associated constants, take and return
+//! `Self` by value and have generic methods. The companion traits take `&self` and return the
+//! precision erased `DynScalar` and `DynVector2`/`DynVector3` of `dispatch`, or a boxed vector of
+//! the same concrete type. They are implemented for every `GenericVector2` and `GenericVector3`.
+//!
+//! The binary operations are evaluated in the concrete type of `self`. The other operand is
+//! downcast to that type, or converted through `DynVector2`/`DynVector3` if it is of another type.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    DynScalar, DynVector2, DynVector3, GenericVector2, GenericVector3, HasXY, ScalarCore,
+    ScalarKind,
+};
+use std::{any::Any, fmt::Debug};
+
+/// The object safe subset of `GenericVector2`.
+pub trait ObjectVector2: Debug + Sync + Send {
+    /// Returns the precision of the scalar type.
+    fn scalar_kind(&self) -> ScalarKind;
+
+    /// Returns the vector with the type erased.
+    fn to_dyn(&self) -> DynVector2;
+
+    /// Returns the vector as `Any`, to downcast it to the concrete type.
+    fn as_any(&self) -> &dyn Any;
+
+    fn clone_boxed(&self) -> Box<dyn ObjectVector2>;
+
+    fn dyn_magnitude(&self) -> DynScalar;
+
+    fn dyn_dot(&self, other: &dyn ObjectVector2) -> DynScalar;
+
+    fn dyn_perp_dot(&self, other: &dyn ObjectVector2) -> DynScalar;
+
+    fn dyn_distance(&self, other: &dyn ObjectVector2) -> DynScalar;
+
+    fn dyn_add(&self, other: &dyn ObjectVector2) -> Box<dyn ObjectVector2>;
+
+    fn dyn_sub(&self, other: &dyn ObjectVector2) -> Box<dyn ObjectVector2>;
+
+    /// Returns the vector times `factor`, rounded to the scalar type.
+    fn dyn_scale(&self, factor: f64) -> Box<dyn ObjectVector2>;
+
+    fn dyn_normalize(&self) -> Box<dyn ObjectVector2>;
+}
+
+/// The object safe subset of `GenericVector3`.
+pub trait ObjectVector3: Debug + Sync + Send {
+    /// Returns the precision of the scalar type.
+    fn scalar_kind(&self) -> ScalarKind;
+
+    /// Returns the vector with the type erased.
+    fn to_dyn(&self) -> DynVector3;
+
+    /// Returns the vector as `Any`, to downcast it to the concrete type.
+    fn as_any(&self) -> &dyn Any;
+
+    fn clone_boxed(&self) -> Box<dyn ObjectVector3>;
+
+    fn dyn_magnitude(&self) -> DynScalar;
+
+    fn dyn_dot(&self, other: &dyn ObjectVector3) -> DynScalar;
+
+    fn dyn_cross(&self, other: &dyn ObjectVector3) -> Box<dyn ObjectVector3>;
+
+    fn dyn_distance(&self, other: &dyn ObjectVector3) -> DynScalar;
+
+    fn dyn_add(&self, other: &dyn ObjectVector3) -> Box<dyn ObjectVector3>;
+
+    fn dyn_sub(&self, other: &dyn ObjectVector3) -> Box<dyn ObjectVector3>;
+
+    /// Returns the vector times `factor`, rounded to the scalar type.
+    fn dyn_scale(&self, factor: f64) -> Box<dyn ObjectVector3>;
+
+    fn dyn_normalize(&self) -> Box<dyn ObjectVector3>;
+}
+
+// Returns `other` as the concrete type `V`, converting it if it is of another type.
+#[inline]
+fn to_concrete2<V: GenericVector2 + 'static>(other: &dyn ObjectVector2) -> V {
+    match other.as_any().downcast_ref::<V>() {
+        Some(v) => *v,
+        None => other.to_dyn().to_xy(),
+    }
+}
+
+#[inline]
+fn to_concrete3<V: GenericVector3 + 'static>(other: &dyn ObjectVector3) -> V {
+    match other.as_any().downcast_ref::<V>() {
+        Some(v) => *v,
+        None => other.to_dyn().to_xyz(),
+    }
+}
+
+#[inline]
+fn to_dyn_scalar<V: HasXY>(value: V::Scalar) -> DynScalar {
+    DynScalar::new(ScalarKind::of::<V::Scalar>(), value.into())
+}
+
+impl<V: GenericVector2 + 'static> ObjectVector2 for V {
+    #[inline]
+    fn scalar_kind(&self) -> ScalarKind {
+        ScalarKind::of::<V::Scalar>()
+    }
+
+    #[inline]
+    fn to_dyn(&self) -> DynVector2 {
+        DynVector2::from_xy(self.scalar_kind(), *self)
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[inline]
+    fn clone_boxed(&self) -> Box<dyn ObjectVector2> {
+        Box::new(*self)
+    }
+
+    #[inline]
+    fn dyn_magnitude(&self) -> DynScalar {
+        to_dyn_scalar::<V>(self.magnitude())
+    }
+
+    #[inline]
+    fn dyn_dot(&self, other: &dyn ObjectVector2) -> DynScalar {
+        to_dyn_scalar::<V>(self.dot(to_concrete2(other)))
+    }
+
+    #[inline]
+    fn dyn_perp_dot(&self, other: &dyn ObjectVector2) -> DynScalar {
+        to_dyn_scalar::<V>(self.perp_dot(to_concrete2(other)))
+    }
+
+    #[inline]
+    fn dyn_distance(&self, other: &dyn ObjectVector2) -> DynScalar {
+        to_dyn_scalar::<V>(self.distance(to_concrete2(other)))
+    }
+
+    #[inline]
+    fn dyn_add(&self, other: &dyn ObjectVector2) -> Box<dyn ObjectVector2> {
+        Box::new(*self + to_concrete2::<V>(other))
+    }
+
+    #[inline]
+    fn dyn_sub(&self, other: &dyn ObjectVector2) -> Box<dyn ObjectVector2> {
+        Box::new(*self - to_concrete2::<V>(other))
+    }
+
+    #[inline]
+    fn dyn_scale(&self, factor: f64) -> Box<dyn ObjectVector2> {
+        Box::new(*self * V::Scalar::from_f64_lossy(factor))
+    }
+
+    #[inline]
+    fn dyn_normalize(&self) -> Box<dyn ObjectVector2> {
+        Box::new(self.normalize())
+    }
+}
+
+impl<V: GenericVector3 + 'static> ObjectVector3 for V {
+    #[inline]
+    fn scalar_kind(&self) -> ScalarKind {
+        ScalarKind::of::<V::Scalar>()
+    }
+
+    #[inline]
+    fn to_dyn(&self) -> DynVector3 {
+        DynVector3::from_xyz(self.scalar_kind(), *self)
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    #[inline]
+    fn clone_boxed(&self) -> Box<dyn ObjectVector3> {
+        Box::new(*self)
+    }
+
+    #[inline]
+    fn dyn_magnitude(&self) -> DynScalar {
+        to_dyn_scalar::<V>(self.magnitude())
+    }
+
+    #[inline]
+    fn dyn_dot(&self, other: &dyn ObjectVector3) -> DynScalar {
+        to_dyn_scalar::<V>(self.dot(to_concrete3(other)))
+    }
+
+    #[inline]
+    fn dyn_cross(&self, other: &dyn ObjectVector3) -> Box<dyn ObjectVector3> {
+        Box::new(self.cross(to_concrete3(other)))
+    }
+
+    #[inline]
+    fn dyn_distance(&self, other: &dyn ObjectVector3) -> DynScalar {
+        to_dyn_scalar::<V>(self.distance(to_concrete3(other)))
+    }
+
+    #[inline]
+    fn dyn_add(&self, other: &dyn ObjectVector3) -> Box<dyn ObjectVector3> {
+        Box::new(*self + to_concrete3::<V>(other))
+    }
+
+    #[inline]
+    fn dyn_sub(&self, other: &dyn ObjectVector3) -> Box<dyn ObjectVector3> {
+        Box::new(*self - to_concrete3::<V>(other))
+    }
+
+    #[inline]
+    fn dyn_scale(&self, factor: f64) -> Box<dyn ObjectVector3> {
+        Box::new(*self * V::Scalar::from_f64_lossy(factor))
+    }
+
+    #[inline]
+    fn dyn_normalize(&self) -> Box<dyn ObjectVector3> {
+        Box::new(self.normalize())
+    }
+}
+
+impl Clone for Box<dyn ObjectVector2> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.clone_boxed()
+    }
+}
+
+impl Clone for Box<dyn ObjectVector3> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.clone_boxed()
+    }
+}
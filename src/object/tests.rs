@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use crate::{
+    ArrayVec2, ArrayVec3, DynScalar, DynVector2, HasXY, HasXYZ, ObjectVector2, ObjectVector3,
+    ScalarKind,
+};
+
+// compiled once, called with any vector type
+fn path_length(points: &[&dyn ObjectVector3]) -> f64 {
+    points
+        .windows(2)
+        .map(|w| w[0].dyn_distance(w[1]).to_f64())
+        .sum()
+}
+
+#[test]
+fn test_object_vector2() {
+    let a = ArrayVec2::<f32>::new_2d(3.0, 4.0);
+    let b = ArrayVec2::<f64>::new_2d(1.0, 2.0);
+    let (a, b): (&dyn ObjectVector2, &dyn ObjectVector2) = (&a, &b);
+    assert_eq!(a.scalar_kind(), ScalarKind::F32);
+    assert_eq!(b.scalar_kind(), ScalarKind::F64);
+    assert_eq!(a.dyn_magnitude(), DynScalar::F32(5.0));
+    // evaluated in the type of self
+    assert_eq!(a.dyn_dot(b), DynScalar::F32(11.0));
+    assert_eq!(b.dyn_dot(a).kind(), ScalarKind::F64);
+    assert_eq!(a.dyn_perp_dot(b), DynScalar::F32(2.0));
+    assert_eq!(
+        a.dyn_add(b).to_dyn(),
+        DynVector2::new(ScalarKind::F32, 4.0, 6.0)
+    );
+    assert_eq!(
+        a.dyn_sub(b).dyn_scale(0.5).to_dyn(),
+        DynVector2::new(ScalarKind::F32, 1.0, 1.0)
+    );
+    let n = a.dyn_normalize();
+    assert_eq!(n.to_dyn(), DynVector2::new(ScalarKind::F32, 0.6, 0.8));
+    let c = n.clone();
+    assert_eq!(
+        c.as_any().downcast_ref::<ArrayVec2<f32>>(),
+        Some(&ArrayVec2::new_2d(0.6, 0.8))
+    );
+    assert!(c.as_any().downcast_ref::<ArrayVec2<f64>>().is_none());
+}
+
+#[test]
+fn test_object_vector3() {
+    let a = ArrayVec3::<f64>::new_3d(0.0, 0.0, 0.0);
+    let b = ArrayVec3::<f32>::new_3d(3.0, 4.0, 0.0);
+    let c = ArrayVec3::<f64>::new_3d(3.0, 4.0, 12.0);
+    assert_eq!(path_length(&[&a, &b, &c]), 17.0);
+
+    let x = ArrayVec3::<f64>::new_3d(1.0, 0.0, 0.0);
+    let y = ArrayVec3::<f32>::new_3d(0.0, 1.0, 0.0);
+    let z = x.dyn_cross(&y);
+    assert_eq!(z.scalar_kind(), ScalarKind::F64);
+    assert_eq!(z.to_dyn().to_f64(), ArrayVec3::new_3d(0.0, 0.0, 1.0));
+    assert_eq!(z.dyn_dot(&x), DynScalar::F64(0.0));
+    let sum = z.dyn_add(&y).dyn_sub(&x).dyn_scale(2.0);
+    assert_eq!(sum.to_dyn().to_f64(), ArrayVec3::new_3d(-2.0, 2.0, 2.0));
+    assert_eq!(sum.dyn_normalize().dyn_magnitude().to_f64(), 1.0);
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn test_object_vector_glam() {
+    let vectors: Vec<Box<dyn ObjectVector2>> = vec![
+        Box::new(glam::Vec2::new(1.0, 2.0)),
+        Box::new(glam::DVec2::new(3.0, 4.0)),
+        Box::new(ArrayVec2::<f32>::new_2d(5.0, 6.0)),
+    ];
+    let sum = vectors[1..]
+        .iter()
+        .fold(vectors[0].clone(), |acc, v| acc.dyn_add(v.as_ref()));
+    assert!(sum.as_any().is::<glam::Vec2>());
+    assert_eq!(sum.to_dyn(), DynVector2::new(ScalarKind::F32, 9.0, 12.0));
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Planes in point-normal form, and least-squares plane fitting of point clouds.
+
+use crate::{checked::is_finite, principal_axes, GenericScalar, GenericVector3};
+use std::fmt::{Display, Formatter};
+
+/// A plane through `point` with the unit `normal`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Plane<V: GenericVector3> {
+    pub point: V,
+    pub normal: V,
+}
+
+impl<V: GenericVector3> Plane<V> {
+    /// Creates a plane through `point`, or `None` if `normal` can not be normalized.
+    #[inline(always)]
+    pub fn new(point: V, normal: V) -> Option<Self> {
+        Some(Self {
+            point,
+            normal: normal.safe_normalize()?,
+        })
+    }
+
+    /// Returns the distance from `p` to the plane, positive on the side the normal points to.
+    #[inline(always)]
+    pub fn signed_distance(&self, p: V) -> V::Scalar {
+        (p - self.point).dot(self.normal)
+    }
+
+    /// Returns the point on the plane closest to `p`.
+    #[inline(always)]
+    pub fn project(&self, p: V) -> V {
+        p - self.normal * self.signed_distance(p)
+    }
+}
+
+/// The reason `fit_plane_checked()` could not fit a plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaneFitError {
+    /// Fewer than three points were given.
+    TooFewPoints,
+    /// All the points are (within rounding) on a single line or at a single point, so every
+    /// plane through that line fits equally well.
+    Collinear,
+    /// A coordinate is NaN or infinite.
+    NonFinite,
+}
+
+impl Display for PlaneFitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooFewPoints => write!(f, "at least three points are needed to fit a plane"),
+            Self::Collinear => write!(f, "the points are collinear, the plane is not defined"),
+            Self::NonFinite => write!(f, "the points are not finite"),
+        }
+    }
+}
+
+impl std::error::Error for PlaneFitError {}
+
+/// The result of `fit_plane_checked()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlaneFit<V: GenericVector3> {
+    pub plane: Plane<V>,
+    /// The root mean square distance from the points to the plane.
+    pub rms_distance: V::Scalar,
+}
+
+/// Fits a plane through `points` that minimizes the sum of the squared (orthogonal) distances.
+///
+/// The plane goes through the centroid, and the normal is the direction of least variance of the
+/// points. Returns the reason when the plane is not well defined, `NonFinite` if any coordinate
+/// is NaN or infinite.
+pub fn fit_plane_checked<V: GenericVector3>(points: &[V]) -> Result<PlaneFit<V>, PlaneFitError> {
+    if points.len() < 3 {
+        return Err(PlaneFitError::TooFewPoints);
+    }
+    if !points.iter().all(|p| is_finite(*p)) {
+        return Err(PlaneFitError::NonFinite);
+    }
+    let pa = principal_axes(points).ok_or(PlaneFitError::TooFewPoints)?;
+    let [largest, second, least] = pa.variances;
+    // the second largest variance is what separates a plane from a line
+    if second <= largest * V::Scalar::EPSILON * 16.0.into() {
+        return Err(PlaneFitError::Collinear);
+    }
+    Ok(PlaneFit {
+        plane: Plane {
+            point: pa.centroid,
+            normal: pa.axes[2],
+        },
+        rms_distance: crate::math::sqrt(least),
+    })
+}
+
+/// Fits a plane through `points`, see `fit_plane_checked()`. Returns `None` if the plane is not
+/// well defined.
+#[inline(always)]
+pub fn fit_plane<V: GenericVector3>(points: &[V]) -> Option<Plane<V>> {
+    fit_plane_checked(points).ok().map(|fit| fit.plane)
+}
@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Robust geometric predicates.
+//!
+//! The predicates are built on the adaptive precision `orient2d` of the `robust` crate, so the
+//! results are exact for any finite input, regardless of how close to degenerate it is.
+
+use crate::{HasXY, Interval};
+use num_traits::float::TotalOrder;
+use std::cmp::Ordering;
+
+/// The orientation of three points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// The points occur in counterclockwise order, the third point is left of the line through the first two.
+    CounterClockwise,
+    /// The points occur in clockwise order, the third point is right of the line through the first two.
+    Clockwise,
+    /// The points are collinear.
+    Collinear,
+}
+
+/// Describes how two line segments intersect, as returned by `segments_intersect()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntersectionKind {
+    /// The segments do not share any point.
+    None,
+    /// The segments cross at a single point that is interior to both of them.
+    Crossing,
+    /// The segments share a single point that is an endpoint of at least one of them.
+    Touching,
+    /// The segments are collinear and share more than a single point.
+    Overlapping,
+}
+
+#[inline(always)]
+fn coord<T: HasXY>(v: T) -> robust::Coord<f64> {
+    robust::Coord {
+        x: v.x().into(),
+        y: v.y().into(),
+    }
+}
+
+/// Returns the exact orientation of the points `a`, `b` and `c`.
+/// Only the x and y components are considered.
+#[inline(always)]
+pub fn orient2d<T: HasXY>(a: T, b: T, c: T) -> Orientation {
+    let det = robust::orient2d(coord(a), coord(b), coord(c));
+    if det > 0.0 {
+        Orientation::CounterClockwise
+    } else if det < 0.0 {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+/// Returns the orientation of the points `a`, `b` and `c` if an interval evaluation of the
+/// determinant can decide it, or `None` if the points are collinear or too close to collinear.
+///
+/// This is a cheap filter in the scalar type of the points. Fall back to the exact `orient2d()`
+/// when it returns `None`. Only the x and y components are considered.
+#[inline(always)]
+pub fn orient2d_interval<T: HasXY>(a: T, b: T, c: T) -> Option<Orientation> {
+    let i = Interval::point;
+    let (abx, aby) = (i(b.x()) - i(a.x()), i(b.y()) - i(a.y()));
+    let (acx, acy) = (i(c.x()) - i(a.x()), i(c.y()) - i(a.y()));
+    let det = abx * acy - aby * acx;
+    match det.sign()? {
+        Ordering::Greater => Some(Orientation::CounterClockwise),
+        Ordering::Less => Some(Orientation::Clockwise),
+        Ordering::Equal => Some(Orientation::Collinear),
+    }
+}
+
+/// Classifies the intersection of the segments `a1`-`a2` and `b1`-`b2`, with exact handling of
+/// endpoint touching and collinear overlap. Degenerate (zero length) segments are treated as points.
+/// Only the x and y components are considered.
+pub fn segments_intersect<T: HasXY>(a1: T, a2: T, b1: T, b2: T) -> IntersectionKind {
+    let o1 = orient2d(a1, a2, b1);
+    let o2 = orient2d(a1, a2, b2);
+    let o3 = orient2d(b1, b2, a1);
+    let o4 = orient2d(b1, b2, a2);
+
+    if [o1, o2, o3, o4]
+        .iter()
+        .all(|o| *o == Orientation::Collinear)
+    {
+        return collinear_intersection(a1, a2, b1, b2);
+    }
+    let same_side = |p: Orientation, q: Orientation| p != Orientation::Collinear && p == q;
+    if same_side(o1, o2) || same_side(o3, o4) {
+        return IntersectionKind::None;
+    }
+    if [o1, o2, o3, o4].contains(&Orientation::Collinear) {
+        IntersectionKind::Touching
+    } else {
+        IntersectionKind::Crossing
+    }
+}
+
+/// Intersects four collinear points by projecting them on the axis with the largest extent.
+fn collinear_intersection<T: HasXY>(a1: T, a2: T, b1: T, b2: T) -> IntersectionKind {
+    let span = |f: fn(T) -> T::Scalar| {
+        let values = [f(a1), f(a2), f(b1), f(b2)];
+        let min = values
+            .iter()
+            .copied()
+            .fold(values[0], num_traits::Float::min);
+        let max = values
+            .iter()
+            .copied()
+            .fold(values[0], num_traits::Float::max);
+        max - min
+    };
+    let axis: fn(T) -> T::Scalar = if span(T::y) > span(T::x) { T::y } else { T::x };
+    let sorted = |p: T::Scalar, q: T::Scalar| if p <= q { (p, q) } else { (q, p) };
+    let (a_min, a_max) = sorted(axis(a1), axis(a2));
+    let (b_min, b_max) = sorted(axis(b1), axis(b2));
+    let lo = if a_min >= b_min { a_min } else { b_min };
+    let hi = if a_max <= b_max { a_max } else { b_max };
+    match lo.partial_cmp(&hi) {
+        Some(Ordering::Less) => IntersectionKind::Overlapping,
+        Some(Ordering::Equal) => IntersectionKind::Touching,
+        _ => IntersectionKind::None,
+    }
+}
+
+/// Orders points by y, then by x, using the IEEE 754 total order.
+///
+/// This is a total, deterministic ordering for the event queues of plane sweep algorithms,
+/// so it can be passed directly to `sort_by()` or used in a `BinaryHeap` key. Note that -0.0
+/// sorts before +0.0 and NaN sorts after infinity, `canonicalize()` the points first if that
+/// is not wanted. Only the x and y components are considered.
+#[inline(always)]
+pub fn sweep_cmp<T: HasXY>(a: &T, b: &T) -> Ordering {
+    a.y()
+        .total_cmp(&b.y())
+        .then_with(|| a.x().total_cmp(&b.x()))
+}
+
+/// Orders points by x, then by y, using the IEEE 754 total order.
+/// This is the x-major mirror of `sweep_cmp()`, for sweeps along the x axis.
+#[inline(always)]
+pub fn sweep_cmp_x<T: HasXY>(a: &T, b: &T) -> Ordering {
+    a.x()
+        .total_cmp(&b.x())
+        .then_with(|| a.y().total_cmp(&b.y()))
+}
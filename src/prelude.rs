@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! The traits of the crate, for a glob import, and the `Vector2Ops`, `Vector3Ops` and
+//! `Vector4Ops` aliases of the common bounds.
+//!
+//! ```
+//! use vector_traits::prelude::*;
+//!
+//! fn centroid<S: GenericScalar, V: Vector2Ops<S>>(points: &[V]) -> V {
+//!     points.iter().sum::<V>() / S::from_usize(points.len()).unwrap()
+//! }
+//!
+//! let points = [ArrayVec2::new_2d(0.0, 0.0), ArrayVec2::new_2d(2.0, 4.0)];
+//! assert_eq!(centroid(&points), ArrayVec2::new_2d(1.0, 2.0));
+//! ```
+//!
+//! Stable Rust has no trait aliases, so the aliases are traits with a blanket impl for every type
+//! that satisfies the bounds.
+
+pub use crate::{
+    Approx, ArrayVec2, ArrayVec3, BigScalar, GenericAffine2, GenericAffine3, GenericIntScalar,
+    GenericIntVector2, GenericIntVector3, GenericMask, GenericMatrix2, GenericMatrix3,
+    GenericMatrix4, GenericRotation3, GenericScalar, GenericVector2, GenericVector2xN,
+    GenericVector3, GenericVector3xN, GenericVector4, HasXY, HasXYZ, HasXYZW, ObjectVector2,
+    ObjectVector3, ScalarBatch, ScalarCore,
+};
+
+/// A `GenericVector2` with the scalar type `S`.
+pub trait Vector2Ops<S: GenericScalar>: GenericVector2<Scalar = S> + 'static {}
+
+impl<S: GenericScalar, V: GenericVector2<Scalar = S> + 'static> Vector2Ops<S> for V {}
+
+/// A `GenericVector3` with the scalar type `S`.
+pub trait Vector3Ops<S: GenericScalar>: GenericVector3<Scalar = S> + 'static {}
+
+impl<S: GenericScalar, V: GenericVector3<Scalar = S> + 'static> Vector3Ops<S> for V {}
+
+/// A `GenericVector4` with the scalar type `S`.
+pub trait Vector4Ops<S: GenericScalar>: GenericVector4<Scalar = S> + 'static {}
+
+impl<S: GenericScalar, V: GenericVector4<Scalar = S> + 'static> Vector4Ops<S> for V {}
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `GenericRotation3`, the unit quaternions of the backends, for orientations in code written
+//! against `GenericVector3`.
+//!
+//! Implemented for `glam::Quat` and `DQuat`, for `cgmath::Quaternion`, for
+//! `nalgebra::UnitQuaternion` and for the cgmath rotation matrix `Basis3`. `Basis3` converts to
+//! and from the quaternion components, so `from_xyzw()` of `to_xyzw()` only returns it within
+//! rounding. `inverse()` and `slerp()` are default methods over the components, so they behave the
+//! same on every backend: cgmath 0.17 interpolates the long way around when the quaternions are in
+//! opposite hemispheres, glam and cgmath 0.18 take the shortest path, like `slerp()` does.
+//! `from_matrix()`, `from_euler()`, `to_euler()` and `look_rotation()` are default methods as
+//! well. glam has inherent `from_euler()` functions with other arguments, call those of the traits
+//! through the trait for the concrete glam types.
+
+use crate::{
+    math, EulerAngles, EulerOrder, GenericMatrix3, GenericScalar, GenericVector3, HasXY, HasXYZ,
+};
+use std::fmt::Debug;
+
+/// A rotation in 3D, with the components of a unit quaternion.
+pub trait GenericRotation3: Copy + Debug + PartialEq + Sync + Send + 'static {
+    type Scalar: GenericScalar;
+    type Vector3: GenericVector3<Scalar = Self::Scalar>;
+    type Matrix3: GenericMatrix3<Scalar = Self::Scalar, Vector3 = Self::Vector3>;
+
+    /// Returns the quaternion `w + xi + yj + zk`, without normalizing it.
+    fn from_xyzw(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar, w: Self::Scalar) -> Self;
+
+    /// Returns the components of the quaternion as `[x, y, z, w]`.
+    fn to_xyzw(self) -> [Self::Scalar; 4];
+
+    /// Returns the rotation by `angle` radians about the unit vector `axis`, counterclockwise when
+    /// looking down the axis.
+    fn from_axis_angle(axis: Self::Vector3, angle: Self::Scalar) -> Self;
+
+    fn rotate_vector(self, v: Self::Vector3) -> Self::Vector3;
+
+    /// Rotates `v` of any `HasXYZ` type with the scalar of the rotation, like a `glam::DVec3` by a
+    /// nalgebra `UnitQuaternion<f64>`.
+    #[inline(always)]
+    fn rotate_xyz<V: HasXYZ<Scalar = Self::Scalar>>(self, v: V) -> V {
+        let r = self.rotate_vector(Self::Vector3::new_3d(v.x(), v.y(), v.z()));
+        V::new_3d(r.x(), r.y(), r.z())
+    }
+
+    /// Returns the rotation that applies `other` first and then `self`, like `self * other`.
+    fn compose(self, other: Self) -> Self;
+
+    /// Returns the rotation matrix.
+    fn to_matrix(self) -> Self::Matrix3;
+
+    #[inline(always)]
+    fn identity() -> Self {
+        let o = Self::Scalar::ZERO;
+        Self::from_xyzw(o, o, o, Self::Scalar::ONE)
+    }
+
+    /// Returns the rotation of the rotation matrix `m`, which must be orthonormal with a
+    /// determinant of one.
+    fn from_matrix(m: &Self::Matrix3) -> Self {
+        let [[m00, m01, m02], [m10, m11, m12], [m20, m21, m22]] = m.to_rows();
+        let (one, two, quarter) = (
+            Self::Scalar::ONE,
+            Self::Scalar::TWO,
+            Self::Scalar::from(0.25f32),
+        );
+        // divide by the largest of 4w, 4x, 4y and 4z, so the square root is well away from zero
+        let trace = m00 + m11 + m22;
+        if trace > Self::Scalar::ZERO {
+            let s = math::sqrt(trace + one) * two;
+            Self::from_xyzw(
+                (m21 - m12) / s,
+                (m02 - m20) / s,
+                (m10 - m01) / s,
+                s * quarter,
+            )
+        } else if m00 > m11 && m00 > m22 {
+            let s = math::sqrt(one + m00 - m11 - m22) * two;
+            Self::from_xyzw(
+                s * quarter,
+                (m01 + m10) / s,
+                (m02 + m20) / s,
+                (m21 - m12) / s,
+            )
+        } else if m11 > m22 {
+            let s = math::sqrt(one + m11 - m00 - m22) * two;
+            Self::from_xyzw(
+                (m01 + m10) / s,
+                s * quarter,
+                (m12 + m21) / s,
+                (m02 - m20) / s,
+            )
+        } else {
+            let s = math::sqrt(one + m22 - m00 - m11) * two;
+            Self::from_xyzw(
+                (m02 + m20) / s,
+                (m12 + m21) / s,
+                s * quarter,
+                (m10 - m01) / s,
+            )
+        }
+    }
+
+    /// Returns the rotation of the Euler angles.
+    #[inline(always)]
+    fn from_euler(euler: &EulerAngles<Self::Scalar>) -> Self {
+        Self::from_matrix(&Self::Matrix3::from_euler(euler))
+    }
+
+    /// Returns the Euler angles of the rotation, for the given order.
+    #[inline(always)]
+    fn to_euler(self, order: EulerOrder) -> EulerAngles<Self::Scalar> {
+        EulerAngles::from_matrix(order, &self.to_matrix().to_rows())
+    }
+
+    /// Returns the rotation of `frame::look_rotation()`, that turns the local x axis to `forward`
+    /// and the local z axis as close to `up` as possible. Returns `None` if either vector has zero
+    /// length or if they are parallel.
+    #[inline(always)]
+    fn look_rotation(forward: Self::Vector3, up: Self::Vector3) -> Option<Self> {
+        let m = Self::Matrix3::look_rotation(forward, up)?;
+        Some(Self::from_matrix(&m))
+    }
+
+    /// Returns the inverse rotation, the conjugate of the unit quaternion.
+    #[inline(always)]
+    fn inverse(self) -> Self {
+        let [x, y, z, w] = self.to_xyzw();
+        Self::from_xyzw(-x, -y, -z, w)
+    }
+
+    /// Interpolates from `self` at `t == 0` to `end` at `t == 1` with a constant angular velocity,
+    /// along the shortest path. Nearly identical rotations are interpolated linearly and normalized.
+    fn slerp(self, end: Self, t: Self::Scalar) -> Self {
+        let a = self.to_xyzw();
+        let mut b = end.to_xyzw();
+        let mut dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+        // q and -q are the same rotation, pick the one in the hemisphere of self
+        if dot < Self::Scalar::ZERO {
+            b = b.map(|v| -v);
+            dot = -dot;
+        }
+        let (s0, s1) = if dot > Self::Scalar::from(0.9995f32) {
+            (Self::Scalar::ONE - t, t)
+        } else {
+            let theta = math::acos(dot);
+            let sin_theta = math::sin(theta);
+            (
+                math::sin(theta * (Self::Scalar::ONE - t)) / sin_theta,
+                math::sin(theta * t) / sin_theta,
+            )
+        };
+        let q: [Self::Scalar; 4] = std::array::from_fn(|i| a[i] * s0 + b[i] * s1);
+        let l = math::sqrt(q[0] * q[0] + q[1] * q[1] + q[2] * q[2] + q[3] * q[3]);
+        Self::from_xyzw(q[0] / l, q[1] / l, q[2] / l, q[3] / l)
+    }
+}
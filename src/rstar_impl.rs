@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `rstar::Point` for wrappers over any vector type, so trait based points can be put directly
+//! into an `rstar::RTree`.
+//!
+//! `rstar::Point` is a foreign trait, so it is implemented by the `RTreePoint2` and `RTreePoint3`
+//! wrappers. `RTreePoint2` accepts any `HasXY` type and indexes x and y only, so a 3D vector wrapped
+//! in it is searched by its projection onto the xy plane. `rtree_2d()` and `rtree_3d()` bulk load
+//! a tree from plain vectors.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{HasXY, HasXYZ};
+use rstar::{RTree, RTreeNum};
+
+/// A wrapper indexing the x and y of a vector in an `rstar::RTree`.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct RTreePoint2<V: HasXY>(pub V);
+
+/// A wrapper indexing the x, y and z of a vector in an `rstar::RTree`.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct RTreePoint3<V: HasXYZ>(pub V);
+
+impl<V: HasXY> From<V> for RTreePoint2<V> {
+    #[inline(always)]
+    fn from(v: V) -> Self {
+        Self(v)
+    }
+}
+
+impl<V: HasXYZ> From<V> for RTreePoint3<V> {
+    #[inline(always)]
+    fn from(v: V) -> Self {
+        Self(v)
+    }
+}
+
+impl<V: HasXY + PartialEq> rstar::Point for RTreePoint2<V>
+where
+    V::Scalar: RTreeNum,
+{
+    type Scalar = V::Scalar;
+    const DIMENSIONS: usize = 2;
+
+    #[inline(always)]
+    fn generate(mut generator: impl FnMut(usize) -> Self::Scalar) -> Self {
+        let x = generator(0);
+        Self(V::new_2d(x, generator(1)))
+    }
+
+    #[inline(always)]
+    fn nth(&self, index: usize) -> Self::Scalar {
+        match index {
+            0 => self.0.x(),
+            1 => self.0.y(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[inline(always)]
+    fn nth_mut(&mut self, index: usize) -> &mut Self::Scalar {
+        match index {
+            0 => self.0.x_mut(),
+            1 => self.0.y_mut(),
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl<V: HasXYZ + PartialEq> rstar::Point for RTreePoint3<V>
+where
+    V::Scalar: RTreeNum,
+{
+    type Scalar = V::Scalar;
+    const DIMENSIONS: usize = 3;
+
+    #[inline(always)]
+    fn generate(mut generator: impl FnMut(usize) -> Self::Scalar) -> Self {
+        let (x, y) = (generator(0), generator(1));
+        Self(V::new_3d(x, y, generator(2)))
+    }
+
+    #[inline(always)]
+    fn nth(&self, index: usize) -> Self::Scalar {
+        match index {
+            0 => self.0.x(),
+            1 => self.0.y(),
+            2 => self.0.z(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[inline(always)]
+    fn nth_mut(&mut self, index: usize) -> &mut Self::Scalar {
+        match index {
+            0 => self.0.x_mut(),
+            1 => self.0.y_mut(),
+            2 => self.0.z_mut(),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// Bulk loads an R-tree indexing the x and y of the vectors.
+pub fn rtree_2d<V: HasXY + PartialEq>(vectors: impl IntoIterator<Item = V>) -> RTree<RTreePoint2<V>>
+where
+    V::Scalar: RTreeNum,
+{
+    RTree::bulk_load(vectors.into_iter().map(RTreePoint2).collect())
+}
+
+/// Bulk loads an R-tree indexing the x, y and z of the vectors.
+pub fn rtree_3d<V: HasXYZ + PartialEq>(
+    vectors: impl IntoIterator<Item = V>,
+) -> RTree<RTreePoint3<V>>
+where
+    V::Scalar: RTreeNum,
+{
+    RTree::bulk_load(vectors.into_iter().map(RTreePoint3).collect())
+}
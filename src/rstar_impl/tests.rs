@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{rtree_2d, rtree_3d, RTreePoint2, RTreePoint3};
+use crate::ArrayVec3;
+use rstar::{Point, RTree};
+
+#[test]
+fn test_rtree_points() {
+    let p = RTreePoint3(ArrayVec3::new(1.0, 2.0, 3.0));
+    assert_eq!((p.nth(0), p.nth(1), p.nth(2)), (1.0, 2.0, 3.0));
+    let q = RTreePoint3::<ArrayVec3<f64>>::generate(|i| i as f64);
+    assert_eq!(q.0, ArrayVec3::new(0.0, 1.0, 2.0));
+    let mut r = RTreePoint2::from((1.0f32, 2.0f32));
+    *r.nth_mut(1) = 5.0;
+    assert_eq!(r, RTreePoint2((1.0, 5.0)));
+}
+
+#[test]
+fn test_rtree() {
+    let points = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 5.0],
+        [0.0, 2.0, 0.0],
+        [3.0, 3.0, 3.0],
+    ];
+    let tree = rtree_3d(points);
+    assert_eq!(tree.size(), 4);
+    let nearest = tree.nearest_neighbor([0.9, 0.0, 4.0].into()).unwrap();
+    assert_eq!(nearest.0, [1.0, 0.0, 5.0]);
+    // the 2D tree ignores z
+    let tree = rtree_2d(points);
+    let nearest = tree
+        .nearest_neighbor(RTreePoint2([0.9, 0.0, 100.0]))
+        .unwrap();
+    assert_eq!(nearest.0, [1.0, 0.0, 5.0]);
+
+    let mut tree = RTree::new();
+    tree.insert(RTreePoint2(ArrayVec3::new(1.0, 1.0, 9.0)));
+    assert!(tree.contains(&RTreePoint2(ArrayVec3::new(1.0, 1.0, 9.0))));
+}
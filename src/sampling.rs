@@ -0,0 +1,433 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Reproducible point set generators for benchmarks and regression tests.
+//!
+//! Every generator takes an explicit seed and produces the same points on every platform, for
+//! every vector type of the same scalar type. The points are generated inside the axis aligned
+//! box spanned by `min` and `max`.
+
+use crate::{GenericScalar, GenericVector2, GenericVector3, HasXY};
+use num_traits::Float;
+use std::ops::{Add, Mul, Sub};
+
+/// A small SplitMix64 pseudo random number generator, with a stable output sequence.
+#[derive(Debug, Clone)]
+pub struct SampleRng {
+    state: u64,
+}
+
+impl SampleRng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a uniformly distributed value in [0, 1).
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64
+    }
+
+    /// Returns a uniformly distributed value in [min, max].
+    pub fn next_in<S: GenericScalar>(&mut self, min: S, max: S) -> S {
+        lerp(min, max, self.next_f64())
+    }
+}
+
+#[inline(always)]
+fn lerp<S: GenericScalar>(min: S, max: S, t: f64) -> S {
+    min + (max - min) * S::from_f64(t).unwrap()
+}
+
+/// Maps the unit coordinates `t` into the box `min..max`, component by component.
+#[inline(always)]
+fn in_box<S: GenericScalar>(min: [S; 3], max: [S; 3], t: [f64; 3]) -> [S; 3] {
+    [
+        lerp(min[0], max[0], t[0]),
+        lerp(min[1], max[1], t[1]),
+        lerp(min[2], max[2], t[2]),
+    ]
+}
+
+#[inline(always)]
+fn array2<T: GenericVector2>(v: T) -> [T::Scalar; 3] {
+    [v.x(), v.y(), T::Scalar::ZERO]
+}
+
+#[inline(always)]
+fn array3<T: GenericVector3>(v: T) -> [T::Scalar; 3] {
+    [v.x(), v.y(), v.z()]
+}
+
+/// Returns the unit coordinates of the centers of an `n[0] * n[1] * n[2]` grid, x varying fastest.
+fn grid_cells(n: [usize; 3], mut jitter: Option<&mut SampleRng>) -> Vec<[f64; 3]> {
+    let mut cells = Vec::with_capacity(n[0] * n[1] * n[2]);
+    for k in 0..n[2] {
+        for j in 0..n[1] {
+            for i in 0..n[0] {
+                let mut t = [0.5; 3];
+                if let Some(rng) = jitter.as_mut() {
+                    t.iter_mut().for_each(|t| *t = rng.next_f64());
+                }
+                cells.push([
+                    (i as f64 + t[0]) / n[0] as f64,
+                    (j as f64 + t[1]) / n[1] as f64,
+                    (k as f64 + t[2]) / n[2] as f64,
+                ]);
+            }
+        }
+    }
+    cells
+}
+
+/// Returns the centers of the cells of an `nx` by `ny` grid spanning `min..max`, row by row.
+pub fn grid_2d<T: GenericVector2>(min: T, max: T, nx: usize, ny: usize) -> Vec<T> {
+    grid_cells([nx, ny, 1], None)
+        .into_iter()
+        .map(|t| in_box(array2(min), array2(max), t))
+        .map(|p| T::new_2d(p[0], p[1]))
+        .collect()
+}
+
+/// Returns the centers of the cells of an `n[0]` by `n[1]` by `n[2]` grid spanning `min..max`.
+pub fn grid_3d<T: GenericVector3>(min: T, max: T, n: [usize; 3]) -> Vec<T> {
+    grid_cells(n, None)
+        .into_iter()
+        .map(|t| in_box(array3(min), array3(max), t))
+        .map(|p| T::new_3d(p[0], p[1], p[2]))
+        .collect()
+}
+
+/// Returns the `i`:th of `n` evenly spaced values from `min` to `max`, both included exactly.
+#[inline(always)]
+fn node<S: GenericScalar>(min: S, max: S, i: usize, n: usize) -> S {
+    if i + 1 == n && n > 1 {
+        max
+    } else {
+        lerp(min, max, i as f64 / (n.max(2) - 1) as f64)
+    }
+}
+
+/// Returns the nodes of an `n[0] * n[1] * n[2]` grid spanning `min..max`, x varying fastest.
+fn grid_nodes<S: GenericScalar>(
+    min: [S; 3],
+    max: [S; 3],
+    n: [usize; 3],
+) -> impl Iterator<Item = [S; 3]> {
+    (0..n[2]).flat_map(move |k| {
+        (0..n[1]).flat_map(move |j| {
+            (0..n[0]).map(move |i| {
+                [
+                    node(min[0], max[0], i, n[0]),
+                    node(min[1], max[1], j, n[1]),
+                    node(min[2], max[2], k, n[2]),
+                ]
+            })
+        })
+    })
+}
+
+/// Returns `n` evenly spaced points from `a` to `b`, both included. A single point is `a`.
+pub fn linspace_points<T>(a: T, b: T, n: usize) -> impl Iterator<Item = T>
+where
+    T: HasXY + Add<T, Output = T> + Sub<T, Output = T> + Mul<T::Scalar, Output = T>,
+{
+    (0..n).map(move |i| {
+        if i == 0 {
+            a
+        } else if i + 1 == n {
+            b
+        } else {
+            a + (b - a)
+                * <T::Scalar as num_traits::FromPrimitive>::from_f64(i as f64 / (n - 1) as f64)
+                    .unwrap()
+        }
+    })
+}
+
+/// Returns the nodes of an `nx` by `ny` grid from `min` to `max`, both included, row by row.
+///
+/// Unlike `grid_2d()`, which returns the centers of the cells, the nodes are on the boundary
+/// of the box too, as with `linspace_points()` along every axis.
+pub fn meshgrid_2d<T: GenericVector2>(
+    min: T,
+    max: T,
+    nx: usize,
+    ny: usize,
+) -> impl Iterator<Item = T> {
+    grid_nodes(array2(min), array2(max), [nx, ny, 1]).map(|p| T::new_2d(p[0], p[1]))
+}
+
+/// Returns the nodes of an `n[0]` by `n[1]` by `n[2]` grid from `min` to `max`, both included,
+/// x varying fastest.
+pub fn meshgrid_3d<T: GenericVector3>(min: T, max: T, n: [usize; 3]) -> impl Iterator<Item = T> {
+    grid_nodes(array3(min), array3(max), n).map(|p| T::new_3d(p[0], p[1], p[2]))
+}
+
+/// Returns one uniformly distributed point in every cell of an `nx` by `ny` grid spanning `min..max`.
+pub fn jittered_grid_2d<T: GenericVector2>(
+    min: T,
+    max: T,
+    nx: usize,
+    ny: usize,
+    seed: u64,
+) -> Vec<T> {
+    grid_cells([nx, ny, 1], Some(&mut SampleRng::new(seed)))
+        .into_iter()
+        .map(|t| in_box(array2(min), array2(max), t))
+        .map(|p| T::new_2d(p[0], p[1]))
+        .collect()
+}
+
+/// Returns one uniformly distributed point in every cell of an `n[0]` by `n[1]` by `n[2]` grid
+/// spanning `min..max`.
+pub fn jittered_grid_3d<T: GenericVector3>(min: T, max: T, n: [usize; 3], seed: u64) -> Vec<T> {
+    grid_cells(n, Some(&mut SampleRng::new(seed)))
+        .into_iter()
+        .map(|t| in_box(array3(min), array3(max), t))
+        .map(|p| T::new_3d(p[0], p[1], p[2]))
+        .collect()
+}
+
+/// Bridson's Poisson-disk sampling in the first `dim` components of the box `min..max`.
+fn poisson_disk<S: GenericScalar>(
+    min: [S; 3],
+    max: [S; 3],
+    dim: usize,
+    radius: S,
+    seed: u64,
+) -> Vec<[S; 3]> {
+    const ATTEMPTS: usize = 30;
+    let mut rng = SampleRng::new(seed);
+    let extent: Vec<S> = (0..dim).map(|d| max[d] - min[d]).collect();
+    let valid = |s: S| Float::is_finite(s) && s >= S::ZERO;
+    if radius <= S::ZERO || !valid(radius) || !extent.iter().all(|e| valid(*e)) {
+        return Vec::new();
+    }
+    // a cell can hold at most one point, so the neighbours within two cells are all that matter
+    let cell = radius / crate::math::sqrt(S::from_usize(dim).unwrap());
+    let Some(cells) = extent
+        .iter()
+        .map(|e| Float::ceil(*e / cell).to_usize().map(|n| n.max(1)))
+        .collect::<Option<Vec<usize>>>()
+    else {
+        return Vec::new();
+    };
+    let total = match cells.iter().try_fold(1_usize, |n, c| n.checked_mul(*c)) {
+        Some(total) if total <= 1 << 26 => total,
+        _ => return Vec::new(),
+    };
+    let cell_of = |p: &[S; 3]| -> [usize; 3] {
+        let mut c = [0; 3];
+        for d in 0..dim {
+            c[d] = Float::floor((p[d] - min[d]) / cell)
+                .to_usize()
+                .unwrap_or(0)
+                .min(cells[d] - 1);
+        }
+        c
+    };
+    let index = |c: [usize; 3]| c[0] + cells[0] * (c[1] + cells[1] * c[2]);
+    let mut grid: Vec<Option<usize>> = vec![None; total];
+    let mut points: Vec<[S; 3]> = Vec::new();
+    let mut active = Vec::new();
+
+    let first = std::array::from_fn(|d| {
+        if d < dim {
+            rng.next_in(min[d], max[d])
+        } else {
+            S::ZERO
+        }
+    });
+    grid[index(cell_of(&first))] = Some(0);
+    points.push(first);
+    active.push(0);
+
+    while !active.is_empty() {
+        let a = (rng.next_u64() % active.len() as u64) as usize;
+        let center = points[active[a]];
+        let mut found = false;
+        for _ in 0..ATTEMPTS {
+            // a uniform candidate in the annulus between radius and two radii
+            let candidate: [S; 3] = loop {
+                let offset: [S; 3] = std::array::from_fn(|d| {
+                    if d < dim {
+                        rng.next_in(-radius - radius, radius + radius)
+                    } else {
+                        S::ZERO
+                    }
+                });
+                let dist_sq = offset.iter().fold(S::ZERO, |s, o| s + *o * *o);
+                if dist_sq >= radius * radius && dist_sq <= radius * radius * 4.0.into() {
+                    break std::array::from_fn(|d| center[d] + offset[d]);
+                }
+            };
+            if (0..dim).any(|d| candidate[d] < min[d] || candidate[d] > max[d]) {
+                continue;
+            }
+            let c = cell_of(&candidate);
+            let mut lo = [0; 3];
+            let mut hi = [0; 3];
+            for d in 0..dim {
+                lo[d] = c[d].saturating_sub(2);
+                hi[d] = (c[d] + 2).min(cells[d] - 1);
+            }
+            let too_close = (lo[2]..=hi[2]).any(|k| {
+                (lo[1]..=hi[1]).any(|j| {
+                    (lo[0]..=hi[0]).any(|i| {
+                        grid[index([i, j, k])].is_some_and(|q| {
+                            let q = points[q];
+                            (0..dim).fold(S::ZERO, |s, d| {
+                                s + (q[d] - candidate[d]) * (q[d] - candidate[d])
+                            }) < radius * radius
+                        })
+                    })
+                })
+            });
+            if !too_close {
+                grid[index(c)] = Some(points.len());
+                active.push(points.len());
+                points.push(candidate);
+                found = true;
+                break;
+            }
+        }
+        if !found {
+            let _ = active.swap_remove(a);
+        }
+    }
+    points
+}
+
+/// Returns an approximately maximal set of points inside `min..max` where no two points are
+/// closer than `radius`, generated with Bridson's algorithm.
+///
+/// Returns an empty set if `radius` is not positive and finite, or if the box would need more than 2^26
+/// background grid cells, including when the cell count along an axis does not fit in a `usize`.
+pub fn poisson_disk_2d<T: GenericVector2>(min: T, max: T, radius: T::Scalar, seed: u64) -> Vec<T> {
+    poisson_disk(array2(min), array2(max), 2, radius, seed)
+        .into_iter()
+        .map(|p| T::new_2d(p[0], p[1]))
+        .collect()
+}
+
+/// Returns an approximately maximal set of points inside `min..max` where no two points are
+/// closer than `radius`, generated with Bridson's algorithm.
+///
+/// Returns an empty set if `radius` is not positive and finite, or if the box would need more than 2^26
+/// background grid cells, including when the cell count along an axis does not fit in a `usize`.
+pub fn poisson_disk_3d<T: GenericVector3>(min: T, max: T, radius: T::Scalar, seed: u64) -> Vec<T> {
+    poisson_disk(array3(min), array3(max), 3, radius, seed)
+        .into_iter()
+        .map(|p| T::new_3d(p[0], p[1], p[2]))
+        .collect()
+}
+
+/// Returns the radical inverse of `index` in `base`, the van der Corput sequence.
+fn radical_inverse(base: u64, mut index: u64) -> f64 {
+    let (mut result, mut scale) = (0.0, 1.0 / base as f64);
+    while index > 0 {
+        result += (index % base) as f64 * scale;
+        index /= base;
+        scale /= base as f64;
+    }
+    result
+}
+
+/// The first `n` points of the Halton sequence with the bases 2, 3 and 5, rotated by a
+/// seeded random offset (Cranley-Patterson rotation).
+fn halton(n: usize, seed: u64) -> Vec<[f64; 3]> {
+    let mut rng = SampleRng::new(seed);
+    let offset = [rng.next_f64(), rng.next_f64(), rng.next_f64()];
+    (1..=n as u64)
+        .map(|i| {
+            let mut t = [0.0; 3];
+            for (d, base) in [2, 3, 5].into_iter().enumerate() {
+                t[d] = (radical_inverse(base, i) + offset[d]).fract();
+            }
+            t
+        })
+        .collect()
+}
+
+/// Returns the first `n` points of the rotated Halton sequence with the bases 2 and 3, in `min..max`.
+pub fn halton_2d<T: GenericVector2>(min: T, max: T, n: usize, seed: u64) -> Vec<T> {
+    halton(n, seed)
+        .into_iter()
+        .map(|t| in_box(array2(min), array2(max), t))
+        .map(|p| T::new_2d(p[0], p[1]))
+        .collect()
+}
+
+/// Returns the first `n` points of the rotated Halton sequence with the bases 2, 3 and 5, in `min..max`.
+pub fn halton_3d<T: GenericVector3>(min: T, max: T, n: usize, seed: u64) -> Vec<T> {
+    halton(n, seed)
+        .into_iter()
+        .map(|t| in_box(array3(min), array3(max), t))
+        .map(|p| T::new_3d(p[0], p[1], p[2]))
+        .collect()
+}
+
+/// The direction numbers of the first three Sobol dimensions, from the primitive polynomials
+/// x, x + 1 and x^2 + x + 1 with the initial numbers of Joe and Kuo.
+fn sobol_directions() -> [[u32; 32]; 3] {
+    let mut v = [[0_u32; 32]; 3];
+    v[0] = std::array::from_fn(|k| 1 << (31 - k));
+    v[1][0] = 1 << 31;
+    for k in 1..32 {
+        v[1][k] = v[1][k - 1] ^ (v[1][k - 1] >> 1);
+    }
+    v[2][0] = 1 << 31;
+    v[2][1] = 3 << 30;
+    for k in 2..32 {
+        v[2][k] = v[2][k - 2] ^ (v[2][k - 2] >> 2) ^ v[2][k - 1];
+    }
+    v
+}
+
+/// The first `n` points of the Sobol sequence, in gray code order, scrambled with a seeded
+/// random digital shift. The shift keeps the stratification of the sequence intact.
+fn sobol(n: usize, seed: u64) -> Vec<[f64; 3]> {
+    let v = sobol_directions();
+    let mut rng = SampleRng::new(seed);
+    let mut x: [u32; 3] = std::array::from_fn(|_| (rng.next_u64() >> 32) as u32);
+    let mut points = Vec::with_capacity(n);
+    for i in 0..n {
+        points.push(std::array::from_fn(|d| x[d] as f64 / 4_294_967_296.0));
+        let c = (i + 1).trailing_zeros() as usize;
+        if c < 32 {
+            for d in 0..3 {
+                x[d] ^= v[d][c];
+            }
+        }
+    }
+    points
+}
+
+/// Returns the first `n` points of the digitally shifted Sobol sequence, in `min..max`.
+/// For `n` a power of two every one of the `n` equal width columns and rows holds one point.
+pub fn sobol_2d<T: GenericVector2>(min: T, max: T, n: usize, seed: u64) -> Vec<T> {
+    sobol(n, seed)
+        .into_iter()
+        .map(|t| in_box(array2(min), array2(max), t))
+        .map(|p| T::new_2d(p[0], p[1]))
+        .collect()
+}
+
+/// Returns the first `n` points of the digitally shifted Sobol sequence, in `min..max`.
+pub fn sobol_3d<T: GenericVector3>(min: T, max: T, n: usize, seed: u64) -> Vec<T> {
+    sobol(n, seed)
+        .into_iter()
+        .map(|t| in_box(array3(min), array3(max), t))
+        .map(|p| T::new_3d(p[0], p[1], p[2]))
+        .collect()
+}
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Closest points and distances from a point to line segments, for 2D and 3D vectors alike, and
+//! distances to infinite lines.
+//!
+//! The segment from `a` to `b` is parameterized as `a + (b - a) * t` with `t` in [0, 1], and the
+//! segment functions return the `t` of the closest point along with the result.
+
+use crate::{math, GenericScalar, GenericVector2, GenericVector3, HasXY};
+use num_traits::Float;
+use std::ops::{Add, Mul, Sub};
+
+/// Returns the point of the segment from `a` to `b` closest to `p`, and its parameter `t` in
+/// [0, 1].
+///
+/// A degenerate segment, with `a == b` or a length that does not fit the scalar type, returns
+/// `(a, 0)`. The end points are returned exactly for `t == 0` and `t == 1`.
+#[inline(always)]
+pub fn closest_point_on_segment<T>(p: T, a: T, b: T) -> (T, T::Scalar)
+where
+    T: HasXY + Add<T, Output = T> + Sub<T, Output = T> + Mul<T::Scalar, Output = T>,
+{
+    let ab = b - a;
+    let length_sq = math::dot(ab, ab);
+    if !(length_sq > T::Scalar::ZERO && Float::is_finite(length_sq)) {
+        return (a, T::Scalar::ZERO);
+    }
+    let t = math::dot(p - a, ab) / length_sq;
+    if t <= T::Scalar::ZERO || Float::is_nan(t) {
+        (a, T::Scalar::ZERO)
+    } else if t >= T::Scalar::ONE {
+        (b, T::Scalar::ONE)
+    } else {
+        (a + ab * t, t)
+    }
+}
+
+/// Returns the distance from `p` to the segment from `a` to `b`, and the parameter `t` in [0, 1]
+/// of the closest point, see `closest_point_on_segment()`.
+#[inline(always)]
+pub fn distance_to_segment<T>(p: T, a: T, b: T) -> (T::Scalar, T::Scalar)
+where
+    T: HasXY + Add<T, Output = T> + Sub<T, Output = T> + Mul<T::Scalar, Output = T>,
+{
+    let (closest, t) = closest_point_on_segment(p, a, b);
+    let d = p - closest;
+    (math::sqrt(math::dot(d, d)), t)
+}
+
+/// Returns the signed distance from `p` to the infinite line through `a` and `b`, positive when
+/// `p` is to the left of the direction from `a` to `b` (counterclockwise) and negative to the right.
+///
+/// A degenerate line, with `a == b` or a length that does not fit the scalar type, is treated as
+/// the point `a`, and the (unsigned) distance to `a` is returned.
+#[inline(always)]
+pub fn distance_to_line_2d<T: GenericVector2>(p: T, a: T, b: T) -> T::Scalar {
+    let length = (b - a).magnitude();
+    if !(length > T::Scalar::ZERO && Float::is_finite(length)) {
+        return (p - a).magnitude();
+    }
+    (b - a).perp_dot(p - a) / length
+}
+
+/// Returns the distance from `p` to the infinite line through `a` and `b`.
+///
+/// A degenerate line, with `a == b` or a length that does not fit the scalar type, is treated as
+/// the point `a`, and the distance to `a` is returned.
+#[inline(always)]
+pub fn distance_to_line_3d<T: GenericVector3>(p: T, a: T, b: T) -> T::Scalar {
+    let length = (b - a).magnitude();
+    if !(length > T::Scalar::ZERO && Float::is_finite(length)) {
+        return (p - a).magnitude();
+    }
+    (b - a).cross(p - a).magnitude() / length
+}
@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Software float scalars, for lockstep multiplayer and consensus code that can not depend on the
+//! floating point unit of the machine.
+//!
+//! `SoftF32` and `SoftF64` hold an IEEE 754 `f32`/`f64`, but evaluate `+`, `-`, `*`, `/` and
+//! `sqrt` with the integer implementations of the `const_soft_float` crate. The results are
+//! correctly rounded, so they are the bits a conforming FPU would give, independent of the
+//! platform, compiler flags, the x87 unit or flushed denormals. Like the fixed point types they
+//! implement the float-agnostic `ScalarCore`, not `GenericScalar`, and work with
+//! `SoftFloatVec2` and `SoftFloatVec3`, the `CoreVec2` and `CoreVec3` vectors over them.
+//!
+//! Comparisons, `abs_value()` and negation only look at the bits. The conversions with
+//! `from_f64_lossy()` and `to_f64_lossy()` are the correctly rounded `as` casts, meant for the
+//! input and rendering boundaries of a simulation.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{CoreVec2, CoreVec3, ScalarCore};
+use const_soft_float::{soft_f32, soft_f64};
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+macro_rules! impl_soft_float {
+    ($soft_type:ident, $float:ty, $inner:ty, $sign_mask:expr) => {
+        impl $soft_type {
+            /// Evaluates `op` on the software float.
+            #[inline(always)]
+            fn soft(self, rhs: Self, op: fn($inner, $inner) -> $inner) -> Self {
+                Self(op(<$inner>::from(self.0), <$inner>::from(rhs.0)).0)
+            }
+        }
+
+        impl fmt::Debug for $soft_type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&self.0, f)
+            }
+        }
+
+        impl fmt::Display for $soft_type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl From<$float> for $soft_type {
+            #[inline(always)]
+            fn from(value: $float) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$soft_type> for $float {
+            #[inline(always)]
+            fn from(value: $soft_type) -> Self {
+                value.0
+            }
+        }
+
+        impl PartialEq for $soft_type {
+            #[inline(always)]
+            fn eq(&self, other: &Self) -> bool {
+                <$inner>::from(self.0) == <$inner>::from(other.0)
+            }
+        }
+
+        impl PartialOrd for $soft_type {
+            #[inline(always)]
+            fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                <$inner>::from(self.0).cmp(<$inner>::from(other.0))
+            }
+        }
+
+        impl Add for $soft_type {
+            type Output = Self;
+            #[inline(always)]
+            fn add(self, rhs: Self) -> Self {
+                self.soft(rhs, <$inner>::add)
+            }
+        }
+
+        impl Sub for $soft_type {
+            type Output = Self;
+            #[inline(always)]
+            fn sub(self, rhs: Self) -> Self {
+                self.soft(rhs, <$inner>::sub)
+            }
+        }
+
+        impl Mul for $soft_type {
+            type Output = Self;
+            #[inline(always)]
+            fn mul(self, rhs: Self) -> Self {
+                self.soft(rhs, <$inner>::mul)
+            }
+        }
+
+        impl Div for $soft_type {
+            type Output = Self;
+            #[inline(always)]
+            fn div(self, rhs: Self) -> Self {
+                self.soft(rhs, <$inner>::div)
+            }
+        }
+
+        impl Neg for $soft_type {
+            type Output = Self;
+            #[inline(always)]
+            fn neg(self) -> Self {
+                Self(<$inner>::from(self.0).neg().0)
+            }
+        }
+
+        impl AddAssign for $soft_type {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $soft_type {
+            #[inline(always)]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign for $soft_type {
+            #[inline(always)]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign for $soft_type {
+            #[inline(always)]
+            fn div_assign(&mut self, rhs: Self) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl num_traits::Zero for $soft_type {
+            #[inline(always)]
+            fn zero() -> Self {
+                Self(0.0)
+            }
+            #[inline(always)]
+            fn is_zero(&self) -> bool {
+                *self == Self(0.0)
+            }
+        }
+
+        impl num_traits::One for $soft_type {
+            #[inline(always)]
+            fn one() -> Self {
+                Self(1.0)
+            }
+        }
+
+        impl ScalarCore for $soft_type {
+            #[inline(always)]
+            fn abs_value(self) -> Self {
+                Self(<$inner>::from_bits(<$inner>::from(self.0).to_bits() & !$sign_mask).0)
+            }
+            #[inline(always)]
+            fn sqrt_value(self) -> Self {
+                Self(<$inner>::from(self.0).sqrt().0)
+            }
+            #[inline(always)]
+            fn to_f64_lossy(self) -> f64 {
+                self.0 as f64
+            }
+            #[inline(always)]
+            fn from_f64_lossy(value: f64) -> Self {
+                Self(value as $float)
+            }
+        }
+    };
+}
+
+/// An `f32` with software evaluated arithmetic.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default)]
+pub struct SoftF32(pub f32);
+
+/// An `f64` with software evaluated arithmetic.
+#[repr(transparent)]
+#[derive(Clone, Copy, Default)]
+pub struct SoftF64(pub f64);
+
+impl_soft_float!(SoftF32, f32, soft_f32::SoftF32, 1_u32 << 31);
+impl_soft_float!(SoftF64, f64, soft_f64::SoftF64, 1_u64 << 63);
+
+/// A two-dimensional vector over a software float scalar.
+pub type SoftFloatVec2<S> = CoreVec2<S>;
+
+/// A three-dimensional vector over a software float scalar.
+pub type SoftFloatVec3<S> = CoreVec3<S>;
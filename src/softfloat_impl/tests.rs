@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{SoftF32, SoftF64, SoftFloatVec2, SoftFloatVec3};
+use crate::ScalarCore;
+
+#[test]
+fn test_soft_float_scalar() {
+    crate::tests::tests::test_core_vector::<SoftF32>();
+    crate::tests::tests::test_core_vector::<SoftF64>();
+
+    // correctly rounded, so the software results are the bits of the IEEE operations, except for
+    // the sign and payload of a NaN, which differ between CPUs
+    let same =
+        |soft: f64, hard: f64| soft.to_bits() == hard.to_bits() || soft.is_nan() && hard.is_nan();
+    let values = [0.1_f64, 0.2, 3.0, -7.5, 1.0e-310, 1.0e300, f64::MAX];
+    for a in values {
+        for b in values {
+            let (sa, sb) = (SoftF64(a), SoftF64(b));
+            assert!(same((sa + sb).0, a + b));
+            assert!(same((sa - sb).0, a - b));
+            assert!(same((sa * sb).0, a * b));
+            assert!(same((sa / sb).0, a / b));
+            let (fa, fb) = (a as f32, b as f32);
+            assert!(same((SoftF32(fa) * SoftF32(fb)).0.into(), (fa * fb).into()));
+        }
+        assert_eq!(SoftF64(a).abs_value().sqrt_value().0, a.abs().sqrt());
+    }
+    assert!(SoftF64(-1.0).sqrt_value().0.is_nan());
+    assert!(SoftF32(f32::NAN) != SoftF32(f32::NAN));
+    assert!(SoftF32(-0.0) == SoftF32(0.0));
+    assert_eq!(SoftF32::from_f64_lossy(0.1).0, 0.1_f32);
+    assert_eq!(SoftF32(0.5).to_f64_lossy(), 0.5);
+    assert_eq!(format!("{} {:?}", SoftF64(1.5), SoftF32(-2.0)), "1.5 -2.0");
+}
+
+#[test]
+fn test_soft_float_vec() {
+    let v = SoftFloatVec3::<SoftF64>::from_float([1.0_f64, 2.0, 2.0]);
+    assert_eq!(v.magnitude(), SoftF64(3.0));
+    let w = SoftFloatVec2::<SoftF32>::from_float([3.0_f32, 4.0]);
+    assert_eq!(w.to_float::<[f64; 2]>(), [3.0, 4.0]);
+
+    // a lockstep step: the same bits on every platform
+    let step = |pos: SoftFloatVec2<SoftF32>, vel| pos + vel / SoftF32(60.0);
+    let mut pos = SoftFloatVec2::<SoftF32>::default();
+    for _ in 0..60 {
+        pos = step(pos, w);
+    }
+    let mut expected = [0.0_f32; 2];
+    for _ in 0..60 {
+        expected = [expected[0] + 3.0 / 60.0, expected[1] + 4.0 / 60.0];
+    }
+    assert_eq!(pos.to_float::<[f32; 2]>(), expected);
+}
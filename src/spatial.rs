@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Point cloud reduction and vertex welding on a uniform grid of cells.
+//!
+//! A point belongs to the cell of its `IntVec3` key, `IntVec3::from_float(p, cell_size)`, so the
+//! cells are centered on the multiples of `cell_size`. The output keeps the order in which the
+//! cells were first visited, so the result is reproducible for the same input.
+
+use crate::{GenericScalar, GenericVector2, GenericVector3, IntScalar, IntVec3};
+use num_traits::Float;
+use std::collections::HashMap;
+
+/// Which point `grid_downsample_2d()` and `grid_downsample_3d()` keep for every occupied cell.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CellRepresentative {
+    /// The first point of the cell, so the output is a subset of the input.
+    #[default]
+    First,
+    /// The centroid of the points of the cell.
+    Centroid,
+}
+
+/// Returns one point per occupied cell, in the order the cells were first visited.
+fn downsample<S: GenericScalar>(
+    points: impl Iterator<Item = [S; 3]>,
+    cell_size: S,
+    representative: CellRepresentative,
+) -> Vec<[S; 3]> {
+    if !(cell_size > S::ZERO && Float::is_finite(cell_size)) {
+        return Vec::new();
+    }
+    let mut cells: HashMap<IntVec3<i64>, usize> = HashMap::new();
+    // the first point of every cell, and the sum of the offsets of the others from it
+    let mut out: Vec<([S; 3], [S; 3], usize)> = Vec::new();
+    for p in points {
+        let Some(key) = cell_key(p, cell_size) else {
+            continue;
+        };
+        match cells.get(&key) {
+            Some(&i) => {
+                let (first, sum, count) = &mut out[i];
+                for k in 0..3 {
+                    sum[k] += p[k] - first[k];
+                }
+                *count += 1;
+            }
+            None => {
+                let _ = cells.insert(key, out.len());
+                out.push((p, [S::ZERO; 3], 1));
+            }
+        }
+    }
+    out.into_iter()
+        .map(|(first, sum, count)| match representative {
+            CellRepresentative::First => first,
+            CellRepresentative::Centroid => {
+                let n = S::from_usize(count).unwrap();
+                std::array::from_fn(|k| first[k] + sum[k] / n)
+            }
+        })
+        .collect()
+}
+
+/// Returns the key of the cell of `p`, the same key as `IntVec3::from_float()` gives.
+#[inline(always)]
+fn cell_key<S: GenericScalar>(p: [S; 3], cell_size: S) -> Option<IntVec3<i64>> {
+    Some(IntVec3::new(
+        i64::from_scalar(p[0] / cell_size)?,
+        i64::from_scalar(p[1] / cell_size)?,
+        i64::from_scalar(p[2] / cell_size)?,
+    ))
+}
+
+/// Welds the points closer than `tolerance` to an earlier kept point, returning the kept points
+/// and the index of the kept point of every input point.
+fn weld<S: GenericScalar>(
+    points: impl ExactSizeIterator<Item = [S; 3]>,
+    tolerance: S,
+    dim: usize,
+) -> (Vec<[S; 3]>, Vec<u32>) {
+    let mut kept: Vec<[S; 3]> = Vec::new();
+    let mut remap = Vec::with_capacity(points.len());
+    let welding = tolerance > S::ZERO && Float::is_finite(tolerance);
+    // with cells twice the tolerance, points within the tolerance have keys at most one apart
+    let cell_size = tolerance * S::TWO;
+    let tolerance_sq = tolerance * tolerance;
+    let mut grid: HashMap<IntVec3<i64>, Vec<u32>> = HashMap::new();
+    // without a usable tolerance only bitwise equal points are welded
+    let mut exact: HashMap<[u64; 3], u32> = HashMap::new();
+    let neighbours: &[i64] = &[-1, 0, 1];
+    let z_neighbours: &[i64] = if dim == 3 { neighbours } else { &[0] };
+    for p in points {
+        let index = u32::try_from(kept.len()).expect("more than u32::MAX welded points");
+        let key = if welding {
+            cell_key(p, cell_size)
+        } else {
+            None
+        };
+        let Some(key) = key else {
+            let bits = p.map(|c| Into::<f64>::into(c.canonicalize()).to_bits());
+            let found = *exact.entry(bits).or_insert(index);
+            if found == index {
+                kept.push(p);
+            }
+            remap.push(found);
+            continue;
+        };
+        let mut found: Option<u32> = None;
+        for dx in neighbours {
+            for dy in neighbours {
+                for dz in z_neighbours {
+                    // a key at the edge of the i64 range has no neighbour beyond it
+                    let (Some(x), Some(y), Some(z)) = (
+                        key.x.checked_add(*dx),
+                        key.y.checked_add(*dy),
+                        key.z.checked_add(*dz),
+                    ) else {
+                        continue;
+                    };
+                    for &i in grid.get(&IntVec3::new(x, y, z)).into_iter().flatten() {
+                        let q = kept[i as usize];
+                        let d = (0..3).fold(S::ZERO, |d, k| d + (p[k] - q[k]) * (p[k] - q[k]));
+                        if d <= tolerance_sq {
+                            found = Some(found.map_or(i, |f| f.min(i)));
+                        }
+                    }
+                }
+            }
+        }
+        if let Some(found) = found {
+            remap.push(found);
+        } else {
+            grid.entry(key).or_default().push(index);
+            kept.push(p);
+            remap.push(index);
+        }
+    }
+    (kept, remap)
+}
+
+/// Reduces `points` to one point per occupied cell of size `cell_size`.
+///
+/// Points with coordinates that are not finite, or too large for the cell key, are dropped.
+/// Returns an empty set if `cell_size` is not positive and finite.
+pub fn grid_downsample_2d<T: GenericVector2>(
+    points: &[T],
+    cell_size: T::Scalar,
+    representative: CellRepresentative,
+) -> Vec<T> {
+    downsample(
+        points.iter().map(|v| [v.x(), v.y(), T::Scalar::ZERO]),
+        cell_size,
+        representative,
+    )
+    .into_iter()
+    .map(|p| T::new_2d(p[0], p[1]))
+    .collect()
+}
+
+/// Reduces `points` to one point per occupied cell of size `cell_size`.
+///
+/// Points with coordinates that are not finite, or too large for the cell key, are dropped.
+/// Returns an empty set if `cell_size` is not positive and finite.
+pub fn grid_downsample_3d<T: GenericVector3>(
+    points: &[T],
+    cell_size: T::Scalar,
+    representative: CellRepresentative,
+) -> Vec<T> {
+    downsample(
+        points.iter().map(|v| [v.x(), v.y(), v.z()]),
+        cell_size,
+        representative,
+    )
+    .into_iter()
+    .map(|p| T::new_3d(p[0], p[1], p[2]))
+    .collect()
+}
+
+/// Merges every point within `tolerance` of an earlier kept point into that point, and returns
+/// the kept points with the index of the kept point of every input point.
+///
+/// The earliest kept point within the tolerance wins, so the result depends on the input order
+/// but not on the grid. If `tolerance` is not positive and finite, only points with equal
+/// coordinates are merged, and that is also the case for points too large for the grid keys.
+///
+/// # Panics
+/// If more than `u32::MAX` points are kept.
+pub fn weld_vertices_2d<T: GenericVector2>(
+    points: &[T],
+    tolerance: T::Scalar,
+) -> (Vec<T>, Vec<u32>) {
+    let (kept, remap) = weld(
+        points.iter().map(|v| [v.x(), v.y(), T::Scalar::ZERO]),
+        tolerance,
+        2,
+    );
+    (
+        kept.into_iter().map(|p| T::new_2d(p[0], p[1])).collect(),
+        remap,
+    )
+}
+
+/// Merges every point within `tolerance` of an earlier kept point into that point, and returns
+/// the kept points with the index of the kept point of every input point.
+///
+/// The earliest kept point within the tolerance wins, so the result depends on the input order
+/// but not on the grid. If `tolerance` is not positive and finite, only points with equal
+/// coordinates are merged, and that is also the case for points too large for the grid keys.
+///
+/// # Panics
+/// If more than `u32::MAX` points are kept.
+pub fn weld_vertices_3d<T: GenericVector3>(
+    points: &[T],
+    tolerance: T::Scalar,
+) -> (Vec<T>, Vec<u32>) {
+    let (kept, remap) = weld(points.iter().map(|v| [v.x(), v.y(), v.z()]), tolerance, 3);
+    (
+        kept.into_iter()
+            .map(|p| T::new_3d(p[0], p[1], p[2]))
+            .collect(),
+        remap,
+    )
+}
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Single pass statistics of streamed point sets, for clouds too large to keep in memory.
+
+use crate::{GenericScalar, HasXY};
+use num_traits::{Float, FromPrimitive};
+use std::ops::{Add, Div, Sub};
+
+/// Accumulates the count, mean, per axis minimum and maximum, and per axis variance of vectors
+/// pushed one at a time, for 2D and 3D vectors alike.
+///
+/// The mean and variance are updated with Welford's algorithm, which stays accurate for points far
+/// from the origin where the naive sum of squares cancels catastrophically. The per axis values are
+/// indexed like `HasXY::get()`, and are `None` for an axis `>= V::DIM` or before the first push.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RunningStats<V: HasXY> {
+    count: usize,
+    mean: Option<V>,
+    /// The sum of the squared differences from the mean, per axis.
+    m2: [V::Scalar; 3],
+    min: [V::Scalar; 3],
+    max: [V::Scalar; 3],
+}
+
+impl<V: HasXY> Default for RunningStats<V> {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            mean: None,
+            m2: [V::Scalar::ZERO; 3],
+            min: [V::Scalar::INFINITY; 3],
+            max: [V::Scalar::NEG_INFINITY; 3],
+        }
+    }
+}
+
+impl<V> RunningStats<V>
+where
+    V: HasXY + Add<V, Output = V> + Sub<V, Output = V> + Div<V::Scalar, Output = V>,
+{
+    /// Creates an empty accumulator.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a vector to the statistics.
+    ///
+    /// NaN components are ignored by the minimum and maximum, but make the mean and variance NaN.
+    pub fn push(&mut self, v: V) {
+        self.count += 1;
+        let old_mean = self.mean.unwrap_or(v);
+        let mean = old_mean + (v - old_mean) / V::Scalar::from_usize(self.count).unwrap();
+        for i in 0..V::DIM {
+            if let (Some(c), Some(old), Some(new)) = (v.get(i), old_mean.get(i), mean.get(i)) {
+                self.m2[i] += (c - old) * (c - new);
+                self.min[i] = Float::min(self.min[i], c);
+                self.max[i] = Float::max(self.max[i], c);
+            }
+        }
+        self.mean = Some(mean);
+    }
+
+    /// Returns the number of pushed vectors.
+    #[inline(always)]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the pushed vectors, or `None` if there are none.
+    #[inline(always)]
+    pub fn mean(&self) -> Option<V> {
+        self.mean
+    }
+
+    /// Returns the smallest value along `axis`.
+    #[inline(always)]
+    pub fn min(&self, axis: usize) -> Option<V::Scalar> {
+        self.axis_value(&self.min, axis)
+    }
+
+    /// Returns the largest value along `axis`.
+    #[inline(always)]
+    pub fn max(&self, axis: usize) -> Option<V::Scalar> {
+        self.axis_value(&self.max, axis)
+    }
+
+    /// Returns the population variance along `axis`, the mean of the squared differences from
+    /// the mean.
+    #[inline(always)]
+    pub fn variance(&self, axis: usize) -> Option<V::Scalar> {
+        Some(self.axis_value(&self.m2, axis)? / V::Scalar::from_usize(self.count)?)
+    }
+
+    /// Returns the sample variance along `axis`, with Bessel's correction. `None` for fewer than
+    /// two vectors.
+    #[inline(always)]
+    pub fn sample_variance(&self, axis: usize) -> Option<V::Scalar> {
+        if self.count < 2 {
+            return None;
+        }
+        Some(self.axis_value(&self.m2, axis)? / V::Scalar::from_usize(self.count - 1)?)
+    }
+
+    /// Returns the population standard deviation along `axis`, the square root of `variance()`.
+    #[inline(always)]
+    pub fn std_dev(&self, axis: usize) -> Option<V::Scalar> {
+        self.variance(axis).map(crate::math::sqrt)
+    }
+
+    #[inline(always)]
+    fn axis_value(&self, values: &[V::Scalar; 3], axis: usize) -> Option<V::Scalar> {
+        (self.count > 0 && axis < V::DIM).then(|| values[axis])
+    }
+}
+
+impl<V> Extend<V> for RunningStats<V>
+where
+    V: HasXY + Add<V, Output = V> + Sub<V, Output = V> + Div<V::Scalar, Output = V>,
+{
+    fn extend<I: IntoIterator<Item = V>>(&mut self, iter: I) {
+        for v in iter {
+            self.push(v);
+        }
+    }
+}
+
+impl<V> FromIterator<V> for RunningStats<V>
+where
+    V: HasXY + Add<V, Output = V> + Sub<V, Output = V> + Div<V::Scalar, Output = V>,
+{
+    fn from_iter<I: IntoIterator<Item = V>>(iter: I) -> Self {
+        let mut stats = Self::new();
+        stats.extend(iter);
+        stats
+    }
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Compensated summation helpers, for sums over many vectors where naive accumulation
+//! would lose most of the significant digits.
+
+use crate::{GenericScalar, HasXY};
+use num_traits::{Float, FromPrimitive};
+use std::ops::{Add, Mul, Sub};
+
+/// A Kahan-Babuška-Neumaier compensated sum accumulator.
+///
+/// The rounding error of every addition is tracked separately and added back at the end,
+/// so the result is about as accurate as if the sum was computed in twice the precision.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct CompensatedSum<S: GenericScalar> {
+    sum: S,
+    compensation: S,
+}
+
+impl<S: GenericScalar> CompensatedSum<S> {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            sum: S::ZERO,
+            compensation: S::ZERO,
+        }
+    }
+
+    /// Adds `value` to the sum.
+    #[inline(always)]
+    pub fn add(&mut self, value: S) {
+        let t = self.sum + value;
+        if Float::abs(self.sum) >= Float::abs(value) {
+            self.compensation += (self.sum - t) + value;
+        } else {
+            self.compensation += (value - t) + self.sum;
+        }
+        self.sum = t;
+    }
+
+    /// Returns the compensated sum of all the added values.
+    #[inline(always)]
+    pub fn value(&self) -> S {
+        self.sum + self.compensation
+    }
+}
+
+impl<S: GenericScalar> Extend<S> for CompensatedSum<S> {
+    fn extend<I: IntoIterator<Item = S>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|v| self.add(v))
+    }
+}
+
+impl<S: GenericScalar> FromIterator<S> for CompensatedSum<S> {
+    fn from_iter<I: IntoIterator<Item = S>>(iter: I) -> Self {
+        let mut sum = Self::new();
+        sum.extend(iter);
+        sum
+    }
+}
+
+/// Adds the squared component differences of `a` and `b` to `sum`.
+#[inline(always)]
+fn add_distance_sq<T: HasXY>(sum: &mut CompensatedSum<T::Scalar>, a: T, b: T) {
+    for (p, q) in (0..T::DIM).filter_map(|i| Some((a.get(i)?, b.get(i)?))) {
+        sum.add((p - q) * (p - q));
+    }
+}
+
+/// Returns the sum of the squared distances from every point to `reference`, using compensated
+/// accumulation.
+pub fn sum_squared_distances<T: HasXY>(points: &[T], reference: T) -> T::Scalar {
+    let mut sum = CompensatedSum::new();
+    for p in points {
+        add_distance_sq(&mut sum, *p, reference);
+    }
+    sum.value()
+}
+
+/// Returns the mean of the squared distances from every point to `reference`, using compensated
+/// accumulation. Returns `None` if `points` is empty.
+pub fn mean_squared_distance<T: HasXY>(points: &[T], reference: T) -> Option<T::Scalar> {
+    let n = T::Scalar::from_usize(points.len())?;
+    (!points.is_empty()).then(|| sum_squared_distances(points, reference) / n)
+}
+
+/// Returns the mean squared error between corresponding vectors of `a` and `b`, using compensated
+/// accumulation. Returns `None` if the slices are empty or have different lengths.
+pub fn mean_squared_error<T: HasXY>(a: &[T], b: &[T]) -> Option<T::Scalar> {
+    if a.is_empty() || a.len() != b.len() {
+        return None;
+    }
+    let mut sum = CompensatedSum::new();
+    for (p, q) in a.iter().zip(b.iter()) {
+        add_distance_sq(&mut sum, *p, *q);
+    }
+    Some(sum.value() / T::Scalar::from_usize(a.len())?)
+}
+
+/// Returns the weighted average of the points, with the weights normalized to sum to one.
+///
+/// Returns `None` if there are no points, if any weight is negative or not finite, or if the
+/// weights sum to zero. The points are averaged as offsets from the first point, so clouds far
+/// from the origin keep their precision.
+pub fn convex_combination<T, I>(points_and_weights: I) -> Option<T>
+where
+    T: HasXY + Add<T, Output = T> + Sub<T, Output = T> + Mul<T::Scalar, Output = T>,
+    I: IntoIterator<Item = (T, T::Scalar)>,
+{
+    let mut iter = points_and_weights.into_iter();
+    let (first, mut total) = iter.next()?;
+    if !(total >= T::Scalar::ZERO && Float::is_finite(total)) {
+        return None;
+    }
+    let mut sum = first * T::Scalar::ZERO;
+    for (p, w) in iter {
+        if !(w >= T::Scalar::ZERO && Float::is_finite(w)) {
+            return None;
+        }
+        sum = sum + (p - first) * w;
+        total += w;
+    }
+    (total > T::Scalar::ZERO && Float::is_finite(total))
+        .then(|| first + sum * (T::Scalar::ONE / total))
+}
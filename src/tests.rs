@@ -3,7 +3,12 @@
 
 // This file is part of vector-traits.
 
+#[cfg(all(feature = "glam", feature = "cgmath"))]
+mod consistency;
+mod ffi;
+
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 pub mod tests {
     use crate::{GenericScalar, GenericVector2, GenericVector3, HasXY, HasXYZ};
     use approx::{AbsDiffEq, UlpsEq};
@@ -30,6 +35,17 @@ pub mod tests {
         assert_eq!(v1.x(), x * mult);
         assert_eq!(v1.y(), y * mult);
 
+        assert_eq!(v1.get(0), Some(x * mult));
+        assert_eq!(v1.get(1), Some(y * mult));
+        assert_eq!(v1.get(T::DIM), None);
+
+        v1.set_xy(x, y);
+        assert_eq!(v1.x(), x);
+        assert_eq!(v1.y(), y);
+        v1.set_from_array([y, x]);
+        assert_eq!(v1.x(), y);
+        assert_eq!(v1.y(), x);
+
         let n = T::Scalar::INFINITY;
         assert!(!n.is_normal());
         assert!(!n.is_finite());
@@ -37,12 +53,58 @@ pub mod tests {
         let b: T::Scalar = 6.0.into();
         let c: T::Scalar = 8.0.into();
 
-        assert_eq!(b.clamp(a, c), b);
-        assert_eq!(a.clamp(b, c), b);
+        assert_eq!(GenericScalar::clamp(b, a, c), b);
+        assert_eq!(GenericScalar::clamp(a, b, c), b);
+
+        let three: T::Scalar = 3.0.into();
+        let four: T::Scalar = 4.0.into();
+        assert_eq!(crate::math::sqrt(four * four), four);
+        assert_eq!(crate::math::hypot(three, four), a);
+        assert_eq!(crate::math::sin(T::Scalar::ZERO), T::Scalar::ZERO);
+        assert_eq!(crate::math::cos(T::Scalar::ZERO), T::Scalar::ONE);
+        assert_eq!(crate::math::acos(T::Scalar::ONE), T::Scalar::ZERO);
+        assert_eq!(
+            crate::math::atan2(T::Scalar::ZERO, T::Scalar::ONE),
+            T::Scalar::ZERO
+        );
+
+        let neg_zero = -T::Scalar::ZERO;
+        assert_ne!(neg_zero.to_bits(), T::Scalar::ZERO.to_bits());
+        assert_eq!(neg_zero.canonicalize().to_bits(), T::Scalar::ZERO.to_bits());
+        let nan = -<T::Scalar as num_traits::Float>::nan();
+        assert_eq!(
+            nan.canonicalize().to_bits(),
+            <T::Scalar as num_traits::Float>::nan().to_bits()
+        );
+        assert_eq!(a.canonicalize(), a);
+        let v = T::new_2d(neg_zero, nan).canonicalize();
+        assert_eq!(v.x().to_bits(), T::Scalar::ZERO.to_bits());
+        assert_eq!(
+            v.y().to_bits(),
+            <T::Scalar as num_traits::Float>::nan().to_bits()
+        );
+
+        let denormal = <T::Scalar as num_traits::Float>::min_positive_value() / 4.0.into();
+        assert!(denormal.is_denormal());
+        assert!(!T::Scalar::ZERO.is_denormal());
+        assert!(!a.is_denormal());
+        assert_eq!(
+            denormal.flush_denormal().to_bits(),
+            T::Scalar::ZERO.to_bits()
+        );
+        assert_eq!((-denormal).flush_denormal().to_bits(), neg_zero.to_bits());
+        assert_eq!(a.flush_denormal(), a);
+        let v = T::new_2d(a, denormal);
+        assert!(v.is_denormal());
+        let v = v.flush_denormals();
+        assert!(!v.is_denormal());
+        assert_eq!(v.x(), a);
+        assert_eq!(v.y(), T::Scalar::ZERO);
     }
 
     #[allow(dead_code)]
     pub fn test_xyz<T: HasXYZ>(x: T::Scalar, y: T::Scalar, z: T::Scalar) {
+        assert_eq!(T::DIM, 3);
         let v0 = T::new_3d(x, y, z);
         assert_eq!(v0.x(), x);
         assert_eq!(v0.y(), y);
@@ -63,10 +125,39 @@ pub mod tests {
         assert_eq!(v1.x(), x * mult);
         assert_eq!(v1.y(), y * mult);
         assert_eq!(v1.z(), z * mult);
+
+        assert_eq!(v1.get(0), Some(x * mult));
+        assert_eq!(v1.get(1), Some(y * mult));
+        assert_eq!(v1.get(2), Some(z * mult));
+        assert_eq!(v1.get(3), None);
+
+        v1.set_xyz(x, y, z);
+        assert_eq!(v1.x(), x);
+        assert_eq!(v1.y(), y);
+        assert_eq!(v1.z(), z);
+        v1.set_from_array3([z, y, x]);
+        assert_eq!(v1.x(), z);
+        assert_eq!(v1.y(), y);
+        assert_eq!(v1.z(), x);
+
+        let v2 = T::new_3d(-T::Scalar::ZERO, x, -T::Scalar::ZERO).canonicalize();
+        assert_eq!(v2.x().to_bits(), T::Scalar::ZERO.to_bits());
+        assert_eq!(v2.y(), x);
+        assert_eq!(v2.z().to_bits(), T::Scalar::ZERO.to_bits());
+
+        let denormal = <T::Scalar as num_traits::Float>::min_positive_value() / 4.0.into();
+        let v2 = T::new_3d(x, y, -denormal);
+        assert!(v2.is_denormal());
+        let v2 = v2.flush_denormals();
+        assert!(!v2.is_denormal());
+        assert_eq!((v2.x(), v2.y()), (x, y));
+        assert_eq!(v2.z().to_bits(), (-T::Scalar::ZERO).to_bits());
     }
 
     #[allow(dead_code)]
     pub fn test_gxy<T: GenericVector2>(x: T::Scalar, y: T::Scalar, z: T::Scalar) {
+        assert_eq!(T::DIM, 2);
+        assert_eq!(T::Vector3::DIM, 3);
         let mut v0 = T::new_2d(x, y);
         assert_eq!(v0.x(), x);
         assert_eq!(v0.y(), y);
@@ -92,24 +183,30 @@ pub mod tests {
         assert_eq!(s, 4_i8.into());
 
         let a: T::Scalar = 4.0.into();
-        assert_eq!(4_u32, a.as_(),);
-        assert_eq!(4_usize, a.as_());
-        assert_eq!(4_f32, a.as_());
-        assert_eq!(4_f64, a.as_());
-        assert_eq!(4_usize, a.as_());
-        assert_eq!(4_isize, a.as_());
-        assert_eq!(4_u64, a.as_());
-        assert_eq!(4_i64, a.as_());
-        assert_eq!(4_u32, a.as_());
-        assert_eq!(4_i32, a.as_());
-        assert_eq!(4_u16, a.as_());
-        assert_eq!(4_i16, a.as_());
-        assert_eq!(4_u8, a.as_());
-        assert_eq!(4_i8, a.as_());
+        assert_eq!(4_u32, AsPrimitive::<u32>::as_(a));
+        assert_eq!(4_usize, AsPrimitive::<usize>::as_(a));
+        assert_eq!(4_f32, AsPrimitive::<f32>::as_(a));
+        assert_eq!(4_f64, AsPrimitive::<f64>::as_(a));
+        assert_eq!(4_usize, AsPrimitive::<usize>::as_(a));
+        assert_eq!(4_isize, AsPrimitive::<isize>::as_(a));
+        assert_eq!(4_u64, AsPrimitive::<u64>::as_(a));
+        assert_eq!(4_i64, AsPrimitive::<i64>::as_(a));
+        assert_eq!(4_u32, AsPrimitive::<u32>::as_(a));
+        assert_eq!(4_i32, AsPrimitive::<i32>::as_(a));
+        assert_eq!(4_u16, AsPrimitive::<u16>::as_(a));
+        assert_eq!(4_i16, AsPrimitive::<i16>::as_(a));
+        assert_eq!(4_u8, AsPrimitive::<u8>::as_(a));
+        assert_eq!(4_i8, AsPrimitive::<i8>::as_(a));
 
         v0 += -v1 - v1 + v1 + v1;
         assert_eq!(v0[0], x);
         assert_eq!(v0[1], y);
+
+        v0 -= v1;
+        v0 *= mult;
+        v0 /= mult * mult;
+        assert_eq!(v0.x(), (x - x * mult) / mult);
+        assert_eq!(v0.y(), (y - y * mult) / mult);
     }
 
     #[allow(dead_code)]
@@ -136,6 +233,15 @@ pub mod tests {
             T::Scalar::default_max_ulps()
         ));
         assert!(!v0.is_abs_diff_eq(v1, T::Scalar::default_epsilon()));
+        assert!(!v0.is_approx_eq(v1));
+        assert!(v1.is_approx_eq(v1));
+
+        let big = T::new_2d(x * 1.0e6.into(), y);
+        let nudged = T::new_2d(big.x(), y + 0.01.into());
+        assert!(!big.is_ulps_eq(nudged, T::Scalar::default_epsilon(), 4));
+        assert!(big.is_eq_relative_to_norm(nudged, 1.0e-6.into()));
+        assert!(!v0.is_eq_relative_to_norm(v1, 0.5.into()));
+        assert!(v0.is_eq_relative_to_norm(v1, 1.0.into()));
 
         let v2 = v0.to_3d(z) * mult;
         assert_eq!(v2.x(), x * mult);
@@ -156,6 +262,15 @@ pub mod tests {
             magnitude_sq
         );
 
+        // Test magnitude_hypot
+        assert!((v0.magnitude_hypot() - magnitude).abs() < epsilon);
+        let max = <T::Scalar as num_traits::Float>::max_value();
+        let big = T::new_2d(max / 2.0.into(), max / 2.0.into());
+        assert!(big.magnitude().is_infinite());
+        assert!(big.magnitude_hypot().is_finite());
+        let tiny = <T::Scalar as num_traits::Float>::min_positive_value();
+        assert!(T::new_2d(tiny, tiny).magnitude_hypot() > tiny);
+
         // Test dot product
         let dot = v0.dot(v1);
         assert_eq!(dot, (x * x * mult + y * y * mult));
@@ -184,18 +299,45 @@ pub mod tests {
 
         // Test safe_normalize
         let safe_normalized = v0.safe_normalize();
-        match safe_normalized {
-            Some(v) => assert!(
+        if let Some(v) = safe_normalized {
+            assert!(
                 (v.magnitude() - T::Scalar::ONE) < epsilon,
                 "{} != {}",
                 v.magnitude(),
                 T::Scalar::from(1.0)
-            ),
-            None => {}
+            )
         };
 
+        // Test angle_between
+        let right_angle = T::new_2d(-y, x);
+        let half_pi = <T::Scalar as num_traits::FloatConst>::FRAC_PI_2();
+        assert!((v0.angle_between(right_angle) - half_pi).abs() < epsilon);
+        assert!((right_angle.angle_between(v0) - half_pi).abs() < epsilon);
+        assert_eq!(v0.angle_between(v1), T::Scalar::ZERO);
+        let pi = <T::Scalar as num_traits::FloatConst>::PI();
+        assert!((v0.angle_between(-v0) - pi).abs() < epsilon);
+        // nearly parallel vectors must not NaN out
+        let nudged = T::new_2d(x, y + T::Scalar::EPSILON);
+        let angle = v0.angle_between(nudged);
+        assert!(angle.is_finite() && angle < crate::math::sqrt(epsilon));
+
         let v0 = T::new_2d(T::Scalar::ZERO, T::Scalar::ZERO);
         assert!(v0.safe_normalize().is_none());
+        // short vectors are normalized, subnormal lengths are rejected
+        let tiny = T::Scalar::EPSILON * T::Scalar::EPSILON;
+        assert!(T::new_2d(tiny, T::Scalar::ZERO)
+            .safe_normalize()
+            .is_some_and(|v| v.is_abs_diff_eq(
+                T::new_2d(T::Scalar::ONE, T::Scalar::ZERO),
+                T::Scalar::EPSILON
+            )));
+        let subnormal = <T::Scalar as num_traits::Float>::min_positive_value() / 4.0.into();
+        assert!(T::new_2d(subnormal, subnormal).safe_normalize().is_none());
+        assert!(T::new_2d(T::Scalar::INFINITY, x).safe_normalize().is_none());
+        assert!(T::new_2d(x, y).safe_normalize_eps(x + y).is_none());
+        assert!(T::new_2d(tiny, T::Scalar::ZERO)
+            .safe_normalize_eps(T::Scalar::ZERO)
+            .is_some());
         assert!(v0.is_ulps_eq(
             v0,
             T::Scalar::default_epsilon(),
@@ -221,6 +363,12 @@ pub mod tests {
         assert_eq!(v1.x(), x * mult);
         assert_eq!(v1.y(), y * mult);
         assert_eq!(v1.z(), z * mult);
+
+        let mut v2 = v1;
+        v2 /= mult;
+        v2 -= v0;
+        v2 *= mult;
+        assert_eq!(v2, T::new_3d(T::Scalar::ZERO, T::Scalar::ZERO, z * mult));
     }
 
     #[allow(dead_code)]
@@ -255,6 +403,10 @@ pub mod tests {
             T::Scalar::default_max_ulps()
         ));
         assert!(!v0.is_abs_diff_eq(v1, T::Scalar::default_epsilon()));
+        assert!(!v0.is_approx_eq(v1));
+        assert!(v1.is_approx_eq(v1));
+        assert!(!v0.is_eq_relative_to_norm(v1, 0.5.into()));
+        assert!(v1.is_eq_relative_to_norm(v1, T::Scalar::ZERO));
 
         // Test magnitude and magnitude_sq
         let magnitude = v0.magnitude();
@@ -266,6 +418,13 @@ pub mod tests {
             magnitude_sq
         );
 
+        // Test magnitude_hypot
+        assert!((v0.magnitude_hypot() - magnitude).abs() < epsilon);
+        let max = <T::Scalar as num_traits::Float>::max_value();
+        let big = T::new_3d(max / 2.0.into(), T::Scalar::ZERO, max / 2.0.into());
+        assert!(big.magnitude().is_infinite());
+        assert!(big.magnitude_hypot().is_finite());
+
         // Test dot product
         let dot = v0.dot(v1);
         assert_eq!(dot, (x * x * mult + y * y * mult + z * z * mult));
@@ -299,17 +458,45 @@ pub mod tests {
 
         // Test safe_normalize
         let safe_normalized = v0.safe_normalize();
-        match safe_normalized {
-            Some(v) => assert!(
+        if let Some(v) = safe_normalized {
+            assert!(
                 (v.magnitude() - T::Scalar::ONE) < epsilon,
                 "{} != {}",
                 v.magnitude(),
                 T::Scalar::from(1.0)
-            ),
-            None => {}
+            )
         };
+        // Test angle_between
+        let half_pi = <T::Scalar as num_traits::FloatConst>::FRAC_PI_2();
+        let ortho = T::new_3d(-y, x, T::Scalar::ZERO);
+        let v2 = T::new_3d(x, y, T::Scalar::ZERO);
+        assert!((v2.angle_between(ortho) - half_pi).abs() < epsilon);
+        assert_eq!(v0.angle_between(v1), T::Scalar::ZERO);
+        let nudged = T::new_3d(x, y, z + T::Scalar::EPSILON);
+        let angle = v0.angle_between(nudged);
+        assert!(angle.is_finite() && angle < crate::math::sqrt(epsilon));
+
         let v0 = T::new_3d(T::Scalar::ZERO, T::Scalar::ZERO, T::Scalar::ZERO);
         assert!(v0.safe_normalize().is_none());
+        // short vectors are normalized, subnormal lengths are rejected
+        let tiny = T::Scalar::EPSILON * T::Scalar::EPSILON;
+        assert!(T::new_3d(T::Scalar::ZERO, tiny, T::Scalar::ZERO)
+            .safe_normalize()
+            .is_some_and(|v| v.is_abs_diff_eq(
+                T::new_3d(T::Scalar::ZERO, T::Scalar::ONE, T::Scalar::ZERO),
+                T::Scalar::EPSILON
+            )));
+        let subnormal = <T::Scalar as num_traits::Float>::min_positive_value() / 4.0.into();
+        assert!(T::new_3d(subnormal, subnormal, subnormal)
+            .safe_normalize()
+            .is_none());
+        assert!(T::new_3d(x, T::Scalar::NEG_INFINITY, z)
+            .safe_normalize()
+            .is_none());
+        assert!(T::new_3d(x, y, z).safe_normalize_eps(x + y + z).is_none());
+        assert!(T::new_3d(tiny, T::Scalar::ZERO, T::Scalar::ZERO)
+            .safe_normalize_eps(T::Scalar::ZERO)
+            .is_some());
         assert!(v0.is_ulps_eq(
             v0,
             T::Scalar::default_epsilon(),
@@ -317,4 +504,2013 @@ pub mod tests {
         ));
         assert!(v0.is_abs_diff_eq(v0, T::Scalar::default_epsilon()));
     }
+
+    #[allow(dead_code)]
+    pub fn test_slices_approx<T: GenericVector2>(x: T::Scalar, y: T::Scalar) {
+        use crate::{slices_abs_diff_eq, slices_ulps_eq, SliceMismatch};
+        let a = [T::new_2d(x, y), T::new_2d(y, x), T::new_2d(x, x)];
+        let mut b = a;
+        assert_eq!(
+            slices_abs_diff_eq(&a, &b, T::Scalar::default_epsilon()),
+            Ok(())
+        );
+        assert_eq!(
+            slices_ulps_eq(
+                &a,
+                &b,
+                T::Scalar::default_epsilon(),
+                T::Scalar::default_max_ulps()
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            slices_abs_diff_eq(&a, &b[..2], T::Scalar::default_epsilon()),
+            Err(SliceMismatch::Length { left: 3, right: 2 })
+        );
+        let delta: T::Scalar = 2.0.into();
+        b[1].set_y(b[1].y() + delta);
+        b[2].set_x(b[2].x() + delta);
+        let mismatch = SliceMismatch::Value {
+            index: 1,
+            left: a[1],
+            right: b[1],
+            delta,
+        };
+        let expected = Err(mismatch);
+        assert_eq!(
+            slices_abs_diff_eq(&a, &b, T::Scalar::default_epsilon()),
+            expected
+        );
+        assert_eq!(
+            slices_ulps_eq(
+                &a,
+                &b,
+                T::Scalar::default_epsilon(),
+                T::Scalar::default_max_ulps()
+            ),
+            expected
+        );
+        assert!(mismatch.to_string().contains("index 1"));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_assert_vec_macros<T: GenericVector2>(x: T::Scalar, y: T::Scalar) {
+        use std::panic::AssertUnwindSafe;
+        let a = T::new_2d(x, y);
+        let b = T::new_2d(x, y + 1.0.into());
+        crate::assert_vec_abs_diff_eq!(a, a);
+        crate::assert_vec_abs_diff_eq!(a, b, epsilon = 2.0.into());
+        crate::assert_vec_ulps_eq!(a, a);
+        crate::assert_vec_ulps_eq!(a, b, epsilon = 2.0.into(), max_ulps = 4);
+
+        let msg =
+            std::panic::catch_unwind(AssertUnwindSafe(|| crate::assert_vec_abs_diff_eq!(a, b)))
+                .unwrap_err()
+                .downcast::<String>()
+                .unwrap();
+        assert!(msg.contains("delta: 1.0"), "{}", msg);
+        let msg = std::panic::catch_unwind(AssertUnwindSafe(|| crate::assert_vec_ulps_eq!(a, b)))
+            .unwrap_err()
+            .downcast::<String>()
+            .unwrap();
+        assert!(msg.contains("delta: 1.0"), "{}", msg);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_axis<T: GenericVector3>(x: T::Scalar, y: T::Scalar, z: T::Scalar) {
+        use crate::{Axis2, Axis3};
+        let v2 = T::Vector2::new_2d(x, y);
+        assert_eq!(v2[Axis2::X], x);
+        assert_eq!(v2[Axis2::Y], y);
+        let mut v3 = T::new_3d(x, y, z);
+        for (axis, val) in Axis3::ALL.into_iter().zip([x, y, z]) {
+            assert_eq!(v3[axis], val);
+            assert_eq!(v3.axis3(axis), val);
+            assert_eq!(v3[axis], v3[axis.index()]);
+        }
+        assert_eq!(v3.axis(Axis2::Y), y);
+        v3.set_axis(Axis2::X, z);
+        v3.set_axis3(Axis3::Z, x);
+        assert_eq!(v3.x(), z);
+        assert_eq!(v3.z(), x);
+        assert_eq!(Axis3::from(Axis2::Y), Axis3::Y);
+
+        let neg: T::Scalar = (-10.0).into();
+        assert_eq!(Axis2::dominant(T::Vector2::new_2d(x, neg)), Axis2::Y);
+        assert_eq!(Axis2::dominant(T::Vector2::new_2d(x, x)), Axis2::X);
+        assert_eq!(Axis3::dominant(T::new_3d(x, y, neg)), Axis3::Z);
+        assert_eq!(Axis3::dominant(T::new_3d(x, neg, neg)), Axis3::Y);
+        assert_eq!(Axis3::dominant(T::new_3d(x, x, x)), Axis3::X);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_int_storage<T: GenericVector3>() {
+        use crate::{IntVec2, IntVec3};
+        let scale: T::Scalar = 0.5.into();
+        let i2 = IntVec2::<i32>::new(3, -4);
+        let v2: T::Vector2 = i2.to_float(scale);
+        assert_eq!(v2.x(), 1.5.into());
+        assert_eq!(v2.y(), (-2.0).into());
+        assert_eq!(IntVec2::from_float(v2, scale), Some(i2));
+
+        let i3: IntVec3<i64> = (1, -2, 7).into();
+        let v3: T = i3.to_float(scale);
+        assert_eq!(v3.z(), 3.5.into());
+        assert_eq!(IntVec3::from_float(v3, scale), Some(i3));
+        // rounds to the nearest grid point
+        let v3 = T::new_3d(0.74.into(), (-0.74).into(), 0.2.into());
+        assert_eq!(IntVec3::from_float(v3, scale), Some(IntVec3::new(1, -1, 0)));
+
+        let v2 = T::Vector2::new_2d(T::Scalar::INFINITY, T::Scalar::ZERO);
+        assert_eq!(IntVec2::<i64>::from_float(v2, scale), None);
+        let v2 = T::Vector2::new_2d(T::Scalar::ZERO, 1.0e10.into());
+        assert_eq!(IntVec2::<i32>::from_float(v2, scale), None);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_segments_intersect<T: GenericVector2>() {
+        use crate::{orient2d, segments_intersect, IntersectionKind, Orientation};
+        let p = |x: f32, y: f32| T::new_2d(x.into(), y.into());
+
+        assert_eq!(
+            orient2d(p(0.0, 0.0), p(1.0, 0.0), p(0.0, 1.0)),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            orient2d(p(0.0, 0.0), p(1.0, 0.0), p(0.0, -1.0)),
+            Orientation::Clockwise
+        );
+        assert_eq!(
+            orient2d(p(0.0, 0.0), p(1.0, 1.0), p(3.0, 3.0)),
+            Orientation::Collinear
+        );
+        // a point a tiny bit off the line is still detected
+        let eps = T::Scalar::EPSILON;
+        let off = T::new_2d(3.0.into(), <T::Scalar>::from(3.0) + eps * 4.0.into());
+        assert_eq!(
+            orient2d(p(0.0, 0.0), p(1.0, 1.0), off),
+            Orientation::CounterClockwise
+        );
+
+        let check = |a1, a2, b1, b2, expected| {
+            assert_eq!(segments_intersect(a1, a2, b1, b2), expected);
+            assert_eq!(segments_intersect(a2, a1, b2, b1), expected);
+            assert_eq!(segments_intersect(b1, b2, a1, a2), expected);
+        };
+        use IntersectionKind::*;
+        check(p(0.0, 0.0), p(2.0, 2.0), p(0.0, 2.0), p(2.0, 0.0), Crossing);
+        check(p(0.0, 0.0), p(1.0, 1.0), p(0.0, 2.0), p(2.0, 3.0), None);
+        check(p(0.0, 0.0), p(1.0, 1.0), p(2.0, 0.0), p(1.5, 1.5), None);
+        // endpoint on the interior of the other segment
+        check(p(0.0, 0.0), p(2.0, 0.0), p(1.0, 0.0), p(1.0, 1.0), Touching);
+        // shared endpoints
+        check(p(0.0, 0.0), p(2.0, 0.0), p(2.0, 0.0), p(3.0, 1.0), Touching);
+        // collinear
+        check(
+            p(0.0, 0.0),
+            p(2.0, 0.0),
+            p(1.0, 0.0),
+            p(3.0, 0.0),
+            Overlapping,
+        );
+        check(p(0.0, 0.0), p(0.0, 2.0), p(0.0, 2.0), p(0.0, 3.0), Touching);
+        check(p(0.0, 0.0), p(1.0, 1.0), p(2.0, 2.0), p(3.0, 3.0), None);
+        check(
+            p(0.0, 0.0),
+            p(3.0, 3.0),
+            p(1.0, 1.0),
+            p(2.0, 2.0),
+            Overlapping,
+        );
+        // degenerate segments
+        check(p(1.0, 0.0), p(1.0, 0.0), p(0.0, 0.0), p(2.0, 0.0), Touching);
+        check(p(1.0, 1.0), p(1.0, 1.0), p(0.0, 0.0), p(2.0, 0.0), None);
+        check(p(1.0, 1.0), p(1.0, 1.0), p(1.0, 1.0), p(1.0, 1.0), Touching);
+        check(p(1.0, 1.0), p(1.0, 1.0), p(2.0, 2.0), p(2.0, 2.0), None);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_summation<T: GenericVector2>() {
+        use crate::{mean_squared_distance, mean_squared_error, sum_squared_distances};
+        let origin = T::new_2d(T::Scalar::ZERO, T::Scalar::ZERO);
+        let far = T::new_2d(1.0e4.into(), T::Scalar::ZERO);
+        let near = T::new_2d(T::Scalar::ZERO, 0.3.into());
+        let mut points = vec![far];
+        points.resize(100_001, near);
+
+        let expected = 1.0e8_f64 + 100_000.0 * 0.3_f32 as f64 * 0.3_f32 as f64;
+        let sum: f64 = sum_squared_distances(&points, origin).into();
+        assert!(((sum - expected) / expected).abs() < 1.0e-6, "{}", sum);
+        let mean: f64 = mean_squared_distance(&points, origin).unwrap().into();
+        let expected_mean = expected / 100_001.0;
+        assert!(((mean - expected_mean) / expected_mean).abs() < 1.0e-6);
+
+        let mse: f64 = mean_squared_error(&[far, near], &[origin, origin])
+            .unwrap()
+            .into();
+        assert_eq!(mse, (far.x() * far.x() + near.y() * near.y()).into() / 2.0);
+        assert!(mean_squared_error(&[far, near], &[origin]).is_none());
+        assert!(mean_squared_error::<T>(&[], &[]).is_none());
+        assert!(mean_squared_distance(&[], origin).is_none());
+        assert_eq!(sum_squared_distances(&[], origin), T::Scalar::ZERO);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_checked<T: GenericVector2>(x: T::Scalar, y: T::Scalar) {
+        use crate::{CheckedOp, CheckedVector};
+        let v = T::new_2d(x, y);
+        let two: T::Scalar = 2.0.into();
+        assert_eq!(v.checked_add(v), Ok(v + v));
+        assert_eq!((v + v).checked_sub(v), Ok(v + v - v));
+        assert_eq!(v.checked_mul(two), Ok(v * two));
+        assert_eq!(v.checked_div(two), Ok(v / two));
+        assert_eq!(v.check_finite(), Ok(v));
+
+        let line = line!() + 1;
+        let err = v.checked_div(T::Scalar::ZERO).unwrap_err();
+        assert_eq!(err.op, CheckedOp::Div);
+        assert_eq!(err.lhs, v);
+        assert_eq!(err.location.file(), file!());
+        assert_eq!(err.location.line(), line);
+        assert!(!err.lhs_was_non_finite());
+        assert!(err.to_string().contains("Div"));
+
+        let huge = T::new_2d(T::Scalar::max_value(), y);
+        let err = huge.checked_mul(two).unwrap_err();
+        assert_eq!(err.op, CheckedOp::Mul);
+        let nan = T::new_2d(x, <T::Scalar as num_traits::Float>::nan());
+        let err = nan.checked_add(v).unwrap_err();
+        assert!(err.lhs_was_non_finite());
+        assert_eq!(nan.check_finite().unwrap_err().op, CheckedOp::Check);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_distance_robust<T: GenericVector3>(x: T::Scalar, y: T::Scalar, z: T::Scalar) {
+        use crate::{distance_robust, distance_sq_scaled};
+        let a = T::new_3d(x, y, z);
+        let b = T::new_3d(z, y, x);
+        assert_eq!(distance_robust(a, b), a.distance(b));
+        assert_eq!(distance_robust(a, a), T::Scalar::ZERO);
+        let (scale, sum) = distance_sq_scaled(a, b);
+        assert!((scale * scale * sum - a.distance_sq(b)).abs() <= T::Scalar::EPSILON);
+
+        // squaring these overflows, the distance itself is representable
+        let max = <T::Scalar as num_traits::Float>::max_value();
+        let big = max / 4.0.into();
+        let a = T::new_3d(big, -big, T::Scalar::ZERO);
+        let b = T::new_3d(-big, big, T::Scalar::ZERO);
+        assert!(a.distance_sq(b).is_infinite());
+        let d = distance_robust(a, b);
+        let expected = big * 2.0.into() * crate::math::sqrt(<T::Scalar>::TWO);
+        assert!(((d - expected) / expected).abs() <= T::Scalar::EPSILON * 2.0.into());
+        let (scale, sum) = distance_sq_scaled(a, b);
+        assert_eq!(scale, big * 2.0.into());
+        assert_eq!(sum, <T::Scalar>::TWO);
+        // the difference itself overflows
+        let a = T::new_3d(max, T::Scalar::ZERO, T::Scalar::ZERO);
+        let b = T::new_3d(-max, T::Scalar::ZERO, T::Scalar::ZERO);
+        assert!(distance_robust(a, b).is_infinite());
+        let (scale, sum) = distance_sq_scaled(a, b);
+        assert_eq!((scale, sum), (max, 4.0.into()));
+
+        // squaring these underflows
+        let tiny = <T::Scalar as num_traits::Float>::min_positive_value() * 16.0.into();
+        let a = T::new_3d(tiny, T::Scalar::ZERO, T::Scalar::ZERO);
+        let b = T::new_3d(T::Scalar::ZERO, tiny, T::Scalar::ZERO);
+        let expected = tiny * crate::math::sqrt(<T::Scalar>::TWO);
+        let d = distance_robust(a, b);
+        assert!(((d - expected) / expected).abs() <= T::Scalar::EPSILON * 2.0.into());
+        assert_eq!(a.distance(b), T::Scalar::ZERO);
+
+        // NaN is not dropped by the scaling
+        let nan = <T::Scalar as num_traits::Float>::nan();
+        let zero = T::new_3d(T::Scalar::ZERO, T::Scalar::ZERO, T::Scalar::ZERO);
+        assert!(distance_robust(T::new_3d(nan, T::Scalar::ZERO, T::Scalar::ZERO), zero).is_nan());
+        assert!(distance_robust(T::new_3d(max, nan, T::Scalar::ZERO), zero).is_nan());
+        assert!(distance_sq_scaled(zero, T::new_3d(x, y, nan)).0.is_nan());
+        let inf = T::Scalar::INFINITY;
+        assert!(distance_robust(T::new_3d(inf, inf, T::Scalar::ZERO), zero).is_infinite());
+    }
+
+    #[allow(dead_code)]
+    pub fn test_strict_fp<T: GenericVector3>(x: T::Scalar, y: T::Scalar, z: T::Scalar) {
+        let a = T::new_3d(x, y, z);
+        let b = T::new_3d(z, -x, y);
+        let expected = x * z + y * -x + z * y;
+        assert_eq!(crate::math::dot(a, b).to_bits(), expected.to_bits());
+        assert_eq!(crate::math::dot(a.to_2d(), b.to_2d()), x * z + y * -x);
+        if cfg!(feature = "strict-fp") {
+            assert_eq!(a.dot(b).to_bits(), expected.to_bits());
+            let d = a - b;
+            assert_eq!(a.distance_sq(b), crate::math::dot(d, d));
+            assert_eq!(a.magnitude(), crate::math::sqrt(crate::math::dot(a, a)));
+            assert_eq!(a.normalize(), a / a.magnitude());
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn test_sweep_cmp<T: GenericVector2>() {
+        use crate::{sweep_cmp, sweep_cmp_x};
+        use std::cmp::Ordering;
+        let p = |x: f32, y: f32| T::new_2d(x.into(), y.into());
+        assert_eq!(sweep_cmp(&p(5.0, 1.0), &p(0.0, 2.0)), Ordering::Less);
+        assert_eq!(sweep_cmp(&p(1.0, 2.0), &p(0.0, 2.0)), Ordering::Greater);
+        assert_eq!(sweep_cmp(&p(1.0, 2.0), &p(1.0, 2.0)), Ordering::Equal);
+        assert_eq!(sweep_cmp_x(&p(5.0, 1.0), &p(0.0, 2.0)), Ordering::Greater);
+        assert_eq!(sweep_cmp_x(&p(0.0, 3.0), &p(0.0, 2.0)), Ordering::Greater);
+        assert_eq!(sweep_cmp(&p(-0.0, 0.0), &p(0.0, 0.0)), Ordering::Less);
+
+        let nan = <T::Scalar as num_traits::Float>::nan();
+        let mut points = vec![
+            T::new_2d(nan, T::Scalar::ZERO),
+            p(1.0, f32::INFINITY),
+            p(2.0, -1.0),
+            p(-2.0, -1.0),
+            T::new_2d(T::Scalar::ZERO, nan),
+        ];
+        points.sort_by(sweep_cmp);
+        assert_eq!(points[0], p(-2.0, -1.0));
+        assert_eq!(points[1], p(2.0, -1.0));
+        assert!(points[2].x().is_nan());
+        assert_eq!(points[3], p(1.0, f32::INFINITY));
+        assert!(points[4].y().is_nan());
+        let sorted = points.clone();
+        points.reverse();
+        points.sort_by(sweep_cmp);
+        assert_eq!(
+            points.iter().map(|v| v.y().to_bits()).collect::<Vec<_>>(),
+            sorted.iter().map(|v| v.y().to_bits()).collect::<Vec<_>>()
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn test_snap<T: GenericVector3>() {
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let cell = s(0.25);
+        // ties round up on both sides of zero
+        assert_eq!(s(0.125).snap(cell), s(0.25));
+        assert_eq!(s(-0.125).snap(cell), s(0.0));
+        assert_eq!(s(-0.375).snap(cell), s(-0.25));
+        assert_eq!(s(0.1).snap(cell), s(0.0));
+        // 0.49999997 + 0.5 rounds to 1 in f32
+        assert_eq!(s(0.49999997).snap(s(1.0)), s(0.0));
+        assert_eq!(s(-0.5).snap(s(1.0)), s(0.0));
+        assert_eq!(s(-0.50000006).snap(s(1.0)), s(-1.0));
+        let huge = <T::Scalar as num_traits::Float>::max_value();
+        assert_eq!(huge.snap(cell), huge);
+        assert!(<T::Scalar as num_traits::Float>::nan().snap(cell).is_nan());
+        assert_eq!(T::Scalar::INFINITY.snap(cell), T::Scalar::INFINITY);
+
+        let v = T::new_3d(s(0.3), s(-0.9), s(1.13));
+        assert_eq!(v.snap(cell), T::new_3d(s(0.25), s(-1.0), s(1.25)));
+        assert_eq!(v.to_2d().snap(cell), v.snap(cell).to_2d());
+
+        // property checks over pseudo random values and cell sizes
+        let mut state = 0x853c_49e6_748f_ea9b_u64;
+        let mut random = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let unit = (state >> 11) as f64 / (1_u64 << 53) as f64;
+            <T::Scalar as num_traits::FromPrimitive>::from_f64(unit * 2.0 - 1.0).unwrap()
+        };
+        for _ in 0..10000 {
+            let exp2 = |e: T::Scalar| num_traits::Float::exp2(num_traits::Float::round(e));
+            let cell = exp2(random() * s(20.0)) * (random() + s(1.5));
+            let scale = exp2(random() * s(40.0));
+            let (a, b) = (random() * scale, random() * scale);
+            let (sa, sb) = (a.snap(cell), b.snap(cell));
+            assert_eq!(sa.snap(cell), sa, "snap({a}, {cell}) is not idempotent");
+            // at most half a cell away, plus the rounding error of a / cell and k * cell
+            let error = num_traits::Float::abs(a) * s(4.0) * T::Scalar::EPSILON;
+            assert!(
+                num_traits::Float::abs(sa - a) <= cell * s(0.5) + error,
+                "snap({a}, {cell}) = {sa} is more than half a cell away"
+            );
+            if a <= b {
+                assert!(
+                    sa <= sb,
+                    "snap is not monotonic for {a} <= {b}, cell {cell}"
+                );
+            }
+            let v = T::new_3d(a, b, -a).snap(cell);
+            assert_eq!(v.snap(cell), v);
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn test_angle<T: GenericVector2>() {
+        use crate::{normalize_angle_0_tau, normalize_angle_pm_pi, shortest_angle_delta};
+        use num_traits::FloatConst;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let (pi, tau) = (T::Scalar::PI(), T::Scalar::TAU());
+        let eps = T::Scalar::EPSILON * s(16.0);
+
+        assert_eq!(normalize_angle_0_tau(s(1.0)), s(1.0));
+        assert_eq!(normalize_angle_0_tau(T::Scalar::ZERO), T::Scalar::ZERO);
+        assert_eq!(normalize_angle_0_tau(tau), T::Scalar::ZERO);
+        assert!((normalize_angle_0_tau(-s(1.0)) - (tau - s(1.0))).abs() <= eps);
+        assert!((normalize_angle_0_tau(s(1.0) + tau * s(3.0)) - s(1.0)).abs() <= eps);
+        let tiny = normalize_angle_0_tau(-T::Scalar::EPSILON * T::Scalar::EPSILON);
+        assert!(tiny >= T::Scalar::ZERO && tiny < tau);
+
+        assert_eq!(normalize_angle_pm_pi(pi), pi);
+        assert_eq!(normalize_angle_pm_pi(-pi), pi);
+        assert!((normalize_angle_pm_pi(pi + s(0.5)) - (s(0.5) - pi)).abs() <= eps);
+        assert!((normalize_angle_pm_pi(-s(0.5) - tau * s(2.0)) + s(0.5)).abs() <= eps);
+        assert!(normalize_angle_pm_pi(T::Scalar::INFINITY).is_nan());
+
+        // wrapping across the +-pi seam takes the short way around
+        let delta = shortest_angle_delta(pi - s(0.1), -pi + s(0.1));
+        assert!((delta - s(0.2)).abs() <= eps);
+        let delta = shortest_angle_delta(s(0.1), tau - s(0.1));
+        assert!((delta + s(0.2)).abs() <= eps);
+        assert_eq!(shortest_angle_delta(s(1.0), s(1.0)), T::Scalar::ZERO);
+        for i in -50..50 {
+            let a = s(i as f32 * 0.37);
+            let r = normalize_angle_0_tau(a);
+            assert!(r >= T::Scalar::ZERO && r < tau, "{a} -> {r}");
+            let r = normalize_angle_pm_pi(a);
+            assert!(r > -pi && r <= pi, "{a} -> {r}");
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn test_interval<T: GenericVector2>() {
+        use crate::{orient2d, orient2d_interval, Interval, Orientation};
+        use std::cmp::Ordering;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let i = |lo: f32, hi: f32| Interval::new(s(lo), s(hi));
+
+        let tenth = Interval::point(s(1.0)) / Interval::point(s(10.0));
+        assert!(tenth.contains(T::Scalar::ONE / s(10.0)));
+        assert!(tenth.lower() < tenth.upper());
+        let sum = (0..10).fold(Interval::point(T::Scalar::ZERO), |acc, _| acc + tenth);
+        assert!(sum.contains(s(1.0)));
+        assert!(sum.width() < s(1.0e-5));
+
+        let p = i(1.0, 2.0) * i(-3.0, 4.0);
+        assert!(p.lower() <= s(-6.0) && p.upper() >= s(8.0));
+        assert_eq!(p.sign(), None);
+        assert_eq!((i(1.0, 2.0) - i(0.0, 0.5)).sign(), Some(Ordering::Greater));
+        assert_eq!((-i(1.0, 2.0)).sign(), Some(Ordering::Less));
+        assert_eq!(
+            Interval::point(T::Scalar::ZERO).sign(),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(i(1.0, 2.0) / i(-1.0, 1.0), Interval::entire());
+        let inf = Interval::point(T::Scalar::INFINITY);
+        assert_eq!(inf * Interval::point(T::Scalar::ZERO), Interval::entire());
+
+        let p = |x: f32, y: f32| T::new_2d(x.into(), y.into());
+        assert_eq!(
+            orient2d_interval(p(0.0, 0.0), p(1.0, 0.0), p(0.0, 1.0)),
+            Some(Orientation::CounterClockwise)
+        );
+        assert_eq!(
+            orient2d_interval(p(0.0, 0.0), p(0.0, 1.0), p(1.0, 0.0)),
+            Some(Orientation::Clockwise)
+        );
+        // the widened intervals never collapse to exactly zero
+        assert_eq!(
+            orient2d_interval(p(1.0, 1.0), p(1.0, 1.0), p(1.0, 1.0)),
+            None
+        );
+        // collinear and nearly collinear, the filter may not decide but must never be wrong
+        let (a, b) = (p(0.1, 0.1), p(0.3, 0.3));
+        let mut undecided = 0;
+        for k in 0..100 {
+            let x = s(k as f32 * 0.013);
+            for c in [T::new_2d(x, x), T::new_2d(x, x + T::Scalar::EPSILON)] {
+                match orient2d_interval(a, b, c) {
+                    Some(o) => assert_eq!(o, orient2d(a, b, c)),
+                    None => undecided += 1,
+                }
+            }
+        }
+        assert!(undecided > 0);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_error_bound<T: GenericVector3>() {
+        use crate::{cross_with_error, dot_with_error, perp_dot_with_error, CompensatedSum};
+        use num_traits::Float;
+        // the exact sum of products, from the error free transformation of every product
+        let exact = |products: &[(T::Scalar, T::Scalar)]| {
+            products
+                .iter()
+                .flat_map(|(a, b)| [*a * *b, Float::mul_add(*a, *b, -(*a * *b))])
+                .collect::<CompensatedSum<_>>()
+                .value()
+        };
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let unit = (state >> 11) as f64 / (1_u64 << 53) as f64;
+            <T::Scalar as num_traits::FromPrimitive>::from_f64(unit * 200.0 - 100.0).unwrap()
+        };
+        for _ in 0..1000 {
+            let a = T::new_3d(random(), random(), random());
+            // nearly orthogonal to a, so the dot product cancels
+            let b = a.cross(T::new_3d(random(), random(), random())) + a * T::Scalar::EPSILON;
+
+            let (value, error) = dot_with_error(a, b);
+            let reference = exact(&[(a.x(), b.x()), (a.y(), b.y()), (a.z(), b.z())]);
+            assert!(
+                Float::abs(value - reference) <= error,
+                "{value} {reference} {error}"
+            );
+            let abs_sum =
+                Float::abs(a.x() * b.x()) + Float::abs(a.y() * b.y()) + Float::abs(a.z() * b.z());
+            assert!(error <= abs_sum * T::Scalar::EPSILON * 4.0.into());
+
+            let (value, error) = perp_dot_with_error(a.to_2d(), b.to_2d());
+            let reference = exact(&[(a.x(), b.y()), (-a.y(), b.x())]);
+            assert!(Float::abs(value - reference) <= error);
+
+            let (value, error) = cross_with_error(a, b);
+            let reference = exact(&[(a.y(), b.z()), (-a.z(), b.y())]);
+            assert!(Float::abs(value.x() - reference) <= error.x());
+            let reference = exact(&[(a.z(), b.x()), (-a.x(), b.z())]);
+            assert!(Float::abs(value.y() - reference) <= error.y());
+            let reference = exact(&[(a.x(), b.y()), (-a.y(), b.x())]);
+            assert!(Float::abs(value.z() - reference) <= error.z());
+        }
+        let zero = T::new_3d(T::Scalar::ZERO, T::Scalar::ZERO, T::Scalar::ZERO);
+        let (value, error) = dot_with_error(zero, zero);
+        assert_eq!(value, T::Scalar::ZERO);
+        assert!(error > T::Scalar::ZERO && error < <T::Scalar as Float>::min_positive_value());
+    }
+
+    #[allow(dead_code)]
+    pub fn test_sampling_2d<T: GenericVector2>() {
+        use crate::sampling::*;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let (min, max) = (T::new_2d(s(-1.0), s(2.0)), T::new_2d(s(3.0), s(4.0)));
+        let inside =
+            |p: &T| p.x() >= min.x() && p.x() <= max.x() && p.y() >= min.y() && p.y() <= max.y();
+
+        let grid = grid_2d(min, max, 4, 2);
+        assert_eq!(grid.len(), 8);
+        assert_eq!(grid[0], T::new_2d(s(-0.5), s(2.5)));
+        assert_eq!(grid[7], T::new_2d(s(2.5), s(3.5)));
+
+        let jittered = jittered_grid_2d(min, max, 4, 2, 7);
+        assert_eq!(jittered, jittered_grid_2d(min, max, 4, 2, 7));
+        assert_ne!(jittered, jittered_grid_2d(min, max, 4, 2, 8));
+        for (p, center) in jittered.iter().zip(grid.iter()) {
+            assert!((p.x() - center.x()).abs() <= s(0.5) && (p.y() - center.y()).abs() <= s(0.5));
+        }
+
+        let radius = s(0.25);
+        let points = poisson_disk_2d(min, max, radius, 42);
+        assert_eq!(points, poisson_disk_2d(min, max, radius, 42));
+        assert!(points.len() > 40, "{}", points.len());
+        assert!(points.iter().all(inside));
+        for (i, p) in points.iter().enumerate() {
+            for q in &points[i + 1..] {
+                assert!(p.distance_sq(*q) >= radius * radius);
+            }
+        }
+        assert!(poisson_disk_2d(min, max, -radius, 42).is_empty());
+        // the cell count overflows a usize along one axis, and the product of both axes
+        let far = T::new_2d(s(1e30), s(1.0));
+        assert!(poisson_disk_2d(min, far, radius, 42).is_empty());
+        let wide = T::new_2d(s(1.0e10), s(1.0e10));
+        assert!(poisson_disk_2d(min, wide, radius, 42).is_empty());
+
+        for sequence in [halton_2d::<T>, sobol_2d::<T>] {
+            let points = sequence(min, max, 64, 3);
+            assert_eq!(points.len(), 64);
+            assert_eq!(points, sequence(min, max, 64, 3));
+            assert_ne!(points, sequence(min, max, 64, 4));
+            assert!(points.iter().all(inside));
+        }
+        // every column and every row of a 2^m Sobol point set holds exactly one point
+        let unit = sobol_2d(T::new_2d(s(0.0), s(0.0)), T::new_2d(s(1.0), s(1.0)), 64, 11);
+        for axis in [T::x, T::y] {
+            let mut seen = [false; 64];
+            for p in &unit {
+                let i = num_traits::ToPrimitive::to_usize(&(axis(*p) * s(64.0)).floor()).unwrap();
+                assert!(!seen[i]);
+                seen[i] = true;
+            }
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn test_sampling_3d<T: GenericVector3>() {
+        use crate::sampling::*;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let (min, max) = (
+            T::new_3d(s(0.0), s(0.0), s(0.0)),
+            T::new_3d(s(1.0), s(2.0), s(1.0)),
+        );
+        let inside = |p: &T| {
+            [(p.x(), max.x()), (p.y(), max.y()), (p.z(), max.z())]
+                .iter()
+                .all(|(c, m)| *c >= T::Scalar::ZERO && c <= m)
+        };
+
+        let grid = grid_3d(min, max, [2, 2, 2]);
+        assert_eq!(grid.len(), 8);
+        assert_eq!(grid[7], T::new_3d(s(0.75), s(1.5), s(0.75)));
+        let jittered = jittered_grid_3d(min, max, [2, 3, 4], 5);
+        assert_eq!(jittered.len(), 24);
+        assert_eq!(jittered, jittered_grid_3d(min, max, [2, 3, 4], 5));
+        assert!(jittered.iter().all(inside));
+
+        let radius = s(0.3);
+        let points = poisson_disk_3d(min, max, radius, 1);
+        assert_eq!(points, poisson_disk_3d(min, max, radius, 1));
+        assert!(points.len() > 20, "{}", points.len());
+        assert!(points.iter().all(inside));
+        for (i, p) in points.iter().enumerate() {
+            for q in &points[i + 1..] {
+                assert!(p.distance_sq(*q) >= radius * radius);
+            }
+        }
+
+        for sequence in [halton_3d::<T>, sobol_3d::<T>] {
+            let points = sequence(min, max, 100, 9);
+            assert_eq!(points, sequence(min, max, 100, 9));
+            assert!(points.iter().all(inside));
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn test_euler<T: GenericVector3>() {
+        use crate::{EulerAngles, EulerOrder};
+        use num_traits::FloatConst;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let eps = T::Scalar::EPSILON * s(64.0);
+        let close = |a: T, b: T| (a - b).magnitude() <= eps;
+        let half_pi = T::Scalar::FRAC_PI_2();
+        let orders = [
+            EulerOrder::XYZ,
+            EulerOrder::XZY,
+            EulerOrder::YXZ,
+            EulerOrder::YZX,
+            EulerOrder::ZXY,
+            EulerOrder::ZYX,
+        ];
+
+        // x first, then z: x is fixed by the first rotation and then turned to y
+        let e = EulerAngles::new(EulerOrder::XZY, [half_pi, half_pi, T::Scalar::ZERO]);
+        let x = T::new_3d(s(1.0), s(0.0), s(0.0));
+        assert!(close(e.rotate_vector(x), T::new_3d(s(0.0), s(1.0), s(0.0))));
+        // y is turned to z by the first rotation, the z rotation leaves it there
+        let y = T::new_3d(s(0.0), s(1.0), s(0.0));
+        assert!(close(e.rotate_vector(y), T::new_3d(s(0.0), s(0.0), s(1.0))));
+
+        let angles = [s(0.3), s(-0.7), s(1.9)];
+        let v = T::new_3d(s(0.2), s(-1.5), s(0.8));
+        for order in orders {
+            let e = EulerAngles::new(order, angles);
+            let back = EulerAngles::from_matrix(order, &e.to_matrix());
+            for (a, b) in back.angles.iter().zip(angles.iter()) {
+                assert!((*a - *b).abs() <= eps, "{:?}: {:?}", order, back);
+            }
+            assert!((e.rotate_vector(v).magnitude() - v.magnitude()).abs() <= eps);
+            for other in orders {
+                let r = e.reorder(other);
+                assert_eq!(r.order, other);
+                assert!(
+                    close(r.rotate_vector(v), e.rotate_vector(v)),
+                    "{:?} {:?}",
+                    order,
+                    other
+                );
+            }
+            // gimbal lock keeps the rotation, even though the angles are not unique
+            let locked = EulerAngles::new(order, [s(0.4), half_pi, s(0.1)]);
+            let back = EulerAngles::from_matrix(order, &locked.to_matrix());
+            assert!(
+                close(back.rotate_vector(v), locked.rotate_vector(v)),
+                "{:?}",
+                order
+            );
+        }
+
+        let d = T::from_yaw_pitch(half_pi, T::Scalar::ZERO);
+        assert!(close(d, T::new_3d(s(0.0), s(1.0), s(0.0))));
+        let d = T::from_yaw_pitch(s(2.5), s(-0.4));
+        assert!((d.magnitude() - T::Scalar::ONE).abs() <= eps);
+        let (yaw, pitch) = (d * s(7.0)).to_yaw_pitch();
+        assert!((yaw - s(2.5)).abs() <= eps && (pitch + s(0.4)).abs() <= eps);
+        let zero = T::new_3d(T::Scalar::ZERO, T::Scalar::ZERO, T::Scalar::ZERO);
+        assert_eq!(zero.to_yaw_pitch(), (T::Scalar::ZERO, T::Scalar::ZERO));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_look_rotation<T: GenericVector3>() {
+        use crate::{look_frame, look_rotation, EulerAngles, EulerOrder};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let eps = T::Scalar::EPSILON * s(64.0);
+        let close = |a: T, b: T| (a - b).magnitude() <= eps;
+        let z = T::new_3d(s(0.0), s(0.0), s(1.0));
+
+        let (yaw, pitch) = (s(0.8), s(0.3));
+        let forward = T::from_yaw_pitch(yaw, pitch);
+        let m = look_rotation(forward * s(5.0), z).unwrap();
+        // the same rotation as pitching the x axis up and then turning it by the yaw
+        let e = EulerAngles::new(EulerOrder::XYZ, [T::Scalar::ZERO, -pitch, yaw]).to_matrix();
+        for (row, expected) in m.iter().zip(e.iter()) {
+            for (a, b) in row.iter().zip(expected.iter()) {
+                assert!((*a - *b).abs() <= eps, "{:?} != {:?}", m, e);
+            }
+        }
+
+        let [f, l, u] = look_frame(forward, z).unwrap();
+        assert!(close(f, forward));
+        assert!(close(l.cross(u), f) && close(u.cross(f), l) && close(f.cross(l), u));
+        assert!(u.z() > T::Scalar::ZERO);
+        assert!(look_frame(z, z * s(2.0)).is_none());
+        assert!(look_frame(z * T::Scalar::ZERO, forward).is_none());
+
+        let a = T::new_3d(s(1.0), s(2.0), s(3.0));
+        let b = T::new_3d(s(1.0), s(2.0), s(-1.0));
+        assert!(close(a.direction_to(b).unwrap(), -z));
+        assert!(a.direction_to(a).is_none());
+    }
+
+    #[allow(dead_code)]
+    pub fn test_bounding_sphere_2d<T: GenericVector2>() {
+        use crate::{bounding_sphere_2d, bounding_sphere_exact_2d, sampling::halton_2d};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let tolerance = s(1e-4);
+        assert!(bounding_sphere_2d(Vec::<T>::new()).is_none());
+        assert!(bounding_sphere_exact_2d(Vec::<T>::new()).is_none());
+        let one = T::new_2d(s(1.0), s(2.0));
+        assert_eq!(
+            bounding_sphere_exact_2d([one]),
+            Some((one, T::Scalar::ZERO))
+        );
+        assert_eq!(bounding_sphere_2d([one, one]), Some((one, T::Scalar::ZERO)));
+
+        // an equilateral triangle is bounded by its circumcircle, not the circle of an edge
+        let h = crate::math::sqrt(s(3.0)) / s(2.0);
+        let triangle = [
+            T::new_2d(s(0.0), s(1.0)),
+            T::new_2d(-h, s(-0.5)),
+            T::new_2d(h, s(-0.5)),
+        ];
+        let (c, r) = bounding_sphere_exact_2d(triangle).unwrap();
+        assert!(c.magnitude() <= tolerance && (r - s(1.0)).abs() <= tolerance);
+
+        let points = halton_2d(
+            T::new_2d(s(-3.0), s(1.0)),
+            T::new_2d(s(5.0), s(2.0)),
+            200,
+            3,
+        );
+        let (exact_c, exact_r) = bounding_sphere_exact_2d(points.iter().copied()).unwrap();
+        let (c, r) = bounding_sphere_2d(points.iter().copied()).unwrap();
+        assert!(r >= exact_r * (s(1.0) - tolerance) && r <= exact_r * s(1.2));
+        for p in &points {
+            assert!(p.distance(c) <= r && p.distance(exact_c) <= exact_r);
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn test_bounding_sphere_3d<T: GenericVector3>() {
+        use crate::{bounding_sphere_3d, bounding_sphere_exact_3d, sampling::halton_3d};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let tolerance = s(1e-4);
+        assert!(bounding_sphere_3d(Vec::<T>::new()).is_none());
+
+        // the corners of a regular tetrahedron, centered on the origin
+        let tetrahedron = [
+            T::new_3d(s(1.0), s(1.0), s(1.0)),
+            T::new_3d(s(1.0), s(-1.0), s(-1.0)),
+            T::new_3d(s(-1.0), s(1.0), s(-1.0)),
+            T::new_3d(s(-1.0), s(-1.0), s(1.0)),
+        ];
+        let (c, r) = bounding_sphere_exact_3d(tetrahedron).unwrap();
+        assert!(c.magnitude() <= tolerance && (r - crate::math::sqrt(s(3.0))).abs() <= tolerance);
+
+        let min = T::new_3d(s(-1.0), s(0.0), s(2.0));
+        let max = T::new_3d(s(1.0), s(4.0), s(3.0));
+        let points = halton_3d(min, max, 300, 5);
+        let (exact_c, exact_r) = bounding_sphere_exact_3d(points.iter().copied()).unwrap();
+        let (c, r) = bounding_sphere_3d(points.iter().copied()).unwrap();
+        assert!(r >= exact_r * (s(1.0) - tolerance) && r <= exact_r * s(1.2));
+        assert!(exact_r <= (max - min).magnitude() / s(2.0));
+        for p in &points {
+            assert!(p.distance(c) <= r && p.distance(exact_c) <= exact_r);
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn test_principal_axes<T: GenericVector3>() {
+        use crate::{centroid3, covariance3, principal_axes};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let tolerance = s(1e-4);
+        assert!(principal_axes::<T>(&[]).is_none());
+        assert!(covariance3::<T>(&[]).is_none());
+
+        // a box of points stretched along (1, 1, 0), squashed along (1, -1, 0) and offset from
+        // the origin
+        let offset = T::new_3d(s(100.0), s(-50.0), s(20.0));
+        let u = T::new_3d(s(1.0), s(1.0), s(0.0)).normalize();
+        let v = T::new_3d(s(1.0), s(-1.0), s(0.0)).normalize();
+        let w = T::new_3d(s(0.0), s(0.0), s(1.0));
+        let mut points = Vec::new();
+        for a in [-3.0, 3.0] {
+            for b in [-1.0, 1.0] {
+                for c in [-2.0, 2.0] {
+                    points.push(offset + u * s(a) + v * s(b) + w * s(c));
+                }
+            }
+        }
+        assert!((centroid3(&points).unwrap() - offset).magnitude() <= tolerance);
+        let c = covariance3(&points).unwrap();
+        assert!((c[2][2] - s(4.0)).abs() <= tolerance && (c[0][1] - s(4.0)).abs() <= tolerance);
+        assert!(c[0][2].abs() <= tolerance && (c[0][1] - c[1][0]).abs() <= tolerance);
+
+        let pa = principal_axes(&points).unwrap();
+        assert!((pa.centroid - offset).magnitude() <= tolerance);
+        for (variance, expected) in pa.variances.iter().zip([9.0, 4.0, 1.0]) {
+            assert!(
+                (*variance - s(expected)).abs() <= tolerance,
+                "{:?}",
+                pa.variances
+            );
+        }
+        for (axis, expected) in pa.axes.iter().zip([u, w, v]) {
+            assert!(
+                (axis.dot(expected).abs() - s(1.0)).abs() <= tolerance,
+                "{:?}",
+                pa.axes
+            );
+        }
+        assert!((pa.axes[0].cross(pa.axes[1]) - pa.axes[2]).magnitude() <= tolerance);
+
+        let single = principal_axes(&[offset]).unwrap();
+        assert_eq!(single.variances, [T::Scalar::ZERO; 3]);
+        assert!((single.axes[0].cross(single.axes[1]) - single.axes[2]).magnitude() <= tolerance);
+
+        for bad in [f32::NAN, f32::INFINITY] {
+            points[3].set_y(s(bad));
+            let pa = principal_axes(&points).unwrap();
+            assert!(
+                pa.variances.iter().all(|v| v.is_nan()),
+                "{:?}",
+                pa.variances
+            );
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn test_matrix3<M: crate::GenericMatrix3>() {
+        let s = |v: f32| -> M::Scalar { v.into() };
+        let v2 = |x: f32, y: f32| M::Vector2::new_2d(s(x), s(y));
+        let v3 = |x: f32, y: f32, z: f32| M::Vector3::new_3d(s(x), s(y), s(z));
+
+        let rows = [
+            [s(2.0), s(0.0), s(1.0)],
+            [s(0.0), s(4.0), s(-3.0)],
+            [s(0.0), s(0.0), s(1.0)],
+        ];
+        let m = M::from_rows(rows);
+        assert_eq!(m.to_rows(), rows);
+        assert_eq!(m.col(2), v3(1.0, -3.0, 1.0));
+        assert_eq!(m.transpose().col(0), v3(2.0, 0.0, 1.0));
+        assert_eq!(m.determinant(), s(8.0));
+        // scale (2, 4), then translate by (1, -3)
+        assert_eq!(
+            m,
+            M::from_translation(v2(1.0, -3.0)) * M::from_scale(v2(2.0, 4.0))
+        );
+        assert_eq!(m.transform_point2(v2(1.0, 1.0)), v2(3.0, 1.0));
+        assert_eq!(m.transform_vector2(v2(1.0, 1.0)), v2(2.0, 4.0));
+        assert_eq!(m.mul_vec3(v3(1.0, 1.0, 0.0)), v3(2.0, 4.0, 0.0));
+
+        let inverse = m.try_inverse().unwrap();
+        assert_eq!(inverse * m, M::identity());
+        assert_eq!(inverse.transform_point2(v2(3.0, 1.0)), v2(1.0, 1.0));
+        assert!(M::from_scale(v2(1.0, 0.0)).try_inverse().is_none());
+
+        let r = M::from_angle(s(std::f32::consts::FRAC_PI_2));
+        let p = r.transform_point2(v2(1.0, 0.0));
+        assert!((p - v2(0.0, 1.0)).magnitude() < s(1e-6));
+        assert!((r.determinant() - s(1.0)).abs() < s(1e-6));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_matrix2<M: crate::GenericMatrix2>() {
+        let s = |v: f32| -> M::Scalar { v.into() };
+        let v2 = |x: f32, y: f32| M::Vector2::new_2d(s(x), s(y));
+
+        let rows = [[s(2.0), s(1.0)], [s(0.0), s(4.0)]];
+        let m = M::from_rows(rows);
+        assert_eq!(m.to_rows(), rows);
+        assert_eq!(m.col(1), v2(1.0, 4.0));
+        assert_eq!(m.transpose().col(0), v2(2.0, 1.0));
+        assert_eq!(m.determinant(), s(8.0));
+        assert_eq!(m.mul_vec2(v2(1.0, 1.0)), v2(3.0, 4.0));
+        assert_eq!(
+            M::from_scale(v2(2.0, 4.0)).mul_vec2(v2(1.0, 1.0)),
+            v2(2.0, 4.0)
+        );
+
+        let inverse = m.try_inverse().unwrap();
+        assert_eq!(inverse * m, M::identity());
+        assert_eq!(inverse.mul_vec2(v2(3.0, 4.0)), v2(1.0, 1.0));
+        assert!(M::from_scale(v2(1.0, 0.0)).try_inverse().is_none());
+
+        let r = M::from_angle(s(std::f32::consts::FRAC_PI_2));
+        assert!((r.mul_vec2(v2(1.0, 0.0)) - v2(0.0, 1.0)).magnitude() < s(1e-6));
+        assert!((r.determinant() - s(1.0)).abs() < s(1e-6));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_matrix4<M: crate::GenericMatrix4>() {
+        use crate::{GenericMatrix3, GenericVector4, HasXYZW};
+        let s = |v: f32| -> M::Scalar { v.into() };
+        let v3 = |x: f32, y: f32, z: f32| M::Vector3::new_3d(s(x), s(y), s(z));
+        let v4 = |x: f32, y: f32, z: f32, w: f32| M::Vector4::new_4d(s(x), s(y), s(z), s(w));
+        let near = |a: M, b: M| {
+            let (a, b) = (a.to_rows(), b.to_rows());
+            (0..4).all(|i| (0..4).all(|j| (a[i][j] - b[i][j]).abs() < s(1e-5)))
+        };
+
+        // scale (2, 4, 8), then translate by (1, -3, 5)
+        let rows = [
+            [s(2.0), s(0.0), s(0.0), s(1.0)],
+            [s(0.0), s(4.0), s(0.0), s(-3.0)],
+            [s(0.0), s(0.0), s(8.0), s(5.0)],
+            [s(0.0), s(0.0), s(0.0), s(1.0)],
+        ];
+        let m = M::from_rows(rows);
+        assert_eq!(m.to_rows(), rows);
+        assert_eq!(m.col(3), v4(1.0, -3.0, 5.0, 1.0));
+        assert_eq!(m.row(0), v4(2.0, 0.0, 0.0, 1.0));
+        assert_eq!(m.transpose().col(0), v4(2.0, 0.0, 0.0, 1.0));
+        assert_eq!(m.determinant(), s(64.0));
+        assert_eq!(
+            m,
+            M::from_translation(v3(1.0, -3.0, 5.0)) * M::from_scale(v3(2.0, 4.0, 8.0))
+        );
+        assert_eq!(m.transform_point3(v3(1.0, 1.0, 1.0)), v3(3.0, 1.0, 13.0));
+        assert_eq!(m.transform_vector3(v3(1.0, 1.0, 1.0)), v3(2.0, 4.0, 8.0));
+        assert_eq!(m.mul_vec4(v4(1.0, 1.0, 1.0, 0.0)), v4(2.0, 4.0, 8.0, 0.0));
+        assert_eq!(m.project_point3(v3(1.0, 1.0, 1.0)), v3(3.0, 1.0, 13.0));
+
+        let inverse = m.try_inverse().unwrap();
+        assert!(near(inverse * m, M::identity()));
+        // a matrix without zeros exercises every cofactor of the inverse
+        let dense = M::from_rows([
+            [s(2.0), s(1.0), s(-1.0), s(3.0)],
+            [s(1.0), s(3.0), s(2.0), s(-2.0)],
+            [s(-1.0), s(2.0), s(4.0), s(1.0)],
+            [s(0.5), s(-1.0), s(1.0), s(2.0)],
+        ]);
+        assert!(near(dense.try_inverse().unwrap() * dense, M::identity()));
+        assert!(near(dense * dense.try_inverse().unwrap(), M::identity()));
+        assert!(M::from_scale(v3(1.0, 0.0, 1.0)).try_inverse().is_none());
+
+        // a quarter turn about z from a 3x3 matrix, with a translation
+        let euler = crate::EulerAngles::new(
+            crate::EulerOrder::XYZ,
+            [s(0.0), s(0.0), s(std::f32::consts::FRAC_PI_2)],
+        );
+        let rotation = M::Matrix3::from_euler(&euler);
+        let r = M::from_matrix3_translation(&rotation, v3(0.0, 0.0, 1.0));
+        let p = r.transform_point3(v3(1.0, 0.0, 0.0));
+        assert!((p - v3(0.0, 1.0, 1.0)).magnitude() < s(1e-6));
+        assert!((r.determinant() - s(1.0)).abs() < s(1e-6));
+        assert_eq!(r.to_matrix3(), rotation);
+        assert_eq!(r.col(3).truncate(), v3(0.0, 0.0, 1.0));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_affine2<A: crate::GenericAffine2>() {
+        let s = |v: f32| -> A::Scalar { v.into() };
+        let v2 = |x: f32, y: f32| A::Vector2::new_2d(s(x), s(y));
+        let near = |a: A::Vector2, b: A::Vector2| (a - b).magnitude() < s(1e-5);
+
+        let p = v2(0.5, -1.5);
+        assert_eq!(A::identity().transform_point2(p), p);
+        // scale by 2, rotate a quarter turn, translate by (1, 2)
+        let a =
+            A::from_scale_angle_translation(s(2.0), s(std::f32::consts::FRAC_PI_2), v2(1.0, 2.0));
+        assert_eq!(a.translation(), v2(1.0, 2.0));
+        assert!(near(a.transform_point2(v2(1.0, 0.0)), v2(1.0, 4.0)));
+        assert!(near(a.transform_vector2(v2(1.0, 0.0)), v2(0.0, 2.0)));
+
+        let inverse = a.try_inverse().unwrap();
+        assert!(near(inverse.transform_point2(a.transform_point2(p)), p));
+        assert!(near(inverse.compose(&a).transform_point2(p), p));
+
+        let b = A::from_scale_angle_translation(s(1.0), s(0.0), v2(3.0, 0.0));
+        assert!(near(
+            a.compose(&b).transform_point2(p),
+            a.transform_point2(b.transform_point2(p))
+        ));
+
+        assert!(A::from_scale_angle_translation(s(0.0), s(1.0), p)
+            .try_inverse()
+            .is_none());
+    }
+
+    #[allow(dead_code)]
+    pub fn test_affine3<A: crate::GenericAffine3>() {
+        let s = |v: f32| -> A::Scalar { v.into() };
+        let v3 = |x: f32, y: f32, z: f32| A::Vector3::new_3d(s(x), s(y), s(z));
+        let near = |a: A::Vector3, b: A::Vector3| (a - b).magnitude() < s(1e-5);
+
+        let p = v3(0.5, -1.5, 2.0);
+        assert_eq!(A::identity().transform_point3(p), p);
+        // scale by 2, rotate a quarter turn about z, translate by (1, 2, 3)
+        let a = A::from_scale_axis_angle_translation(
+            s(2.0),
+            v3(0.0, 0.0, 1.0),
+            s(std::f32::consts::FRAC_PI_2),
+            v3(1.0, 2.0, 3.0),
+        );
+        assert_eq!(a.translation(), v3(1.0, 2.0, 3.0));
+        assert!(near(
+            a.transform_point3(v3(1.0, 0.0, 1.0)),
+            v3(1.0, 4.0, 5.0)
+        ));
+        assert!(near(
+            a.transform_vector3(v3(1.0, 0.0, 0.0)),
+            v3(0.0, 2.0, 0.0)
+        ));
+
+        let inverse = a.try_inverse().unwrap();
+        assert!(near(inverse.transform_point3(a.transform_point3(p)), p));
+        assert!(near(inverse.compose(&a).transform_point3(p), p));
+
+        let b = A::from_scale_axis_angle_translation(
+            s(1.0),
+            v3(1.0, 0.0, 0.0),
+            s(1.0),
+            v3(3.0, 0.0, 0.0),
+        );
+        assert!(near(
+            a.compose(&b).transform_point3(p),
+            a.transform_point3(b.transform_point3(p))
+        ));
+
+        assert!(
+            A::from_scale_axis_angle_translation(s(0.0), v3(1.0, 0.0, 0.0), s(1.0), p)
+                .try_inverse()
+                .is_none()
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn test_rotation3<R: crate::GenericRotation3>() {
+        test_matrix_rotation3::<R>();
+        let s = |v: f32| -> R::Scalar { v.into() };
+        let z = R::Vector3::new_3d(s(0.0), s(0.0), s(1.0));
+        let q = R::from_axis_angle(z, s(std::f32::consts::FRAC_PI_2));
+        let [qx, qy, qz, qw] = q.to_xyzw();
+        assert_eq!(R::from_xyzw(qx, qy, qz, qw), q);
+    }
+
+    /// `test_rotation3()` without the exact round trip of the components, for rotations stored
+    /// as matrices.
+    #[allow(dead_code)]
+    pub fn test_matrix_rotation3<R: crate::GenericRotation3>() {
+        use crate::GenericMatrix3;
+        let s = |v: f32| -> R::Scalar { v.into() };
+        let v3 = |x: f32, y: f32, z: f32| R::Vector3::new_3d(s(x), s(y), s(z));
+        let near = |a: R::Vector3, b: R::Vector3| (a - b).magnitude() < s(1e-5);
+        let (x, y) = (v3(1.0, 0.0, 0.0), v3(0.0, 1.0, 0.0));
+
+        assert!(near(R::identity().rotate_vector(x), x));
+        // a quarter turn about z
+        let q = R::from_axis_angle(v3(0.0, 0.0, 1.0), s(std::f32::consts::FRAC_PI_2));
+        assert!(near(q.rotate_vector(x), y));
+        assert!(near(q.to_matrix().mul_vec3(x), y));
+        let t = q.rotate_xyz((s(1.0), s(0.0), s(0.0)));
+        assert!(near(R::Vector3::new_3d(t.0, t.1, t.2), y));
+        assert!(near(q.inverse().rotate_vector(y), x));
+        assert!(near(q.compose(q).rotate_vector(x), -x));
+        let [qx, qy, qz, qw] = q.to_xyzw();
+        assert!(near(R::from_xyzw(qx, qy, qz, qw).rotate_vector(x), y));
+
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+        let expected = v3(half, half, 0.0);
+        assert!(near(
+            R::identity().slerp(q, s(0.5)).rotate_vector(x),
+            expected
+        ));
+        assert!(near(R::identity().slerp(q, s(0.0)).rotate_vector(x), x));
+        // -q is the same rotation, the interpolation takes the short path anyway
+        let minus_q = R::from_xyzw(-qx, -qy, -qz, -qw);
+        assert!(near(
+            R::identity().slerp(minus_q, s(0.5)).rotate_vector(x),
+            expected
+        ));
+
+        // from_matrix() in each of its branches, the trace and the x, y and z diagonals
+        let pi = s(std::f32::consts::PI);
+        for r in [
+            R::from_axis_angle(v3(0.6, 0.0, 0.8), s(1.0)),
+            R::from_axis_angle(x, pi),
+            R::from_axis_angle(y, pi),
+            R::from_axis_angle(v3(0.0, 0.0, 1.0), pi),
+        ] {
+            let back = R::from_matrix(&r.to_matrix());
+            for v in [x, y, v3(0.3, -0.4, 2.0)] {
+                assert!(near(back.rotate_vector(v), r.rotate_vector(v)));
+            }
+        }
+
+        let euler = crate::EulerAngles::new(crate::EulerOrder::ZYX, [s(0.3), s(-0.7), s(1.1)]);
+        let r = R::from_euler(&euler);
+        let m = R::Matrix3::from_euler(&euler);
+        for v in [x, y, v3(0.3, -0.4, 2.0)] {
+            assert!(near(r.rotate_vector(v), euler.rotate_vector(v)));
+            assert!(near(m.mul_vec3(v), euler.rotate_vector(v)));
+        }
+        let angles = r.to_euler(crate::EulerOrder::ZYX).angles;
+        for (a, b) in angles.into_iter().zip(euler.angles) {
+            assert!((a - b).abs() < s(1e-5));
+        }
+
+        // look along y with z up, the local x axis turns to y and the local z axis stays up
+        let (z, forward) = (v3(0.0, 0.0, 1.0), v3(0.0, 2.0, 0.0));
+        let r = R::look_rotation(forward, z).unwrap();
+        let m = R::Matrix3::look_rotation(forward, z).unwrap();
+        assert!(near(r.rotate_vector(x), y));
+        assert!(near(r.rotate_vector(z), z));
+        assert!(near(m.mul_vec3(x), y));
+        assert!(near(m.mul_vec3(z), z));
+        assert!(R::look_rotation(z, z).is_none());
+        assert!(R::Matrix3::look_rotation(z * s(0.0), z).is_none());
+    }
+
+    #[allow(dead_code)]
+    pub fn test_fit_plane<T: GenericVector3>() {
+        use crate::{fit_plane, fit_plane_checked, sampling::halton_2d, PlaneFitError};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let tolerance = s(1e-4);
+        let normal = T::new_3d(s(1.0), s(2.0), s(-2.0)).normalize();
+        let origin = T::new_3d(s(10.0), s(-5.0), s(3.0));
+        let u = normal.cross(T::new_3d(s(1.0), s(0.0), s(0.0))).normalize();
+        let v = normal.cross(u);
+
+        // points on the plane, alternating just above and below it
+        let points: Vec<T> = halton_2d(
+            T::Vector2::new_2d(s(-2.0), s(-2.0)),
+            T::Vector2::new_2d(s(2.0), s(2.0)),
+            64,
+            1,
+        )
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let offset = if i % 2 == 0 { s(0.01) } else { s(-0.01) };
+            origin + u * p.x() + v * p.y() + normal * offset
+        })
+        .collect();
+        let fit = fit_plane_checked(&points).unwrap();
+        assert!(fit.plane.normal.dot(normal).abs() >= s(1.0) - tolerance);
+        assert!(fit.plane.signed_distance(origin).abs() <= s(0.01));
+        // the least-squares plane can only be closer to the points than the original plane
+        assert!(fit.rms_distance <= s(0.01) + tolerance, "{:?}", fit);
+        let projected = fit.plane.project(points[0]);
+        assert!(fit.plane.signed_distance(projected).abs() <= tolerance);
+        assert_eq!(fit_plane(&points), Some(fit.plane));
+
+        assert_eq!(
+            fit_plane_checked(&points[..2]).unwrap_err(),
+            PlaneFitError::TooFewPoints
+        );
+        let line: Vec<T> = (0..5).map(|i| origin + u * s(i as f32)).collect();
+        assert_eq!(
+            fit_plane_checked(&line).unwrap_err(),
+            PlaneFitError::Collinear
+        );
+        assert_eq!(
+            fit_plane_checked(&[origin; 4]).unwrap_err(),
+            PlaneFitError::Collinear
+        );
+        assert!(fit_plane(&line).is_none());
+        for bad in [f32::NAN, f32::INFINITY, f32::NEG_INFINITY] {
+            let mut points = points.clone();
+            points[5].set_z(s(bad));
+            assert_eq!(
+                fit_plane_checked(&points).unwrap_err(),
+                PlaneFitError::NonFinite
+            );
+            assert!(fit_plane(&points).is_none());
+        }
+    }
+
+    #[allow(dead_code)]
+    pub fn test_grid_downsample_2d<T: GenericVector2>() {
+        use crate::{grid_downsample_2d, CellRepresentative};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let p = |x: f32, y: f32| T::new_2d(s(x), s(y));
+        let points = [
+            p(0.1, 0.1),
+            p(0.3, -0.2),
+            p(2.0, 2.0),
+            p(-0.1, 0.4),
+            p(2.2, 1.8),
+            p(f32::NAN, 0.0),
+        ];
+        let first = grid_downsample_2d(&points, s(1.0), CellRepresentative::First);
+        assert_eq!(first, vec![points[0], points[2]]);
+        let centroids = grid_downsample_2d(&points, s(1.0), CellRepresentative::Centroid);
+        assert_eq!(centroids.len(), 2);
+        assert!(centroids[0].is_abs_diff_eq(p(0.1, 0.1), s(1e-6)));
+        assert!(centroids[1].is_abs_diff_eq(p(2.1, 1.9), s(1e-6)));
+        assert!(grid_downsample_2d(&points, s(0.0), CellRepresentative::First).is_empty());
+        assert_eq!(
+            grid_downsample_2d(&points, s(0.01), CellRepresentative::First).len(),
+            5
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn test_grid_downsample_3d<T: GenericVector3>() {
+        use crate::{grid_downsample_3d, sampling::grid_3d, CellRepresentative};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        // 4x4x4 points with a spacing of 0.5, two points per cell along every axis
+        let min = T::new_3d(s(0.0), s(0.0), s(0.0));
+        let max = T::new_3d(s(2.0), s(2.0), s(2.0));
+        let points = grid_3d(min, max, [4, 4, 4]);
+        let first = grid_downsample_3d(&points, s(1.0), CellRepresentative::First);
+        assert_eq!(first.len(), 27);
+        assert!(first.iter().all(|p| points.contains(p)));
+        let centroids = grid_downsample_3d(&points, s(1.0), CellRepresentative::Centroid);
+        assert_eq!(centroids.len(), first.len());
+        assert_eq!(centroids[0], first[0]);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_weld_vertices_2d<T: GenericVector2>() {
+        use crate::weld_vertices_2d;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let p = |x: f32, y: f32| T::new_2d(s(x), s(y));
+        let points = [
+            p(0.0, 0.0),
+            p(1.0, 0.0),
+            p(0.05, -0.05),
+            p(1.0, 0.0),
+            p(-0.09, 0.0),
+            p(0.0, 0.2),
+        ];
+        let (kept, remap) = weld_vertices_2d(&points, s(0.1));
+        assert_eq!(kept, vec![points[0], points[1], points[5]]);
+        assert_eq!(remap, vec![0, 1, 0, 1, 0, 2]);
+        for (p, i) in points.iter().zip(remap.iter()) {
+            assert!(p.distance(kept[*i as usize]) <= s(0.1));
+        }
+
+        // only equal points are merged without a tolerance
+        let (kept, remap) = weld_vertices_2d(&points, s(0.0));
+        assert_eq!(kept.len(), 5);
+        assert_eq!(remap, vec![0, 1, 2, 1, 3, 4]);
+        let (kept, remap) = weld_vertices_2d(&[p(f32::NAN, 0.0), p(f32::NAN, 0.0)], s(0.1));
+        assert_eq!((kept.len(), remap), (1, vec![0, 0]));
+        // the cell key of -2^63 is i64::MIN, which has no neighbour below it
+        let edge = p(-9.223_372e18, 0.0);
+        let (kept, remap) = weld_vertices_2d(&[edge, edge], s(0.5));
+        assert_eq!((kept, remap), (vec![edge], vec![0, 0]));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_weld_vertices_3d<T: GenericVector3>() {
+        use crate::{sampling::grid_3d, weld_vertices_3d};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let min = T::new_3d(s(-1.0), s(-1.0), s(-1.0));
+        let max = T::new_3d(s(1.0), s(1.0), s(1.0));
+        let grid = grid_3d(min, max, [4, 4, 4]);
+        // every grid point, followed by a slightly moved copy of it
+        let nudge = T::new_3d(s(0.01), s(-0.01), s(0.01));
+        let points: Vec<T> = grid
+            .iter()
+            .copied()
+            .chain(grid.iter().map(|p| *p + nudge))
+            .collect();
+        let (kept, remap) = weld_vertices_3d(&points, s(0.05));
+        assert_eq!(kept, grid);
+        let expected: Vec<u32> = (0..grid.len() as u32).chain(0..grid.len() as u32).collect();
+        assert_eq!(remap, expected);
+        let (kept, _) = weld_vertices_3d(&points, s(1.0));
+        assert!(kept.len() < grid.len());
+    }
+
+    #[allow(dead_code)]
+    pub fn test_direction_encoding<T: GenericVector3>() {
+        use crate::{
+            octahedral_decode, octahedral_encode, stereographic_project, stereographic_unproject,
+        };
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let tolerance = s(1e-5);
+        let mut directions = vec![
+            T::new_3d(s(0.0), s(0.0), s(1.0)),
+            T::new_3d(s(0.0), s(0.0), s(-1.0)),
+            T::new_3d(s(1.0), s(0.0), s(0.0)),
+            T::new_3d(s(0.0), s(-1.0), s(0.0)),
+            T::new_3d(s(-1.0), s(-1.0), s(-1.0)),
+        ];
+        for i in 0..40 {
+            let (yaw, pitch) = (s(i as f32 * 0.7), s((i as f32 * 0.37).sin() * 1.5));
+            directions.push(T::from_yaw_pitch(yaw, pitch));
+        }
+        for d in directions {
+            let unit = d.normalize();
+            let e = octahedral_encode(d);
+            assert!(e.x().abs() <= s(1.0) && e.y().abs() <= s(1.0));
+            let back = octahedral_decode(e);
+            assert!((back - unit).magnitude() <= tolerance, "{:?} {:?}", d, back);
+
+            match stereographic_project(d) {
+                Some(p) => {
+                    assert_eq!(p.magnitude() <= s(1.0) + tolerance, unit.z() >= -tolerance);
+                    let back = stereographic_unproject(p);
+                    assert!((back - unit).magnitude() <= tolerance, "{:?} {:?}", d, back);
+                }
+                None => assert!(unit.z() <= s(-1.0) + tolerance),
+            }
+        }
+        assert!(stereographic_project(T::new_3d(s(0.0), s(0.0), s(0.0))).is_none());
+    }
+
+    #[allow(dead_code)]
+    pub fn test_meshgrid_2d<T: GenericVector2>() {
+        use crate::sampling::{linspace_points, meshgrid_2d};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let (a, b) = (T::new_2d(s(-1.0), s(2.0)), T::new_2d(s(0.3), s(-0.7)));
+        let line: Vec<T> = linspace_points(a, b, 5).collect();
+        assert_eq!(line.len(), 5);
+        assert_eq!((line[0], line[4]), (a, b));
+        assert!(line[2].is_abs_diff_eq((a + b) / s(2.0), s(1e-6)));
+        assert_eq!(linspace_points(a, b, 1).collect::<Vec<_>>(), vec![a]);
+        assert_eq!(linspace_points(a, b, 0).count(), 0);
+
+        let grid: Vec<T> = meshgrid_2d(a, b, 3, 2).collect();
+        assert_eq!(grid.len(), 6);
+        assert_eq!((grid[0], grid[5]), (a, b));
+        assert_eq!(grid[2], T::new_2d(b.x(), a.y()));
+        assert_eq!(grid[3], T::new_2d(a.x(), b.y()));
+        assert!(grid[1].is_abs_diff_eq(T::new_2d(s(-0.35), s(2.0)), s(1e-6)));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_meshgrid_3d<T: GenericVector3>() {
+        use crate::sampling::{linspace_points, meshgrid_3d};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let (min, max) = (
+            T::new_3d(s(0.0), s(0.0), s(0.0)),
+            T::new_3d(s(1.0), s(2.0), s(4.0)),
+        );
+        let line: Vec<T> = linspace_points(min, max, 3).collect();
+        assert_eq!(line[1], T::new_3d(s(0.5), s(1.0), s(2.0)));
+
+        let grid: Vec<T> = meshgrid_3d(min, max, [2, 3, 5]).collect();
+        assert_eq!(grid.len(), 30);
+        assert_eq!((grid[0], grid[29]), (min, max));
+        assert_eq!(grid[1], T::new_3d(s(1.0), s(0.0), s(0.0)));
+        assert_eq!(grid[2], T::new_3d(s(0.0), s(1.0), s(0.0)));
+        assert_eq!(grid[6], T::new_3d(s(0.0), s(0.0), s(1.0)));
+        assert_eq!(meshgrid_3d(min, max, [2, 0, 5]).count(), 0);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_convex_combination<T: GenericVector2>() {
+        use crate::convex_combination;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let p = |x: f32, y: f32| T::new_2d(s(x), s(y));
+        let (a, b, c) = (p(0.0, 0.0), p(4.0, 0.0), p(0.0, 8.0));
+        assert_eq!(
+            convex_combination([(a, s(2.0)), (b, s(1.0)), (c, s(1.0))]),
+            Some(p(1.0, 2.0))
+        );
+        // the weights do not have to sum to one, and zero weights are allowed
+        assert_eq!(
+            convex_combination([(a, s(0.0)), (b, s(3.0)), (c, s(0.0))]),
+            Some(b)
+        );
+        let far = p(1e6, -1e6);
+        assert_eq!(
+            convex_combination([(far, s(0.5)), (far, s(0.25))]),
+            Some(far)
+        );
+
+        assert_eq!(convex_combination(Vec::<(T, T::Scalar)>::new()), None);
+        assert_eq!(convex_combination([(a, s(0.0)), (b, s(0.0))]), None);
+        assert_eq!(convex_combination([(a, s(1.0)), (b, s(-0.5))]), None);
+        assert_eq!(convex_combination([(a, s(f32::NAN)), (b, s(1.0))]), None);
+        assert_eq!(
+            convex_combination([(a, s(1.0)), (b, s(f32::INFINITY))]),
+            None
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn test_rotate_90<T: GenericVector2>() {
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let v = T::new_2d(s(0.1), s(-3.7));
+        assert_eq!(v.rotate_90_ccw(), T::new_2d(s(3.7), s(0.1)));
+        assert_eq!(v.rotate_90_cw(), T::new_2d(s(-3.7), s(-0.1)));
+        assert_eq!(v.rotate_180(), -v);
+        assert_eq!(v.rotate_90_ccw().rotate_90_ccw(), v.rotate_180());
+        assert_eq!(v.rotate_90_ccw().rotate_90_cw(), v);
+        assert_eq!(v.rotate_180().rotate_180(), v);
+        // counterclockwise means a positive perp dot product
+        assert!(v.perp_dot(v.rotate_90_ccw()) > T::Scalar::ZERO);
+        assert_eq!(v.dot(v.rotate_90_cw()), T::Scalar::ZERO);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_orientation<T: GenericVector2>() {
+        use crate::Orientation;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let p = |x: f32, y: f32| T::new_2d(s(x), s(y));
+        let (a, b) = (p(0.0, 0.0), p(2.0, 0.0));
+        assert_eq!(
+            T::orientation(a, b, p(1.0, 1.0)),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(T::orientation(a, b, p(1.0, -1.0)), Orientation::Clockwise);
+        assert_eq!(T::orientation(a, b, p(5.0, 0.0)), Orientation::Collinear);
+        assert_eq!(
+            T::orientation(a, b, p(1.0, 0.001)),
+            Orientation::CounterClockwise
+        );
+
+        let eps = s(0.01);
+        assert_eq!(
+            T::orientation_eps(a, b, p(1.0, 0.001), eps),
+            Orientation::Collinear
+        );
+        assert_eq!(
+            T::orientation_eps(a, b, p(1.0, -0.005), eps),
+            Orientation::Collinear
+        );
+        assert_eq!(
+            T::orientation_eps(a, b, p(1.0, 0.02), eps),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            T::orientation_eps(a, b, p(9.0, -0.02), eps),
+            Orientation::Clockwise
+        );
+        // the tolerance is a distance, it does not scale with the length of the base line
+        let far = p(2000.0, 0.0);
+        assert_eq!(
+            T::orientation_eps(a, far, p(1.0, 0.02), eps),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            T::orientation_eps(a, a, p(1.0, 1.0), eps),
+            Orientation::Collinear
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn test_project_onto_plane<T: GenericVector3>() {
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let v = T::new_3d(s(1.0), s(2.0), s(3.0));
+        let up = T::new_3d(s(0.0), s(0.0), s(2.0));
+        assert_eq!(v.project_onto_plane(up), T::new_3d(s(1.0), s(2.0), s(0.0)));
+        let n = T::new_3d(s(1.0), s(-1.0), s(0.5));
+        let p = v.project_onto_plane(n);
+        assert!(p.dot(n).abs() <= s(1e-5));
+        assert!((v - p).cross(n).magnitude() <= s(1e-5));
+        let zero = T::new_3d(s(0.0), s(0.0), s(0.0));
+        assert_eq!(v.project_onto_plane(zero), v);
+
+        let (min, max) = (
+            T::new_3d(s(-1.0), s(0.0), s(0.0)),
+            T::new_3d(s(1.0), s(1.0), s(5.0)),
+        );
+        assert_eq!(v.clamp_to_aabb(min, max), T::new_3d(s(1.0), s(1.0), s(3.0)));
+        let inside = T::new_3d(s(0.5), s(0.5), s(0.5));
+        assert_eq!(inside.clamp_to_aabb(min, max), inside);
+        assert_eq!(
+            (-v).clamp_to_aabb(min, max),
+            T::new_3d(s(-1.0), s(0.0), s(0.0))
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn test_segment_2d<T: GenericVector2>() {
+        use crate::{closest_point_on_segment, distance_to_segment};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let p = |x: f32, y: f32| T::new_2d(s(x), s(y));
+        let (a, b) = (p(0.0, 0.0), p(4.0, 0.0));
+        assert_eq!(
+            closest_point_on_segment(p(1.0, 3.0), a, b),
+            (p(1.0, 0.0), s(0.25))
+        );
+        assert_eq!(distance_to_segment(p(1.0, 3.0), a, b), (s(3.0), s(0.25)));
+        // beyond the end points the closest point is clamped
+        assert_eq!(closest_point_on_segment(p(-2.0, 1.0), a, b), (a, s(0.0)));
+        assert_eq!(closest_point_on_segment(p(7.0, 4.0), a, b), (b, s(1.0)));
+        assert_eq!(distance_to_segment(p(7.0, 4.0), a, b), (s(5.0), s(1.0)));
+        // a degenerate segment is a point
+        assert_eq!(closest_point_on_segment(p(3.0, 4.0), a, a), (a, s(0.0)));
+        assert_eq!(distance_to_segment(p(3.0, 4.0), a, a), (s(5.0), s(0.0)));
+        let (q, t) = closest_point_on_segment(p(f32::NAN, 0.0), a, b);
+        assert_eq!((q, t), (a, s(0.0)));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_segment_3d<T: GenericVector3>() {
+        use crate::{closest_point_on_segment, distance_to_segment};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let p = |x: f32, y: f32, z: f32| T::new_3d(s(x), s(y), s(z));
+        let (a, b) = (p(0.0, 0.0, 2.0), p(0.0, 8.0, 2.0));
+        assert_eq!(
+            closest_point_on_segment(p(3.0, 2.0, 6.0), a, b),
+            (p(0.0, 2.0, 2.0), s(0.25))
+        );
+        assert_eq!(
+            distance_to_segment(p(3.0, 2.0, 6.0), a, b),
+            (s(5.0), s(0.25))
+        );
+        assert_eq!(
+            distance_to_segment(p(0.0, 10.0, 2.0), a, b),
+            (s(2.0), s(1.0))
+        );
+        assert_eq!(
+            distance_to_segment(p(0.0, 0.0, 2.0), b, b),
+            (s(8.0), s(0.0))
+        );
+    }
+
+    #[allow(dead_code)]
+    pub fn test_distance_to_line<T: GenericVector3>() {
+        use crate::{distance_to_line_2d, distance_to_line_3d};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let p2 = |x: f32, y: f32| T::Vector2::new_2d(s(x), s(y));
+        let (a, b) = (p2(1.0, 1.0), p2(5.0, 1.0));
+        // unlike the segment, the line extends past the end points
+        assert_eq!(distance_to_line_2d(p2(9.0, 4.0), a, b), s(3.0));
+        assert_eq!(distance_to_line_2d(p2(-9.0, -1.0), a, b), s(-2.0));
+        assert_eq!(distance_to_line_2d(p2(-9.0, 4.0), b, a), s(-3.0));
+        assert_eq!(distance_to_line_2d(p2(4.0, 5.0), a, a), s(5.0));
+
+        let p3 = |x: f32, y: f32, z: f32| T::new_3d(s(x), s(y), s(z));
+        let (a, b) = (p3(0.0, 0.0, 1.0), p3(0.0, 0.0, 3.0));
+        assert_eq!(distance_to_line_3d(p3(3.0, 4.0, -7.0), a, b), s(5.0));
+        assert_eq!(distance_to_line_3d(p3(3.0, 4.0, 7.0), b, a), s(5.0));
+        assert_eq!(distance_to_line_3d(p3(0.0, 0.0, 5.0), a, b), s(0.0));
+        assert_eq!(distance_to_line_3d(p3(0.0, 4.0, 4.0), a, a), s(5.0));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_signed_angle_about_axis<T: GenericVector3>() {
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let p = |x: f32, y: f32, z: f32| T::new_3d(s(x), s(y), s(z));
+        let close = |a: T::Scalar, b: f32| (a - s(b)).abs() <= s(1e-5);
+        let (x, y, z) = (p(1.0, 0.0, 0.0), p(0.0, 1.0, 0.0), p(0.0, 0.0, 1.0));
+        assert!(close(x.signed_angle_about_axis(y, z), FRAC_PI_2));
+        assert!(close(y.signed_angle_about_axis(x, z), -FRAC_PI_2));
+        assert!(close(x.signed_angle_about_axis(y, -z), -FRAC_PI_2));
+        assert!(close(y.signed_angle_about_axis(z, x), FRAC_PI_2));
+        assert!(close(x.signed_angle_about_axis(-x, z).abs(), PI));
+        // the components along the axis and the lengths are ignored
+        let a = p(2.0, 0.0, 5.0);
+        let b = p(3.0, 3.0, -7.0);
+        assert!(close(
+            a.signed_angle_about_axis(b, p(0.0, 0.0, 4.0)),
+            FRAC_PI_4
+        ));
+        assert!(close(b.signed_angle_about_axis(a, z), -FRAC_PI_4));
+        // degenerate input
+        assert_eq!(x.signed_angle_about_axis(y, p(0.0, 0.0, 0.0)), s(0.0));
+        assert_eq!(z.signed_angle_about_axis(x, z), s(0.0));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_running_stats<T: GenericVector3>() {
+        use crate::RunningStats;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let p2 = |x: f32, y: f32| T::Vector2::new_2d(s(x), s(y));
+        let mut stats = RunningStats::<T::Vector2>::new();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.mean(), None);
+        assert_eq!(stats.min(0), None);
+        assert_eq!(stats.variance(0), None);
+        stats.push(p2(1.0, 10.0));
+        assert_eq!(stats.sample_variance(0), None);
+        stats.extend([p2(3.0, 10.0), p2(5.0, 10.0), p2(7.0, 10.0)]);
+        assert_eq!(stats.count(), 4);
+        assert_eq!(stats.mean(), Some(p2(4.0, 10.0)));
+        assert_eq!((stats.min(0), stats.max(0)), (Some(s(1.0)), Some(s(7.0))));
+        assert_eq!(stats.variance(0), Some(s(5.0)));
+        assert_eq!(stats.std_dev(1), Some(s(0.0)));
+        assert!((stats.sample_variance(0).unwrap() - s(20.0 / 3.0)).abs() <= s(1e-5));
+        assert_eq!(stats.min(2), None);
+
+        // far from the origin, where a sum of squares would cancel out
+        let p3 = |x: f32, y: f32, z: f32| T::new_3d(s(x), s(y), s(z));
+        let stats: RunningStats<T> = [-1.0, 1.0, -1.0, 1.0]
+            .into_iter()
+            .map(|d| p3(1e6 + d, 2.0, -1e6 - 2.0 * d))
+            .collect();
+        assert_eq!(stats.mean(), Some(p3(1e6, 2.0, -1e6)));
+        assert_eq!(stats.variance(0), Some(s(1.0)));
+        assert_eq!(stats.variance(2), Some(s(4.0)));
+        assert_eq!(
+            (stats.min(2), stats.max(2)),
+            (Some(s(-1e6 - 2.0)), Some(s(-1e6 + 2.0)))
+        );
+        assert_eq!(stats.min(3), None);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_flip<T: GenericVector3>() {
+        use crate::{Axis2, Axis3};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let v2 = T::Vector2::new_2d(s(1.5), s(-2.0));
+        assert_eq!(v2.flip_x(), T::Vector2::new_2d(s(-1.5), s(-2.0)));
+        assert_eq!(v2.flip_y(), T::Vector2::new_2d(s(1.5), s(2.0)));
+        assert_eq!(v2.mirror_across_axis(Axis2::X), v2.flip_y());
+        assert_eq!(v2.mirror_across_axis(Axis2::Y), v2.flip_x());
+        assert_eq!(v2.flip_x().flip_x(), v2);
+
+        let v = T::new_3d(s(1.5), s(-2.0), s(3.0));
+        assert_eq!(v.flip_x(), T::new_3d(s(-1.5), s(-2.0), s(3.0)));
+        assert_eq!(v.flip_y(), T::new_3d(s(1.5), s(2.0), s(3.0)));
+        assert_eq!(v.flip_z(), T::new_3d(s(1.5), s(-2.0), s(-3.0)));
+        assert_eq!(v.mirror_across_axis(Axis3::X), v.flip_y().flip_z());
+        assert_eq!(v.mirror_across_axis(Axis3::Y), v.flip_x().flip_z());
+        assert_eq!(v.mirror_across_axis(Axis3::Z), v.flip_x().flip_y());
+        // the sign of zero is flipped too
+        let zero = T::new_3d(s(0.0), s(0.0), s(0.0));
+        assert!(zero.flip_x().x().is_sign_negative());
+    }
+
+    #[allow(dead_code)]
+    pub fn test_drop_dominant_axis<T: GenericVector3>() {
+        use crate::{Axis3, GenericVector2};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let p = |x: f32, y: f32, z: f32| T::new_3d(s(x), s(y), s(z));
+        let p2 = |x: f32, y: f32| T::Vector2::new_2d(s(x), s(y));
+        for (v, expected, axis) in [
+            (p(-5.0, 2.0, 3.0), p2(2.0, 3.0), Axis3::X),
+            (p(1.0, 4.0, 3.0), p2(3.0, 1.0), Axis3::Y),
+            (p(1.0, 2.0, -3.0), p2(1.0, 2.0), Axis3::Z),
+            // ties go to the lowest axis
+            (p(2.0, -2.0, 2.0), p2(-2.0, 2.0), Axis3::X),
+        ] {
+            assert_eq!(v.drop_dominant_axis(), (expected, axis));
+            assert_eq!(expected.insert_axis(axis, v[axis]), v);
+        }
+
+        // a counterclockwise triangle seen from the tip of its normal stays counterclockwise
+        // when the dropped component is positive
+        let tri = [p(0.0, 0.0, 0.0), p(0.0, 1.0, 0.0), p(0.0, 0.0, 1.0)];
+        let normal = (tri[1] - tri[0]).cross(tri[2] - tri[0]);
+        let (_, axis) = normal.drop_dominant_axis();
+        assert_eq!(axis, Axis3::X);
+        let [a, b, c] = tri.map(|v| v.drop_axis(axis));
+        assert!((b - a).perp_dot(c - a) > s(0.0));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_cgmath_compat<T: GenericVector3>() {
+        use crate::CgmathCompat;
+        use std::f32::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let close = |a: T::Scalar, b: f32| (a - s(b)).abs() <= s(1e-6);
+        let v2 = T::Vector2::new_2d(s(3.0), s(4.0));
+        assert_eq!(v2.magnitude2(), s(25.0));
+        assert_eq!(v2.normalize_to(s(10.0)), T::Vector2::new_2d(s(6.0), s(8.0)));
+        assert!(close(
+            v2.angle(T::Vector2::new_2d(s(-4.0), s(3.0))),
+            FRAC_PI_2
+        ));
+        assert_eq!(
+            v2.lerp(T::Vector2::new_2d(s(5.0), s(0.0)), s(0.5)),
+            T::Vector2::new_2d(s(4.0), s(2.0))
+        );
+
+        let x = T::new_3d(s(2.0), s(0.0), s(0.0));
+        let xy = T::new_3d(s(1.0), s(1.0), s(0.0));
+        assert_eq!(xy.magnitude2(), s(2.0));
+        assert!(close(x.angle(xy), FRAC_PI_4));
+        assert!(close(x.angle(-x), PI));
+        assert_eq!(x.angle(x), s(0.0));
+        // nearly parallel vectors, where an acos based angle rounds to zero
+        let tiny = T::new_3d(s(1.0), s(1e-4), s(0.0));
+        assert!((x.angle(tiny) - s(1e-4)).abs() <= s(1e-9));
+        assert_eq!(x.lerp(xy, s(0.0)), x);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_sum<T: GenericVector3>() {
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let points = [
+            T::new_3d(s(1.0), s(2.0), s(3.0)),
+            T::new_3d(s(-4.0), s(0.5), s(0.0)),
+        ];
+        let expected = T::new_3d(s(-3.0), s(2.5), s(3.0));
+        assert_eq!(points.iter().sum::<T>(), expected);
+        assert_eq!(points.into_iter().sum::<T>(), expected);
+        assert_eq!(
+            std::iter::empty::<T>().sum::<T>(),
+            T::new_3d(s(0.0), s(0.0), s(0.0))
+        );
+        let points2 = points.map(|p| p.to_2d());
+        assert_eq!(points2.iter().sum::<T::Vector2>(), expected.to_2d());
+    }
+
+    #[allow(dead_code)]
+    pub fn test_scale<T: GenericVector3>() {
+        use crate::math::scale;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let v = T::new_3d(s(1.0), s(-2.0), s(0.5));
+        assert_eq!(scale(s(2.0), v), T::new_3d(s(2.0), s(-4.0), s(1.0)));
+        assert_eq!(scale(s(-0.5), v.to_2d()), v.to_2d() * s(-0.5));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_batch<T: GenericVector3>() {
+        use crate::{
+            batches_2d, batches_3d, ArrayBatch, GenericVector2xN, GenericVector3xN, ScalarBatch,
+            Vec2xN, Vec3xN,
+        };
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let p = |x: f32, y: f32, z: f32| T::new_3d(s(x), s(y), s(z));
+        let points = [
+            p(1.0, 2.0, 3.0),
+            p(0.0, 3.0, 4.0),
+            p(-2.0, 0.0, 0.0),
+            p(1.0, 1.0, 1.0),
+            p(0.5, -4.0, 2.0),
+        ];
+        let axis = p(0.0, 0.0, 1.0);
+        let mut used = 0;
+        let mut out = [p(0.0, 0.0, 0.0); 5];
+        for (k, (batch, lanes)) in batches_3d::<ArrayBatch<T::Scalar, 4>, T>(&points).enumerate() {
+            let dots = batch.dot(&Vec3xN::splat(axis));
+            let crosses = batch.cross(&Vec3xN::splat(axis));
+            for i in 0..lanes {
+                let v = points[k * 4 + i];
+                assert_eq!(batch.lane::<T>(i), v);
+                assert_eq!(dots.lane(i), v.dot(axis));
+                assert_eq!(crosses.lane::<T>(i), v.cross(axis));
+            }
+            // the unused lanes are zero
+            assert!(batch.magnitude_sq().0[lanes..].iter().all(|m| *m == s(0.0)));
+            batch.store(&mut out[k * 4..]);
+            used += lanes;
+        }
+        assert_eq!(used, points.len());
+        assert_eq!(out, points);
+
+        let normalized = Vec3xN::<ArrayBatch<_, 4>>::load(&points[1..]).normalize();
+        assert!(normalized
+            .lane::<T>(0)
+            .is_abs_diff_eq(p(0.0, 0.6, 0.8), s(1e-6)));
+        assert_eq!(normalized.magnitude().lane(1), s(1.0));
+
+        let points2 = points.map(|v| v.to_2d());
+        let (mut batch, lanes) = batches_2d::<ArrayBatch<T::Scalar, 8>, T::Vector2>(&points2)
+            .next()
+            .unwrap();
+        assert_eq!(lanes, 5);
+        let ones = Vec2xN::splat(T::Vector2::new_2d(s(1.0), s(1.0)));
+        assert_eq!(batch.dot(&ones).lane(1), s(3.0));
+        assert_eq!(batch.perp_dot(&ones).lane(1), s(-3.0));
+        assert_eq!(batch.magnitude().lane(1), s(3.0));
+        assert_eq!(batch.lane::<T::Vector2>(4), points2[4]);
+        batch.set_lane(7, points2[1]);
+        let normalized: T::Vector2 = batch.normalize().lane(7);
+        assert!((normalized - points2[1].normalize()).magnitude() < s(1e-6));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_flat<T: GenericVector3>() {
+        use crate::{
+            from_flat_arrays, from_flat_scalars, to_flat_arrays, to_flat_scalars, FromFlat,
+            IntoFlat,
+        };
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let v = T::new_3d(s(1.0), s(2.0), s(3.0));
+        let a: [T::Scalar; 3] = v.into_flat();
+        let b: [T::Scalar; 2] = v.into_flat();
+        assert_eq!(a, [s(1.0), s(2.0), s(3.0)]);
+        assert_eq!(b, [s(1.0), s(2.0)]);
+        assert_eq!(T::from_flat(a), v);
+        assert_eq!(T::from_flat(b), T::new_3d(s(1.0), s(2.0), s(0.0)));
+        assert_eq!(T::Vector2::from_flat(b), v.to_2d());
+
+        let points = [v, -v];
+        let arrays = to_flat_arrays::<T, 3>(&points);
+        assert_eq!(arrays[1], [s(-1.0), s(-2.0), s(-3.0)]);
+        assert_eq!(from_flat_arrays::<T, 3>(&arrays), points);
+        let scalars = to_flat_scalars::<T, 3>(&points);
+        assert_eq!(scalars.len(), 6);
+        assert_eq!(scalars[3], s(-1.0));
+        assert_eq!(from_flat_scalars::<T, 3>(&scalars), Some(points.to_vec()));
+        assert_eq!(from_flat_scalars::<T, 3>(&scalars[1..]), None);
+        let pairs = from_flat_scalars::<T::Vector2, 2>(&scalars[..4]).unwrap();
+        assert_eq!(pairs, [v.to_2d(), T::Vector2::new_2d(s(3.0), s(-1.0))]);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_int_vector<T: crate::GenericIntVector3>() {
+        use crate::GenericIntVector2;
+        use num_traits::Bounded;
+        let s = |v: u8| -> T::Scalar { num_traits::NumCast::from(v).unwrap() };
+        let a = T::new_3d(s(1), s(5), s(3));
+        let b = T::new_3d(s(4), s(2), s(6));
+        assert_eq!(a.dot(b), s(32));
+        assert_eq!(a.manhattan_length(), s(9));
+        assert_eq!(a.min(b), T::new_3d(s(1), s(2), s(3)));
+        assert_eq!(a.max(b), T::new_3d(s(4), s(5), s(6)));
+        assert_eq!((a.min_element(), a.max_element()), (s(1), s(5)));
+        assert_eq!(a + b - a, b);
+        assert_eq!(a * s(2), T::new_3d(s(2), s(10), s(6)));
+        assert_eq!(a[2], s(3));
+
+        let max = T::Scalar::max_value();
+        let min = T::Scalar::min_value();
+        let m = T::new_3d(max, max, max);
+        let ones = T::new_3d(s(1), s(1), s(1));
+        assert_eq!(m.wrapping_add(ones), T::new_3d(min, min, min));
+        assert_eq!(T::new_3d(min, min, min).wrapping_sub(ones), m);
+        assert_eq!(m.wrapping_sub(m), T::new_3d(s(0), s(0), s(0)));
+        assert_eq!(a.wrapping_mul(b), T::new_3d(s(4), s(10), s(18)));
+
+        let a2 = a.to_2d();
+        assert_eq!(a2, T::Vector2::new_2d(s(1), s(5)));
+        assert_eq!(a2.to_3d(s(3)), a);
+        assert_eq!(a2.dot(b.to_2d()), s(14));
+        assert_eq!(a2.manhattan_length(), s(6));
+        assert_eq!(
+            m.to_2d().wrapping_add(ones.to_2d()),
+            T::Vector2::new_2d(min, min)
+        );
+
+        // the float conversions
+        let f: [f64; 3] = a.to_float_lossy();
+        assert_eq!(f, [1.0, 5.0, 3.0]);
+        assert_eq!(a.to_float_checked::<[f64; 3]>(), Some(f));
+        assert_eq!(T::from_float_checked([1.0, 5.0, 3.0]), Some(a));
+        assert_eq!(T::from_float_checked([1.5f32, 5.0, 3.0]), None);
+        assert_eq!(T::from_float_checked([f64::NAN, 5.0, 3.0]), None);
+        assert_eq!(T::from_float_lossy([1.9f32, 5.2, 3.0]), a);
+        assert_eq!(T::from_float_lossy([f64::INFINITY, 5.0, 3.0]).x(), max);
+        assert_eq!(T::Vector2::from_float_checked([1.0f32, 5.0]), Some(a2));
+        assert_eq!(a2.to_float_lossy::<(f32, f32)>(), (1.0, 5.0));
+        // not every large integer is a float
+        let big = T::new_3d(s(1), s(1), max);
+        assert_eq!(big.to_float_checked::<[f32; 3]>(), None);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_vector4<T: crate::GenericVector4>() {
+        use crate::GenericVector4;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let a = T::new_4d(s(1.0), s(2.0), s(2.0), s(4.0));
+        let b = T::new_4d(s(2.0), s(0.0), s(1.0), s(-1.0));
+        assert_eq!(T::DIM, 4);
+        assert_eq!(
+            (a.x(), a.y(), a.z(), a.w()),
+            (s(1.0), s(2.0), s(2.0), s(4.0))
+        );
+        assert_eq!(a.get(3), Some(s(4.0)));
+        assert_eq!(a.get(4), None);
+        assert_eq!(a[3], s(4.0));
+        assert_eq!(T::new_3d(s(1.0), s(2.0), s(3.0)).w(), s(0.0));
+        assert_eq!(T::new_2d(s(1.0), s(2.0)).z(), s(0.0));
+
+        assert_eq!(GenericVector4::dot(a, b), s(0.0));
+        assert_eq!(GenericVector4::magnitude_sq(a), s(25.0));
+        assert_eq!(GenericVector4::magnitude(a), s(5.0));
+        assert_eq!(GenericVector4::distance_sq(a, a + b), s(6.0));
+        assert!(
+            GenericVector4::normalize(a).is_abs_diff_eq(a / s(5.0), T::Scalar::default_epsilon())
+        );
+        assert!(GenericVector4::safe_normalize(a)
+            .unwrap()
+            .is_approx_eq(GenericVector4::normalize(a)));
+        assert_eq!(GenericVector4::safe_normalize(a * s(0.0)), None);
+
+        let v3 = a.truncate();
+        assert_eq!(v3, T::Vector3::new_3d(s(1.0), s(2.0), s(2.0)));
+        assert_eq!(T::extend(v3, s(4.0)), a);
+        assert_eq!([a, b].iter().sum::<T>(), a + b);
+
+        let mut c = a;
+        *c.w_mut() = s(7.0);
+        assert_eq!(c.w(), s(7.0));
+        c.set_from_array4([s(4.0), s(3.0), s(2.0), s(1.0)]);
+        assert_eq!(c, T::new_4d(s(4.0), s(3.0), s(2.0), s(1.0)));
+        assert!(!c.is_approx_eq(T::new_4d(s(4.0), s(3.0), s(2.0), s(1.5))));
+    }
+
+    // written against the scalar core, so it runs on fixed point and float scalars alike
+    #[allow(dead_code)]
+    pub fn test_core_vector<S: crate::ScalarCore>() {
+        use crate::{CoreVec2, CoreVec3};
+        let s = S::from_f64_lossy;
+        let a = CoreVec3::new(s(1.0), s(2.0), s(2.0));
+        let b = CoreVec3::new(s(2.0), s(0.0), s(-1.0));
+        assert_eq!(a.dot(b), S::zero());
+        assert_eq!(a.magnitude_sq(), s(9.0));
+        assert_eq!(a.magnitude(), s(3.0));
+        assert_eq!(a.cross(b), CoreVec3::new(s(-2.0), s(5.0), s(-4.0)));
+        assert_eq!(a.distance_sq(a + b), s(5.0));
+        assert_eq!((a * s(2.0)) / s(2.0), a);
+        assert_eq!(-a + a, CoreVec3::default());
+        assert_eq!([a, b].into_iter().sum::<CoreVec3<S>>(), a + b);
+        assert_eq!(a.to_2d().to_3d(s(2.0)), a);
+        assert_eq!(a.to_2d().perp_dot(b.to_2d()), s(-4.0));
+        assert_eq!(
+            CoreVec2::new(s(3.0), s(4.0)).distance(CoreVec2::default()),
+            s(5.0)
+        );
+        assert_eq!(s(-1.5).abs_value(), s(1.5));
+        let f: [f64; 3] = a.to_float();
+        assert_eq!(f, [1.0, 2.0, 2.0]);
+        assert_eq!(CoreVec3::<S>::from_float(f), a);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_mask_2d<T: GenericVector2>() {
+        use crate::GenericMask;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let (a, b) = (T::new_2d(s(1.0), s(5.0)), T::new_2d(s(2.0), s(5.0)));
+        assert_eq!(a.cmplt(b).bitmask(), 0b01);
+        assert_eq!(a.cmple(b).bitmask(), 0b11);
+        assert_eq!(a.cmpgt(b).bitmask(), 0b00);
+        assert_eq!(b.cmpgt(a).bitmask(), 0b01);
+        assert_eq!(a.cmpeq(b).bitmask(), 0b10);
+        assert!(a.cmple(b).all() && !a.cmpgt(b).any());
+        assert_eq!(T::select(a.cmplt(b), b, a), T::new_2d(s(2.0), s(5.0)));
+        assert_eq!((!a.cmplt(b) & a.cmple(b)).bitmask(), 0b10);
+        // NaN compares false both ways
+        let nan = T::new_2d(T::Scalar::nan(), s(5.0));
+        assert_eq!((nan.cmplt(a) | nan.cmpgt(a)).bitmask(), 0b00);
+        assert_eq!(T::Mask::splat(true).bitmask(), 0b11);
+        assert!(T::Mask::from_bitmask(0b10).test(1));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_mask_3d<T: GenericVector3>() {
+        use crate::GenericMask;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        // a branch free point in box test
+        let (min, max) = (
+            T::new_3d(s(0.0), s(0.0), s(0.0)),
+            T::new_3d(s(1.0), s(2.0), s(3.0)),
+        );
+        let inside = |p: T| (min.cmple(p) & p.cmple(max)).all();
+        assert!(inside(T::new_3d(s(0.5), s(2.0), s(0.0))));
+        assert!(!inside(T::new_3d(s(0.5), s(2.5), s(0.0))));
+        let (a, b) = (
+            T::new_3d(s(1.0), s(5.0), s(-1.0)),
+            T::new_3d(s(2.0), s(5.0), s(-2.0)),
+        );
+        assert_eq!(a.cmplt(b).bitmask(), 0b001);
+        assert_eq!(a.cmpgt(b).bitmask(), 0b100);
+        assert_eq!(a.cmpeq(b).bitmask(), 0b010);
+        // the componentwise maximum
+        assert_eq!(
+            T::select(a.cmpgt(b), a, b),
+            T::new_3d(s(2.0), s(5.0), s(-1.0))
+        );
+        assert_eq!(T::Mask::DIM, 3);
+        assert_eq!(T::Mask::splat(true).bitmask(), 0b111);
+    }
+
+    #[allow(dead_code)]
+    pub fn test_int_conversion_2d<T: GenericVector2>() {
+        use crate::GenericIntVector2;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let i = |x: i32, y: i32| T::IntVector::new_2d(x, y);
+        let v = T::new_2d(s(1.5), s(-1.5));
+        assert_eq!(v.as_ivec(), i(1, -1));
+        assert_eq!(v.floor_to_int(), i(1, -2));
+        assert_eq!(v.round_to_int(), i(2, -2));
+        // the grid cell of a point
+        assert_eq!((T::new_2d(s(-0.25), s(7.75))).floor_to_int(), i(-1, 7));
+        let big = T::new_2d(s(1.0e10), s(-1.0e10));
+        assert_eq!(big.as_ivec(), i(i32::MAX, i32::MIN));
+        assert_eq!(big.round_to_int(), i(i32::MAX, i32::MIN));
+    }
+
+    #[allow(dead_code)]
+    pub fn test_int_conversion_3d<T: GenericVector3>() {
+        use crate::GenericIntVector3;
+        let s = |v: f32| -> T::Scalar { v.into() };
+        let i = |x: i32, y: i32, z: i32| T::IntVector::new_3d(x, y, z);
+        let v = T::new_3d(s(1.5), s(-1.5), s(2.49));
+        assert_eq!(v.as_ivec(), i(1, -1, 2));
+        assert_eq!(v.floor_to_int(), i(1, -2, 2));
+        assert_eq!(v.round_to_int(), i(2, -2, 2));
+        assert_eq!(v.round_to_int()[2], 2);
+        let big = T::new_3d(s(1.0e10), s(-1.0e10), s(0.0));
+        assert_eq!(big.floor_to_int(), i(i32::MAX, i32::MIN, 0));
+    }
 }
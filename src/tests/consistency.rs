@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Runs identical pseudo random workloads through the glam and cgmath implementations and checks
+//! that every trait method agrees within a few ULPs. Only compiled when both backends are enabled.
+
+use crate::{
+    glam_impl::Vec2A, GenericScalar, GenericVector2, GenericVector3, GenericVector4, HasXY,
+};
+use num_traits::Float;
+
+const ITERATIONS: usize = 1000;
+const MAX_ULPS: u32 = 4;
+
+/// A xorshift generator, so the workload is identical on every run.
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Returns a value in [-1000, 1000), or an edge case value once in a while.
+    fn scalar<S: GenericScalar>(&mut self) -> S {
+        let r = self.next_u64();
+        match r % 16 {
+            0 => S::ZERO,
+            1 => -S::ZERO,
+            2 => S::EPSILON,
+            _ => {
+                let unit = (r >> 11) as f64 / (1_u64 << 53) as f64;
+                S::from_f64(unit * 2000.0 - 1000.0).unwrap()
+            }
+        }
+    }
+}
+
+/// Both NaN, or equal within `MAX_ULPS`, or within a few epsilon of `scale` to allow for the
+/// cancellation in sums computed in a different order.
+#[track_caller]
+fn assert_agree<S: GenericScalar>(what: &str, a: S, b: S, scale: S) {
+    let tolerance = S::EPSILON * scale * 4.0.into();
+    assert!(
+        (Float::is_nan(a) && Float::is_nan(b)) || a.ulps_eq(&b, tolerance, MAX_ULPS) || a == b,
+        "{}: {:?} != {:?}",
+        what,
+        a,
+        b
+    );
+}
+
+#[track_caller]
+fn assert_agree_xy<A: HasXY, B: HasXY<Scalar = A::Scalar>>(
+    what: &str,
+    a: A,
+    b: B,
+    scale: A::Scalar,
+) {
+    assert_eq!(A::DIM, B::DIM, "{}", what);
+    for i in 0..A::DIM {
+        assert_agree(what, a.get(i).unwrap(), b.get(i).unwrap(), scale);
+    }
+}
+
+#[track_caller]
+fn assert_same_bits<A: HasXY, B: HasXY<Scalar = A::Scalar>>(what: &str, a: A, b: B) {
+    for i in 0..A::DIM {
+        assert_eq!(
+            a.get(i).unwrap().to_bits(),
+            b.get(i).unwrap().to_bits(),
+            "{}",
+            what
+        );
+    }
+}
+
+fn compare_2d<A: GenericVector2, B: GenericVector2<Scalar = A::Scalar>>(seed: u64) {
+    let mut rng = Rng(seed);
+    for _ in 0..ITERATIONS {
+        let (x0, y0, x1, y1, s) = (
+            rng.scalar::<A::Scalar>(),
+            rng.scalar(),
+            rng.scalar(),
+            rng.scalar(),
+            rng.scalar(),
+        );
+        let (a0, a1) = (A::new_2d(x0, y0), A::new_2d(x1, y1));
+        let (b0, b1) = (B::new_2d(x0, y0), B::new_2d(x1, y1));
+        let m0 = a0.magnitude_hypot();
+        let m1 = a1.magnitude_hypot();
+        let one = A::Scalar::ONE;
+
+        assert_same_bits("new_2d", a0, b0);
+        assert_same_bits("canonicalize", a0.canonicalize(), b0.canonicalize());
+        assert_same_bits(
+            "flush_denormals",
+            a0.flush_denormals(),
+            b0.flush_denormals(),
+        );
+        assert_agree("magnitude", a0.magnitude(), b0.magnitude(), m0);
+        assert_agree(
+            "magnitude_sq",
+            a0.magnitude_sq(),
+            b0.magnitude_sq(),
+            m0 * m0,
+        );
+        assert_agree("magnitude_hypot", m0, b0.magnitude_hypot(), m0);
+        assert_agree("dot", a0.dot(a1), b0.dot(b1), m0 * m1);
+        assert_agree("perp_dot", a0.perp_dot(a1), b0.perp_dot(b1), m0 * m1);
+        assert_agree("distance", a0.distance(a1), b0.distance(b1), m0 + m1);
+        let d_sq = (m0 + m1) * (m0 + m1);
+        assert_agree("distance_sq", a0.distance_sq(a1), b0.distance_sq(b1), d_sq);
+        assert_agree(
+            "angle_between",
+            a0.angle_between(a1),
+            b0.angle_between(b1),
+            one,
+        );
+        assert_agree_xy("normalize", a0.normalize(), b0.normalize(), one);
+        match (a0.safe_normalize(), b0.safe_normalize()) {
+            (Some(a), Some(b)) => assert_agree_xy("safe_normalize", a, b, one),
+            (None, None) => {}
+            (a, b) => panic!("safe_normalize: {:?} != {:?}", a, b),
+        }
+        assert_agree_xy("add", a0 + a1, b0 + b1, m0 + m1);
+        assert_agree_xy("sub", a0 - a1, b0 - b1, m0 + m1);
+        assert_agree_xy("neg", -a0, -b0, m0);
+        assert_agree_xy("mul", a0 * s, b0 * s, m0 * Float::abs(s));
+        assert_agree_xy("div", a0 / s, b0 / s, m0 / Float::abs(s));
+        assert_agree_xy("to_3d", a0.to_3d(s), b0.to_3d(s), m0);
+        assert_eq!(
+            a0.is_eq_relative_to_norm(a1, 0.5.into()),
+            b0.is_eq_relative_to_norm(b1, 0.5.into())
+        );
+    }
+}
+
+fn compare_3d<A: GenericVector3, B: GenericVector3<Scalar = A::Scalar>>(seed: u64) {
+    let mut rng = Rng(seed);
+    for _ in 0..ITERATIONS {
+        let (x0, y0, z0, x1, y1, z1, s) = (
+            rng.scalar::<A::Scalar>(),
+            rng.scalar(),
+            rng.scalar(),
+            rng.scalar(),
+            rng.scalar(),
+            rng.scalar(),
+            rng.scalar(),
+        );
+        let (a0, a1) = (A::new_3d(x0, y0, z0), A::new_3d(x1, y1, z1));
+        let (b0, b1) = (B::new_3d(x0, y0, z0), B::new_3d(x1, y1, z1));
+        let m0 = a0.magnitude_hypot();
+        let m1 = a1.magnitude_hypot();
+        let one = A::Scalar::ONE;
+
+        assert_same_bits("new_3d", a0, b0);
+        assert_same_bits("new_2d", A::new_2d(x0, y0), B::new_2d(x0, y0));
+        assert_same_bits("canonicalize", a0.canonicalize(), b0.canonicalize());
+        assert_same_bits(
+            "flush_denormals",
+            a0.flush_denormals(),
+            b0.flush_denormals(),
+        );
+        assert_agree("magnitude", a0.magnitude(), b0.magnitude(), m0);
+        assert_agree(
+            "magnitude_sq",
+            a0.magnitude_sq(),
+            b0.magnitude_sq(),
+            m0 * m0,
+        );
+        assert_agree("magnitude_hypot", m0, b0.magnitude_hypot(), m0);
+        assert_agree("dot", a0.dot(a1), b0.dot(b1), m0 * m1);
+        assert_agree_xy("cross", a0.cross(a1), b0.cross(b1), m0 * m1);
+        assert_agree("distance", a0.distance(a1), b0.distance(b1), m0 + m1);
+        let d_sq = (m0 + m1) * (m0 + m1);
+        assert_agree("distance_sq", a0.distance_sq(a1), b0.distance_sq(b1), d_sq);
+        assert_agree(
+            "angle_between",
+            a0.angle_between(a1),
+            b0.angle_between(b1),
+            one,
+        );
+        assert_agree_xy("normalize", a0.normalize(), b0.normalize(), one);
+        match (a0.safe_normalize(), b0.safe_normalize()) {
+            (Some(a), Some(b)) => assert_agree_xy("safe_normalize", a, b, one),
+            (None, None) => {}
+            (a, b) => panic!("safe_normalize: {:?} != {:?}", a, b),
+        }
+        assert_agree_xy("add", a0 + a1, b0 + b1, m0 + m1);
+        assert_agree_xy("sub", a0 - a1, b0 - b1, m0 + m1);
+        assert_agree_xy("neg", -a0, -b0, m0);
+        assert_agree_xy("mul", a0 * s, b0 * s, m0 * Float::abs(s));
+        assert_agree_xy("div", a0 / s, b0 / s, m0 / Float::abs(s));
+        assert_agree_xy("to_2d", a0.to_2d(), b0.to_2d(), m0);
+        assert_eq!(
+            a0.is_eq_relative_to_norm(a1, 0.5.into()),
+            b0.is_eq_relative_to_norm(b1, 0.5.into())
+        );
+    }
+}
+
+fn compare_4d<A: GenericVector4, B: GenericVector4<Scalar = A::Scalar>>(seed: u64) {
+    let mut rng = Rng(seed);
+    for _ in 0..ITERATIONS {
+        let c0: [A::Scalar; 4] = std::array::from_fn(|_| rng.scalar());
+        let c1: [A::Scalar; 4] = std::array::from_fn(|_| rng.scalar());
+        let (a0, a1) = (
+            A::new_4d(c0[0], c0[1], c0[2], c0[3]),
+            A::new_4d(c1[0], c1[1], c1[2], c1[3]),
+        );
+        let (b0, b1) = (
+            B::new_4d(c0[0], c0[1], c0[2], c0[3]),
+            B::new_4d(c1[0], c1[1], c1[2], c1[3]),
+        );
+        let m0 = a0.magnitude();
+        let m1 = a1.magnitude();
+        let one = A::Scalar::ONE;
+
+        assert_same_bits("new_4d", a0, b0);
+        assert_same_bits(
+            "new_3d",
+            A::new_3d(c0[0], c0[1], c0[2]),
+            B::new_3d(c0[0], c0[1], c0[2]),
+        );
+        assert_same_bits("truncate", a0.truncate(), b0.truncate());
+        assert_agree("magnitude", m0, b0.magnitude(), m0);
+        assert_agree(
+            "magnitude_sq",
+            a0.magnitude_sq(),
+            b0.magnitude_sq(),
+            m0 * m0,
+        );
+        assert_agree("dot", a0.dot(a1), b0.dot(b1), m0 * m1);
+        assert_agree("distance", a0.distance(a1), b0.distance(b1), m0 + m1);
+        let d_sq = (m0 + m1) * (m0 + m1);
+        assert_agree("distance_sq", a0.distance_sq(a1), b0.distance_sq(b1), d_sq);
+        assert_agree_xy("normalize", a0.normalize(), b0.normalize(), one);
+        match (a0.safe_normalize(), b0.safe_normalize()) {
+            (Some(a), Some(b)) => assert_agree_xy("safe_normalize", a, b, one),
+            (None, None) => {}
+            (a, b) => panic!("safe_normalize: {:?} != {:?}", a, b),
+        }
+        assert_agree_xy("add", a0 + a1, b0 + b1, m0 + m1);
+        assert_agree_xy("sub", a0 - a1, b0 - b1, m0 + m1);
+    }
+}
+
+#[test]
+fn test_consistency_2d() {
+    compare_2d::<glam::Vec2, cgmath::Vector2<f32>>(0x2545_f491_4f6c_dd1d);
+    compare_2d::<Vec2A, cgmath::Vector2<f32>>(0x2545_f491_4f6c_dd1d);
+    compare_2d::<glam::DVec2, cgmath::Vector2<f64>>(0x9e37_79b9_7f4a_7c15);
+}
+
+#[test]
+fn test_consistency_3d() {
+    compare_3d::<glam::Vec3, cgmath::Vector3<f32>>(0x2545_f491_4f6c_dd1d);
+    compare_3d::<glam::Vec3A, cgmath::Vector3<f32>>(0x2545_f491_4f6c_dd1d);
+    compare_3d::<glam::DVec3, cgmath::Vector3<f64>>(0x9e37_79b9_7f4a_7c15);
+}
+
+#[test]
+fn test_consistency_4d() {
+    compare_4d::<glam::Vec4, cgmath::Vector4<f32>>(0x2545_f491_4f6c_dd1d);
+    compare_4d::<glam::DVec4, cgmath::Vector4<f64>>(0x9e37_79b9_7f4a_7c15);
+}
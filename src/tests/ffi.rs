@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! A custom FFI storage type, implementing only `HasXY`, `HasXYZ` and an empty `Approx`.
+
+use crate::{Approx, GenericScalar, HasXY, HasXYZ};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FfiVec3 {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl HasXY for FfiVec3 {
+    type Scalar = f64;
+    const DIM: usize = 3;
+    fn new_2d(x: f64, y: f64) -> Self {
+        Self { x, y, z: 0.0 }
+    }
+    fn x(self) -> f64 {
+        self.x
+    }
+    fn x_mut(&mut self) -> &mut f64 {
+        &mut self.x
+    }
+    fn set_x(&mut self, val: f64) {
+        self.x = val;
+    }
+    fn y(self) -> f64 {
+        self.y
+    }
+    fn y_mut(&mut self) -> &mut f64 {
+        &mut self.y
+    }
+    fn set_y(&mut self, val: f64) {
+        self.y = val;
+    }
+    fn get(self, index: usize) -> Option<f64> {
+        HasXY::get([self.x, self.y, self.z], index)
+    }
+    fn canonicalize(self) -> Self {
+        Self::new_3d(
+            self.x.canonicalize(),
+            self.y.canonicalize(),
+            self.z.canonicalize(),
+        )
+    }
+    fn flush_denormals(self) -> Self {
+        Self::new_3d(
+            self.x.flush_denormal(),
+            self.y.flush_denormal(),
+            self.z.flush_denormal(),
+        )
+    }
+}
+
+impl HasXYZ for FfiVec3 {
+    fn new_3d(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+    fn z(self) -> f64 {
+        self.z
+    }
+    fn z_mut(&mut self) -> &mut f64 {
+        &mut self.z
+    }
+    fn set_z(&mut self, val: f64) {
+        self.z = val;
+    }
+}
+
+impl Approx for FfiVec3 {}
+
+#[test]
+fn test_default_approx() {
+    let a = FfiVec3::new_3d(1.0, 2.0, 3.0);
+    let b = FfiVec3::new_3d(1.0, 2.0, 3.0 + f64::EPSILON * 2.0);
+    assert!(a.is_approx_eq(b));
+    assert!(a.is_ulps_eq(b, 0.0, 2));
+    assert!(!a.is_ulps_eq(b, 0.0, 0));
+    assert!(a.is_abs_diff_eq(FfiVec3::new_3d(1.0, 2.0, 3.1), 0.11));
+    // the z component takes part in the comparison
+    assert!(!a.is_abs_diff_eq(FfiVec3::new_3d(1.0, 2.0, 3.1), 0.09));
+    assert!(!a.is_approx_eq(FfiVec3::new_3d(1.0, 2.0, f64::NAN)));
+    assert!(crate::slices_abs_diff_eq(&[a, b], &[b, a], 1e-12).is_ok());
+}
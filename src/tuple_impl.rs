@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `HasXY`, `HasXYZ` and `Approx` for the scalar tuples `(S, S)` and `(S, S, S)`, as produced by
+//! CSV and JSON ingestion code, so parsed points go through the algorithms written against the
+//! storage traits as they are.
+//!
+//! Like plain arrays, tuples have no arithmetic operators. Convert them to `ArrayVec2` or
+//! `ArrayVec3`, or any other vector type with `HasXY::new_2d()`, when the generic vector traits
+//! are needed.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{Approx, Axis2, Axis3, GenericScalar, HasXY, HasXYZ};
+use std::ops::Index;
+
+impl<S: GenericScalar> HasXY for (S, S) {
+    type Scalar = S;
+    const DIM: usize = 2;
+    #[inline(always)]
+    fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+        (x, y)
+    }
+    #[inline(always)]
+    fn x(self) -> Self::Scalar {
+        self.0
+    }
+    #[inline(always)]
+    fn set_x(&mut self, val: Self::Scalar) {
+        self.0 = val
+    }
+    #[inline(always)]
+    fn x_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.0
+    }
+    #[inline(always)]
+    fn y(self) -> Self::Scalar {
+        self.1
+    }
+    #[inline(always)]
+    fn set_y(&mut self, val: Self::Scalar) {
+        self.1 = val
+    }
+    #[inline(always)]
+    fn y_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.1
+    }
+    #[inline(always)]
+    fn get(self, index: usize) -> Option<Self::Scalar> {
+        match index {
+            0 => Some(self.0),
+            1 => Some(self.1),
+            _ => None,
+        }
+    }
+    #[inline(always)]
+    fn canonicalize(self) -> Self {
+        (self.0.canonicalize(), self.1.canonicalize())
+    }
+    #[inline(always)]
+    fn flush_denormals(self) -> Self {
+        (self.0.flush_denormal(), self.1.flush_denormal())
+    }
+}
+
+impl<S: GenericScalar> Index<Axis2> for (S, S) {
+    type Output = S;
+
+    #[inline(always)]
+    fn index(&self, axis: Axis2) -> &Self::Output {
+        match axis {
+            Axis2::X => &self.0,
+            Axis2::Y => &self.1,
+        }
+    }
+}
+
+impl<S: GenericScalar> Approx for (S, S) {}
+
+impl<S: GenericScalar> HasXY for (S, S, S) {
+    type Scalar = S;
+    const DIM: usize = 3;
+    #[inline(always)]
+    fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+        (x, y, S::ZERO)
+    }
+    #[inline(always)]
+    fn x(self) -> Self::Scalar {
+        self.0
+    }
+    #[inline(always)]
+    fn set_x(&mut self, val: Self::Scalar) {
+        self.0 = val
+    }
+    #[inline(always)]
+    fn x_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.0
+    }
+    #[inline(always)]
+    fn y(self) -> Self::Scalar {
+        self.1
+    }
+    #[inline(always)]
+    fn set_y(&mut self, val: Self::Scalar) {
+        self.1 = val
+    }
+    #[inline(always)]
+    fn y_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.1
+    }
+    #[inline(always)]
+    fn get(self, index: usize) -> Option<Self::Scalar> {
+        match index {
+            0 => Some(self.0),
+            1 => Some(self.1),
+            2 => Some(self.2),
+            _ => None,
+        }
+    }
+    #[inline(always)]
+    fn canonicalize(self) -> Self {
+        (
+            self.0.canonicalize(),
+            self.1.canonicalize(),
+            self.2.canonicalize(),
+        )
+    }
+    #[inline(always)]
+    fn flush_denormals(self) -> Self {
+        (
+            self.0.flush_denormal(),
+            self.1.flush_denormal(),
+            self.2.flush_denormal(),
+        )
+    }
+}
+
+impl<S: GenericScalar> HasXYZ for (S, S, S) {
+    #[inline(always)]
+    fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+        (x, y, z)
+    }
+    #[inline(always)]
+    fn z(self) -> Self::Scalar {
+        self.2
+    }
+    #[inline(always)]
+    fn set_z(&mut self, val: Self::Scalar) {
+        self.2 = val
+    }
+    #[inline(always)]
+    fn z_mut(&mut self) -> &mut Self::Scalar {
+        &mut self.2
+    }
+}
+
+impl<S: GenericScalar> Index<Axis3> for (S, S, S) {
+    type Output = S;
+
+    #[inline(always)]
+    fn index(&self, axis: Axis3) -> &Self::Output {
+        match axis {
+            Axis3::X => &self.0,
+            Axis3::Y => &self.1,
+            Axis3::Z => &self.2,
+        }
+    }
+}
+
+impl<S: GenericScalar> Approx for (S, S, S) {}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use crate::{distance_robust, slices_abs_diff_eq, Approx, ArrayVec3, Axis2, Axis3, HasXY, HasXYZ};
+
+#[test]
+fn test_tuples() {
+    crate::tests::tests::test_xy::<(f32, f32)>(1.0, 2.0);
+    crate::tests::tests::test_xy::<(f64, f64)>(1.0, 2.0);
+    crate::tests::tests::test_xy::<(f32, f32, f32)>(1.0, 2.0);
+    crate::tests::tests::test_xyz::<(f64, f64, f64)>(1.0, 2.0, 3.0);
+
+    let p = (1.0, 2.0, 3.0);
+    assert_eq!((p[Axis3::Z], (5.0, 6.0)[Axis2::Y]), (3.0, 6.0));
+    assert!(p.is_abs_diff_eq((1.0, 2.0, 3.0 + 1e-9), 1e-6));
+    assert_eq!(distance_robust(p, (4.0, 6.0, 3.0)), 5.0);
+    assert_eq!(<(f32, f32, f32)>::new_2d(1.0, 2.0), (1.0, 2.0, 0.0));
+}
+
+#[test]
+fn test_tuple_ingestion() {
+    // parsed rows go straight into the generic code
+    let rows = [(1.0, 2.0, 3.0), (3.0, 2.0, 1.0)];
+    let parsed = [(1.0, 2.0, 3.0 + 1e-9), (3.0, 2.0, 1.0)];
+    assert!(slices_abs_diff_eq(&rows, &parsed, 1e-6).is_ok());
+    let v: ArrayVec3<f64> = HasXYZ::new_3d(rows[1].x(), rows[1].y(), rows[1].z());
+    assert_eq!(v, ArrayVec3::new(3.0, 2.0, 1.0));
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! Double-double scalars, for geometry where the robustness of `f64` is not enough.
+//!
+//! A `twofloat::TwoFloat` is the unevaluated sum of two `f64`, with about 106 bits of precision.
+//! It does not implement the `num_traits` float interface `GenericScalar` requires, and this crate
+//! can not implement it for a foreign type, so `TwoFloat` implements the float-agnostic
+//! `ScalarCore` and works with `TwoFloatVec2` and `TwoFloatVec3`, the `CoreVec2` and `CoreVec3`
+//! vectors over it. `from_float()` converts an `f64` vector exactly, so a computation can be
+//! redone in double-double precision from the original input, e.g. the orientation test of nearly
+//! collinear points, and rounded back with `to_float()`.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{CoreVec2, CoreVec3, ScalarCore};
+pub use ::twofloat::TwoFloat;
+
+impl ScalarCore for TwoFloat {
+    #[inline(always)]
+    fn abs_value(self) -> Self {
+        TwoFloat::abs(&self)
+    }
+    #[inline(always)]
+    fn sqrt_value(self) -> Self {
+        TwoFloat::sqrt(self)
+    }
+    #[inline(always)]
+    fn to_f64_lossy(self) -> f64 {
+        self.into()
+    }
+    #[inline(always)]
+    fn from_f64_lossy(value: f64) -> Self {
+        value.into()
+    }
+}
+
+/// A two-dimensional vector over double-double scalars.
+pub type TwoFloatVec2 = CoreVec2<TwoFloat>;
+
+/// A three-dimensional vector over double-double scalars.
+pub type TwoFloatVec3 = CoreVec3<TwoFloat>;
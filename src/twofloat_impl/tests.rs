@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{TwoFloat, TwoFloatVec2, TwoFloatVec3};
+use crate::ScalarCore;
+
+#[test]
+fn test_twofloat() {
+    crate::tests::tests::test_core_vector::<TwoFloat>();
+
+    // the f64 products round away the difference, the double-double products keep it
+    let (a, b) = ([1.0 + f64::EPSILON, 1.0], [1.0, 1.0 - f64::EPSILON / 2.0]);
+    assert_eq!(a[0] * b[1] - a[1] * b[0], 0.0);
+    let perp = TwoFloatVec2::from_float(a).perp_dot(TwoFloatVec2::from_float(b));
+    assert!(perp > TwoFloat::from(0.0));
+    // the exact value, 2^-53 - 2^-105, fits in an f64
+    let eps = f64::EPSILON;
+    assert_eq!(perp.to_f64_lossy(), eps / 2.0 - eps * eps / 2.0);
+
+    let v = TwoFloatVec3::from_float([1.0_f64, 1.0, 1.0]);
+    let third = v / TwoFloat::from(3.0);
+    let round_trip: [f64; 3] = (third * TwoFloat::from(3.0)).to_float();
+    assert_eq!(round_trip, [1.0, 1.0, 1.0]);
+    assert!((v.magnitude().to_f64_lossy() - 3.0_f64.sqrt()).abs() <= f64::EPSILON);
+    assert_eq!(TwoFloat::from(-4.0).abs_value(), TwoFloat::from(4.0));
+}
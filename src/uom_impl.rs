@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! A bridge to `uom`, for vectors of physical quantities such as `uom::si::f64::Length`.
+//!
+//! The products are computed with the operators of the quantities themselves, so the dimension
+//! of every result is checked at compile time: the dot product of two length vectors is an
+//! area, and adding a length vector to a velocity vector does not compile. The values can be
+//! moved to and from any `GenericVector2` or `GenericVector3`, in the base units of the quantity.
+
+#[cfg(all(test, feature = "glam"))]
+mod tests;
+
+use crate::{math, GenericScalar, GenericVector2, GenericVector3};
+use std::{
+    marker::PhantomData,
+    ops::{Add, Mul, Neg, Sub},
+};
+use uom::{
+    num::Num,
+    si::{Dimension, Quantity, Units},
+    Conversion,
+};
+
+/// A two-dimensional vector of quantities of the same kind, e.g. `QuantityVec2<Length>`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct QuantityVec2<Q> {
+    pub x: Q,
+    pub y: Q,
+}
+
+/// A three-dimensional vector of quantities of the same kind, e.g. `QuantityVec3<Length>`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct QuantityVec3<Q> {
+    pub x: Q,
+    pub y: Q,
+    pub z: Q,
+}
+
+#[inline(always)]
+fn quantity<D, U, S>(value: S) -> Quantity<D, U, S>
+where
+    D: Dimension + ?Sized,
+    U: Units<S> + ?Sized,
+    S: Num + Conversion<S>,
+{
+    Quantity {
+        dimension: PhantomData,
+        units: PhantomData,
+        value,
+    }
+}
+
+impl<Q: Copy> QuantityVec2<Q> {
+    #[inline(always)]
+    pub fn new(x: Q, y: Q) -> Self {
+        Self { x, y }
+    }
+
+    /// Returns the dot product, with the dimension of the product of the quantities.
+    #[inline(always)]
+    pub fn dot<R>(self, rhs: QuantityVec2<R>) -> <Q as Mul<R>>::Output
+    where
+        Q: Mul<R>,
+        <Q as Mul<R>>::Output: Add<Output = <Q as Mul<R>>::Output>,
+    {
+        self.x * rhs.x + self.y * rhs.y
+    }
+
+    /// Returns the perp dot product, with the dimension of the product of the quantities.
+    #[inline(always)]
+    pub fn perp_dot<R>(self, rhs: QuantityVec2<R>) -> <Q as Mul<R>>::Output
+    where
+        Q: Mul<R>,
+        <Q as Mul<R>>::Output: Sub<Output = <Q as Mul<R>>::Output>,
+    {
+        self.x * rhs.y - self.y * rhs.x
+    }
+
+    /// Multiplies every component by `rhs`, e.g. a velocity vector by a time gives a length vector.
+    #[inline(always)]
+    pub fn scale<R: Copy>(self, rhs: R) -> QuantityVec2<<Q as Mul<R>>::Output>
+    where
+        Q: Mul<R>,
+        <Q as Mul<R>>::Output: Copy,
+    {
+        QuantityVec2::new(self.x * rhs, self.y * rhs)
+    }
+}
+
+impl<D, U, S> QuantityVec2<Quantity<D, U, S>>
+where
+    D: Dimension + ?Sized,
+    U: Units<S> + ?Sized,
+    S: GenericScalar + Num + Conversion<S>,
+{
+    /// Creates a vector from the components of `v`, in the base units of the quantity.
+    #[inline(always)]
+    pub fn from_base<V: GenericVector2<Scalar = S>>(v: V) -> Self {
+        Self {
+            x: quantity(v.x()),
+            y: quantity(v.y()),
+        }
+    }
+
+    /// Returns the components in the base units of the quantity.
+    #[inline(always)]
+    pub fn to_base<V: GenericVector2<Scalar = S>>(self) -> V {
+        V::new_2d(self.x.value, self.y.value)
+    }
+
+    #[inline(always)]
+    pub fn magnitude(self) -> Quantity<D, U, S> {
+        quantity(math::hypot(self.x.value, self.y.value))
+    }
+}
+
+impl<Q: Copy + Add<Output = Q>> Add for QuantityVec2<Q> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl<Q: Copy + Sub<Output = Q>> Sub for QuantityVec2<Q> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl<Q: Copy + Neg<Output = Q>> Neg for QuantityVec2<Q> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<Q: Copy> QuantityVec3<Q> {
+    #[inline(always)]
+    pub fn new(x: Q, y: Q, z: Q) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns the dot product, with the dimension of the product of the quantities.
+    #[inline(always)]
+    pub fn dot<R>(self, rhs: QuantityVec3<R>) -> <Q as Mul<R>>::Output
+    where
+        Q: Mul<R>,
+        <Q as Mul<R>>::Output: Add<Output = <Q as Mul<R>>::Output>,
+    {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    /// Returns the cross product, with the dimension of the product of the quantities.
+    #[inline(always)]
+    pub fn cross<R: Copy>(self, rhs: QuantityVec3<R>) -> QuantityVec3<<Q as Mul<R>>::Output>
+    where
+        Q: Mul<R>,
+        <Q as Mul<R>>::Output: Copy + Sub<Output = <Q as Mul<R>>::Output>,
+    {
+        QuantityVec3::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+
+    /// Multiplies every component by `rhs`, e.g. a velocity vector by a time gives a length vector.
+    #[inline(always)]
+    pub fn scale<R: Copy>(self, rhs: R) -> QuantityVec3<<Q as Mul<R>>::Output>
+    where
+        Q: Mul<R>,
+        <Q as Mul<R>>::Output: Copy,
+    {
+        QuantityVec3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<D, U, S> QuantityVec3<Quantity<D, U, S>>
+where
+    D: Dimension + ?Sized,
+    U: Units<S> + ?Sized,
+    S: GenericScalar + Num + Conversion<S>,
+{
+    /// Creates a vector from the components of `v`, in the base units of the quantity.
+    #[inline(always)]
+    pub fn from_base<V: GenericVector3<Scalar = S>>(v: V) -> Self {
+        Self {
+            x: quantity(v.x()),
+            y: quantity(v.y()),
+            z: quantity(v.z()),
+        }
+    }
+
+    /// Returns the components in the base units of the quantity.
+    #[inline(always)]
+    pub fn to_base<V: GenericVector3<Scalar = S>>(self) -> V {
+        V::new_3d(self.x.value, self.y.value, self.z.value)
+    }
+
+    #[inline(always)]
+    pub fn magnitude(self) -> Quantity<D, U, S> {
+        quantity(math::hypot(
+            math::hypot(self.x.value, self.y.value),
+            self.z.value,
+        ))
+    }
+}
+
+impl<Q: Copy + Add<Output = Q>> Add for QuantityVec3<Q> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<Q: Copy + Sub<Output = Q>> Sub for QuantityVec3<Q> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<Q: Copy + Neg<Output = Q>> Neg for QuantityVec3<Q> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y, -self.z)
+    }
+}
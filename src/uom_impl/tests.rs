@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{QuantityVec2, QuantityVec3};
+use uom::si::{
+    area::square_meter,
+    f64::{Area, Length, Time, Velocity},
+    length::{kilometer, meter},
+    time::second,
+    velocity::meter_per_second,
+};
+
+#[test]
+fn test_quantity_vec2() {
+    let m = Length::new::<meter>;
+    let a = QuantityVec2::new(m(3.0), m(4.0));
+    let b = QuantityVec2::new(Length::new::<kilometer>(1.0), m(2.0));
+
+    let area: Area = a.dot(b);
+    assert_eq!(area, Area::new::<square_meter>(3008.0));
+    let area: Area = a.perp_dot(b);
+    assert_eq!(area, Area::new::<square_meter>(6.0 - 4000.0));
+    assert_eq!(a.magnitude(), m(5.0));
+    assert_eq!(a + b - b, a);
+    assert_eq!(-(-a), a);
+    assert_eq!(QuantityVec2::default(), QuantityVec2::new(m(0.0), m(0.0)));
+
+    let v: glam::DVec2 = b.to_base();
+    assert_eq!(v, glam::dvec2(1000.0, 2.0));
+    assert_eq!(QuantityVec2::<Length>::from_base(v), b);
+
+    let velocity = QuantityVec2::new(
+        Velocity::new::<meter_per_second>(2.0),
+        Velocity::new::<meter_per_second>(-1.0),
+    );
+    let moved: QuantityVec2<Length> = velocity.scale(Time::new::<second>(3.0));
+    assert_eq!(moved, QuantityVec2::new(m(6.0), m(-3.0)));
+}
+
+#[test]
+fn test_quantity_vec3() {
+    let m = Length::new::<meter>;
+    let x = QuantityVec3::new(m(1.0), m(0.0), m(0.0));
+    let y = QuantityVec3::new(m(0.0), m(2.0), m(0.0));
+
+    let normal: QuantityVec3<Area> = x.cross(y);
+    assert_eq!(normal.to_base::<glam::DVec3>(), glam::dvec3(0.0, 0.0, 2.0));
+    let volume = normal.dot(QuantityVec3::new(m(0.0), m(0.0), m(3.0)));
+    assert_eq!(volume.value, 6.0);
+    assert_eq!((x + y).magnitude(), m(5.0_f64.sqrt()));
+    assert_eq!(
+        QuantityVec3::<Length>::from_base(glam::dvec3(1.0, 2.0, 3.0)),
+        QuantityVec3::new(m(1.0), m(2.0), m(3.0))
+    );
+}
@@ -0,0 +1,235 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! The `impl_generic_vector2!` and `impl_generic_vector3!` macros, which implement
+//! `GenericVector2` and `GenericVector3` for a new vector type in terms of its accessors.
+//!
+//! The type must implement `HasXY` (and `HasXYZ` for 3D), `PartialEq`, `Index<usize>` and the
+//! by value operators `Add`, `Sub`, `Neg`, and `Mul`/`Div` by the scalar. The macros implement
+//! the rest: `Approx`, `Index<Axis2>`/`Index<Axis3>`, the assignment operators, `Sum` and the
+//! vector trait itself. The 2D and 3D types are passed to both macros, as they name each other in
+//! `to_3d()` and `to_2d()`. Vectors with native operations, like SIMD types, are faster with a
+//! hand written implementation.
+//!
+//! ```ignore
+//! vector_traits::impl_generic_vector2!(MyVec2, MyVec3);
+//! vector_traits::impl_generic_vector3!(MyVec3, MyVec2);
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+// The operators and traits shared by the 2D and 3D implementations. Expanded in a `const _`
+// block where the traits of the crate are in scope.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_generic_vector_common {
+    ($vec_type:ty, $axis_type:ty, $zero:expr) => {
+        impl Approx for $vec_type {}
+
+        impl Index<$axis_type> for $vec_type {
+            type Output = <$vec_type as HasXY>::Scalar;
+
+            #[inline(always)]
+            fn index(&self, axis: $axis_type) -> &Self::Output {
+                &self[axis.index()]
+            }
+        }
+
+        impl AddAssign for $vec_type {
+            #[inline(always)]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $vec_type {
+            #[inline(always)]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+
+        impl MulAssign<<$vec_type as HasXY>::Scalar> for $vec_type {
+            #[inline(always)]
+            fn mul_assign(&mut self, rhs: <$vec_type as HasXY>::Scalar) {
+                *self = *self * rhs;
+            }
+        }
+
+        impl DivAssign<<$vec_type as HasXY>::Scalar> for $vec_type {
+            #[inline(always)]
+            fn div_assign(&mut self, rhs: <$vec_type as HasXY>::Scalar) {
+                *self = *self / rhs;
+            }
+        }
+
+        impl Sum for $vec_type {
+            #[inline]
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold($zero, |a, b| a + b)
+            }
+        }
+
+        impl<'a> Sum<&'a Self> for $vec_type {
+            #[inline]
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold($zero, |a, b| a + *b)
+            }
+        }
+    };
+}
+
+/// Implements `GenericVector2` for `$vec2_type`, with `$vec3_type` as the `Vector3`. See the
+/// `vector_macros` module for the traits the type must implement.
+#[macro_export]
+macro_rules! impl_generic_vector2 {
+    ($vec2_type:ty, $vec3_type:ty) => {
+        const _: () = {
+            use ::std::{
+                iter::Sum,
+                ops::{AddAssign, DivAssign, Index, MulAssign, SubAssign},
+            };
+            use $crate::{
+                math, num_traits::Float, Approx, Axis2, GenericScalar, GenericVector2, HasXY,
+                HasXYZ, IntVec2, Mask2,
+            };
+
+            $crate::__impl_generic_vector_common!($vec2_type, Axis2, {
+                let o = <<$vec2_type as HasXY>::Scalar as GenericScalar>::ZERO;
+                <$vec2_type as HasXY>::new_2d(o, o)
+            });
+
+            impl GenericVector2 for $vec2_type {
+                type Vector3 = $vec3_type;
+                type Mask = Mask2;
+                type IntVector = IntVec2<i32>;
+
+                #[inline(always)]
+                fn to_3d(self, z: Self::Scalar) -> Self::Vector3 {
+                    <$vec3_type as HasXYZ>::new_3d(self.x(), self.y(), z)
+                }
+
+                #[inline(always)]
+                fn magnitude(self) -> Self::Scalar {
+                    math::sqrt(self.magnitude_sq())
+                }
+
+                #[inline(always)]
+                fn magnitude_sq(self) -> Self::Scalar {
+                    math::dot(self, self)
+                }
+
+                #[inline(always)]
+                fn dot(self, other: Self) -> Self::Scalar {
+                    math::dot(self, other)
+                }
+
+                #[inline(always)]
+                fn perp_dot(self, rhs: Self) -> Self::Scalar {
+                    self.x() * rhs.y() - self.y() * rhs.x()
+                }
+
+                #[inline(always)]
+                fn distance(self, rhs: Self) -> Self::Scalar {
+                    math::sqrt(self.distance_sq(rhs))
+                }
+
+                #[inline(always)]
+                fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                    (self - rhs).magnitude_sq()
+                }
+
+                #[inline(always)]
+                fn normalize(self) -> Self {
+                    self / self.magnitude()
+                }
+
+                #[inline(always)]
+                fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                    let l = self.magnitude();
+                    (l > epsilon && Float::is_finite(l)).then(|| self / l)
+                }
+            }
+        };
+    };
+}
+
+/// Implements `GenericVector3` for `$vec3_type`, with `$vec2_type` as the `Vector2`. See the
+/// `vector_macros` module for the traits the type must implement.
+#[macro_export]
+macro_rules! impl_generic_vector3 {
+    ($vec3_type:ty, $vec2_type:ty) => {
+        const _: () = {
+            use ::std::{
+                iter::Sum,
+                ops::{AddAssign, DivAssign, Index, MulAssign, SubAssign},
+            };
+            use $crate::{
+                math, num_traits::Float, Approx, Axis3, GenericScalar, GenericVector3, HasXY,
+                HasXYZ, IntVec3, Mask3,
+            };
+
+            $crate::__impl_generic_vector_common!($vec3_type, Axis3, {
+                let o = <<$vec3_type as HasXY>::Scalar as GenericScalar>::ZERO;
+                <$vec3_type as HasXYZ>::new_3d(o, o, o)
+            });
+
+            impl GenericVector3 for $vec3_type {
+                type Vector2 = $vec2_type;
+                type Mask = Mask3;
+                type IntVector = IntVec3<i32>;
+
+                #[inline(always)]
+                fn to_2d(&self) -> Self::Vector2 {
+                    <$vec2_type as HasXY>::new_2d(self.x(), self.y())
+                }
+
+                #[inline(always)]
+                fn magnitude(self) -> Self::Scalar {
+                    math::sqrt(self.magnitude_sq())
+                }
+
+                #[inline(always)]
+                fn magnitude_sq(self) -> Self::Scalar {
+                    math::dot(self, self)
+                }
+
+                #[inline(always)]
+                fn dot(self, other: Self) -> Self::Scalar {
+                    math::dot(self, other)
+                }
+
+                #[inline(always)]
+                fn cross(self, rhs: Self) -> Self {
+                    let (x, y, z) = (self.x(), self.y(), self.z());
+                    let (rx, ry, rz) = (rhs.x(), rhs.y(), rhs.z());
+                    Self::new_3d(y * rz - z * ry, z * rx - x * rz, x * ry - y * rx)
+                }
+
+                #[inline(always)]
+                fn normalize(self) -> Self {
+                    self / self.magnitude()
+                }
+
+                #[inline(always)]
+                fn safe_normalize_eps(self, epsilon: Self::Scalar) -> Option<Self> {
+                    let l = self.magnitude();
+                    (l > epsilon && Float::is_finite(l)).then(|| self / l)
+                }
+
+                #[inline(always)]
+                fn distance(self, other: Self) -> Self::Scalar {
+                    math::sqrt(self.distance_sq(other))
+                }
+
+                #[inline(always)]
+                fn distance_sq(self, rhs: Self) -> Self::Scalar {
+                    (self - rhs).magnitude_sq()
+                }
+            }
+        };
+    };
+}
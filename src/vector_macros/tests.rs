@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use crate::{GenericScalar, GenericVector2, GenericVector3, HasXY, HasXYZ};
+use std::ops::{Add, Div, Index, Mul, Neg, Sub};
+
+// a backend with nothing but the accessors and the by value operators
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point2 {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Point3 {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl HasXY for Point2 {
+    type Scalar = f64;
+    const DIM: usize = 2;
+    fn new_2d(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+    fn x(self) -> f64 {
+        self.x
+    }
+    fn set_x(&mut self, val: f64) {
+        self.x = val
+    }
+    fn x_mut(&mut self) -> &mut f64 {
+        &mut self.x
+    }
+    fn y(self) -> f64 {
+        self.y
+    }
+    fn set_y(&mut self, val: f64) {
+        self.y = val
+    }
+    fn y_mut(&mut self) -> &mut f64 {
+        &mut self.y
+    }
+    fn get(self, index: usize) -> Option<f64> {
+        (index < Self::DIM).then(|| self[index])
+    }
+    fn canonicalize(self) -> Self {
+        Self {
+            x: self.x.canonicalize(),
+            y: self.y.canonicalize(),
+        }
+    }
+    fn flush_denormals(self) -> Self {
+        Self {
+            x: self.x.flush_denormal(),
+            y: self.y.flush_denormal(),
+        }
+    }
+}
+
+impl HasXY for Point3 {
+    type Scalar = f64;
+    const DIM: usize = 3;
+    fn new_2d(x: f64, y: f64) -> Self {
+        Self { x, y, z: 0.0 }
+    }
+    fn x(self) -> f64 {
+        self.x
+    }
+    fn set_x(&mut self, val: f64) {
+        self.x = val
+    }
+    fn x_mut(&mut self) -> &mut f64 {
+        &mut self.x
+    }
+    fn y(self) -> f64 {
+        self.y
+    }
+    fn set_y(&mut self, val: f64) {
+        self.y = val
+    }
+    fn y_mut(&mut self) -> &mut f64 {
+        &mut self.y
+    }
+    fn get(self, index: usize) -> Option<f64> {
+        (index < Self::DIM).then(|| self[index])
+    }
+    fn canonicalize(self) -> Self {
+        Self {
+            x: self.x.canonicalize(),
+            y: self.y.canonicalize(),
+            z: self.z.canonicalize(),
+        }
+    }
+    fn flush_denormals(self) -> Self {
+        Self {
+            x: self.x.flush_denormal(),
+            y: self.y.flush_denormal(),
+            z: self.z.flush_denormal(),
+        }
+    }
+}
+
+impl HasXYZ for Point3 {
+    fn new_3d(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+    fn z(self) -> f64 {
+        self.z
+    }
+    fn set_z(&mut self, val: f64) {
+        self.z = val
+    }
+    fn z_mut(&mut self) -> &mut f64 {
+        &mut self.z
+    }
+}
+
+macro_rules! impl_point_ops {
+    ($point:ident, $($field:ident = $index:literal),+) => {
+        impl Index<usize> for $point {
+            type Output = f64;
+            fn index(&self, index: usize) -> &f64 {
+                match index {
+                    $($index => &self.$field,)+
+                    _ => panic!("index out of bounds"),
+                }
+            }
+        }
+
+        impl Add for $point {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<f64> for $point {
+            type Output = Self;
+            fn mul(self, rhs: f64) -> Self {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl Div<f64> for $point {
+            type Output = Self;
+            fn div(self, rhs: f64) -> Self {
+                Self { $($field: self.$field / rhs),+ }
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2, x = 0, y = 1);
+impl_point_ops!(Point3, x = 0, y = 1, z = 2);
+
+crate::impl_generic_vector2!(Point2, Point3);
+crate::impl_generic_vector3!(Point3, Point2);
+
+#[test]
+fn test_impl_generic_vector() {
+    crate::tests::tests::test_gxy::<Point2>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_generic_xy::<Point2>(1.0, 2.0, 3.0, 0.0000000000001);
+    crate::tests::tests::test_gxyz::<Point3>(1.0, 2.0, 3.0);
+    crate::tests::tests::test_generic_xyz::<Point3>(1.0, 2.0, 3.0, 4.0, 0.0000000000001);
+    crate::tests::tests::test_mask_2d::<Point2>();
+    crate::tests::tests::test_mask_3d::<Point3>();
+    crate::tests::tests::test_sum::<Point3>();
+
+    let a = Point3::new_3d(1.0, 0.0, 0.0);
+    let b = Point3::new_3d(0.0, 1.0, 0.0);
+    assert_eq!(a.cross(b), Point3::new_3d(0.0, 0.0, 1.0));
+    assert_eq!(a.to_2d().perp_dot(b.to_2d()), 1.0);
+    assert_eq!(Point2::new_2d(3.0, 4.0).magnitude(), 5.0);
+    assert_eq!(Point2::new_2d(0.0, 0.0).safe_normalize(), None);
+}
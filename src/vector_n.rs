@@ -0,0 +1,216 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `GenericVectorN<N>`, vectors of any fixed dimension, for optimization and statistics code
+//! that works in more than three dimensions with the same choice of `f32` or `f64`.
+//!
+//! The trait converts to and from `[S; N]` and provides the rest as default methods over the
+//! arrays. It is implemented for the `VecN` newtype of `[S; N]`, and through blanket impls for
+//! every `GenericVector2` as `GenericVectorN<2>` and every `GenericVector3` as
+//! `GenericVectorN<3>`. `dot()`, `distance()` and `lerp()` share their names with the methods of
+//! the 2D and 3D traits, so `GenericVectorN` is not in the `prelude`; call them through the trait
+//! where both are in scope.
+
+#[cfg(test)]
+mod tests;
+
+use crate::{math, GenericScalar, GenericVector2, GenericVector3};
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Index, IndexMut, Mul, Neg, Sub},
+};
+
+/// A vector of `N` components.
+pub trait GenericVectorN<const N: usize>: Copy + Debug + PartialEq + Sync + Send {
+    type Scalar: GenericScalar;
+
+    fn from_array(components: [Self::Scalar; N]) -> Self;
+
+    fn to_array(self) -> [Self::Scalar; N];
+
+    /// Returns the vector with every component set to `value`.
+    #[inline(always)]
+    fn splat(value: Self::Scalar) -> Self {
+        Self::from_array([value; N])
+    }
+
+    /// Returns component `index`.
+    ///
+    /// # Panics
+    /// If `index >= N`.
+    #[inline(always)]
+    fn component(self, index: usize) -> Self::Scalar {
+        self.to_array()[index]
+    }
+
+    /// Returns the dot product, summed left to right like `math::dot()`.
+    #[inline(always)]
+    fn dot(self, other: Self) -> Self::Scalar {
+        let (a, b) = (self.to_array(), other.to_array());
+        (0..N).fold(Self::Scalar::ZERO, |sum, i| sum + a[i] * b[i])
+    }
+
+    #[inline(always)]
+    fn norm_sq(self) -> Self::Scalar {
+        self.dot(self)
+    }
+
+    /// Returns the euclidean length of the vector.
+    #[inline(always)]
+    fn norm(self) -> Self::Scalar {
+        math::sqrt(self.norm_sq())
+    }
+
+    #[inline(always)]
+    fn distance(self, other: Self) -> Self::Scalar {
+        let (a, b) = (self.to_array(), other.to_array());
+        math::sqrt((0..N).fold(Self::Scalar::ZERO, |sum, i| {
+            let d = a[i] - b[i];
+            sum + d * d
+        }))
+    }
+
+    /// Interpolates from `self` at `t == 0` to `other` at `t == 1`.
+    #[inline(always)]
+    fn lerp(self, other: Self, t: Self::Scalar) -> Self {
+        let (a, b) = (self.to_array(), other.to_array());
+        Self::from_array(std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t))
+    }
+}
+
+/// An `N` dimensional vector stored as `[S; N]`, implementing `GenericVectorN`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VecN<S: GenericScalar, const N: usize>(pub [S; N]);
+
+impl<S: GenericScalar, const N: usize> VecN<S, N> {
+    #[inline(always)]
+    pub const fn new(components: [S; N]) -> Self {
+        Self(components)
+    }
+}
+
+impl<S: GenericScalar, const N: usize> Default for VecN<S, N> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self([S::ZERO; N])
+    }
+}
+
+impl<S: GenericScalar, const N: usize> GenericVectorN<N> for VecN<S, N> {
+    type Scalar = S;
+
+    #[inline(always)]
+    fn from_array(components: [S; N]) -> Self {
+        Self(components)
+    }
+
+    #[inline(always)]
+    fn to_array(self) -> [S; N] {
+        self.0
+    }
+}
+
+impl<V: GenericVector2> GenericVectorN<2> for V {
+    type Scalar = V::Scalar;
+
+    #[inline(always)]
+    fn from_array(components: [Self::Scalar; 2]) -> Self {
+        V::new_2d(components[0], components[1])
+    }
+
+    #[inline(always)]
+    fn to_array(self) -> [Self::Scalar; 2] {
+        [self.x(), self.y()]
+    }
+}
+
+impl<V: GenericVector3> GenericVectorN<3> for V {
+    type Scalar = V::Scalar;
+
+    #[inline(always)]
+    fn from_array(components: [Self::Scalar; 3]) -> Self {
+        V::new_3d(components[0], components[1], components[2])
+    }
+
+    #[inline(always)]
+    fn to_array(self) -> [Self::Scalar; 3] {
+        [self.x(), self.y(), self.z()]
+    }
+}
+
+impl<S: GenericScalar, const N: usize> From<[S; N]> for VecN<S, N> {
+    #[inline(always)]
+    fn from(components: [S; N]) -> Self {
+        Self(components)
+    }
+}
+
+impl<S: GenericScalar, const N: usize> From<VecN<S, N>> for [S; N] {
+    #[inline(always)]
+    fn from(v: VecN<S, N>) -> Self {
+        v.0
+    }
+}
+
+impl<S: GenericScalar, const N: usize> Index<usize> for VecN<S, N> {
+    type Output = S;
+
+    #[inline(always)]
+    fn index(&self, index: usize) -> &S {
+        &self.0[index]
+    }
+}
+
+impl<S: GenericScalar, const N: usize> IndexMut<usize> for VecN<S, N> {
+    #[inline(always)]
+    fn index_mut(&mut self, index: usize) -> &mut S {
+        &mut self.0[index]
+    }
+}
+
+impl<S: GenericScalar, const N: usize> Add for VecN<S, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] + rhs.0[i]))
+    }
+}
+
+impl<S: GenericScalar, const N: usize> Sub for VecN<S, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn sub(self, rhs: Self) -> Self {
+        Self(std::array::from_fn(|i| self.0[i] - rhs.0[i]))
+    }
+}
+
+impl<S: GenericScalar, const N: usize> Mul<S> for VecN<S, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: S) -> Self {
+        Self(self.0.map(|v| v * rhs))
+    }
+}
+
+impl<S: GenericScalar, const N: usize> Div<S> for VecN<S, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn div(self, rhs: S) -> Self {
+        Self(self.0.map(|v| v / rhs))
+    }
+}
+
+impl<S: GenericScalar, const N: usize> Neg for VecN<S, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        Self(self.0.map(|v| -v))
+    }
+}
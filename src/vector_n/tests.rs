@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use crate::{ArrayVec2, ArrayVec3, GenericScalar, GenericVectorN, HasXY, HasXYZ, VecN};
+
+// written once for any dimension
+fn centroid<V: GenericVectorN<N>, const N: usize>(points: &[V]) -> V {
+    let n = V::Scalar::from(points.len() as u16);
+    let sum = points.iter().fold([V::Scalar::ZERO; N], |mut sum, p| {
+        let p = p.to_array();
+        (0..N).for_each(|i| sum[i] += p[i] / n);
+        sum
+    });
+    V::from_array(sum)
+}
+
+#[test]
+fn test_vector_n() {
+    let a = VecN::new([1.0f64, 2.0, 3.0, 4.0, 5.0]);
+    let b = VecN::splat(1.0);
+    assert_eq!(a.component(4), 5.0);
+    assert_eq!(a.dot(b), 15.0);
+    assert_eq!(VecN::new([3.0f32, 4.0, 0.0, 0.0]).norm(), 5.0);
+    assert_eq!(a.norm_sq(), 55.0);
+    assert_eq!(a.distance(a), 0.0);
+    assert_eq!(VecN::<f64, 4>::splat(2.0).distance(VecN::splat(1.0)), 2.0);
+    assert_eq!(a.lerp(b, 0.5), VecN::new([1.0, 1.5, 2.0, 2.5, 3.0]));
+    assert_eq!(a - b + b, a);
+    assert_eq!(-a * 2.0 / 2.0, VecN::new([-1.0, -2.0, -3.0, -4.0, -5.0]));
+    let mut c = VecN::<f32, 6>::default();
+    c[5] = 1.0;
+    assert_eq!(<[f32; 6]>::from(c), [0.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(
+        centroid(&[a, VecN::splat(0.0)]),
+        VecN::new([0.5, 1.0, 1.5, 2.0, 2.5])
+    );
+}
+
+#[test]
+fn test_vector_n_bridges() {
+    let a = ArrayVec3::<f32>::new_3d(1.0, 2.0, 3.0);
+    assert_eq!(GenericVectorN::to_array(a), [1.0, 2.0, 3.0]);
+    assert_eq!(<ArrayVec3<f32> as GenericVectorN<3>>::norm_sq(a), 14.0);
+    assert_eq!(
+        centroid(&[a, ArrayVec3::new_3d(3.0, 2.0, 1.0)]),
+        ArrayVec3::new_3d(2.0, 2.0, 2.0)
+    );
+    let b = ArrayVec2::<f64>::new_2d(1.0, 1.0);
+    assert_eq!(
+        GenericVectorN::lerp(b, ArrayVec2::new_2d(3.0, 5.0), 0.5),
+        ArrayVec2::new_2d(2.0, 3.0)
+    );
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `ScalarBatch` for the SIMD types of `wide`: `f32x4` and `f32x8` for `f32`, `f64x2` and `f64x4`
+//! for `f64`. `wide` selects the instructions of the target at compile time, with a scalar
+//! fallback, so the batches work on every platform.
+
+#[cfg(test)]
+mod tests;
+
+use crate::ScalarBatch;
+pub use ::wide::{f32x4, f32x8, f64x2, f64x4};
+
+macro_rules! impl_scalar_batch {
+    ($batch_type:ty, $scalar_type:ty, $lanes:expr) => {
+        impl ScalarBatch for $batch_type {
+            type Scalar = $scalar_type;
+            const LANES: usize = $lanes;
+            #[inline(always)]
+            fn splat(value: Self::Scalar) -> Self {
+                <$batch_type>::splat(value)
+            }
+            #[inline(always)]
+            fn from_fn<F: FnMut(usize) -> Self::Scalar>(f: F) -> Self {
+                <$batch_type>::from(std::array::from_fn::<_, $lanes, _>(f))
+            }
+            #[inline(always)]
+            fn lane(self, i: usize) -> Self::Scalar {
+                self.to_array()[i]
+            }
+            #[inline(always)]
+            fn set_lane(&mut self, i: usize, value: Self::Scalar) {
+                let mut lanes = self.to_array();
+                lanes[i] = value;
+                *self = <$batch_type>::from(lanes);
+            }
+            #[inline(always)]
+            fn write_to(self, out: &mut [Self::Scalar]) {
+                for (o, v) in out.iter_mut().zip(self.to_array()) {
+                    *o = v;
+                }
+            }
+            #[inline(always)]
+            fn sqrt(self) -> Self {
+                <$batch_type>::sqrt(self)
+            }
+        }
+    };
+}
+
+impl_scalar_batch!(f32x4, f32, 4);
+impl_scalar_batch!(f32x8, f32, 8);
+impl_scalar_batch!(f64x2, f64, 2);
+impl_scalar_batch!(f64x4, f64, 4);
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+use super::{f32x4, f32x8, f64x2, f64x4};
+use crate::{
+    batch_distances_2d, batch_distances_3d, batch_dots_2d, batch_dots_3d, math, ArrayVec2,
+    ArrayVec3, GenericScalar, GenericVector2, GenericVector2xN, GenericVector3, GenericVector3xN,
+    ScalarBatch, Vec2xN, Vec3xN,
+};
+use num_traits::float::FloatCore;
+
+fn test_batch<B: ScalarBatch>() {
+    let s = |v: f32| -> B::Scalar { v.into() };
+    let p = |x: f32, y: f32, z: f32| ArrayVec3([s(x), s(y), s(z)]);
+    // more points than lanes, and a partial last batch for every lane count
+    let points: Vec<_> = (0..11)
+        .map(|i| {
+            let f = i as f32;
+            p(f - 5.0, f * 0.5, 3.0 - f * f * 0.25)
+        })
+        .collect();
+    let axis = p(0.0, 0.6, 0.8);
+
+    let dots = batch_dots_3d::<B, _>(&points, &[axis; 9]);
+    assert_eq!(dots.len(), 9);
+    for (d, v) in dots.iter().zip(&points) {
+        assert_eq!(*d, math::dot(*v, axis));
+    }
+    let distances = batch_distances_3d::<B, _>(&points, axis);
+    assert_eq!(distances.len(), points.len());
+    for (d, v) in distances.iter().zip(&points) {
+        assert_eq!(*d, math::sqrt(math::dot(*v - axis, *v - axis)));
+    }
+
+    let points2: Vec<ArrayVec2<B::Scalar>> = points.iter().map(|v| v.to_2d()).collect();
+    let dots2 = batch_dots_2d::<B, _>(&points2, &points2);
+    assert_eq!(dots2[3], points2[3].magnitude_sq());
+    let distances2 = batch_distances_2d::<B, _>(&points2, points2[0]);
+    assert_eq!(distances2[0], B::Scalar::ZERO);
+    assert_eq!(distances2[2], points2[2].distance(points2[0]));
+
+    let batch = Vec3xN::<B>::load(&points);
+    let crosses = batch.cross(&Vec3xN::splat(axis));
+    let mut out = vec![p(0.0, 0.0, 0.0); B::LANES];
+    (-(-crosses)).store(&mut out);
+    for (i, c) in out.iter().enumerate() {
+        assert_eq!(*c, points[i].cross(axis));
+        assert_eq!(crosses.lane::<ArrayVec3<B::Scalar>>(i), *c);
+    }
+    assert_eq!((batch * B::splat(s(2.0))).x.lane(1), s(-8.0));
+    assert_eq!(
+        (batch * B::splat(s(2.0)) / B::splat(s(2.0))).lane::<ArrayVec3<B::Scalar>>(1),
+        points[1]
+    );
+    assert_eq!(
+        (batch + batch - batch).lane::<ArrayVec3<B::Scalar>>(1),
+        points[1]
+    );
+    assert_eq!(Vec3xN::<B>::load(&points[..1]).magnitude().lane(1), s(0.0));
+
+    let mut batch = Vec3xN::<B>::splat(p(3.0, 0.0, 4.0));
+    batch.set_lane(1, p(0.0, -2.0, 0.0));
+    let normalized = batch.normalize();
+    assert_eq!(
+        normalized.lane::<ArrayVec3<B::Scalar>>(0),
+        p(3.0, 0.0, 4.0) / s(5.0)
+    );
+    assert_eq!(
+        normalized.lane::<ArrayVec3<B::Scalar>>(1),
+        p(0.0, -1.0, 0.0)
+    );
+    let mut batch2 = Vec2xN::<B>::load(&points2);
+    batch2.set_lane(0, ArrayVec2([s(0.0), s(0.5)]));
+    assert_eq!(batch2.normalize().y.lane(0), s(1.0));
+    assert_eq!(batch2.lane::<ArrayVec2<B::Scalar>>(1), points2[1]);
+    assert!(FloatCore::is_nan(
+        Vec2xN::<B>::default().normalize().x.lane(0)
+    ));
+}
+
+#[test]
+fn test_wide() {
+    test_batch::<f32x4>();
+    test_batch::<f32x8>();
+    test_batch::<f64x2>();
+    test_batch::<f64x4>();
+    assert_eq!(<f32x8 as ScalarBatch>::LANES, 8);
+    assert_eq!(
+        ScalarBatch::sqrt(f64x2::from([4.0, 9.0])).to_array(),
+        [2.0, 3.0]
+    );
+}
+
+#[test]
+fn test_f32x8_partial_batch() {
+    // one full batch of 8 and a last batch with 5 of the 8 lanes in use, against scalar results
+    let points: Vec<ArrayVec3<f32>> = (0..13)
+        .map(|i| {
+            let f = i as f32;
+            ArrayVec3([f * 0.7 - 4.0, 1.5 - f * 0.3, f * f * 0.1 + 0.25])
+        })
+        .collect();
+    let to = ArrayVec3([0.5, -1.0, 2.0]);
+    let rhs: Vec<_> = points.iter().map(|v| v.cross(to)).collect();
+
+    let dots = batch_dots_3d::<f32x8, _>(&points, &rhs);
+    let distances = batch_distances_3d::<f32x8, _>(&points, to);
+    assert_eq!(dots.len(), 13);
+    assert_eq!(distances.len(), 13);
+    for i in 0..13 {
+        assert_eq!(dots[i], points[i].dot(rhs[i]));
+        assert_eq!(distances[i], points[i].distance(to));
+    }
+
+    let mut normalized = [ArrayVec3([0.0; 3]); 13];
+    for (k, (batch, lanes)) in crate::batches_3d::<f32x8, _>(&points).enumerate() {
+        assert_eq!(lanes, if k == 0 { 8 } else { 5 });
+        batch.normalize().store(&mut normalized[k * 8..]);
+    }
+    for (n, v) in normalized.iter().zip(&points) {
+        assert_eq!(*n, v.normalize());
+    }
+}
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+#![cfg(feature = "derive")]
+
+use std::ops::{Add, Div, Index, Mul, Neg, Sub};
+use vector_traits::{GenericVector2, GenericVector3, HasXY, HasXYZ};
+
+// an FFI vertex, with the coordinates marked and a padding field
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, HasXY, HasXYZ)]
+struct Vertex {
+    flags: u32,
+    #[vector(x)]
+    px: f32,
+    #[vector(y)]
+    py: f32,
+    #[vector(z)]
+    pz: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, HasXY)]
+struct Generic2<S: vector_traits::GenericScalar> {
+    x: S,
+    y: S,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, HasXY)]
+#[vector(generic = Point3)]
+struct Point2 {
+    x: f64,
+    y: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, HasXY, HasXYZ)]
+#[vector(generic = Point2)]
+struct Point3 {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+macro_rules! impl_point_ops {
+    ($point:ident, $($field:ident = $index:literal),+) => {
+        impl Index<usize> for $point {
+            type Output = f64;
+            fn index(&self, index: usize) -> &f64 {
+                match index {
+                    $($index => &self.$field,)+
+                    _ => panic!("index out of bounds"),
+                }
+            }
+        }
+
+        impl Add for $point {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<f64> for $point {
+            type Output = Self;
+            fn mul(self, rhs: f64) -> Self {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl Div<f64> for $point {
+            type Output = Self;
+            fn div(self, rhs: f64) -> Self {
+                Self { $($field: self.$field / rhs),+ }
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2, x = 0, y = 1);
+impl_point_ops!(Point3, x = 0, y = 1, z = 2);
+
+#[test]
+fn test_derive_marked_fields() {
+    assert_eq!(Vertex::DIM, 3);
+    let mut v = Vertex::new_3d(1.0, 2.0, 3.0);
+    assert_eq!(v.flags, 0);
+    assert_eq!((v.x(), v.y(), v.z()), (1.0, 2.0, 3.0));
+    assert_eq!(Vertex::new_2d(1.0, 2.0).z(), 0.0);
+    v.flags = 7;
+    v.set_y(5.0);
+    *v.z_mut() = -0.0;
+    assert_eq!((v.py, v.flags), (5.0, 7));
+    assert_eq!(v.get(2), Some(-0.0));
+    assert_eq!(v.get(3), None);
+    let c = v.canonicalize();
+    assert_eq!(c.pz.to_bits(), 0.0f32.to_bits());
+    assert_eq!(c.flags, 7);
+}
+
+#[test]
+fn test_derive_generic_struct() {
+    let v = Generic2::<f32>::new_2d(1.0, 2.0);
+    assert_eq!(Generic2::<f32>::DIM, 2);
+    assert_eq!((v.x(), v.y()), (1.0, 2.0));
+    assert_eq!(v.get(2), None);
+}
+
+#[test]
+fn test_derive_generic_vector() {
+    let a = Point3::new_3d(1.0, 0.0, 0.0);
+    let b = Point3::new_3d(0.0, 1.0, 0.0);
+    assert_eq!(a.cross(b), Point3::new_3d(0.0, 0.0, 1.0));
+    assert_eq!(a.to_2d().perp_dot(b.to_2d()), 1.0);
+    assert_eq!(Point2::new_2d(3.0, 4.0).magnitude(), 5.0);
+    assert_eq!(Point2::new_2d(3.0, 4.0).to_3d(1.0).z(), 1.0);
+}
@@ -0,0 +1,294 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+// Copyright (c) 2023 lacklustr@protonmail.com https://github.com/eadf
+
+// This file is part of vector-traits.
+
+//! `#[derive(HasXY)]` and `#[derive(HasXYZ)]` for structs with named fields, e.g. the `#[repr(C)]`
+//! structs of an FFI boundary. Use them through the `derive` feature of `vector-traits`, which
+//! re-exports them next to the traits.
+//!
+//! The coordinates are the fields named `x`, `y` and `z`, or the fields marked with
+//! `#[vector(x)]`, `#[vector(y)]` and `#[vector(z)]`. The type of the x field is the scalar type.
+//! `new_2d()` and `new_3d()` set any other field, like padding, to its `Default`. A struct with a z
+//! field is a 3D type and must derive both traits.
+//!
+//! The struct attribute `#[vector(generic = Other)]` also implements `GenericVector2` (on a 2D
+//! type, with `Other` as the 3D type) or `GenericVector3` (on a 3D type, with `Other` as the 2D
+//! type) with `impl_generic_vector2!`/`impl_generic_vector3!`, which need the operators
+//! listed in the `vector_macros` module of `vector-traits`.
+//!
+//! ```ignore
+//! use vector_traits::{HasXY, HasXYZ};
+//!
+//! #[repr(C)]
+//! #[derive(Debug, Clone, Copy, HasXY, HasXYZ)]
+//! struct Vertex {
+//!     #[vector(x)]
+//!     px: f32,
+//!     #[vector(y)]
+//!     py: f32,
+//!     #[vector(z)]
+//!     pz: f32,
+//!     padding: u32,
+//! }
+//! ```
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, Type};
+
+#[proc_macro_derive(HasXY, attributes(vector))]
+pub fn derive_has_xy(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    Layout::parse(&input)
+        .and_then(|layout| layout.has_xy(&input))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(HasXYZ, attributes(vector))]
+pub fn derive_has_xyz(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    Layout::parse(&input)
+        .and_then(|layout| layout.has_xyz(&input))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The coordinate fields of a struct.
+struct Layout {
+    x: Ident,
+    y: Ident,
+    z: Option<Ident>,
+    // every other field, set to its `Default` by the constructors
+    others: Vec<Ident>,
+    scalar: Type,
+    // the other type of `#[vector(generic = ...)]`
+    generic: Option<Type>,
+}
+
+impl Layout {
+    fn parse(input: &DeriveInput) -> syn::Result<Self> {
+        let fields = match &input.data {
+            Data::Struct(data) => match &data.fields {
+                Fields::Named(fields) => &fields.named,
+                _ => {
+                    return Err(Error::new_spanned(
+                        &input.ident,
+                        "the vector derives need a struct with named fields",
+                    ))
+                }
+            },
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "the vector derives need a struct with named fields",
+                ))
+            }
+        };
+
+        let mut generic = None;
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("vector")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("generic") {
+                    generic = Some(meta.value()?.parse::<Type>()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("expected `generic = Type`"))
+                }
+            })?;
+        }
+        if generic.is_some() && !input.generics.params.is_empty() {
+            return Err(Error::new_spanned(
+                &input.generics,
+                "`#[vector(generic = ...)]` needs a struct without generic parameters",
+            ));
+        }
+
+        // the explicitly marked fields, by axis
+        let mut marked: [Option<&syn::Field>; 3] = [None, None, None];
+        for field in fields {
+            for attr in field.attrs.iter().filter(|a| a.path().is_ident("vector")) {
+                attr.parse_nested_meta(|meta| {
+                    let axis = ["x", "y", "z"]
+                        .iter()
+                        .position(|name| meta.path.is_ident(name))
+                        .ok_or_else(|| meta.error("expected `x`, `y` or `z`"))?;
+                    if marked[axis].is_some() {
+                        return Err(meta.error("the axis is already marked on another field"));
+                    }
+                    marked[axis] = Some(field);
+                    Ok(())
+                })?;
+            }
+        }
+        // fall back to the fields named after the unmarked axes
+        let axes: Vec<Option<&syn::Field>> = ["x", "y", "z"]
+            .iter()
+            .zip(marked)
+            .map(|(name, field)| {
+                field.or_else(|| {
+                    fields.iter().find(|f| {
+                        f.ident.as_ref().is_some_and(|i| i == name)
+                            && !marked.iter().flatten().any(|m| std::ptr::eq(*m, *f))
+                    })
+                })
+            })
+            .collect();
+
+        let (Some(x), Some(y)) = (axes[0], axes[1]) else {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "expected the fields `x` and `y`, or fields marked `#[vector(x)]` and `#[vector(y)]`",
+            ));
+        };
+        let ident = |f: &syn::Field| f.ident.clone().unwrap();
+        let others = fields
+            .iter()
+            .filter(|f| !axes.iter().flatten().any(|a| std::ptr::eq(*a, *f)))
+            .map(ident)
+            .collect();
+        Ok(Self {
+            x: ident(x),
+            y: ident(y),
+            z: axes[2].map(ident),
+            others,
+            scalar: x.ty.clone(),
+            generic,
+        })
+    }
+
+    fn has_xy(&self, input: &DeriveInput) -> syn::Result<TokenStream2> {
+        let name = &input.ident;
+        let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+        let Self {
+            x, y, z, scalar, ..
+        } = self;
+        let dim = if z.is_some() { 3usize } else { 2usize };
+        let others = &self.others;
+        let zero_z = z.iter();
+        let get_z = z.iter();
+        let canonicalize_z = z.iter();
+        let flush_z = z.iter();
+        // a 3D type gets its `GenericVector3` from the `HasXYZ` derive
+        let generic = match (&self.generic, z) {
+            (Some(other), None) => {
+                quote! { ::vector_traits::impl_generic_vector2!(#name, #other); }
+            }
+            _ => quote! {},
+        };
+
+        Ok(quote! {
+            impl #impl_generics ::vector_traits::HasXY for #name #ty_generics #where_clause {
+                type Scalar = #scalar;
+                const DIM: usize = #dim;
+                #[inline(always)]
+                fn new_2d(x: Self::Scalar, y: Self::Scalar) -> Self {
+                    Self {
+                        #x: x,
+                        #y: y,
+                        #(#zero_z: <Self::Scalar as ::vector_traits::GenericScalar>::ZERO,)*
+                        #(#others: ::core::default::Default::default(),)*
+                    }
+                }
+                #[inline(always)]
+                fn x(self) -> Self::Scalar {
+                    self.#x
+                }
+                #[inline(always)]
+                fn set_x(&mut self, val: Self::Scalar) {
+                    self.#x = val
+                }
+                #[inline(always)]
+                fn x_mut(&mut self) -> &mut Self::Scalar {
+                    &mut self.#x
+                }
+                #[inline(always)]
+                fn y(self) -> Self::Scalar {
+                    self.#y
+                }
+                #[inline(always)]
+                fn set_y(&mut self, val: Self::Scalar) {
+                    self.#y = val
+                }
+                #[inline(always)]
+                fn y_mut(&mut self) -> &mut Self::Scalar {
+                    &mut self.#y
+                }
+                #[inline(always)]
+                fn get(self, index: usize) -> Option<Self::Scalar> {
+                    match index {
+                        0 => Some(self.#x),
+                        1 => Some(self.#y),
+                        #(2 => Some(self.#get_z),)*
+                        _ => None,
+                    }
+                }
+                #[inline(always)]
+                fn canonicalize(self) -> Self {
+                    use ::vector_traits::GenericScalar;
+                    let mut v = self;
+                    v.#x = v.#x.canonicalize();
+                    v.#y = v.#y.canonicalize();
+                    #(v.#canonicalize_z = v.#canonicalize_z.canonicalize();)*
+                    v
+                }
+                #[inline(always)]
+                fn flush_denormals(self) -> Self {
+                    use ::vector_traits::GenericScalar;
+                    let mut v = self;
+                    v.#x = v.#x.flush_denormal();
+                    v.#y = v.#y.flush_denormal();
+                    #(v.#flush_z = v.#flush_z.flush_denormal();)*
+                    v
+                }
+            }
+            #generic
+        })
+    }
+
+    fn has_xyz(&self, input: &DeriveInput) -> syn::Result<TokenStream2> {
+        let name = &input.ident;
+        let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+        let Some(z) = &self.z else {
+            return Err(Error::new_spanned(
+                name,
+                "expected the field `z`, or a field marked `#[vector(z)]`",
+            ));
+        };
+        let Self { x, y, .. } = self;
+        let others = &self.others;
+        let generic = match &self.generic {
+            Some(other) => quote! { ::vector_traits::impl_generic_vector3!(#name, #other); },
+            None => quote! {},
+        };
+
+        Ok(quote! {
+            impl #impl_generics ::vector_traits::HasXYZ for #name #ty_generics #where_clause {
+                #[inline(always)]
+                fn new_3d(x: Self::Scalar, y: Self::Scalar, z: Self::Scalar) -> Self {
+                    Self {
+                        #x: x,
+                        #y: y,
+                        #z: z,
+                        #(#others: ::core::default::Default::default(),)*
+                    }
+                }
+                #[inline(always)]
+                fn z(self) -> Self::Scalar {
+                    self.#z
+                }
+                #[inline(always)]
+                fn set_z(&mut self, val: Self::Scalar) {
+                    self.#z = val
+                }
+                #[inline(always)]
+                fn z_mut(&mut self) -> &mut Self::Scalar {
+                    &mut self.#z
+                }
+            }
+            #generic
+        })
+    }
+}